@@ -0,0 +1 @@
+vec![0x7F,0x45,0x4C,0x46,0x2,0x1,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x3E,0x0,0x1,0x0,0x0,0x0,0x18,0x1,0x40,0x0,0x0,0x0,0x0,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x50,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x0,0x38,0x0,0x3,0x0,0x40,0x0,0x4,0x0,0x3,0x0,0x1,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0xE8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE8,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0xE8,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x3D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0xE8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE8,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0xE8,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x30,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x30,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x53,0xE5,0x74,0x64,0x4,0x0,0x0,0x0,0xE8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE8,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0xE8,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x30,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x30,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x47,0x4E,0x55,0x0,0x2,0x0,0x0,0xC0,0x4,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x80,0x0,0xC0,0x4,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF3,0xF,0x1E,0xFA,0xB8,0x3C,0x0,0x0,0x0,0x31,0xFF,0xF,0x5,0x0,0x2E,0x73,0x68,0x73,0x74,0x72,0x74,0x61,0x62,0x0,0x2E,0x6E,0x6F,0x74,0x65,0x2E,0x67,0x6E,0x75,0x2E,0x70,0x72,0x6F,0x70,0x65,0x72,0x74,0x79,0x0,0x2E,0x74,0x65,0x78,0x74,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE8,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0xE8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x30,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1E,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x1,0x40,0x0,0x0,0x0,0x0,0x0,0x18,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x25,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x24,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0]
\ No newline at end of file
@@ -0,0 +1 @@
+vec![0x7F,0x45,0x4C,0x46,0x2,0x1,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x3E,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD8,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x0,0x38,0x0,0x6,0x0,0x40,0x0,0xF,0x0,0xE,0x0,0x1,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x6C,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x6C,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x14,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x14,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0xF0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x50,0xE5,0x74,0x64,0x4,0x0,0x0,0x0,0xC8,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xC8,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xC8,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x24,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x24,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x51,0xE5,0x74,0x64,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x52,0xE5,0x74,0x64,0x4,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0xA0,0x0,0x10,0x1,0x0,0x8,0x8,0x2,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x33,0xD8,0xFE,0x6D,0x4F,0xA3,0x2C,0x1E,0xDD,0xFE,0xF9,0x6E,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2A,0x0,0x0,0x0,0x12,0x0,0x6,0x0,0xBE,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x12,0x0,0x6,0x0,0xB0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xE,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1D,0x0,0x0,0x0,0x11,0x0,0xB,0x0,0x88,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x67,0x6F,0x62,0x6C,0x69,0x6E,0x5F,0x61,0x6E,0x73,0x77,0x65,0x72,0x0,0x67,0x6F,0x62,0x6C,0x69,0x6E,0x5F,0x69,0x6D,0x70,0x6F,0x72,0x74,0x0,0x67,0x6F,0x62,0x6C,0x69,0x6E,0x5F,0x76,0x61,0x6C,0x75,0x65,0x0,0x67,0x6F,0x62,0x6C,0x69,0x6E,0x5F,0x68,0x65,0x6C,0x6C,0x6F,0x0,0x0,0x80,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xFF,0x35,0xDA,0x1,0x0,0x0,0xFF,0x25,0xDC,0x1,0x0,0x0,0xF,0x1F,0x40,0x0,0xFF,0x25,0xDA,0x1,0x0,0x0,0x68,0x0,0x0,0x0,0x0,0xE9,0xE0,0xFF,0xFF,0xFF,0x55,0x48,0x89,0xE5,0xE8,0xE7,0xFF,0xFF,0xFF,0x83,0xC0,0x2A,0x5D,0xC3,0x55,0x48,0x89,0xE5,0x90,0x5D,0xC3,0x0,0x0,0x0,0x1,0x1B,0x3,0x3B,0x24,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0xC8,0xFF,0xFF,0xFF,0x80,0x0,0x0,0x0,0xE8,0xFF,0xFF,0xFF,0x40,0x0,0x0,0x0,0xF6,0xFF,0xFF,0xFF,0x60,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x14,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x7A,0x52,0x0,0x1,0x78,0x10,0x1,0x1B,0xC,0x7,0x8,0x90,0x1,0x0,0x0,0x1C,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0xA0,0xFF,0xFF,0xFF,0xE,0x0,0x0,0x0,0x0,0x41,0xE,0x10,0x86,0x2,0x43,0xD,0x6,0x49,0xC,0x7,0x8,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0x3C,0x0,0x0,0x0,0x8E,0xFF,0xFF,0xFF,0x7,0x0,0x0,0x0,0x0,0x41,0xE,0x10,0x86,0x2,0x43,0xD,0x6,0x42,0xC,0x7,0x8,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x5C,0x0,0x0,0x0,0x40,0xFF,0xFF,0xFF,0x20,0x0,0x0,0x0,0x0,0xE,0x10,0x46,0xE,0x18,0x4A,0xF,0xB,0x77,0x8,0x80,0x0,0x3F,0x1A,0x3B,0x2A,0x33,0x24,0x22,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF5,0xFE,0xFF,0x6F,0x0,0x0,0x0,0x0,0x90,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x38,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0xA,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x37,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x68,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x14,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x17,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x70,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA6,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x47,0x43,0x43,0x3A,0x20,0x28,0x44,0x65,0x62,0x69,0x61,0x6E,0x20,0x31,0x32,0x2E,0x32,0x2E,0x30,0x2D,0x31,0x34,0x2B,0x64,0x65,0x62,0x31,0x32,0x75,0x31,0x29,0x20,0x31,0x32,0x2E,0x32,0x2E,0x30,0x0,0xFD,0x37,0x7A,0x58,0x5A,0x0,0x0,0x4,0xE6,0xD6,0xB4,0x46,0x2,0x0,0x21,0x1,0x16,0x0,0x0,0x0,0x74,0x2F,0xE5,0xA3,0xE0,0x8,0x8F,0x2,0x61,0x5D,0x0,0x3F,0x91,0x45,0x84,0x68,0x3D,0x89,0xA6,0xDA,0x8A,0xE1,0x83,0x32,0x4E,0xF1,0xED,0xEF,0x67,0x18,0x27,0x2F,0x1F,0xC9,0x50,0xB3,0x8,0x27,0xC0,0xE2,0x13,0xE7,0x23,0x56,0xF2,0x42,0x77,0x94,0x1A,0xEC,0xB9,0xDC,0x78,0xF4,0xF3,0x9F,0xB3,0xD1,0x24,0x24,0xD3,0x29,0xDF,0xE3,0x7A,0x94,0x42,0x37,0xA0,0xBA,0x1B,0xA3,0x5B,0x72,0x32,0x68,0x1E,0x1E,0x39,0x77,0x33,0xE4,0x74,0x25,0x75,0xB2,0xBF,0x33,0xF0,0xFD,0x4E,0x1D,0x81,0x93,0x3F,0xF,0x64,0x99,0xDA,0x3E,0x63,0xC5,0x62,0x16,0x5,0xF3,0xAF,0xBB,0x77,0x40,0x76,0x34,0xB8,0x7E,0xBD,0x92,0x73,0xB,0x65,0x2D,0x2D,0x73,0xE4,0x2F,0xAB,0x5C,0x2,0x2,0x7E,0xA9,0x73,0x4A,0xD8,0xBE,0xE0,0x4C,0x7C,0xA1,0x20,0x42,0x66,0xD5,0xB5,0xAE,0x2C,0x2D,0xE8,0x9C,0x11,0xCA,0xF8,0xCF,0x67,0xCC,0xF5,0x92,0xB6,0x84,0x43,0x70,0x80,0x10,0x63,0x49,0x5,0x51,0x5D,0x53,0xB2,0xB8,0xDC,0x92,0x98,0x91,0xA4,0x20,0xB1,0x3D,0x5B,0xA3,0x1E,0x6C,0x50,0x54,0x53,0x18,0xD6,0xDE,0xD,0x2F,0xF3,0xC9,0x8C,0xFF,0xE0,0x0,0x4E,0x79,0xA4,0xE5,0xDA,0xFC,0xF4,0xEB,0xE8,0x3,0x8B,0x5A,0x54,0x62,0x41,0x15,0xC4,0x63,0x2A,0x15,0x90,0x88,0xD0,0x6D,0xA3,0x79,0xD5,0xC2,0xF4,0x8B,0xE7,0x3D,0x93,0x66,0x46,0xBC,0xBB,0x2,0x5C,0x10,0x8F,0x7A,0xE6,0xF9,0xD5,0xFC,0xBF,0x63,0x17,0x3E,0x51,0x8E,0x93,0xF4,0x73,0x24,0x1,0x15,0xB0,0x6E,0x42,0xF1,0x68,0x9A,0x17,0x2A,0xA0,0x36,0x3A,0xD9,0x68,0x4E,0x2D,0x95,0x30,0x8C,0x22,0x2E,0xF7,0x32,0x79,0xCD,0x55,0x4A,0xC5,0x67,0xEC,0x54,0x32,0xFA,0xB4,0x5B,0xD0,0xBF,0x6E,0xBE,0xBC,0x9A,0x61,0x9B,0x5A,0x6A,0x86,0x79,0x7B,0xF,0xC8,0x97,0x6D,0x2F,0xED,0x49,0xE0,0x6C,0x86,0xF9,0x99,0xE,0x62,0xFE,0xA7,0xB7,0xE5,0xA7,0x2D,0xEB,0x49,0x40,0x9D,0x28,0x73,0x9A,0x29,0xBF,0x1B,0x17,0x7A,0xE9,0x84,0x2E,0xB7,0xA,0x66,0xE4,0xBD,0xB4,0x15,0x5E,0xDC,0x68,0x87,0xA4,0x68,0xB5,0x9E,0xC3,0x3E,0x9A,0x42,0x98,0xD8,0x93,0xAF,0xB4,0x97,0x4F,0x6E,0x34,0x92,0x72,0x6E,0x1B,0xA9,0x6,0xFF,0xAA,0x59,0x10,0x5A,0x4D,0x9D,0x91,0x9A,0xF5,0xF,0xD9,0x1,0x4B,0xF,0x3C,0x15,0xF3,0x69,0x17,0xF,0xDC,0xAD,0xFE,0x2A,0x15,0x51,0xBF,0xAA,0x3D,0xCD,0xC8,0xEE,0x10,0x41,0xD7,0x1C,0x40,0xA9,0x70,0xDD,0x77,0xC9,0x5E,0xD7,0xA8,0x6D,0x4A,0xEB,0xDF,0xA5,0x46,0xE5,0xE1,0x41,0x77,0xD0,0x26,0x3E,0xDD,0xFB,0x47,0x7E,0x8C,0x12,0xD8,0x3A,0xD9,0xF6,0x64,0xF5,0x70,0xAB,0x65,0xCA,0x58,0xDC,0x81,0x15,0x87,0x66,0x87,0xB,0xA5,0xDF,0xEB,0xCC,0xB8,0x8C,0x5,0x98,0x94,0x9E,0xD4,0x6A,0x8C,0xA1,0x3,0xD8,0xF9,0xAA,0x3E,0xEB,0x89,0x13,0x86,0x75,0x3A,0xAC,0xE1,0xD8,0xA1,0x4A,0x26,0xCD,0x55,0x92,0x1A,0xA4,0x55,0xE5,0xB1,0x81,0x3C,0x29,0x0,0x7F,0xE4,0xE7,0x55,0x77,0xAB,0x7D,0xA7,0xED,0x43,0x2C,0xC4,0xE1,0x3D,0xB9,0xCE,0xBB,0x7B,0xD4,0x7F,0x9,0xC7,0xE1,0x4A,0x93,0x8B,0x64,0xBA,0xC1,0x99,0xA5,0x12,0xE9,0x62,0x20,0xB1,0xCC,0xE6,0x45,0x6A,0x6C,0x6E,0x2F,0x31,0xFC,0x97,0xE9,0xF1,0x43,0x1D,0x58,0xD6,0xBD,0xB1,0x8F,0x9B,0x43,0xAB,0x46,0x3,0xC4,0xF8,0xAC,0xB9,0x30,0x0,0x45,0xBF,0x2,0x13,0xA4,0xD3,0x4E,0x62,0x5A,0xC5,0xF0,0x4,0xAE,0x50,0x88,0x17,0x4D,0x24,0x82,0xB,0x84,0x29,0x93,0xCF,0x4E,0x9A,0xC3,0xC4,0xFB,0x9F,0xFC,0x8C,0xE6,0xC5,0xD4,0xE3,0xEE,0x94,0xBF,0x0,0x89,0xA3,0x52,0x5,0x23,0xBD,0xE6,0xD9,0xEF,0x73,0xFD,0xF4,0xE2,0xF4,0xAD,0xAA,0x60,0x0,0x0,0x0,0x0,0x0,0x3A,0x11,0xC9,0xA3,0x2A,0xFD,0xB9,0x3E,0x0,0x1,0xFD,0x4,0x90,0x11,0x0,0x0,0x39,0x50,0x7D,0x3F,0xB1,0xC4,0x67,0xFB,0x2,0x0,0x0,0x0,0x0,0x4,0x59,0x5A,0x0,0x2E,0x73,0x68,0x73,0x74,0x72,0x74,0x61,0x62,0x0,0x2E,0x67,0x6E,0x75,0x2E,0x68,0x61,0x73,0x68,0x0,0x2E,0x64,0x79,0x6E,0x73,0x79,0x6D,0x0,0x2E,0x64,0x79,0x6E,0x73,0x74,0x72,0x0,0x2E,0x72,0x65,0x6C,0x61,0x2E,0x70,0x6C,0x74,0x0,0x2E,0x74,0x65,0x78,0x74,0x0,0x2E,0x65,0x68,0x5F,0x66,0x72,0x61,0x6D,0x65,0x5F,0x68,0x64,0x72,0x0,0x2E,0x65,0x68,0x5F,0x66,0x72,0x61,0x6D,0x65,0x0,0x2E,0x64,0x79,0x6E,0x61,0x6D,0x69,0x63,0x0,0x2E,0x67,0x6F,0x74,0x2E,0x70,0x6C,0x74,0x0,0x2E,0x64,0x61,0x74,0x61,0x0,0x2E,0x63,0x6F,0x6D,0x6D,0x65,0x6E,0x74,0x0,0x2E,0x67,0x6E,0x75,0x5F,0x64,0x65,0x62,0x75,0x67,0x64,0x61,0x74,0x61,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB,0x0,0x0,0x0,0xF6,0xFF,0xFF,0x6F,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x30,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x15,0x0,0x0,0x0,0xB,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0xC0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1D,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x38,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x38,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x37,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x25,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x42,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x70,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x70,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0xA,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2A,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2F,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xB0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x15,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x35,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC8,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xC8,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x24,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x43,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xF0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x7C,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4D,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0xF0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x56,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x68,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x68,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5F,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x88,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x88,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x65,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x30,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8C,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x27,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x6E,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB3,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0xA4,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x57,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x7D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0]
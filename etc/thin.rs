@@ -0,0 +1 @@
+vec![0x21,0x3C,0x74,0x68,0x69,0x6E,0x3E,0xA,0x2F,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x32,0x36,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x60,0xA,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0xA8,0x0,0x0,0x0,0xE4,0x74,0x68,0x69,0x6E,0x5F,0x61,0x0,0x74,0x68,0x69,0x6E,0x5F,0x62,0x0,0x2F,0x2F,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x31,0x34,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x60,0xA,0x61,0x2E,0x6F,0x2F,0xA,0x73,0x75,0x62,0x2F,0x62,0x2E,0x6F,0x2F,0xA,0x2F,0x30,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x36,0x34,0x34,0x20,0x20,0x20,0x20,0x20,0x36,0x32,0x34,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x60,0xA,0x2F,0x35,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x30,0x20,0x20,0x20,0x20,0x20,0x36,0x34,0x34,0x20,0x20,0x20,0x20,0x20,0x36,0x32,0x34,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x60,0xA]
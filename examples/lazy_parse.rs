@@ -0,0 +1,57 @@
+//! Benchmarks a full `Elf::parse` against `Elf::lazy_parse` followed by a single section lookup, on the given binary
+//!
+//! `#[bench]` needs a nightly compiler, so this is an example rather than a bench: `cargo run --release --example lazy_parse -- <elf file>`
+
+extern crate goblin;
+
+use goblin::elf::Elf;
+use std::env;
+use std::process;
+use std::fs::File;
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+fn usage() -> ! {
+    println!("usage: lazy_parse <elf file> [section name] [iterations]");
+    process::exit(1);
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs() as f64 * 1e6 + duration.subsec_nanos() as f64 / 1e3
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 {
+        usage();
+    }
+    let section = args.get(2).map_or(".note.gnu.build-id", |s| s.as_str());
+    let iterations = match args.get(3).map(|s| s.parse::<u32>()) {
+        Some(Ok(iterations)) if iterations > 0 => iterations,
+        Some(_) => usage(),
+        None => 100,
+    };
+    let mut fd = File::open(&args[1]).unwrap();
+    let buffer = { let mut v = Vec::new(); fd.read_to_end(&mut v).unwrap(); v };
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let elf = Elf::parse(&buffer).unwrap();
+        let shdr = elf.section_headers.iter().find(|shdr| elf.shdr_strtab.get(shdr.sh_name).map_or(false, |r| r.ok() == Some(section)));
+        assert!(shdr.is_some(), "no {} section", section);
+    }
+    let full = micros(start.elapsed()) / iterations as f64;
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let elf = Elf::lazy_parse(&buffer).unwrap();
+        let shdr = elf.section_by_name(&buffer, section).unwrap();
+        assert!(shdr.is_some(), "no {} section", section);
+    }
+    let lazy = micros(start.elapsed()) / iterations as f64;
+
+    println!("{} bytes, {} iterations, looking up {}", buffer.len(), iterations, section);
+    println!("full parse:            {:>12.1}us", full);
+    println!("lazy parse + section:  {:>12.1}us", lazy);
+    println!("speedup:               {:>12.1}x", full / lazy);
+}
@@ -0,0 +1,89 @@
+//! Structural anomalies found by inspecting a binary's already parsed header tables.
+//!
+//! Crafted binaries often carry section or segment tables which are internally inconsistent - two sections claiming the same bytes,
+//! a range reaching past the end of the file, and so on - in order to confuse analysis tools. None of these prevent goblin from parsing,
+//! but forensic tools usually want to know about them; see `Elf::anomalies` and `MachO::anomalies`.
+
+use core::ops::Range;
+use alloc::vec::Vec;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// An entry in one of the binary's header tables
+pub enum Region {
+    /// The section at this index; for Mach-o, sections are numbered consecutively across all segments, in load command order
+    Section(usize),
+    /// The segment (or program header) at this index
+    Segment(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A structural inconsistency in a binary
+pub enum Anomaly {
+    /// `first` and `second` claim overlapping bytes of the file
+    Overlap { first: Region, second: Region },
+    /// The file range of `region` extends past the end of the file, which is `file_size` bytes long
+    OutOfBounds { region: Region, range: Range<u64>, file_size: u64 },
+    /// The zero sized `segment` nevertheless contains `section`, which is not empty
+    EmptySegment { segment: usize, section: usize },
+    /// The entry point address does not lie within any executable range
+    EntryNotExecutable { entry: u64 },
+}
+
+/// Returns the range `offset..offset + size`, or `None` if it overflows
+pub fn checked_range(offset: u64, size: u64) -> Option<Range<u64>> {
+    offset.checked_add(size).map(|end| offset..end)
+}
+
+/// Whether the two (non-empty) ranges share at least one byte
+pub fn overlaps(a: &Range<u64>, b: &Range<u64>) -> bool {
+    a.start < a.end && b.start < b.end && a.start < b.end && b.start < a.end
+}
+
+/// Pushes an `Anomaly::Overlap` for every pair of overlapping `ranges`
+#[allow(dead_code)]
+pub(crate) fn push_overlaps(anomalies: &mut Vec<Anomaly>, ranges: &[(Region, Range<u64>)]) {
+    for (i, &(first, ref a)) in ranges.iter().enumerate() {
+        for &(second, ref b) in &ranges[i + 1..] {
+            if overlaps(a, b) {
+                anomalies.push(Anomaly::Overlap { first: first, second: second });
+            }
+        }
+    }
+}
+
+/// Pushes an `Anomaly::OutOfBounds` for `region` if `offset + size` overflows or exceeds `file_size`
+#[allow(dead_code)]
+pub(crate) fn push_out_of_bounds(anomalies: &mut Vec<Anomaly>, region: Region, offset: u64, size: u64, file_size: u64) -> Option<Range<u64>> {
+    match checked_range(offset, size) {
+        Some(range) => {
+            if range.end > file_size {
+                anomalies.push(Anomaly::OutOfBounds { region: region, range: range.clone(), file_size: file_size });
+            }
+            Some(range)
+        },
+        None => {
+            anomalies.push(Anomaly::OutOfBounds { region: region, range: offset..::core::u64::MAX, file_size: file_size });
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_ranges() {
+        assert!(overlaps(&(0..10), &(9..11)));
+        assert!(overlaps(&(4..6), &(0..10)));
+        assert!(!overlaps(&(0..10), &(10..20)));
+        assert!(!overlaps(&(5..5), &(0..10)));
+        assert_eq!(checked_range(::core::u64::MAX, 1), None);
+        let mut anomalies = Vec::new();
+        push_overlaps(&mut anomalies, &[(Region::Section(1), 0..8), (Region::Section(2), 8..16), (Region::Section(3), 4..12)]);
+        assert_eq!(anomalies, vec![
+            Anomaly::Overlap { first: Region::Section(1), second: Region::Section(3) },
+            Anomaly::Overlap { first: Region::Section(2), second: Region::Section(3) },
+        ]);
+    }
+}
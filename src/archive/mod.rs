@@ -2,9 +2,15 @@
 //!
 //! There are two "common" formats: BSD and SysV
 //!
-//! This crate currently only implements the SysV version, which essentially postfixes all
-//! names in the archive with a / as a sigil for the end of the name, and uses a special symbol
-//! index for looking up symbols faster.
+//! The SysV (and GNU) version essentially postfixes all names in the archive with a / as a sigil
+//! for the end of the name, keeps names longer than 15 bytes in a `//` string table member, and
+//! uses a special symbol index for looking up symbols faster; the BSD version stores long names
+//! (`#1/len`) right after the member header. Both kinds of names are resolved, so `Archive::extract`
+//! finds a member by its full name either way.
+//!
+//! GNU `ar` can also write "thin" archives (`!<thin>\n`), whose members are paths to files on disk
+//! rather than their contents, and a `/SYM64/` symbol index with 64-bit offsets, for archives
+//! larger than 4GiB.
 
 use scroll::{self, Pread};
 
@@ -18,6 +24,8 @@ use alloc::vec::Vec;
 pub const SIZEOF_MAGIC: usize = 8;
 /// The magic number of a Unix Archive
 pub const MAGIC: &'static [u8; SIZEOF_MAGIC] = b"!<arch>\x0A";
+/// The magic number of a GNU thin archive
+pub const THIN_MAGIC: &'static [u8; SIZEOF_MAGIC] = b"!<thin>\x0A";
 
 const SIZEOF_FILE_IDENTIFER: usize = 16;
 const SIZEOF_FILE_SIZE: usize = 10;
@@ -85,12 +93,14 @@ pub struct Member<'a> {
     pub header: Header<'a>,
     /// File offset from the start of the archive to where the header begins
     pub header_offset: u64,
-    /// File offset from the start of the archive to where the file begins
+    /// File offset from the start of the archive to where the file begins; for a thin member, where the next header begins
     pub offset: u64,
     /// BSD `ar` members store the filename separately
     bsd_name: Option<&'a str>,
     /// SysV `ar` members store the filename in a string table, a copy of which we hold here
     sysv_name: Option<&'a str>,
+    /// Whether this is a member of a thin archive, and so has no contents in the archive
+    thin: bool,
 }
 
 impl<'a> Member<'a> {
@@ -114,7 +124,10 @@ impl<'a> Member<'a> {
 
             // adjust the offset and size accordingly
             *offset = header_offset + SIZEOF_HEADER + len;
-            header.size -= len;
+            header.size = match header.size.checked_sub(len) {
+                Some(size) => size,
+                None => return Err(Error::Malformed(format!("BSD filename of member {:?} is {} bytes long, larger than the member itself ({} bytes)", name, len, header.size))),
+            };
 
             // the name may have trailing NULs which we don't really want to keep
             Some(name.trim_right_matches('\0'))
@@ -128,6 +141,7 @@ impl<'a> Member<'a> {
             offset: *offset as u64,
             bsd_name: bsd_name,
             sysv_name: None,
+            thin: false,
         })
     }
 
@@ -170,6 +184,20 @@ impl<'a> Member<'a> {
         self.header.name
     }
 
+    /// Whether this member is a reference to a file on disk, from a thin archive, rather than embedded in the archive
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
+    /// The path of the file a thin member refers to, relative to the archive's directory; `None` if the member is embedded
+    pub fn path(&self) -> Option<&'a str> {
+        if self.thin {
+            Some(self.extended_name())
+        } else {
+            None
+        }
+    }
+
 }
 
 #[derive(Debug, Default)]
@@ -187,6 +215,8 @@ pub struct Index<'a> {
 
 /// SysV Archive Variant Symbol Lookup Table "Magic" Name
 const INDEX_NAME: &'static str = "/               ";
+/// SysV Archive Variant Symbol Lookup Table with 64-bit offsets
+const INDEX64_NAME: &'static str = "/SYM64/         ";
 /// SysV Archive Variant Extended Filename String Table Name
 const NAME_INDEX_NAME: &'static str = "//              ";
 /// BSD symbol definitions
@@ -211,6 +241,31 @@ impl<'a> Index<'a> {
         })
     }
 
+    /// Parses the given byte buffer into an Index, from a `/SYM64/` index, whose count and offsets are Big Endian u64s
+    pub fn parse_sysv_index64(buffer: &'a [u8]) -> Result<Self> {
+        let offset = &mut 0;
+        let sizeof_table = buffer.gread_with::<u64>(offset, scroll::BE)? as usize;
+        if sizeof_table > buffer.len() / 8 {
+            return Err(Error::Malformed(format!("{} symbols do not fit in the {} byte 64-bit symbol index", sizeof_table, buffer.len())));
+        }
+        let mut indexes = Vec::with_capacity(sizeof_table);
+        for i in 0..sizeof_table {
+            let index = buffer.gread_with::<u64>(offset, scroll::BE)?;
+            // the members are found by their 32-bit offsets
+            if index > u32::max_value() as u64 {
+                return Err(Error::Malformed(format!("64-bit symbol index entry {} has member offset {:#x}, which is beyond 4GiB", i, index)));
+            }
+            indexes.push(index as u32);
+        }
+        let sizeof_strtab = buffer.len() - *offset;
+        let strtab = strtab::Strtab::parse(buffer, *offset, sizeof_strtab, 0x0)?;
+        Ok (Index {
+            size: sizeof_table,
+            symbol_indexes: indexes,
+            strtab: strtab.to_vec()?,
+        })
+    }
+
     /// Parses the given byte buffer into an Index, in BSD style archives
     pub fn parse_bsd_symdef(buffer: &'a [u8]) -> Result<Self> {
         // `llvm-ar` is a suitable reference:
@@ -335,6 +390,7 @@ pub struct Archive<'a> {
     sysv_name_index: NameIndex<'a>,
     // the array of members, which are indexed by the members hash and symbol index
     member_array: Vec<Member<'a>>,
+    thin: bool,
     members: BTreeMap<&'a str, usize>,
     // symbol -> member
     symbol_index: BTreeMap<&'a str, usize>
@@ -346,7 +402,8 @@ impl<'a> Archive<'a> {
         let mut magic = [0u8; SIZEOF_MAGIC];
         let offset = &mut 0usize;
         buffer.gread_inout(offset, &mut magic)?;
-        if &magic != MAGIC {
+        let thin = &magic == THIN_MAGIC;
+        if &magic != MAGIC && !thin {
             use scroll::Pread;
             return Err(Error::BadMagic(magic.pread(0)?).into());
         }
@@ -359,7 +416,7 @@ impl<'a> Archive<'a> {
                 *offset += 1;
             }
 
-            let member = Member::parse(buffer, offset)?;
+            let mut member = Member::parse(buffer, offset)?;
 
             // advance to the next record
             *offset = member.offset as usize + member.size() as usize;
@@ -369,6 +426,10 @@ impl<'a> Archive<'a> {
                 let data: &[u8] = buffer.pread_with(member.offset as usize, member.size())?;
                 index = Index::parse_sysv_index(data)?;
 
+            } else if name == INDEX64_NAME {
+                let data: &[u8] = buffer.pread_with(member.offset as usize, member.size())?;
+                index = Index::parse_sysv_index64(data)?;
+
             } else if member.bsd_name == Some(BSD_SYMDEF_NAME) || member.bsd_name == Some(BSD_SYMDEF_SORTED_NAME) {
                 let data: &[u8] = buffer.pread_with(member.offset as usize, member.size())?;
                 index = Index::parse_bsd_symdef(data)?;
@@ -378,6 +439,11 @@ impl<'a> Archive<'a> {
                 sysv_name_index = NameIndex::parse(buffer, &mut name_index_offset, member.size())?;
 
             } else {
+                // the contents of a thin archive's members are elsewhere, so the next header follows this one
+                if thin {
+                    *offset = member.offset as usize;
+                    member.thin = true;
+                }
                 // record this as an archive member
                 member_array.push(member);
             }
@@ -400,17 +466,18 @@ impl<'a> Archive<'a> {
             member_index_by_offset.insert(member.header_offset as u32, i);
         }
 
-        // build the symbol index, translating symbol names into member indexes
+        // build the symbol index, translating symbol names into member indexes; like a linker, the first member defining a symbol wins
         let mut symbol_index: BTreeMap<&str, usize> = BTreeMap::new();
         for (member_offset, name) in index.symbol_indexes.iter().zip(index.strtab.iter()) {
             let name = name.clone();
             let member_index = member_index_by_offset[member_offset];
-            symbol_index.insert(name, member_index);
+            symbol_index.entry(name).or_insert(member_index);
         }
 
         let archive = Archive {
             index: index,
             member_array: member_array,
+            thin: thin,
             sysv_name_index: sysv_name_index,
             members: members,
             symbol_index: symbol_index,
@@ -428,9 +495,12 @@ impl<'a> Archive<'a> {
         }
     }
 
-    /// Returns a slice of the raw bytes for the given `member` in the scrollable `buffer`
+    /// Returns a slice of the raw bytes for the given `member` in the scrollable `buffer`; a thin archive's members are not in it, see `Member::path`
     pub fn extract<'b>(&self, member: &str, buffer: &'b [u8]) -> Result<&'b [u8]> {
         if let Some(member) = self.get(member) {
+            if member.thin {
+                return Err(Error::Malformed(format!("Member {:?} of a thin archive is not in the archive", member.extended_name())));
+            }
             let bytes = buffer.pread_with(member.offset as usize, member.size())?;
             Ok(bytes)
         } else {
@@ -455,12 +525,18 @@ impl<'a> Archive<'a> {
         result
     }
 
+    /// Whether this is a GNU thin archive, whose members are references to files on disk
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
     /// Get the list of member names in this archive
     pub fn members(&self) -> Vec<&'a str> {
         self.members.keys().map(|s| *s).collect()
     }
 
-    /// Returns the member's name which contains the given `symbol`, if it is in the archive
+    /// Returns the member's name which contains the given `symbol`, if it is in the archive;
+    /// if several members define it, this is the first, like `member_defining`
     pub fn member_of_symbol (&self, symbol: &str) -> Option<&'a str> {
         if let Some(idx) = self.symbol_index.get(symbol) {
             Some(self.member_array[*idx].extended_name())
@@ -468,12 +544,90 @@ impl<'a> Archive<'a> {
             None
         }
     }
+
+    /// Returns the name of the member a linker would pull in to resolve `symbol`, i.e., the first member in the archive's symbol index which defines it,
+    /// even if later members define it too
+    pub fn member_defining(&self, symbol: &str) -> Option<&'a str> {
+        let offset = match self.index.strtab.iter().position(|name| *name == symbol) {
+            Some(position) => match self.index.symbol_indexes.get(position) {
+                Some(offset) => *offset,
+                None => return None,
+            },
+            None => return None,
+        };
+        self.member_array.iter()
+            .find(|member| member.header_offset == offset as u64)
+            .map(|member| member.extended_name())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn member_header(name: &str, size: usize) -> Vec<u8> {
+        format!("{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n", name, 0, 0, 0, 644, size).into_bytes()
+    }
+
+    #[test]
+    fn extract_long_names() {
+        // GNU: names longer than 15 bytes live in the `//` member, and are referred to as `/offset`
+        let mut gnu = MAGIC.to_vec();
+        let names = b"a_very_long_object_name.o/\n";
+        gnu.extend(member_header(NAME_INDEX_NAME, names.len()));
+        gnu.extend(&names[..]);
+        gnu.push(b'\n');
+        gnu.extend(member_header("/0", 4));
+        gnu.extend(b"ELF!");
+        gnu.extend(member_header("short.o/", 2));
+        gnu.extend(b"hi");
+        let archive = Archive::parse(&gnu).unwrap();
+        assert_eq!(archive.members(), vec!["a_very_long_object_name.o", "short.o"]);
+        assert_eq!(archive.extract("a_very_long_object_name.o", &gnu).unwrap(), b"ELF!");
+        assert_eq!(archive.extract("short.o", &gnu).unwrap(), b"hi");
+        assert!(archive.extract("/0", &gnu).is_err());
+
+        // BSD: the name follows the header, and is counted in the member's size
+        let mut bsd = MAGIC.to_vec();
+        bsd.extend(member_header("#1/20", 24));
+        bsd.extend(b"a_long_bsd_name.o\0\0\0ELF!");
+        let archive = Archive::parse(&bsd).unwrap();
+        assert_eq!(archive.members(), vec!["a_long_bsd_name.o"]);
+        assert_eq!(archive.extract("a_long_bsd_name.o", &bsd).unwrap(), b"ELF!");
+        // a name longer than the member is malformed, rather than an underflow
+        let mut bsd = MAGIC.to_vec();
+        bsd.extend(member_header("#1/20", 4));
+        bsd.extend(b"a_long_bsd_name.o\0\0\0");
+        assert!(Archive::parse(&bsd).is_err());
+    }
+
+    #[test]
+    fn duplicate_definitions() {
+        use scroll::{self, Pwrite};
+        // `a.o` and then `b.o` define `dup`, and only `b.o` defines `b`
+        let names = b"dup\0dup\0b\0";
+        let index_size = 4 + 3 * 4 + names.len();
+        let a_offset = MAGIC.len() + SIZEOF_HEADER + index_size;
+        let b_offset = a_offset + SIZEOF_HEADER + 2;
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend(member_header("/", index_size));
+        for value in [3, a_offset, b_offset, b_offset].iter() {
+            let mut word = [0u8; 4];
+            word.pwrite_with(*value as u32, 0, scroll::BE).unwrap();
+            bytes.extend(&word);
+        }
+        bytes.extend(&names[..]);
+        bytes.extend(member_header("a.o/", 2));
+        bytes.extend(b"aa");
+        bytes.extend(member_header("b.o/", 2));
+        bytes.extend(b"bb");
+        let archive = Archive::parse(&bytes).unwrap();
+        assert_eq!(archive.member_of_symbol("dup"), Some("a.o"));
+        assert_eq!(archive.member_defining("dup"), Some("a.o"));
+        assert_eq!(archive.member_of_symbol("b"), Some("b.o"));
+        assert_eq!(archive.member_defining("b"), Some("b.o"));
+    }
+
     #[test]
     fn test_member_bsd_filename_length() {
         // non-BSD names should fall through
@@ -0,0 +1,116 @@
+//! Detection and decompression of binaries wrapped in a general purpose compression format.
+//!
+//! Goblin never decompresses anything behind your back; this module is an opt-in helper (enabled via the `compression` feature)
+//! for pipelines which store their artifacts as `.gz`, `.xz`, or `.zst` files.
+
+use std::io::Read;
+
+use error;
+
+/// The gzip magic bytes
+pub const GZIP_MAGIC: &'static [u8; 2] = b"\x1f\x8b";
+/// The xz magic bytes
+pub const XZ_MAGIC: &'static [u8; 6] = b"\xfd7zXZ\x00";
+/// The zstd frame magic bytes
+pub const ZSTD_MAGIC: &'static [u8; 4] = b"\x28\xb5\x2f\xfd";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A compression format goblin knows how to unwrap
+pub enum Compression {
+    Gzip,
+    Xz,
+    Zstd,
+}
+
+/// Peeks at `bytes`, and returns the compression format they are wrapped in, if any
+pub fn peek_compression(bytes: &[u8]) -> Option<Compression> {
+    if bytes.starts_with(XZ_MAGIC) {
+        Some(Compression::Xz)
+    } else if bytes.starts_with(ZSTD_MAGIC) {
+        Some(Compression::Zstd)
+    } else if bytes.starts_with(GZIP_MAGIC) {
+        Some(Compression::Gzip)
+    } else {
+        None
+    }
+}
+
+/// Decompresses the gzip stream in `bytes`
+pub fn decompress_gzip(bytes: &[u8]) -> error::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    ::flate2::read::GzDecoder::new(bytes).read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Reads `reader` to the end, and errors as soon as it produces more than `limit` bytes, instead of buffering all of them
+fn read_limited<R: Read>(reader: R, limit: u64) -> error::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    reader.take(limit.saturating_add(1)).read_to_end(&mut buffer)?;
+    if buffer.len() as u64 > limit {
+        return Err(error::Error::Malformed(format!("Compressed data decompresses to more than its expected {:#x} bytes", limit)));
+    }
+    Ok(buffer)
+}
+
+/// Decompresses the raw zlib stream in `bytes`, which is malformed if it decompresses to more than `limit` bytes
+pub fn decompress_zlib_limited(bytes: &[u8], limit: u64) -> error::Result<Vec<u8>> {
+    read_limited(::flate2::read::ZlibDecoder::new(bytes), limit)
+}
+
+/// Decompresses the xz stream in `bytes`
+pub fn decompress_xz(bytes: &[u8]) -> error::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    ::xz2::read::XzDecoder::new(bytes).read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Decompresses the zstd stream in `bytes`
+pub fn decompress_zstd(bytes: &[u8]) -> error::Result<Vec<u8>> {
+    Ok(::zstd::stream::decode_all(bytes)?)
+}
+
+/// Decompresses the zstd stream in `bytes`, which is malformed if it decompresses to more than `limit` bytes
+pub fn decompress_zstd_limited(bytes: &[u8], limit: u64) -> error::Result<Vec<u8>> {
+    read_limited(::zstd::stream::read::Decoder::new(bytes)?, limit)
+}
+
+/// Decompresses `bytes` if they start with a known compression magic, otherwise returns `None`
+pub fn decompress(bytes: &[u8]) -> error::Result<Option<Vec<u8>>> {
+    match peek_compression(bytes) {
+        Some(Compression::Gzip) => decompress_gzip(bytes).map(Some),
+        Some(Compression::Xz) => decompress_xz(bytes).map(Some),
+        Some(Compression::Zstd) => decompress_zstd(bytes).map(Some),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peek_magics() {
+        assert_eq!(peek_compression(b"\x1f\x8b\x08\x00"), Some(Compression::Gzip));
+        assert_eq!(peek_compression(b"\xfd7zXZ\x00\x00\x04"), Some(Compression::Xz));
+        assert_eq!(peek_compression(b"\x28\xb5\x2f\xfd\x00"), Some(Compression::Zstd));
+        assert_eq!(peek_compression(b"\x7fELF\x02\x01\x01"), None);
+        assert_eq!(peek_compression(b"\x1f"), None);
+    }
+
+    #[test]
+    fn gzip_roundtrip() {
+        // `printf '\x7fELF' | gzip -n`
+        let compressed = [0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xab, 0x77, 0xf5, 0x71, 0x03, 0x00, 0x51, 0xc4, 0x3a, 0xa7, 0x04, 0x00, 0x00, 0x00];
+        let bytes = decompress(&compressed).unwrap().unwrap();
+        assert_eq!(bytes, b"\x7fELF");
+        assert!(decompress(b"\x7fELF").unwrap().is_none());
+    }
+
+    #[test]
+    fn zlib_limit() {
+        // `\x7fELF` four times, from python's `zlib.compress`
+        let compressed = [0x78, 0x9c, 0xab, 0x77, 0xf5, 0x71, 0xab, 0x47, 0xc2, 0x00, 0x2e, 0xc4, 0x05, 0x59];
+        assert_eq!(decompress_zlib_limited(&compressed, 16).unwrap().len(), 16);
+        assert!(decompress_zlib_limited(&compressed, 15).is_err());
+    }
+}
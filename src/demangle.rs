@@ -0,0 +1,49 @@
+//! Demangling of symbol names, detecting which scheme mangled them.
+//!
+//! This module is an opt-in helper (enabled via the `demangle` feature), since it pulls in a demangler for every scheme:
+//! legacy and v0 Rust, Itanium C++ (ELF and Mach-o), and MSVC C++ (PE).
+
+use alloc::borrow::Cow;
+
+use rustc_demangle;
+use cpp_demangle;
+use msvc_demangler;
+
+/// Demangles `name`, or returns it as is if it isn't mangled, or fails to demangle.
+///
+/// Rust names are demangled without their hashes, e.g., `core::fmt::write`; a Mach-o name may keep the underscore Mach-o prefixes to every C name, e.g., `__ZN3foo3barEv`
+pub fn demangle(name: &str) -> Cow<str> {
+    // legacy Rust names are Itanium names too, so they are tried first, to drop their hashes
+    if let Ok(demangled) = rustc_demangle::try_demangle(name) {
+        return Cow::Owned(format!("{:#}", demangled));
+    }
+    let itanium = if name.starts_with("__Z") { &name[1..] } else { name };
+    if itanium.starts_with("_Z") {
+        let demangled = cpp_demangle::Symbol::new(itanium).ok().and_then(|symbol| symbol.demangle(&cpp_demangle::DemangleOptions::default()).ok());
+        if let Some(demangled) = demangled {
+            return Cow::Owned(demangled);
+        }
+    } else if name.starts_with('?') {
+        if let Ok(demangled) = msvc_demangler::demangle(name, msvc_demangler::DemangleFlags::llvm()) {
+            return Cow::Owned(demangled);
+        }
+    }
+    Cow::Borrowed(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schemes() {
+        assert_eq!(demangle("_ZN4core3fmt5write17h0123456789abcdefE"), "core::fmt::write");
+        assert_eq!(demangle("_RNvNtCs1234_7mycrate3foo3bar"), "mycrate::foo::bar");
+        assert_eq!(demangle("_ZN3foo3barEv"), "foo::bar()");
+        assert_eq!(demangle("__ZN3foo3barEv"), "foo::bar()");
+        assert_eq!(demangle("?foo@@YAXXZ"), "void __cdecl foo(void)");
+        // C names, and names which fail to demangle, are left alone
+        assert_eq!(demangle("main"), "main");
+        assert_eq!(demangle("_Zfoo"), "_Zfoo");
+    }
+}
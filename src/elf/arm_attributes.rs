@@ -0,0 +1,305 @@
+//! The ARM build attributes of the `.ARM.attributes` (`SHT_ARM_ATTRIBUTES`) section, which record the architecture, FPU and procedure call standard
+//! the code was built for, so that a linker, or you, can check objects for ABI compatibility.
+//!
+//! The section is a format version byte (`A`), followed by vendor subsections; each is the length, the vendor name, e.g., `aeabi`,
+//! and then a sequence of attribute groups, which apply to the whole file, to some sections, or to some symbols.
+//! Only the attributes of the `aeabi` vendor have a known encoding; the others are kept as raw bytes.
+//!
+//! See: "Addenda to, and Errata in, the ABI for the ARM Architecture", section 2
+
+/// The format version byte which starts the section
+pub const ATTRIBUTES_FORMAT_VERSION: u8 = b'A';
+/// The vendor of the attributes defined by the ARM ABI
+pub const AEABI_VENDOR: &'static str = "aeabi";
+
+/// The attributes apply to the whole file
+pub const TAG_FILE: u8 = 1;
+/// The attributes apply to the listed sections
+pub const TAG_SECTION: u8 = 2;
+/// The attributes apply to the listed symbols
+pub const TAG_SYMBOL: u8 = 3;
+
+/// The CPU name as given on the command line (string)
+pub const TAG_CPU_RAW_NAME: u64 = 4;
+/// The canonical CPU name (string)
+pub const TAG_CPU_NAME: u64 = 5;
+/// The architecture version, see the `CPU_ARCH_*` values
+pub const TAG_CPU_ARCH: u64 = 6;
+/// The architecture profile: 'A'pplication, 'R'ealtime, 'M'icrocontroller, or 'S' for A or R
+pub const TAG_CPU_ARCH_PROFILE: u64 = 7;
+/// Whether ARM instructions are permitted
+pub const TAG_ARM_ISA_USE: u64 = 8;
+/// Whether, and which, Thumb instructions are permitted
+pub const TAG_THUMB_ISA_USE: u64 = 9;
+/// The floating point architecture, e.g., 3 for VFPv3
+pub const TAG_FP_ARCH: u64 = 10;
+/// The Advanced SIMD (NEON) architecture
+pub const TAG_ADVANCED_SIMD_ARCH: u64 = 12;
+/// How R9 is used
+pub const TAG_ABI_PCS_R9_USE: u64 = 14;
+/// The size of wchar_t
+pub const TAG_ABI_PCS_WCHAR_T: u64 = 18;
+/// The floating point number model
+pub const TAG_ABI_FP_NUMBER_MODEL: u64 = 23;
+/// The size of enums
+pub const TAG_ABI_ENUM_SIZE: u64 = 26;
+/// Which registers floating point arguments are passed in, see the `VFP_ARGS_*` values
+pub const TAG_ABI_VFP_ARGS: u64 = 28;
+/// The toolchain specific compatibility of the code (a flag and a string)
+pub const TAG_COMPATIBILITY: u64 = 32;
+/// Whether unaligned accesses are permitted
+pub const TAG_CPU_UNALIGNED_ACCESS: u64 = 34;
+/// A secondary architecture the code is also compatible with (string)
+pub const TAG_ALSO_COMPATIBLE_WITH: u64 = 65;
+/// The version of the ABI the attributes conform to (string)
+pub const TAG_CONFORMANCE: u64 = 67;
+
+/// `Tag_ABI_VFP_args`: floating point arguments are passed in the core registers (soft-float)
+pub const VFP_ARGS_BASE: u64 = 0;
+/// `Tag_ABI_VFP_args`: floating point arguments are passed in the VFP registers (hard-float)
+pub const VFP_ARGS_VFP: u64 = 1;
+/// `Tag_ABI_VFP_args`: a toolchain specific convention
+pub const VFP_ARGS_TOOLCHAIN: u64 = 2;
+/// `Tag_ABI_VFP_args`: the code passes no floating point arguments, and is compatible with both
+pub const VFP_ARGS_COMPATIBLE: u64 = 3;
+
+/// `Tag_CPU_arch`: ARMv7
+pub const CPU_ARCH_V7: u64 = 10;
+/// `Tag_CPU_arch`: ARMv7E-M
+pub const CPU_ARCH_V7E_M: u64 = 13;
+/// `Tag_CPU_arch`: ARMv8-A
+pub const CPU_ARCH_V8_A: u64 = 14;
+
+if_alloc! {
+    use core::str;
+    use scroll::{Pread, Uleb128};
+    use alloc::vec::Vec;
+    use error;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// The value of an attribute
+    pub enum AttributeValue<'a> {
+        Int(u64),
+        Str(&'a str),
+        /// The flag and the vendor name of `Tag_compatibility`
+        Compatibility(u64, &'a str),
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// A single tag-value pair
+    pub struct Attribute<'a> {
+        pub tag: u64,
+        pub value: AttributeValue<'a>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// What an attribute group applies to
+    pub enum Scope {
+        File,
+        /// The section indices
+        Section(Vec<u64>),
+        /// The symbol indices
+        Symbol(Vec<u64>),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// The attributes of one `Tag_File`, `Tag_Section` or `Tag_Symbol` group
+    pub struct AttributeGroup<'a> {
+        pub scope: Scope,
+        pub attributes: Vec<Attribute<'a>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// The attributes of one vendor
+    pub struct VendorSection<'a> {
+        pub vendor: &'a str,
+        /// The decoded groups; always empty for vendors other than `aeabi`, whose encoding is unknown
+        pub groups: Vec<AttributeGroup<'a>>,
+        /// The raw bytes of the groups
+        pub data: &'a [u8],
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    /// The parsed contents of an `.ARM.attributes` section
+    pub struct ArmAttributes<'a> {
+        pub vendors: Vec<VendorSection<'a>>,
+    }
+
+    fn read_str<'a>(bytes: &'a [u8], offset: &mut usize) -> error::Result<&'a str> {
+        let s: &'a str = bytes.pread(*offset)?;
+        // skip the terminator too
+        *offset += s.len() + 1;
+        Ok(s)
+    }
+
+    fn read_uleb(bytes: &[u8], offset: &mut usize) -> error::Result<u64> {
+        Ok(Uleb128::read(bytes, offset)?)
+    }
+
+    /// Whether the `aeabi` attribute `tag` has a string value; for unknown tags, the ABI decrees that odd tags above 32 are strings
+    fn is_string_tag(tag: u64) -> bool {
+        match tag {
+            TAG_CPU_RAW_NAME | TAG_CPU_NAME | TAG_ALSO_COMPATIBLE_WITH | TAG_CONFORMANCE => true,
+            _ => tag > 32 && tag % 2 == 1,
+        }
+    }
+
+    fn parse_attributes<'a>(bytes: &'a [u8]) -> error::Result<Vec<Attribute<'a>>> {
+        let mut attributes = Vec::new();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let tag = read_uleb(bytes, &mut offset)?;
+            let value = if tag == TAG_COMPATIBILITY {
+                let flag = read_uleb(bytes, &mut offset)?;
+                AttributeValue::Compatibility(flag, read_str(bytes, &mut offset)?)
+            } else if is_string_tag(tag) {
+                AttributeValue::Str(read_str(bytes, &mut offset)?)
+            } else {
+                AttributeValue::Int(read_uleb(bytes, &mut offset)?)
+            };
+            attributes.push(Attribute { tag, value });
+        }
+        Ok(attributes)
+    }
+
+    fn parse_groups<'a>(bytes: &'a [u8], le: ::scroll::Endian) -> error::Result<Vec<AttributeGroup<'a>>> {
+        let mut groups = Vec::new();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let start = offset;
+            let tag: u8 = bytes.gread(&mut offset)?;
+            // the size includes the tag and the size itself
+            let size: u32 = bytes.gread_with(&mut offset, le)?;
+            let end = match start.checked_add(size as usize) {
+                Some(end) if end <= bytes.len() && end >= offset => end,
+                _ => return Err(error::Error::Malformed(format!("ARM attribute group at {:#x} has bad size {:#x}", start, size))),
+            };
+            let group = &bytes[..end];
+            let scope = match tag {
+                TAG_FILE => Scope::File,
+                TAG_SECTION | TAG_SYMBOL => {
+                    // a 0 terminated list of indices
+                    let mut indices = Vec::new();
+                    loop {
+                        let index = read_uleb(group, &mut offset)?;
+                        if index == 0 { break }
+                        indices.push(index);
+                    }
+                    if tag == TAG_SECTION { Scope::Section(indices) } else { Scope::Symbol(indices) }
+                },
+                _ => return Err(error::Error::Malformed(format!("ARM attribute group at {:#x} has unknown tag {}", start, tag))),
+            };
+            let attributes = parse_attributes(&group[offset..])?;
+            groups.push(AttributeGroup { scope, attributes });
+            offset = end;
+        }
+        Ok(groups)
+    }
+
+    impl<'a> ArmAttributes<'a> {
+        /// Parses the contents of an `.ARM.attributes` section; the lengths are in the byte order of `le`
+        pub fn parse(bytes: &'a [u8], le: ::scroll::Endian) -> error::Result<Self> {
+            let mut vendors = Vec::new();
+            let mut offset = 0;
+            let version: u8 = bytes.gread(&mut offset)?;
+            if version != ATTRIBUTES_FORMAT_VERSION {
+                return Err(error::Error::Malformed(format!("ARM attributes have unknown format version {:#x}", version)));
+            }
+            while offset < bytes.len() {
+                let start = offset;
+                // the length includes itself
+                let len: u32 = bytes.gread_with(&mut offset, le)?;
+                let end = match start.checked_add(len as usize) {
+                    Some(end) if end <= bytes.len() && end >= offset => end,
+                    _ => return Err(error::Error::Malformed(format!("ARM attributes vendor subsection at {:#x} has bad length {:#x}", start, len))),
+                };
+                let subsection = &bytes[..end];
+                let vendor = read_str(subsection, &mut offset)?;
+                let data = subsection.get(offset..).unwrap_or(&[]);
+                let groups = if vendor == AEABI_VENDOR { parse_groups(data, le)? } else { Vec::new() };
+                vendors.push(VendorSection { vendor, groups, data });
+                offset = end;
+            }
+            Ok(ArmAttributes { vendors })
+        }
+        /// The value of the `aeabi` attribute `tag` which applies to the whole file
+        pub fn get(&self, tag: u64) -> Option<AttributeValue<'a>> {
+            self.vendors.iter()
+                .filter(|vendor| vendor.vendor == AEABI_VENDOR)
+                .flat_map(|vendor| vendor.groups.iter())
+                .filter(|group| group.scope == Scope::File)
+                .flat_map(|group| group.attributes.iter())
+                .find(|attribute| attribute.tag == tag)
+                .map(|attribute| attribute.value)
+        }
+        fn get_int(&self, tag: u64) -> Option<u64> {
+            match self.get(tag) {
+                Some(AttributeValue::Int(value)) => Some(value),
+                _ => None,
+            }
+        }
+        /// The canonical name of the CPU, e.g., `"7-A"` or `"CORTEX-M4"`
+        pub fn cpu_name(&self) -> Option<&'a str> {
+            match self.get(TAG_CPU_NAME) {
+                Some(AttributeValue::Str(name)) => Some(name),
+                _ => None,
+            }
+        }
+        /// The `Tag_CPU_arch`, e.g., `CPU_ARCH_V7`
+        pub fn cpu_arch(&self) -> Option<u64> {
+            self.get_int(TAG_CPU_ARCH)
+        }
+        /// The `Tag_FP_arch`, 0 if the code uses no floating point hardware
+        pub fn fp_arch(&self) -> Option<u64> {
+            self.get_int(TAG_FP_ARCH)
+        }
+        /// The `Tag_ABI_VFP_args`, which tells hard-float (`VFP_ARGS_VFP`) from soft-float (`VFP_ARGS_BASE`) code
+        pub fn vfp_args(&self) -> Option<u64> {
+            self.get_int(TAG_ABI_VFP_ARGS)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        // the file attributes gcc records for `-march=armv7-a -mfpu=vfpv3-d16 -mfloat-abi=hard`, followed by a subsection of another vendor
+        const ATTRIBUTES: [u8; 51] = [
+            0x41, 0x28, 0x00, 0x00, 0x00, 0x61, 0x65, 0x61, 0x62, 0x69, 0x00, 0x01, 0x1e, 0x00, 0x00, 0x00,
+            0x05, 0x37, 0x2d, 0x41, 0x00, 0x06, 0x0a, 0x07, 0x41, 0x08, 0x01, 0x09, 0x02, 0x0a, 0x03, 0x1c,
+            0x01, 0x20, 0x01, 0x67, 0x6e, 0x75, 0x00, 0x22, 0x01, 0x0a, 0x00, 0x00, 0x00, 0x67, 0x6e, 0x75,
+            0x00, 0x7f, 0x7f,
+        ];
+
+        #[test]
+        fn parse_arm_attributes() {
+            let attributes = ArmAttributes::parse(&ATTRIBUTES, ::scroll::LE).unwrap();
+            assert_eq!(attributes.vendors.len(), 2);
+            assert_eq!(attributes.vendors[1].vendor, "gnu");
+            assert_eq!(attributes.vendors[1].groups, vec![]);
+            assert_eq!(attributes.cpu_name(), Some("7-A"));
+            assert_eq!(attributes.cpu_arch(), Some(CPU_ARCH_V7));
+            assert_eq!(attributes.fp_arch(), Some(3));
+            assert_eq!(attributes.vfp_args(), Some(VFP_ARGS_VFP));
+            assert_eq!(attributes.get(TAG_CPU_ARCH_PROFILE), Some(AttributeValue::Int(b'A' as u64)));
+            assert_eq!(attributes.get(TAG_COMPATIBILITY), Some(AttributeValue::Compatibility(1, "gnu")));
+            assert_eq!(attributes.get(TAG_ABI_ENUM_SIZE), None);
+            assert!(ArmAttributes::parse(&ATTRIBUTES[..40], ::scroll::LE).is_err());
+            assert!(ArmAttributes::parse(&ATTRIBUTES[1..], ::scroll::LE).is_err());
+        }
+
+        #[test]
+        fn parse_big_endian_arm_attributes() {
+            // the same attributes, with the subsection lengths and the group size byte swapped, as in a big endian object
+            let mut attributes = ATTRIBUTES;
+            for &offset in [1, 12, 41].iter() {
+                attributes[offset..offset + 4].reverse();
+            }
+            let attributes = ArmAttributes::parse(&attributes, ::scroll::BE).unwrap();
+            assert_eq!(attributes.vendors[0].groups, ArmAttributes::parse(&ATTRIBUTES, ::scroll::LE).unwrap().vendors[0].groups);
+            assert_eq!(attributes.cpu_name(), Some("7-A"));
+            assert_eq!(attributes.vfp_args(), Some(VFP_ARGS_VFP));
+            assert!(ArmAttributes::parse(&ATTRIBUTES, ::scroll::BE).is_err());
+        }
+    }
+}
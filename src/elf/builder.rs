@@ -0,0 +1,271 @@
+//! A builder for small ELF executables, e.g., test fixtures, or the input of a tiny loader.
+//!
+//! The file is laid out as the header, the program headers, the segment data, the section data, the section header string table, and the section headers.
+//! An allocated section which lies within a segment isn't written again, but points into the segment's data instead.
+
+use std::io::Write;
+use alloc::vec::Vec;
+use scroll::Pwrite;
+
+use error;
+use container::{Container, Ctx};
+use elf::header::{self, Header};
+use elf::program_header::ProgramHeader;
+use elf::section_header::{self, SectionHeader};
+
+/// The name of the section header string table the builder adds
+const SHSTRTAB: &'static str = ".shstrtab";
+
+/// Builds an ELF binary; the offsets, sizes and counts of the header, its tables, and the data it is given are computed by `write_to`, everything else is written as given
+#[derive(Debug)]
+pub struct Builder<'a> {
+    ctx: Ctx,
+    header: Header,
+    /// The program headers, with the data of their segment, if they have one
+    segments: Vec<(ProgramHeader, Option<&'a [u8]>)>,
+    sections: Vec<(&'a str, SectionHeader, &'a [u8])>,
+}
+
+/// Pads `bytes` up to a multiple of `align`, plus `rem`
+fn pad(bytes: &mut Vec<u8>, align: u64, rem: u64) {
+    if align > 1 {
+        let len = bytes.len() as u64;
+        let mut offset = len - len % align + rem % align;
+        if offset < len {
+            offset += align;
+        }
+        bytes.resize(offset as usize, 0);
+    }
+}
+
+impl<'a> Builder<'a> {
+    /// A builder for an `ET_EXEC` binary of the class and byte order of `ctx`, for `EM_NONE`, with no entry point, segments or sections
+    pub fn new(ctx: Ctx) -> Self {
+        let mut header = Header::new(ctx);
+        header.e_type = header::ET_EXEC;
+        Builder { ctx, header, segments: Vec::new(), sections: Vec::new() }
+    }
+    /// Sets the object file type, one of the `ET_*`
+    pub fn file_type(mut self, e_type: u16) -> Self {
+        self.header.e_type = e_type;
+        self
+    }
+    /// Sets the machine, one of the `EM_*`
+    pub fn machine(mut self, e_machine: u16) -> Self {
+        self.header.e_machine = e_machine;
+        self
+    }
+    /// Sets the virtual address of the entry point
+    pub fn entry(mut self, e_entry: u64) -> Self {
+        self.header.e_entry = e_entry;
+        self
+    }
+    /// Adds a program header as is, e.g., a `PT_GNU_STACK`
+    pub fn program_header(mut self, phdr: ProgramHeader) -> Self {
+        self.segments.push((phdr, None));
+        self
+    }
+    /// Adds a program header for a segment holding `data`; its `p_offset` is chosen congruent to its `p_vaddr` modulo `p_align`, as a loader requires,
+    /// its `p_filesz` is the size of `data`, and its `p_memsz` is at least that
+    pub fn segment(mut self, phdr: ProgramHeader, data: &'a [u8]) -> Self {
+        self.segments.push((phdr, Some(data)));
+        self
+    }
+    /// Adds a section named `name`, holding `data`, at an offset aligned to its `sh_addralign`; unless it is `SHT_NOBITS`, its `sh_size` is the size of `data`.
+    ///
+    /// If it is `SHF_ALLOC`, and its addresses lie within those of a segment's data (or, for `SHT_NOBITS`, its memory), it is placed at the corresponding offset
+    /// of that segment instead, as a linker would, and `data` must be the same as the segment's data there
+    pub fn section(mut self, name: &'a str, shdr: SectionHeader, data: &'a [u8]) -> Self {
+        self.sections.push((name, shdr, data));
+        self
+    }
+    /// Lays out and writes the binary to `writer`
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> error::Result<()> {
+        let ctx = self.ctx;
+        let mut header = self.header;
+        let ehsize = Header::size(&ctx);
+        let phentsize = ProgramHeader::size(&ctx);
+        let shentsize = SectionHeader::size(&ctx);
+        if self.segments.len() > 0xffff || self.sections.len() + 2 > 0xffff {
+            return Err(error::Error::Malformed(format!("{} program headers and {} sections do not fit in an ELF header", self.segments.len(), self.sections.len())));
+        }
+        let mut bytes = vec![0u8; ehsize + self.segments.len() * phentsize];
+        let mut phdrs = Vec::with_capacity(self.segments.len());
+        for &(ref phdr, data) in &self.segments {
+            let mut phdr = phdr.clone();
+            if let Some(data) = data {
+                pad(&mut bytes, phdr.p_align, phdr.p_vaddr);
+                phdr.p_offset = bytes.len() as u64;
+                phdr.p_filesz = data.len() as u64;
+                phdr.p_memsz = ::core::cmp::max(phdr.p_memsz, phdr.p_filesz);
+                bytes.extend_from_slice(data);
+            }
+            phdrs.push(phdr);
+        }
+        header.e_phoff = if phdrs.is_empty() { 0 } else { ehsize as u64 };
+        header.e_phnum = phdrs.len() as u16;
+        let mut shdrs = Vec::new();
+        if !self.sections.is_empty() {
+            // the null section, then the sections, then the string table of their names
+            let mut shstrtab = vec![0u8];
+            shdrs.push(SectionHeader::new());
+            for &(name, ref shdr, data) in &self.sections {
+                let mut shdr = shdr.clone();
+                shdr.sh_name = shstrtab.len();
+                shstrtab.extend_from_slice(name.as_bytes());
+                shstrtab.push(0);
+                let is_nobits = shdr.sh_type == section_header::SHT_NOBITS;
+                if !is_nobits {
+                    shdr.sh_size = data.len() as u64;
+                }
+                let segment = if shdr.is_alloc() {
+                    phdrs.iter().zip(&self.segments)
+                        .filter(|&(_, &(_, data))| data.is_some())
+                        .map(|(phdr, _)| phdr)
+                        .find(|phdr| {
+                            let size = if is_nobits { phdr.p_memsz } else { phdr.p_filesz };
+                            shdr.sh_addr >= phdr.p_vaddr && shdr.sh_addr - phdr.p_vaddr <= size && shdr.sh_size <= size - (shdr.sh_addr - phdr.p_vaddr)
+                        })
+                } else {
+                    None
+                };
+                if let Some(phdr) = segment {
+                    shdr.sh_offset = phdr.p_offset + (shdr.sh_addr - phdr.p_vaddr);
+                    let in_segment = if is_nobits { true } else { bytes.get(shdr.sh_offset as usize..).map_or(false, |contents| contents.starts_with(data)) };
+                    if !in_segment {
+                        return Err(error::Error::Malformed(format!("section {} at {:#x} lies within a segment, but its data differs from the segment's", name, shdr.sh_addr)));
+                    }
+                } else {
+                    pad(&mut bytes, shdr.sh_addralign, 0);
+                    shdr.sh_offset = bytes.len() as u64;
+                    if !is_nobits {
+                        bytes.extend_from_slice(data);
+                    }
+                }
+                shdrs.push(shdr);
+            }
+            let mut shdr = SectionHeader::new();
+            shdr.sh_name = shstrtab.len();
+            shstrtab.extend_from_slice(SHSTRTAB.as_bytes());
+            shstrtab.push(0);
+            shdr.sh_type = section_header::SHT_STRTAB;
+            shdr.sh_offset = bytes.len() as u64;
+            shdr.sh_size = shstrtab.len() as u64;
+            shdr.sh_addralign = 1;
+            bytes.extend_from_slice(&shstrtab);
+            shdrs.push(shdr);
+            pad(&mut bytes, if ctx.container == Container::Big { 8 } else { 4 }, 0);
+            header.e_shoff = bytes.len() as u64;
+            header.e_shnum = shdrs.len() as u16;
+            header.e_shstrndx = shdrs.len() as u16 - 1;
+        }
+        let mut offset = bytes.len();
+        bytes.resize(offset + shdrs.len() * shentsize, 0);
+        for shdr in shdrs {
+            bytes.gwrite_with(shdr, &mut offset, ctx)?;
+        }
+        bytes.pwrite_with(header, 0, ctx.le)?;
+        let mut offset = ehsize;
+        for phdr in phdrs {
+            bytes.gwrite_with(phdr, &mut offset, ctx)?;
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll;
+    use elf::Elf;
+    use elf::program_header::{PT_LOAD, PT_GNU_STACK, PF_R, PF_W, PF_X};
+
+    /// `_exit(0)` on x86-64
+    const TEXT: [u8; 12] = [0xb8, 0x3c, 0x00, 0x00, 0x00, 0x31, 0xff, 0x0f, 0x05, 0x90, 0x90, 0x90];
+
+    #[test]
+    fn round_trip() {
+        for &ctx in [Ctx::new(Container::Big, scroll::LE), Ctx::new(Container::Little, scroll::BE)].iter() {
+            let mut load = ProgramHeader::new();
+            load.p_type = PT_LOAD;
+            load.p_flags = PF_R | PF_X;
+            load.p_vaddr = 0x40_1000;
+            load.p_paddr = 0x40_1000;
+            load.p_align = 0x1000;
+            let mut bss = ProgramHeader { p_vaddr: 0x40_2000, p_paddr: 0x40_2000, p_flags: PF_R | PF_W, p_memsz: 0x100, .. load.clone() };
+            let mut stack = ProgramHeader::new();
+            stack.p_type = PT_GNU_STACK;
+            stack.p_flags = PF_R | PF_W;
+            let mut text = SectionHeader::new();
+            text.sh_type = section_header::SHT_PROGBITS;
+            text.sh_flags = (section_header::SHF_ALLOC | section_header::SHF_EXECINSTR) as u64;
+            text.sh_addr = 0x40_1000;
+            text.sh_addralign = 16;
+            let mut bss_section = SectionHeader { sh_type: section_header::SHT_NOBITS, sh_flags: (section_header::SHF_ALLOC | section_header::SHF_WRITE) as u64, sh_addr: 0x40_2010, sh_size: 0x20, .. text.clone() };
+            let mut comment = SectionHeader { sh_type: section_header::SHT_PROGBITS, sh_flags: 0, sh_addr: 0, sh_addralign: 1, .. text.clone() };
+            let mut bytes = Vec::new();
+            Builder::new(ctx)
+                .machine(header::EM_X86_64)
+                .entry(0x40_1000)
+                .segment(load.clone(), &TEXT)
+                .segment(bss.clone(), &[])
+                .program_header(stack.clone())
+                .section(".text", text.clone(), &TEXT)
+                .section(".bss", bss_section.clone(), &[])
+                .section(".comment", comment.clone(), b"goblin")
+                .write_to(&mut bytes)
+                .unwrap();
+            let elf = Elf::parse(&bytes).unwrap();
+            assert_eq!(elf.is_64, ctx.container == Container::Big);
+            assert_eq!(elf.little_endian, ctx.le == scroll::LE);
+            let mut expected = Header::new(ctx);
+            expected.e_type = header::ET_EXEC;
+            expected.e_machine = header::EM_X86_64;
+            expected.e_entry = 0x40_1000;
+            expected.e_phoff = Header::size(&ctx) as u64;
+            expected.e_phnum = 3;
+            expected.e_shoff = elf.header.e_shoff;
+            expected.e_shnum = 5;
+            expected.e_shstrndx = 4;
+            assert_eq!(elf.header, expected);
+            assert_eq!(elf.header.e_shoff as usize, bytes.len() - 5 * SectionHeader::size(&ctx));
+            // the segment's offset is congruent to its address
+            load.p_offset = 0x1000;
+            load.p_filesz = TEXT.len() as u64;
+            load.p_memsz = TEXT.len() as u64;
+            bss.p_offset = 0x2000;
+            assert_eq!(elf.program_headers, vec![load.clone(), bss.clone(), stack]);
+            let sections = elf.section_headers.iter().map(|shdr| &elf.shdr_strtab[shdr.sh_name]).collect::<Vec<_>>();
+            assert_eq!(sections, vec!["", ".text", ".bss", ".comment", SHSTRTAB]);
+            // the allocated sections are placed within their segments, at the offsets of their addresses, and the others after all segments
+            text.sh_offset = load.p_offset;
+            text.sh_size = TEXT.len() as u64;
+            bss_section.sh_offset = bss.p_offset + 0x10;
+            comment.sh_offset = 0x2000;
+            comment.sh_size = 6;
+            for (shdr, expected) in elf.section_headers[1..4].iter().zip([text, bss_section, comment].iter()) {
+                assert_eq!(SectionHeader { sh_name: 0, .. shdr.clone() }, *expected);
+            }
+            assert_eq!(&bytes[0x1000..0x1000 + TEXT.len()], &TEXT[..]);
+            assert_eq!(&bytes[0x2000..0x2006], b"goblin");
+        }
+    }
+
+    #[test]
+    fn section_differing_from_its_segment() {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let mut load = ProgramHeader::new();
+        load.p_type = PT_LOAD;
+        load.p_vaddr = 0x40_1000;
+        load.p_align = 0x1000;
+        let mut text = SectionHeader::new();
+        text.sh_type = section_header::SHT_PROGBITS;
+        text.sh_flags = section_header::SHF_ALLOC as u64;
+        text.sh_addr = 0x40_1004;
+        let builder = Builder::new(ctx).segment(load.clone(), &TEXT);
+        assert!(builder.section(".text", text.clone(), &TEXT[4..]).write_to(&mut Vec::new()).is_ok());
+        let builder = Builder::new(ctx).segment(load, &TEXT);
+        assert!(builder.section(".text", text, &TEXT[..8]).write_to(&mut Vec::new()).is_err());
+    }
+}
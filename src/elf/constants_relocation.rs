@@ -794,399 +794,526 @@ pub const R_MIPS_COPY: u32 = 126;
 pub const R_MIPS_JUMP_SLOT: u32 = 127;
 pub const R_MIPS_NUM: u32 = 128;
 
+/////////////////////
+// RISC-V
+/////////////////////
+/// No reloc
+pub const R_RISCV_NONE: u32 = 0;
+/// Direct 32 bit
+pub const R_RISCV_32: u32 = 1;
+/// Direct 64 bit
+pub const R_RISCV_64: u32 = 2;
+/// Adjust by program base
+pub const R_RISCV_RELATIVE: u32 = 3;
+/// Copy symbol at runtime
+pub const R_RISCV_COPY: u32 = 4;
+/// Create PLT entry
+pub const R_RISCV_JUMP_SLOT: u32 = 5;
+/// 32 bit ID of module containing symbol
+pub const R_RISCV_TLS_DTPMOD32: u32 = 6;
+/// 64 bit ID of module containing symbol
+pub const R_RISCV_TLS_DTPMOD64: u32 = 7;
+/// 32 bit offset in TLS block
+pub const R_RISCV_TLS_DTPREL32: u32 = 8;
+/// 64 bit offset in TLS block
+pub const R_RISCV_TLS_DTPREL64: u32 = 9;
+/// 32 bit offset relative to the thread pointer
+pub const R_RISCV_TLS_TPREL32: u32 = 10;
+/// 64 bit offset relative to the thread pointer
+pub const R_RISCV_TLS_TPREL64: u32 = 11;
+/// TLS descriptor
+pub const R_RISCV_TLSDESC: u32 = 12;
+/// 12 bit PC-relative branch offset
+pub const R_RISCV_BRANCH: u32 = 16;
+/// 20 bit PC-relative jump offset
+pub const R_RISCV_JAL: u32 = 17;
+/// 32 bit PC-relative call, deprecated
+pub const R_RISCV_CALL: u32 = 18;
+/// 32 bit PC-relative call through the PLT
+pub const R_RISCV_CALL_PLT: u32 = 19;
+/// High 20 bits of a 32 bit PC-relative GOT entry
+pub const R_RISCV_GOT_HI20: u32 = 20;
+/// High 20 bits of a 32 bit PC-relative TLS IE GOT entry
+pub const R_RISCV_TLS_GOT_HI20: u32 = 21;
+/// High 20 bits of a 32 bit PC-relative TLS GD GOT entry
+pub const R_RISCV_TLS_GD_HI20: u32 = 22;
+/// High 20 bits of a 32 bit PC-relative offset
+pub const R_RISCV_PCREL_HI20: u32 = 23;
+/// Low 12 bits of a 32 bit PC-relative offset, I-type
+pub const R_RISCV_PCREL_LO12_I: u32 = 24;
+/// Low 12 bits of a 32 bit PC-relative offset, S-type
+pub const R_RISCV_PCREL_LO12_S: u32 = 25;
+/// High 20 bits of a 32 bit absolute address
+pub const R_RISCV_HI20: u32 = 26;
+/// Low 12 bits of a 32 bit absolute address, I-type
+pub const R_RISCV_LO12_I: u32 = 27;
+/// Low 12 bits of a 32 bit absolute address, S-type
+pub const R_RISCV_LO12_S: u32 = 28;
+/// High 20 bits of a TLS LE thread pointer offset
+pub const R_RISCV_TPREL_HI20: u32 = 29;
+/// Low 12 bits of a TLS LE thread pointer offset, I-type
+pub const R_RISCV_TPREL_LO12_I: u32 = 30;
+/// Low 12 bits of a TLS LE thread pointer offset, S-type
+pub const R_RISCV_TPREL_LO12_S: u32 = 31;
+/// Marks the thread pointer add of a TLS LE access
+pub const R_RISCV_TPREL_ADD: u32 = 32;
+/// 8 bit label addition
+pub const R_RISCV_ADD8: u32 = 33;
+/// 16 bit label addition
+pub const R_RISCV_ADD16: u32 = 34;
+/// 32 bit label addition
+pub const R_RISCV_ADD32: u32 = 35;
+/// 64 bit label addition
+pub const R_RISCV_ADD64: u32 = 36;
+/// 8 bit label subtraction
+pub const R_RISCV_SUB8: u32 = 37;
+/// 16 bit label subtraction
+pub const R_RISCV_SUB16: u32 = 38;
+/// 32 bit label subtraction
+pub const R_RISCV_SUB32: u32 = 39;
+/// 64 bit label subtraction
+pub const R_RISCV_SUB64: u32 = 40;
+/// 32 bit PC-relative offset to a GOT entry
+pub const R_RISCV_GOT32_PCREL: u32 = 41;
+/// Alignment statement, for linker relaxation
+pub const R_RISCV_ALIGN: u32 = 43;
+/// 8 bit PC-relative compressed branch offset
+pub const R_RISCV_RVC_BRANCH: u32 = 44;
+/// 11 bit PC-relative compressed jump offset
+pub const R_RISCV_RVC_JUMP: u32 = 45;
+/// Marks the preceding relocation as relaxable
+pub const R_RISCV_RELAX: u32 = 51;
+/// Local label subtraction, low 6 bits
+pub const R_RISCV_SUB6: u32 = 52;
+/// Local label assignment, low 6 bits
+pub const R_RISCV_SET6: u32 = 53;
+/// 8 bit local label assignment
+pub const R_RISCV_SET8: u32 = 54;
+/// 16 bit local label assignment
+pub const R_RISCV_SET16: u32 = 55;
+/// 32 bit local label assignment
+pub const R_RISCV_SET32: u32 = 56;
+/// 32 bit PC-relative offset
+pub const R_RISCV_32_PCREL: u32 = 57;
+/// Adjust indirectly by program base
+pub const R_RISCV_IRELATIVE: u32 = 58;
+/// 32 bit PC-relative offset to a PLT entry
+pub const R_RISCV_PLT32: u32 = 59;
+/// ULEB128 local label assignment
+pub const R_RISCV_SET_ULEB128: u32 = 60;
+/// ULEB128 local label subtraction
+pub const R_RISCV_SUB_ULEB128: u32 = 61;
+
+/// The name of the relocation type `typ` on `machine`, as `r_type_name` spells it, but without its `R_` prefix (e.g., `X86_64_GLOB_DAT`),
+/// except for MIPS, `R_X86_64_GOTPCRELX` and `R_X86_64_REX_GOTPCRELX`, and the `R_UNKNOWN_*` of types it doesn't know
 #[inline]
 pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
+    use elf::header::{EM_MIPS, EM_MIPS_RS3_LE, EM_MIPS_X, EM_X86_64};
+    let name = r_type_name(typ, machine);
+    match machine {
+        EM_MIPS | EM_MIPS_RS3_LE | EM_MIPS_X => name,
+        EM_X86_64 if typ == R_X86_64_GOTPCRELX || typ == R_X86_64_REX_GOTPCRELX => name,
+        _ if name.starts_with("R_UNKNOWN") => name,
+        _ => &name[2..],
+    }
+}
+
+/// The name of the relocation type `typ` on `machine`, spelled like its constant (e.g., `R_X86_64_GLOB_DAT`), or `R_UNKNOWN_*` if it isn't known
+pub fn r_type_name(typ: u32, machine: u16) -> &'static str {
     use elf::header::*;
     match machine {
         // x86
         EM_386 => { match typ {
-        R_386_NONE => "386_NONE",
-        R_386_32 => "386_32",
-        R_386_PC32 => "386_PC32",
-        R_386_GOT32 => "386_GOT32",
-        R_386_PLT32 => "386_PLT32",
-        R_386_COPY => "386_COPY",
-        R_386_GLOB_DAT => "386_GLOB_DAT",
-        R_386_JMP_SLOT => "386_JMP_SLOT",
-        R_386_RELATIVE => "386_RELATIVE",
-        R_386_GOTOFF => "386_GOTOFF",
-        R_386_GOTPC => "386_GOTPC",
-        R_386_32PLT => "386_32PLT",
-        R_386_TLS_TPOFF => "386_TLS_TPOFF",
-        R_386_TLS_IE => "386_TLS_IE",
-        R_386_TLS_GOTIE => "386_TLS_GOTIE",
-        R_386_TLS_LE => "386_TLS_LE",
-        R_386_TLS_GD => "386_TLS_GD",
-        R_386_TLS_LDM => "386_TLS_LDM",
-        R_386_16 => "386_16",
-        R_386_PC16 => "386_PC16",
-        R_386_8 => "386_8",
-        R_386_PC8 => "386_PC8",
-        R_386_TLS_GD_32 => "386_TLS_GD_32",
-        R_386_TLS_GD_PUSH => "386_TLS_GD_PUSH",
-        R_386_TLS_GD_CALL => "386_TLS_GD_CALL",
-        R_386_TLS_GD_POP => "386_TLS_GD_POP",
-        R_386_TLS_LDM_32 => "386_TLS_LDM_32",
-        R_386_TLS_LDM_PUSH => "386_TLS_LDM_PUSH",
-        R_386_TLS_LDM_CALL => "386_TLS_LDM_CALL",
-        R_386_TLS_LDM_POP => "386_TLS_LDM_POP",
-        R_386_TLS_LDO_32 => "386_TLS_LDO_32",
-        R_386_TLS_IE_32 => "386_TLS_IE_32",
-        R_386_TLS_LE_32 => "386_TLS_LE_32",
-        R_386_TLS_DTPMOD32 => "386_TLS_DTPMOD32",
-        R_386_TLS_DTPOFF32 => "386_TLS_DTPOFF32",
-        R_386_TLS_TPOFF32 => "386_TLS_TPOFF32",
-        R_386_SIZE32 => "386_SIZE32",
-        R_386_TLS_GOTDESC => "386_TLS_GOTDESC",
-        R_386_TLS_DESC_CALL => "386_TLS_DESC_CALL",
-        R_386_TLS_DESC => "386_TLS_DESC",
-        R_386_IRELATIVE => "386_IRELATIVE",
-        R_386_GOT32X => "386_GOT32X",
+        R_386_NONE => "R_386_NONE",
+        R_386_32 => "R_386_32",
+        R_386_PC32 => "R_386_PC32",
+        R_386_GOT32 => "R_386_GOT32",
+        R_386_PLT32 => "R_386_PLT32",
+        R_386_COPY => "R_386_COPY",
+        R_386_GLOB_DAT => "R_386_GLOB_DAT",
+        R_386_JMP_SLOT => "R_386_JMP_SLOT",
+        R_386_RELATIVE => "R_386_RELATIVE",
+        R_386_GOTOFF => "R_386_GOTOFF",
+        R_386_GOTPC => "R_386_GOTPC",
+        R_386_32PLT => "R_386_32PLT",
+        R_386_TLS_TPOFF => "R_386_TLS_TPOFF",
+        R_386_TLS_IE => "R_386_TLS_IE",
+        R_386_TLS_GOTIE => "R_386_TLS_GOTIE",
+        R_386_TLS_LE => "R_386_TLS_LE",
+        R_386_TLS_GD => "R_386_TLS_GD",
+        R_386_TLS_LDM => "R_386_TLS_LDM",
+        R_386_16 => "R_386_16",
+        R_386_PC16 => "R_386_PC16",
+        R_386_8 => "R_386_8",
+        R_386_PC8 => "R_386_PC8",
+        R_386_TLS_GD_32 => "R_386_TLS_GD_32",
+        R_386_TLS_GD_PUSH => "R_386_TLS_GD_PUSH",
+        R_386_TLS_GD_CALL => "R_386_TLS_GD_CALL",
+        R_386_TLS_GD_POP => "R_386_TLS_GD_POP",
+        R_386_TLS_LDM_32 => "R_386_TLS_LDM_32",
+        R_386_TLS_LDM_PUSH => "R_386_TLS_LDM_PUSH",
+        R_386_TLS_LDM_CALL => "R_386_TLS_LDM_CALL",
+        R_386_TLS_LDM_POP => "R_386_TLS_LDM_POP",
+        R_386_TLS_LDO_32 => "R_386_TLS_LDO_32",
+        R_386_TLS_IE_32 => "R_386_TLS_IE_32",
+        R_386_TLS_LE_32 => "R_386_TLS_LE_32",
+        R_386_TLS_DTPMOD32 => "R_386_TLS_DTPMOD32",
+        R_386_TLS_DTPOFF32 => "R_386_TLS_DTPOFF32",
+        R_386_TLS_TPOFF32 => "R_386_TLS_TPOFF32",
+        R_386_SIZE32 => "R_386_SIZE32",
+        R_386_TLS_GOTDESC => "R_386_TLS_GOTDESC",
+        R_386_TLS_DESC_CALL => "R_386_TLS_DESC_CALL",
+        R_386_TLS_DESC => "R_386_TLS_DESC",
+        R_386_IRELATIVE => "R_386_IRELATIVE",
+        R_386_GOT32X => "R_386_GOT32X",
         _ => "R_UNKNOWN_386",
         }},
         EM_X86_64 => { match typ {
-        R_X86_64_64 => "X86_64_64",
-        R_X86_64_PC32 => "X86_64_PC32",
-        R_X86_64_GOT32 => "X86_64_GOT32",
-        R_X86_64_PLT32 => "X86_64_PLT32",
-        R_X86_64_COPY => "X86_64_COPY",
-        R_X86_64_GLOB_DAT => "X86_64_GLOB_DAT",
-        R_X86_64_JUMP_SLOT => "X86_64_JUMP_SLOT",
-        R_X86_64_RELATIVE => "X86_64_RELATIVE",
-        R_X86_64_GOTPCREL => "X86_64_GOTPCREL",
-        R_X86_64_32 => "X86_64_32",
-        R_X86_64_32S => "X86_64_32S",
-        R_X86_64_16 => "X86_64_16",
-        R_X86_64_PC16 => "X86_64_PC16",
-        R_X86_64_8 => "X86_64_8",
-        R_X86_64_PC8 => "X86_64_PC8",
-        R_X86_64_DTPMOD64 => "X86_64_DTPMOD64",
-        R_X86_64_DTPOFF64 => "X86_64_DTPOFF64",
-        R_X86_64_TPOFF64 => "X86_64_TPOFF64",
-        R_X86_64_TLSGD => "X86_64_TLSGD",
-        R_X86_64_TLSLD => "X86_64_TLSLD",
-        R_X86_64_DTPOFF32 => "X86_64_DTPOFF32",
-        R_X86_64_GOTTPOFF => "X86_64_GOTTPOFF",
-        R_X86_64_TPOFF32 => "X86_64_TPOFF32",
-        R_X86_64_PC64 => "X86_64_PC64",
-        R_X86_64_GOTOFF64 => "X86_64_GOTOFF64",
-        R_X86_64_GOTPC32 => "X86_64_GOTPC32",
-        R_X86_64_GOT64 => "X86_64_GOT64",
-        R_X86_64_GOTPCREL64 => "X86_64_GOTPCREL64",
-        R_X86_64_GOTPC64 => "X86_64_GOTPC64",
-        R_X86_64_GOTPLT64 => "X86_64_GOTPLT64",
-        R_X86_64_PLTOFF64 => "X86_64_PLTOFF64",
-        R_X86_64_SIZE32 => "X86_64_SIZE32",
-        R_X86_64_SIZE64 => "X86_64_SIZE64",
-        R_X86_64_GOTPC32_TLSDESC => "X86_64_GOTPC32_TLSDESC",
-        R_X86_64_TLSDESC_CALL => "X86_64_TLSDESC_CALL",
-        R_X86_64_TLSDESC => "X86_64_TLSDESC",
-        R_X86_64_IRELATIVE => "X86_64_IRELATIVE",
-        R_X86_64_RELATIVE64 => "X86_64_RELATIVE64",
+        R_X86_64_NONE => "R_X86_64_NONE",
+        R_X86_64_64 => "R_X86_64_64",
+        R_X86_64_PC32 => "R_X86_64_PC32",
+        R_X86_64_GOT32 => "R_X86_64_GOT32",
+        R_X86_64_PLT32 => "R_X86_64_PLT32",
+        R_X86_64_COPY => "R_X86_64_COPY",
+        R_X86_64_GLOB_DAT => "R_X86_64_GLOB_DAT",
+        R_X86_64_JUMP_SLOT => "R_X86_64_JUMP_SLOT",
+        R_X86_64_RELATIVE => "R_X86_64_RELATIVE",
+        R_X86_64_GOTPCREL => "R_X86_64_GOTPCREL",
+        R_X86_64_32 => "R_X86_64_32",
+        R_X86_64_32S => "R_X86_64_32S",
+        R_X86_64_16 => "R_X86_64_16",
+        R_X86_64_PC16 => "R_X86_64_PC16",
+        R_X86_64_8 => "R_X86_64_8",
+        R_X86_64_PC8 => "R_X86_64_PC8",
+        R_X86_64_DTPMOD64 => "R_X86_64_DTPMOD64",
+        R_X86_64_DTPOFF64 => "R_X86_64_DTPOFF64",
+        R_X86_64_TPOFF64 => "R_X86_64_TPOFF64",
+        R_X86_64_TLSGD => "R_X86_64_TLSGD",
+        R_X86_64_TLSLD => "R_X86_64_TLSLD",
+        R_X86_64_DTPOFF32 => "R_X86_64_DTPOFF32",
+        R_X86_64_GOTTPOFF => "R_X86_64_GOTTPOFF",
+        R_X86_64_TPOFF32 => "R_X86_64_TPOFF32",
+        R_X86_64_PC64 => "R_X86_64_PC64",
+        R_X86_64_GOTOFF64 => "R_X86_64_GOTOFF64",
+        R_X86_64_GOTPC32 => "R_X86_64_GOTPC32",
+        R_X86_64_GOT64 => "R_X86_64_GOT64",
+        R_X86_64_GOTPCREL64 => "R_X86_64_GOTPCREL64",
+        R_X86_64_GOTPC64 => "R_X86_64_GOTPC64",
+        R_X86_64_GOTPLT64 => "R_X86_64_GOTPLT64",
+        R_X86_64_PLTOFF64 => "R_X86_64_PLTOFF64",
+        R_X86_64_SIZE32 => "R_X86_64_SIZE32",
+        R_X86_64_SIZE64 => "R_X86_64_SIZE64",
+        R_X86_64_GOTPC32_TLSDESC => "R_X86_64_GOTPC32_TLSDESC",
+        R_X86_64_TLSDESC_CALL => "R_X86_64_TLSDESC_CALL",
+        R_X86_64_TLSDESC => "R_X86_64_TLSDESC",
+        R_X86_64_IRELATIVE => "R_X86_64_IRELATIVE",
+        R_X86_64_RELATIVE64 => "R_X86_64_RELATIVE64",
         R_X86_64_GOTPCRELX => "R_X86_64_GOTPCRELX",
         R_X86_64_REX_GOTPCRELX => "R_X86_64_REX_GOTPCRELX",
         _ => "R_UNKNOWN_X86_64",
         }},
         // openrisc
         EM_OPENRISC => { match typ {
-        R_OR1K_NONE => "OR1K_NONE",
-        R_OR1K_32 => "OR1K_32",
-        R_OR1K_16 => "OR1K_16",
-        R_OR1K_8 => "OR1K_8",
-        R_OR1K_LO_16_IN_INSN => "OR1K_LO_16_IN_INSN",
-        R_OR1K_HI_16_IN_INSN => "OR1K_HI_16_IN_INSN",
-        R_OR1K_INSN_REL_26 => "OR1K_INSN_REL_26",
-        R_OR1K_GNU_VTENTRY => "OR1K_GNU_VTENTRY",
-        R_OR1K_GNU_VTINHERIT => "OR1K_GNU_VTINHERIT",
-        R_OR1K_32_PCREL => "OR1K_32_PCREL",
-        R_OR1K_16_PCREL => "OR1K_16_PCREL",
-        R_OR1K_8_PCREL => "OR1K_8_PCREL",
-        R_OR1K_GOTPC_HI16 => "OR1K_GOTPC_HI16",
-        R_OR1K_GOTPC_LO16 => "OR1K_GOTPC_LO16",
-        R_OR1K_GOT16 => "OR1K_GOT16",
-        R_OR1K_PLT26 => "OR1K_PLT26",
-        R_OR1K_GOTOFF_HI16 => "OR1K_GOTOFF_HI16",
-        R_OR1K_GOTOFF_LO16 => "OR1K_GOTOFF_LO16",
-        R_OR1K_COPY => "OR1K_COPY",
-        R_OR1K_GLOB_DAT => "OR1K_GLOB_DAT",
-        R_OR1K_JMP_SLOT => "OR1K_JMP_SLOT",
-        R_OR1K_RELATIVE => "OR1K_RELATIVE",
-        R_OR1K_TLS_GD_HI16 => "OR1K_TLS_GD_HI16",
-        R_OR1K_TLS_GD_LO16 => "OR1K_TLS_GD_LO16",
-        R_OR1K_TLS_LDM_HI16 => "OR1K_TLS_LDM_HI16",
-        R_OR1K_TLS_LDM_LO16 => "OR1K_TLS_LDM_LO16",
-        R_OR1K_TLS_LDO_HI16 => "OR1K_TLS_LDO_HI16",
-        R_OR1K_TLS_LDO_LO16 => "OR1K_TLS_LDO_LO16",
-        R_OR1K_TLS_IE_HI16 => "OR1K_TLS_IE_HI16",
-        R_OR1K_TLS_IE_LO16 => "OR1K_TLS_IE_LO16",
-        R_OR1K_TLS_LE_HI16 => "OR1K_TLS_LE_HI16",
-        R_OR1K_TLS_LE_LO16 => "OR1K_TLS_LE_LO16",
-        R_OR1K_TLS_TPOFF => "OR1K_TLS_TPOFF",
-        R_OR1K_TLS_DTPOFF => "OR1K_TLS_DTPOFF",
-        R_OR1K_TLS_DTPMOD => "OR1K_TLS_DTPMOD",
+        R_OR1K_NONE => "R_OR1K_NONE",
+        R_OR1K_32 => "R_OR1K_32",
+        R_OR1K_16 => "R_OR1K_16",
+        R_OR1K_8 => "R_OR1K_8",
+        R_OR1K_LO_16_IN_INSN => "R_OR1K_LO_16_IN_INSN",
+        R_OR1K_HI_16_IN_INSN => "R_OR1K_HI_16_IN_INSN",
+        R_OR1K_INSN_REL_26 => "R_OR1K_INSN_REL_26",
+        R_OR1K_GNU_VTENTRY => "R_OR1K_GNU_VTENTRY",
+        R_OR1K_GNU_VTINHERIT => "R_OR1K_GNU_VTINHERIT",
+        R_OR1K_32_PCREL => "R_OR1K_32_PCREL",
+        R_OR1K_16_PCREL => "R_OR1K_16_PCREL",
+        R_OR1K_8_PCREL => "R_OR1K_8_PCREL",
+        R_OR1K_GOTPC_HI16 => "R_OR1K_GOTPC_HI16",
+        R_OR1K_GOTPC_LO16 => "R_OR1K_GOTPC_LO16",
+        R_OR1K_GOT16 => "R_OR1K_GOT16",
+        R_OR1K_PLT26 => "R_OR1K_PLT26",
+        R_OR1K_GOTOFF_HI16 => "R_OR1K_GOTOFF_HI16",
+        R_OR1K_GOTOFF_LO16 => "R_OR1K_GOTOFF_LO16",
+        R_OR1K_COPY => "R_OR1K_COPY",
+        R_OR1K_GLOB_DAT => "R_OR1K_GLOB_DAT",
+        R_OR1K_JMP_SLOT => "R_OR1K_JMP_SLOT",
+        R_OR1K_RELATIVE => "R_OR1K_RELATIVE",
+        R_OR1K_TLS_GD_HI16 => "R_OR1K_TLS_GD_HI16",
+        R_OR1K_TLS_GD_LO16 => "R_OR1K_TLS_GD_LO16",
+        R_OR1K_TLS_LDM_HI16 => "R_OR1K_TLS_LDM_HI16",
+        R_OR1K_TLS_LDM_LO16 => "R_OR1K_TLS_LDM_LO16",
+        R_OR1K_TLS_LDO_HI16 => "R_OR1K_TLS_LDO_HI16",
+        R_OR1K_TLS_LDO_LO16 => "R_OR1K_TLS_LDO_LO16",
+        R_OR1K_TLS_IE_HI16 => "R_OR1K_TLS_IE_HI16",
+        R_OR1K_TLS_IE_LO16 => "R_OR1K_TLS_IE_LO16",
+        R_OR1K_TLS_LE_HI16 => "R_OR1K_TLS_LE_HI16",
+        R_OR1K_TLS_LE_LO16 => "R_OR1K_TLS_LE_LO16",
+        R_OR1K_TLS_TPOFF => "R_OR1K_TLS_TPOFF",
+        R_OR1K_TLS_DTPOFF => "R_OR1K_TLS_DTPOFF",
+        R_OR1K_TLS_DTPMOD => "R_OR1K_TLS_DTPMOD",
         _ => "R_UNKNOWN_OR1K",
         }},
         // arm64
         EM_AARCH64 => { match typ {
-        R_AARCH64_P32_ABS32 => "AARCH64_P32_ABS32",
-        R_AARCH64_P32_COPY => "AARCH64_P32_COPY",
-        R_AARCH64_P32_GLOB_DAT => "AARCH64_P32_GLOB_DAT",
-        R_AARCH64_P32_JUMP_SLOT => "AARCH64_P32_JUMP_SLOT",
-        R_AARCH64_P32_RELATIVE => "AARCH64_P32_RELATIVE",
-        R_AARCH64_P32_TLS_DTPMOD => "AARCH64_P32_TLS_DTPMOD",
-        R_AARCH64_P32_TLS_DTPREL => "AARCH64_P32_TLS_DTPREL",
-        R_AARCH64_P32_TLS_TPREL => "AARCH64_P32_TLS_TPREL",
-        R_AARCH64_P32_TLSDESC => "AARCH64_P32_TLSDESC",
-        R_AARCH64_P32_IRELATIVE => "AARCH64_P32_IRELATIVE",
-        R_AARCH64_ABS64 => "AARCH64_ABS64",
-        R_AARCH64_ABS32 => "AARCH64_ABS32",
-        R_AARCH64_ABS16 => "AARCH64_ABS16",
-        R_AARCH64_PREL64 => "AARCH64_PREL64",
-        R_AARCH64_PREL32 => "AARCH64_PREL32",
-        R_AARCH64_PREL16 => "AARCH64_PREL16",
-        R_AARCH64_MOVW_UABS_G0 => "AARCH64_MOVW_UABS_G0",
-        R_AARCH64_MOVW_UABS_G0_NC => "AARCH64_MOVW_UABS_G0_NC",
-        R_AARCH64_MOVW_UABS_G1 => "AARCH64_MOVW_UABS_G1",
-        R_AARCH64_MOVW_UABS_G1_NC => "AARCH64_MOVW_UABS_G1_NC",
-        R_AARCH64_MOVW_UABS_G2 => "AARCH64_MOVW_UABS_G2",
-        R_AARCH64_MOVW_UABS_G2_NC => "AARCH64_MOVW_UABS_G2_NC",
-        R_AARCH64_MOVW_UABS_G3 => "AARCH64_MOVW_UABS_G3",
-        R_AARCH64_MOVW_SABS_G0 => "AARCH64_MOVW_SABS_G0",
-        R_AARCH64_MOVW_SABS_G1 => "AARCH64_MOVW_SABS_G1",
-        R_AARCH64_MOVW_SABS_G2 => "AARCH64_MOVW_SABS_G2",
-        R_AARCH64_LD_PREL_LO19 => "AARCH64_LD_PREL_LO19",
-        R_AARCH64_ADR_PREL_LO21 => "AARCH64_ADR_PREL_LO21",
-        R_AARCH64_ADR_PREL_PG_HI21 => "AARCH64_ADR_PREL_PG_HI21",
-        R_AARCH64_ADR_PREL_PG_HI21_NC => "AARCH64_ADR_PREL_PG_HI21_NC",
-        R_AARCH64_ADD_ABS_LO12_NC => "AARCH64_ADD_ABS_LO12_NC",
-        R_AARCH64_LDST8_ABS_LO12_NC => "AARCH64_LDST8_ABS_LO12_NC",
-        R_AARCH64_TSTBR14 => "AARCH64_TSTBR14",
-        R_AARCH64_CONDBR19 => "AARCH64_CONDBR19",
-        R_AARCH64_JUMP26 => "AARCH64_JUMP26",
-        R_AARCH64_CALL26 => "AARCH64_CALL26",
-        R_AARCH64_LDST16_ABS_LO12_NC => "AARCH64_LDST16_ABS_LO12_NC",
-        R_AARCH64_LDST32_ABS_LO12_NC => "AARCH64_LDST32_ABS_LO12_NC",
-        R_AARCH64_LDST64_ABS_LO12_NC => "AARCH64_LDST64_ABS_LO12_NC",
-        R_AARCH64_MOVW_PREL_G0 => "AARCH64_MOVW_PREL_G0",
-        R_AARCH64_MOVW_PREL_G0_NC => "AARCH64_MOVW_PREL_G0_NC",
-        R_AARCH64_MOVW_PREL_G1 => "AARCH64_MOVW_PREL_G1",
-        R_AARCH64_MOVW_PREL_G1_NC => "AARCH64_MOVW_PREL_G1_NC",
-        R_AARCH64_MOVW_PREL_G2 => "AARCH64_MOVW_PREL_G2",
-        R_AARCH64_MOVW_PREL_G2_NC => "AARCH64_MOVW_PREL_G2_NC",
-        R_AARCH64_MOVW_PREL_G3 => "AARCH64_MOVW_PREL_G3",
-        R_AARCH64_LDST128_ABS_LO12_NC => "AARCH64_LDST128_ABS_LO12_NC",
-        R_AARCH64_MOVW_GOTOFF_G0 => "AARCH64_MOVW_GOTOFF_G0",
-        R_AARCH64_MOVW_GOTOFF_G0_NC => "AARCH64_MOVW_GOTOFF_G0_NC",
-        R_AARCH64_MOVW_GOTOFF_G1 => "AARCH64_MOVW_GOTOFF_G1",
-        R_AARCH64_MOVW_GOTOFF_G1_NC => "AARCH64_MOVW_GOTOFF_G1_NC",
-        R_AARCH64_MOVW_GOTOFF_G2 => "AARCH64_MOVW_GOTOFF_G2",
-        R_AARCH64_MOVW_GOTOFF_G2_NC => "AARCH64_MOVW_GOTOFF_G2_NC",
-        R_AARCH64_MOVW_GOTOFF_G3 => "AARCH64_MOVW_GOTOFF_G3",
-        R_AARCH64_GOTREL64 => "AARCH64_GOTREL64",
-        R_AARCH64_GOTREL32 => "AARCH64_GOTREL32",
-        R_AARCH64_GOT_LD_PREL19 => "AARCH64_GOT_LD_PREL19",
-        R_AARCH64_LD64_GOTOFF_LO15 => "AARCH64_LD64_GOTOFF_LO15",
-        R_AARCH64_ADR_GOT_PAGE => "AARCH64_ADR_GOT_PAGE",
-        R_AARCH64_LD64_GOT_LO12_NC => "AARCH64_LD64_GOT_LO12_NC",
-        R_AARCH64_LD64_GOTPAGE_LO15 => "AARCH64_LD64_GOTPAGE_LO15",
-        R_AARCH64_TLSGD_ADR_PREL21 => "AARCH64_TLSGD_ADR_PREL21",
-        R_AARCH64_TLSGD_ADR_PAGE21 => "AARCH64_TLSGD_ADR_PAGE21",
-        R_AARCH64_TLSGD_ADD_LO12_NC => "AARCH64_TLSGD_ADD_LO12_NC",
-        R_AARCH64_TLSGD_MOVW_G1 => "AARCH64_TLSGD_MOVW_G1",
-        R_AARCH64_TLSGD_MOVW_G0_NC => "AARCH64_TLSGD_MOVW_G0_NC",
-        R_AARCH64_TLSLD_ADR_PREL21 => "AARCH64_TLSLD_ADR_PREL21",
-        R_AARCH64_TLSLD_ADR_PAGE21 => "AARCH64_TLSLD_ADR_PAGE21",
-        R_AARCH64_TLSLD_ADD_LO12_NC => "AARCH64_TLSLD_ADD_LO12_NC",
-        R_AARCH64_TLSLD_MOVW_G1 => "AARCH64_TLSLD_MOVW_G1",
-        R_AARCH64_TLSLD_MOVW_G0_NC => "AARCH64_TLSLD_MOVW_G0_NC",
-        R_AARCH64_TLSLD_LD_PREL19 => "AARCH64_TLSLD_LD_PREL19",
-        R_AARCH64_TLSLD_MOVW_DTPREL_G2 => "AARCH64_TLSLD_MOVW_DTPREL_G2",
-        R_AARCH64_TLSLD_MOVW_DTPREL_G1 => "AARCH64_TLSLD_MOVW_DTPREL_G1",
-        R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC => "AARCH64_TLSLD_MOVW_DTPREL_G1_NC",
-        R_AARCH64_TLSLD_MOVW_DTPREL_G0 => "AARCH64_TLSLD_MOVW_DTPREL_G0",
-        R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC => "AARCH64_TLSLD_MOVW_DTPREL_G0_NC",
-        R_AARCH64_TLSLD_ADD_DTPREL_HI12 => "AARCH64_TLSLD_ADD_DTPREL_HI12",
-        R_AARCH64_TLSLD_ADD_DTPREL_LO12 => "AARCH64_TLSLD_ADD_DTPREL_LO12",
-        R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC => "AARCH64_TLSLD_ADD_DTPREL_LO12_NC",
-        R_AARCH64_TLSLD_LDST8_DTPREL_LO12 => "AARCH64_TLSLD_LDST8_DTPREL_LO12",
-        R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC => "AARCH64_TLSLD_LDST8_DTPREL_LO12_NC",
-        R_AARCH64_TLSLD_LDST16_DTPREL_LO12 => "AARCH64_TLSLD_LDST16_DTPREL_LO12",
-        R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC => "AARCH64_TLSLD_LDST16_DTPREL_LO12_NC",
-        R_AARCH64_TLSLD_LDST32_DTPREL_LO12 => "AARCH64_TLSLD_LDST32_DTPREL_LO12",
-        R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC => "AARCH64_TLSLD_LDST32_DTPREL_LO12_NC",
-        R_AARCH64_TLSLD_LDST64_DTPREL_LO12 => "AARCH64_TLSLD_LDST64_DTPREL_LO12",
-        R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC => "AARCH64_TLSLD_LDST64_DTPREL_LO12_NC",
-        R_AARCH64_TLSIE_MOVW_GOTTPREL_G1 => "AARCH64_TLSIE_MOVW_GOTTPREL_G1",
-        R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC => "AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC",
-        R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21 => "AARCH64_TLSIE_ADR_GOTTPREL_PAGE21",
-        R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC => "AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC",
-        R_AARCH64_TLSIE_LD_GOTTPREL_PREL19 => "AARCH64_TLSIE_LD_GOTTPREL_PREL19",
-        R_AARCH64_TLSLE_MOVW_TPREL_G2 => "AARCH64_TLSLE_MOVW_TPREL_G2",
-        R_AARCH64_TLSLE_MOVW_TPREL_G1 => "AARCH64_TLSLE_MOVW_TPREL_G1",
-        R_AARCH64_TLSLE_MOVW_TPREL_G1_NC => "AARCH64_TLSLE_MOVW_TPREL_G1_NC",
-        R_AARCH64_TLSLE_MOVW_TPREL_G0 => "AARCH64_TLSLE_MOVW_TPREL_G0",
-        R_AARCH64_TLSLE_MOVW_TPREL_G0_NC => "AARCH64_TLSLE_MOVW_TPREL_G0_NC",
-        R_AARCH64_TLSLE_ADD_TPREL_HI12 => "AARCH64_TLSLE_ADD_TPREL_HI12",
-        R_AARCH64_TLSLE_ADD_TPREL_LO12 => "AARCH64_TLSLE_ADD_TPREL_LO12",
-        R_AARCH64_TLSLE_ADD_TPREL_LO12_NC => "AARCH64_TLSLE_ADD_TPREL_LO12_NC",
-        R_AARCH64_TLSLE_LDST8_TPREL_LO12 => "AARCH64_TLSLE_LDST8_TPREL_LO12",
-        R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC => "AARCH64_TLSLE_LDST8_TPREL_LO12_NC",
-        R_AARCH64_TLSLE_LDST16_TPREL_LO12 => "AARCH64_TLSLE_LDST16_TPREL_LO12",
-        R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC => "AARCH64_TLSLE_LDST16_TPREL_LO12_NC",
-        R_AARCH64_TLSLE_LDST32_TPREL_LO12 => "AARCH64_TLSLE_LDST32_TPREL_LO12",
-        R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC => "AARCH64_TLSLE_LDST32_TPREL_LO12_NC",
-        R_AARCH64_TLSLE_LDST64_TPREL_LO12 => "AARCH64_TLSLE_LDST64_TPREL_LO12",
-        R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC => "AARCH64_TLSLE_LDST64_TPREL_LO12_NC",
-        R_AARCH64_TLSDESC_LD_PREL19 => "AARCH64_TLSDESC_LD_PREL19",
-        R_AARCH64_TLSDESC_ADR_PREL21 => "AARCH64_TLSDESC_ADR_PREL21",
-        R_AARCH64_TLSDESC_ADR_PAGE21 => "AARCH64_TLSDESC_ADR_PAGE21",
-        R_AARCH64_TLSDESC_LD64_LO12 => "AARCH64_TLSDESC_LD64_LO12",
-        R_AARCH64_TLSDESC_ADD_LO12 => "AARCH64_TLSDESC_ADD_LO12",
-        R_AARCH64_TLSDESC_OFF_G1 => "AARCH64_TLSDESC_OFF_G1",
-        R_AARCH64_TLSDESC_OFF_G0_NC => "AARCH64_TLSDESC_OFF_G0_NC",
-        R_AARCH64_TLSDESC_LDR => "AARCH64_TLSDESC_LDR",
-        R_AARCH64_TLSDESC_ADD => "AARCH64_TLSDESC_ADD",
-        R_AARCH64_TLSDESC_CALL => "AARCH64_TLSDESC_CALL",
-        R_AARCH64_TLSLE_LDST128_TPREL_LO12 => "AARCH64_TLSLE_LDST128_TPREL_LO12",
-        R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC => "AARCH64_TLSLE_LDST128_TPREL_LO12_NC",
-        R_AARCH64_TLSLD_LDST128_DTPREL_LO12 => "AARCH64_TLSLD_LDST128_DTPREL_LO12",
-        R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC => "AARCH64_TLSLD_LDST128_DTPREL_LO12_NC",
-        R_AARCH64_COPY => "AARCH64_COPY",
-        R_AARCH64_GLOB_DAT => "AARCH64_GLOB_DAT",
-        R_AARCH64_JUMP_SLOT => "AARCH64_JUMP_SLOT",
-        R_AARCH64_RELATIVE => "AARCH64_RELATIVE",
-        R_AARCH64_TLS_DTPMOD => "AARCH64_TLS_DTPMOD",
-        R_AARCH64_TLS_DTPREL => "AARCH64_TLS_DTPREL",
-        R_AARCH64_TLS_TPREL => "AARCH64_TLS_TPREL",
-        R_AARCH64_TLSDESC => "AARCH64_TLSDESC",
-        R_AARCH64_IRELATIVE => "AARCH64_IRELATIVE",
+        R_AARCH64_NONE => "R_AARCH64_NONE",
+        R_AARCH64_P32_ABS32 => "R_AARCH64_P32_ABS32",
+        R_AARCH64_P32_COPY => "R_AARCH64_P32_COPY",
+        R_AARCH64_P32_GLOB_DAT => "R_AARCH64_P32_GLOB_DAT",
+        R_AARCH64_P32_JUMP_SLOT => "R_AARCH64_P32_JUMP_SLOT",
+        R_AARCH64_P32_RELATIVE => "R_AARCH64_P32_RELATIVE",
+        R_AARCH64_P32_TLS_DTPMOD => "R_AARCH64_P32_TLS_DTPMOD",
+        R_AARCH64_P32_TLS_DTPREL => "R_AARCH64_P32_TLS_DTPREL",
+        R_AARCH64_P32_TLS_TPREL => "R_AARCH64_P32_TLS_TPREL",
+        R_AARCH64_P32_TLSDESC => "R_AARCH64_P32_TLSDESC",
+        R_AARCH64_P32_IRELATIVE => "R_AARCH64_P32_IRELATIVE",
+        R_AARCH64_ABS64 => "R_AARCH64_ABS64",
+        R_AARCH64_ABS32 => "R_AARCH64_ABS32",
+        R_AARCH64_ABS16 => "R_AARCH64_ABS16",
+        R_AARCH64_PREL64 => "R_AARCH64_PREL64",
+        R_AARCH64_PREL32 => "R_AARCH64_PREL32",
+        R_AARCH64_PREL16 => "R_AARCH64_PREL16",
+        R_AARCH64_MOVW_UABS_G0 => "R_AARCH64_MOVW_UABS_G0",
+        R_AARCH64_MOVW_UABS_G0_NC => "R_AARCH64_MOVW_UABS_G0_NC",
+        R_AARCH64_MOVW_UABS_G1 => "R_AARCH64_MOVW_UABS_G1",
+        R_AARCH64_MOVW_UABS_G1_NC => "R_AARCH64_MOVW_UABS_G1_NC",
+        R_AARCH64_MOVW_UABS_G2 => "R_AARCH64_MOVW_UABS_G2",
+        R_AARCH64_MOVW_UABS_G2_NC => "R_AARCH64_MOVW_UABS_G2_NC",
+        R_AARCH64_MOVW_UABS_G3 => "R_AARCH64_MOVW_UABS_G3",
+        R_AARCH64_MOVW_SABS_G0 => "R_AARCH64_MOVW_SABS_G0",
+        R_AARCH64_MOVW_SABS_G1 => "R_AARCH64_MOVW_SABS_G1",
+        R_AARCH64_MOVW_SABS_G2 => "R_AARCH64_MOVW_SABS_G2",
+        R_AARCH64_LD_PREL_LO19 => "R_AARCH64_LD_PREL_LO19",
+        R_AARCH64_ADR_PREL_LO21 => "R_AARCH64_ADR_PREL_LO21",
+        R_AARCH64_ADR_PREL_PG_HI21 => "R_AARCH64_ADR_PREL_PG_HI21",
+        R_AARCH64_ADR_PREL_PG_HI21_NC => "R_AARCH64_ADR_PREL_PG_HI21_NC",
+        R_AARCH64_ADD_ABS_LO12_NC => "R_AARCH64_ADD_ABS_LO12_NC",
+        R_AARCH64_LDST8_ABS_LO12_NC => "R_AARCH64_LDST8_ABS_LO12_NC",
+        R_AARCH64_TSTBR14 => "R_AARCH64_TSTBR14",
+        R_AARCH64_CONDBR19 => "R_AARCH64_CONDBR19",
+        R_AARCH64_JUMP26 => "R_AARCH64_JUMP26",
+        R_AARCH64_CALL26 => "R_AARCH64_CALL26",
+        R_AARCH64_LDST16_ABS_LO12_NC => "R_AARCH64_LDST16_ABS_LO12_NC",
+        R_AARCH64_LDST32_ABS_LO12_NC => "R_AARCH64_LDST32_ABS_LO12_NC",
+        R_AARCH64_LDST64_ABS_LO12_NC => "R_AARCH64_LDST64_ABS_LO12_NC",
+        R_AARCH64_MOVW_PREL_G0 => "R_AARCH64_MOVW_PREL_G0",
+        R_AARCH64_MOVW_PREL_G0_NC => "R_AARCH64_MOVW_PREL_G0_NC",
+        R_AARCH64_MOVW_PREL_G1 => "R_AARCH64_MOVW_PREL_G1",
+        R_AARCH64_MOVW_PREL_G1_NC => "R_AARCH64_MOVW_PREL_G1_NC",
+        R_AARCH64_MOVW_PREL_G2 => "R_AARCH64_MOVW_PREL_G2",
+        R_AARCH64_MOVW_PREL_G2_NC => "R_AARCH64_MOVW_PREL_G2_NC",
+        R_AARCH64_MOVW_PREL_G3 => "R_AARCH64_MOVW_PREL_G3",
+        R_AARCH64_LDST128_ABS_LO12_NC => "R_AARCH64_LDST128_ABS_LO12_NC",
+        R_AARCH64_MOVW_GOTOFF_G0 => "R_AARCH64_MOVW_GOTOFF_G0",
+        R_AARCH64_MOVW_GOTOFF_G0_NC => "R_AARCH64_MOVW_GOTOFF_G0_NC",
+        R_AARCH64_MOVW_GOTOFF_G1 => "R_AARCH64_MOVW_GOTOFF_G1",
+        R_AARCH64_MOVW_GOTOFF_G1_NC => "R_AARCH64_MOVW_GOTOFF_G1_NC",
+        R_AARCH64_MOVW_GOTOFF_G2 => "R_AARCH64_MOVW_GOTOFF_G2",
+        R_AARCH64_MOVW_GOTOFF_G2_NC => "R_AARCH64_MOVW_GOTOFF_G2_NC",
+        R_AARCH64_MOVW_GOTOFF_G3 => "R_AARCH64_MOVW_GOTOFF_G3",
+        R_AARCH64_GOTREL64 => "R_AARCH64_GOTREL64",
+        R_AARCH64_GOTREL32 => "R_AARCH64_GOTREL32",
+        R_AARCH64_GOT_LD_PREL19 => "R_AARCH64_GOT_LD_PREL19",
+        R_AARCH64_LD64_GOTOFF_LO15 => "R_AARCH64_LD64_GOTOFF_LO15",
+        R_AARCH64_ADR_GOT_PAGE => "R_AARCH64_ADR_GOT_PAGE",
+        R_AARCH64_LD64_GOT_LO12_NC => "R_AARCH64_LD64_GOT_LO12_NC",
+        R_AARCH64_LD64_GOTPAGE_LO15 => "R_AARCH64_LD64_GOTPAGE_LO15",
+        R_AARCH64_TLSGD_ADR_PREL21 => "R_AARCH64_TLSGD_ADR_PREL21",
+        R_AARCH64_TLSGD_ADR_PAGE21 => "R_AARCH64_TLSGD_ADR_PAGE21",
+        R_AARCH64_TLSGD_ADD_LO12_NC => "R_AARCH64_TLSGD_ADD_LO12_NC",
+        R_AARCH64_TLSGD_MOVW_G1 => "R_AARCH64_TLSGD_MOVW_G1",
+        R_AARCH64_TLSGD_MOVW_G0_NC => "R_AARCH64_TLSGD_MOVW_G0_NC",
+        R_AARCH64_TLSLD_ADR_PREL21 => "R_AARCH64_TLSLD_ADR_PREL21",
+        R_AARCH64_TLSLD_ADR_PAGE21 => "R_AARCH64_TLSLD_ADR_PAGE21",
+        R_AARCH64_TLSLD_ADD_LO12_NC => "R_AARCH64_TLSLD_ADD_LO12_NC",
+        R_AARCH64_TLSLD_MOVW_G1 => "R_AARCH64_TLSLD_MOVW_G1",
+        R_AARCH64_TLSLD_MOVW_G0_NC => "R_AARCH64_TLSLD_MOVW_G0_NC",
+        R_AARCH64_TLSLD_LD_PREL19 => "R_AARCH64_TLSLD_LD_PREL19",
+        R_AARCH64_TLSLD_MOVW_DTPREL_G2 => "R_AARCH64_TLSLD_MOVW_DTPREL_G2",
+        R_AARCH64_TLSLD_MOVW_DTPREL_G1 => "R_AARCH64_TLSLD_MOVW_DTPREL_G1",
+        R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC => "R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC",
+        R_AARCH64_TLSLD_MOVW_DTPREL_G0 => "R_AARCH64_TLSLD_MOVW_DTPREL_G0",
+        R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC => "R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC",
+        R_AARCH64_TLSLD_ADD_DTPREL_HI12 => "R_AARCH64_TLSLD_ADD_DTPREL_HI12",
+        R_AARCH64_TLSLD_ADD_DTPREL_LO12 => "R_AARCH64_TLSLD_ADD_DTPREL_LO12",
+        R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC => "R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC",
+        R_AARCH64_TLSLD_LDST8_DTPREL_LO12 => "R_AARCH64_TLSLD_LDST8_DTPREL_LO12",
+        R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC => "R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC",
+        R_AARCH64_TLSLD_LDST16_DTPREL_LO12 => "R_AARCH64_TLSLD_LDST16_DTPREL_LO12",
+        R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC => "R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC",
+        R_AARCH64_TLSLD_LDST32_DTPREL_LO12 => "R_AARCH64_TLSLD_LDST32_DTPREL_LO12",
+        R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC => "R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC",
+        R_AARCH64_TLSLD_LDST64_DTPREL_LO12 => "R_AARCH64_TLSLD_LDST64_DTPREL_LO12",
+        R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC => "R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC",
+        R_AARCH64_TLSIE_MOVW_GOTTPREL_G1 => "R_AARCH64_TLSIE_MOVW_GOTTPREL_G1",
+        R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC => "R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC",
+        R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21 => "R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21",
+        R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC => "R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC",
+        R_AARCH64_TLSIE_LD_GOTTPREL_PREL19 => "R_AARCH64_TLSIE_LD_GOTTPREL_PREL19",
+        R_AARCH64_TLSLE_MOVW_TPREL_G2 => "R_AARCH64_TLSLE_MOVW_TPREL_G2",
+        R_AARCH64_TLSLE_MOVW_TPREL_G1 => "R_AARCH64_TLSLE_MOVW_TPREL_G1",
+        R_AARCH64_TLSLE_MOVW_TPREL_G1_NC => "R_AARCH64_TLSLE_MOVW_TPREL_G1_NC",
+        R_AARCH64_TLSLE_MOVW_TPREL_G0 => "R_AARCH64_TLSLE_MOVW_TPREL_G0",
+        R_AARCH64_TLSLE_MOVW_TPREL_G0_NC => "R_AARCH64_TLSLE_MOVW_TPREL_G0_NC",
+        R_AARCH64_TLSLE_ADD_TPREL_HI12 => "R_AARCH64_TLSLE_ADD_TPREL_HI12",
+        R_AARCH64_TLSLE_ADD_TPREL_LO12 => "R_AARCH64_TLSLE_ADD_TPREL_LO12",
+        R_AARCH64_TLSLE_ADD_TPREL_LO12_NC => "R_AARCH64_TLSLE_ADD_TPREL_LO12_NC",
+        R_AARCH64_TLSLE_LDST8_TPREL_LO12 => "R_AARCH64_TLSLE_LDST8_TPREL_LO12",
+        R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC => "R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC",
+        R_AARCH64_TLSLE_LDST16_TPREL_LO12 => "R_AARCH64_TLSLE_LDST16_TPREL_LO12",
+        R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC => "R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC",
+        R_AARCH64_TLSLE_LDST32_TPREL_LO12 => "R_AARCH64_TLSLE_LDST32_TPREL_LO12",
+        R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC => "R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC",
+        R_AARCH64_TLSLE_LDST64_TPREL_LO12 => "R_AARCH64_TLSLE_LDST64_TPREL_LO12",
+        R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC => "R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC",
+        R_AARCH64_TLSDESC_LD_PREL19 => "R_AARCH64_TLSDESC_LD_PREL19",
+        R_AARCH64_TLSDESC_ADR_PREL21 => "R_AARCH64_TLSDESC_ADR_PREL21",
+        R_AARCH64_TLSDESC_ADR_PAGE21 => "R_AARCH64_TLSDESC_ADR_PAGE21",
+        R_AARCH64_TLSDESC_LD64_LO12 => "R_AARCH64_TLSDESC_LD64_LO12",
+        R_AARCH64_TLSDESC_ADD_LO12 => "R_AARCH64_TLSDESC_ADD_LO12",
+        R_AARCH64_TLSDESC_OFF_G1 => "R_AARCH64_TLSDESC_OFF_G1",
+        R_AARCH64_TLSDESC_OFF_G0_NC => "R_AARCH64_TLSDESC_OFF_G0_NC",
+        R_AARCH64_TLSDESC_LDR => "R_AARCH64_TLSDESC_LDR",
+        R_AARCH64_TLSDESC_ADD => "R_AARCH64_TLSDESC_ADD",
+        R_AARCH64_TLSDESC_CALL => "R_AARCH64_TLSDESC_CALL",
+        R_AARCH64_TLSLE_LDST128_TPREL_LO12 => "R_AARCH64_TLSLE_LDST128_TPREL_LO12",
+        R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC => "R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC",
+        R_AARCH64_TLSLD_LDST128_DTPREL_LO12 => "R_AARCH64_TLSLD_LDST128_DTPREL_LO12",
+        R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC => "R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC",
+        R_AARCH64_COPY => "R_AARCH64_COPY",
+        R_AARCH64_GLOB_DAT => "R_AARCH64_GLOB_DAT",
+        R_AARCH64_JUMP_SLOT => "R_AARCH64_JUMP_SLOT",
+        R_AARCH64_RELATIVE => "R_AARCH64_RELATIVE",
+        R_AARCH64_TLS_DTPMOD => "R_AARCH64_TLS_DTPMOD",
+        R_AARCH64_TLS_DTPREL => "R_AARCH64_TLS_DTPREL",
+        R_AARCH64_TLS_TPREL => "R_AARCH64_TLS_TPREL",
+        R_AARCH64_TLSDESC => "R_AARCH64_TLSDESC",
+        R_AARCH64_IRELATIVE => "R_AARCH64_IRELATIVE",
          _ => "R_UNKNOWN_AARCH64",
         }},
         // arm
         EM_ARM => { match typ {
-        R_ARM_PC24 => "ARM_PC24",
-        R_ARM_ABS32 => "ARM_ABS32",
-        R_ARM_REL32 => "ARM_REL32",
-        R_ARM_PC13 => "ARM_PC13",
-        R_ARM_ABS16 => "ARM_ABS16",
-        R_ARM_ABS12 => "ARM_ABS12",
-        R_ARM_THM_ABS5 => "ARM_THM_ABS5",
-        R_ARM_ABS8 => "ARM_ABS8",
-        R_ARM_SBREL32 => "ARM_SBREL32",
-        R_ARM_THM_PC22 => "ARM_THM_PC22",
-        R_ARM_THM_PC8 => "ARM_THM_PC8",
-        R_ARM_AMP_VCALL9 => "ARM_AMP_VCALL9",
-        R_ARM_TLS_DESC => "ARM_TLS_DESC",
-        R_ARM_THM_SWI8 => "ARM_THM_SWI8",
-        R_ARM_XPC25 => "ARM_XPC25",
-        R_ARM_THM_XPC22 => "ARM_THM_XPC22",
-        R_ARM_TLS_DTPMOD32 => "ARM_TLS_DTPMOD32",
-        R_ARM_TLS_DTPOFF32 => "ARM_TLS_DTPOFF32",
-        R_ARM_TLS_TPOFF32 => "ARM_TLS_TPOFF32",
-        R_ARM_COPY => "ARM_COPY",
-        R_ARM_GLOB_DAT => "ARM_GLOB_DAT",
-        R_ARM_JUMP_SLOT => "ARM_JUMP_SLOT",
-        R_ARM_RELATIVE => "ARM_RELATIVE",
-        R_ARM_GOTOFF => "ARM_GOTOFF",
-        R_ARM_GOTPC => "ARM_GOTPC",
-        R_ARM_GOT32 => "ARM_GOT32",
-        R_ARM_PLT32 => "ARM_PLT32",
-        R_ARM_CALL => "ARM_CALL",
-        R_ARM_JUMP24 => "ARM_JUMP24",
-        R_ARM_THM_JUMP24 => "ARM_THM_JUMP24",
-        R_ARM_BASE_ABS => "ARM_BASE_ABS",
-        R_ARM_ALU_PCREL_7_0 => "ARM_ALU_PCREL_7_0",
-        R_ARM_ALU_PCREL_15_8 => "ARM_ALU_PCREL_15_8",
-        R_ARM_ALU_PCREL_23_15 => "ARM_ALU_PCREL_23_15",
-        R_ARM_LDR_SBREL_11_0 => "ARM_LDR_SBREL_11_0",
-        R_ARM_ALU_SBREL_19_12 => "ARM_ALU_SBREL_19_12",
-        R_ARM_ALU_SBREL_27_20 => "ARM_ALU_SBREL_27_20",
-        R_ARM_TARGET1 => "ARM_TARGET1",
-        R_ARM_SBREL31 => "ARM_SBREL31",
-        R_ARM_V4BX => "ARM_V4BX",
-        R_ARM_TARGET2 => "ARM_TARGET2",
-        R_ARM_PREL31 => "ARM_PREL31",
-        R_ARM_MOVW_ABS_NC => "ARM_MOVW_ABS_NC",
-        R_ARM_MOVT_ABS => "ARM_MOVT_ABS",
-        R_ARM_MOVW_PREL_NC => "ARM_MOVW_PREL_NC",
-        R_ARM_MOVT_PREL => "ARM_MOVT_PREL",
-        R_ARM_THM_MOVW_ABS_NC => "ARM_THM_MOVW_ABS_NC",
-        R_ARM_THM_MOVT_ABS => "ARM_THM_MOVT_ABS",
-        R_ARM_THM_MOVW_PREL_NC => "ARM_THM_MOVW_PREL_NC",
-        R_ARM_THM_MOVT_PREL => "ARM_THM_MOVT_PREL",
-        R_ARM_THM_JUMP19 => "ARM_THM_JUMP19",
-        R_ARM_THM_JUMP6 => "ARM_THM_JUMP6",
-        R_ARM_THM_ALU_PREL_11_0 => "ARM_THM_ALU_PREL_11_0",
-        R_ARM_THM_PC12 => "ARM_THM_PC12",
-        R_ARM_ABS32_NOI => "ARM_ABS32_NOI",
-        R_ARM_REL32_NOI => "ARM_REL32_NOI",
-        R_ARM_ALU_PC_G0_NC => "ARM_ALU_PC_G0_NC",
-        R_ARM_ALU_PC_G0 => "ARM_ALU_PC_G0",
-        R_ARM_ALU_PC_G1_NC => "ARM_ALU_PC_G1_NC",
-        R_ARM_ALU_PC_G1 => "ARM_ALU_PC_G1",
-        R_ARM_ALU_PC_G2 => "ARM_ALU_PC_G2",
-        R_ARM_LDR_PC_G1 => "ARM_LDR_PC_G1",
-        R_ARM_LDR_PC_G2 => "ARM_LDR_PC_G2",
-        R_ARM_LDRS_PC_G0 => "ARM_LDRS_PC_G0",
-        R_ARM_LDRS_PC_G1 => "ARM_LDRS_PC_G1",
-        R_ARM_LDRS_PC_G2 => "ARM_LDRS_PC_G2",
-        R_ARM_LDC_PC_G0 => "ARM_LDC_PC_G0",
-        R_ARM_LDC_PC_G1 => "ARM_LDC_PC_G1",
-        R_ARM_LDC_PC_G2 => "ARM_LDC_PC_G2",
-        R_ARM_ALU_SB_G0_NC => "ARM_ALU_SB_G0_NC",
-        R_ARM_ALU_SB_G0 => "ARM_ALU_SB_G0",
-        R_ARM_ALU_SB_G1_NC => "ARM_ALU_SB_G1_NC",
-        R_ARM_ALU_SB_G1 => "ARM_ALU_SB_G1",
-        R_ARM_ALU_SB_G2 => "ARM_ALU_SB_G2",
-        R_ARM_LDR_SB_G0 => "ARM_LDR_SB_G0",
-        R_ARM_LDR_SB_G1 => "ARM_LDR_SB_G1",
-        R_ARM_LDR_SB_G2 => "ARM_LDR_SB_G2",
-        R_ARM_LDRS_SB_G0 => "ARM_LDRS_SB_G0",
-        R_ARM_LDRS_SB_G1 => "ARM_LDRS_SB_G1",
-        R_ARM_LDRS_SB_G2 => "ARM_LDRS_SB_G2",
-        R_ARM_LDC_SB_G0 => "ARM_LDC_SB_G0",
-        R_ARM_LDC_SB_G1 => "ARM_LDC_SB_G1",
-        R_ARM_LDC_SB_G2 => "ARM_LDC_SB_G2",
-        R_ARM_MOVW_BREL_NC => "ARM_MOVW_BREL_NC",
-        R_ARM_MOVT_BREL => "ARM_MOVT_BREL",
-        R_ARM_MOVW_BREL => "ARM_MOVW_BREL",
-        R_ARM_THM_MOVW_BREL_NC => "ARM_THM_MOVW_BREL_NC",
-        R_ARM_THM_MOVT_BREL => "ARM_THM_MOVT_BREL",
-        R_ARM_THM_MOVW_BREL => "ARM_THM_MOVW_BREL",
-        R_ARM_TLS_GOTDESC => "ARM_TLS_GOTDESC",
-        R_ARM_TLS_CALL => "ARM_TLS_CALL",
-        R_ARM_TLS_DESCSEQ => "ARM_TLS_DESCSEQ",
-        R_ARM_THM_TLS_CALL => "ARM_THM_TLS_CALL",
-        R_ARM_PLT32_ABS => "ARM_PLT32_ABS",
-        R_ARM_GOT_ABS => "ARM_GOT_ABS",
-        R_ARM_GOT_PREL => "ARM_GOT_PREL",
-        R_ARM_GOT_BREL12 => "ARM_GOT_BREL12",
-        R_ARM_GOTOFF12 => "ARM_GOTOFF12",
-        R_ARM_GOTRELAX => "ARM_GOTRELAX",
-        R_ARM_GNU_VTENTRY => "ARM_GNU_VTENTRY",
-        R_ARM_GNU_VTINHERIT => "ARM_GNU_VTINHERIT",
-        R_ARM_THM_PC11 => "ARM_THM_PC11",
-        R_ARM_THM_PC9 => "ARM_THM_PC9",
-        R_ARM_TLS_GD32 => "ARM_TLS_GD32",
-        R_ARM_TLS_LDM32 => "ARM_TLS_LDM32",
-        R_ARM_TLS_LDO32 => "ARM_TLS_LDO32",
-        R_ARM_TLS_IE32 => "ARM_TLS_IE32",
-        R_ARM_TLS_LE32 => "ARM_TLS_LE32",
-        R_ARM_TLS_LDO12 => "ARM_TLS_LDO12",
-        R_ARM_TLS_LE12 => "ARM_TLS_LE12",
-        R_ARM_TLS_IE12GP => "ARM_TLS_IE12GP",
-        R_ARM_ME_TOO => "ARM_ME_TOO",
-        R_ARM_THM_TLS_DESCSEQ16 => "ARM_THM_TLS_DESCSEQ16",
-        R_ARM_THM_TLS_DESCSEQ32 => "ARM_THM_TLS_DESCSEQ32",
-        R_ARM_THM_GOT_BREL12 => "ARM_THM_GOT_BREL12",
-        R_ARM_IRELATIVE => "ARM_IRELATIVE",
-        R_ARM_RXPC25 => "ARM_RXPC25",
-        R_ARM_RSBREL32 => "ARM_RSBREL32",
-        R_ARM_THM_RPC22 => "ARM_THM_RPC22",
-        R_ARM_RREL32 => "ARM_RREL32",
-        R_ARM_RABS22 => "ARM_RABS22",
-        R_ARM_RPC24 => "ARM_RPC24",
-        R_ARM_RBASE => "ARM_RBASE",
+        R_ARM_NONE => "R_ARM_NONE",
+        R_ARM_PC24 => "R_ARM_PC24",
+        R_ARM_ABS32 => "R_ARM_ABS32",
+        R_ARM_REL32 => "R_ARM_REL32",
+        R_ARM_PC13 => "R_ARM_PC13",
+        R_ARM_ABS16 => "R_ARM_ABS16",
+        R_ARM_ABS12 => "R_ARM_ABS12",
+        R_ARM_THM_ABS5 => "R_ARM_THM_ABS5",
+        R_ARM_ABS8 => "R_ARM_ABS8",
+        R_ARM_SBREL32 => "R_ARM_SBREL32",
+        R_ARM_THM_PC22 => "R_ARM_THM_PC22",
+        R_ARM_THM_PC8 => "R_ARM_THM_PC8",
+        R_ARM_AMP_VCALL9 => "R_ARM_AMP_VCALL9",
+        R_ARM_TLS_DESC => "R_ARM_TLS_DESC",
+        R_ARM_THM_SWI8 => "R_ARM_THM_SWI8",
+        R_ARM_XPC25 => "R_ARM_XPC25",
+        R_ARM_THM_XPC22 => "R_ARM_THM_XPC22",
+        R_ARM_TLS_DTPMOD32 => "R_ARM_TLS_DTPMOD32",
+        R_ARM_TLS_DTPOFF32 => "R_ARM_TLS_DTPOFF32",
+        R_ARM_TLS_TPOFF32 => "R_ARM_TLS_TPOFF32",
+        R_ARM_COPY => "R_ARM_COPY",
+        R_ARM_GLOB_DAT => "R_ARM_GLOB_DAT",
+        R_ARM_JUMP_SLOT => "R_ARM_JUMP_SLOT",
+        R_ARM_RELATIVE => "R_ARM_RELATIVE",
+        R_ARM_GOTOFF => "R_ARM_GOTOFF",
+        R_ARM_GOTPC => "R_ARM_GOTPC",
+        R_ARM_GOT32 => "R_ARM_GOT32",
+        R_ARM_PLT32 => "R_ARM_PLT32",
+        R_ARM_CALL => "R_ARM_CALL",
+        R_ARM_JUMP24 => "R_ARM_JUMP24",
+        R_ARM_THM_JUMP24 => "R_ARM_THM_JUMP24",
+        R_ARM_BASE_ABS => "R_ARM_BASE_ABS",
+        R_ARM_ALU_PCREL_7_0 => "R_ARM_ALU_PCREL_7_0",
+        R_ARM_ALU_PCREL_15_8 => "R_ARM_ALU_PCREL_15_8",
+        R_ARM_ALU_PCREL_23_15 => "R_ARM_ALU_PCREL_23_15",
+        R_ARM_LDR_SBREL_11_0 => "R_ARM_LDR_SBREL_11_0",
+        R_ARM_ALU_SBREL_19_12 => "R_ARM_ALU_SBREL_19_12",
+        R_ARM_ALU_SBREL_27_20 => "R_ARM_ALU_SBREL_27_20",
+        R_ARM_TARGET1 => "R_ARM_TARGET1",
+        R_ARM_SBREL31 => "R_ARM_SBREL31",
+        R_ARM_V4BX => "R_ARM_V4BX",
+        R_ARM_TARGET2 => "R_ARM_TARGET2",
+        R_ARM_PREL31 => "R_ARM_PREL31",
+        R_ARM_MOVW_ABS_NC => "R_ARM_MOVW_ABS_NC",
+        R_ARM_MOVT_ABS => "R_ARM_MOVT_ABS",
+        R_ARM_MOVW_PREL_NC => "R_ARM_MOVW_PREL_NC",
+        R_ARM_MOVT_PREL => "R_ARM_MOVT_PREL",
+        R_ARM_THM_MOVW_ABS_NC => "R_ARM_THM_MOVW_ABS_NC",
+        R_ARM_THM_MOVT_ABS => "R_ARM_THM_MOVT_ABS",
+        R_ARM_THM_MOVW_PREL_NC => "R_ARM_THM_MOVW_PREL_NC",
+        R_ARM_THM_MOVT_PREL => "R_ARM_THM_MOVT_PREL",
+        R_ARM_THM_JUMP19 => "R_ARM_THM_JUMP19",
+        R_ARM_THM_JUMP6 => "R_ARM_THM_JUMP6",
+        R_ARM_THM_ALU_PREL_11_0 => "R_ARM_THM_ALU_PREL_11_0",
+        R_ARM_THM_PC12 => "R_ARM_THM_PC12",
+        R_ARM_ABS32_NOI => "R_ARM_ABS32_NOI",
+        R_ARM_REL32_NOI => "R_ARM_REL32_NOI",
+        R_ARM_ALU_PC_G0_NC => "R_ARM_ALU_PC_G0_NC",
+        R_ARM_ALU_PC_G0 => "R_ARM_ALU_PC_G0",
+        R_ARM_ALU_PC_G1_NC => "R_ARM_ALU_PC_G1_NC",
+        R_ARM_ALU_PC_G1 => "R_ARM_ALU_PC_G1",
+        R_ARM_ALU_PC_G2 => "R_ARM_ALU_PC_G2",
+        R_ARM_LDR_PC_G1 => "R_ARM_LDR_PC_G1",
+        R_ARM_LDR_PC_G2 => "R_ARM_LDR_PC_G2",
+        R_ARM_LDRS_PC_G0 => "R_ARM_LDRS_PC_G0",
+        R_ARM_LDRS_PC_G1 => "R_ARM_LDRS_PC_G1",
+        R_ARM_LDRS_PC_G2 => "R_ARM_LDRS_PC_G2",
+        R_ARM_LDC_PC_G0 => "R_ARM_LDC_PC_G0",
+        R_ARM_LDC_PC_G1 => "R_ARM_LDC_PC_G1",
+        R_ARM_LDC_PC_G2 => "R_ARM_LDC_PC_G2",
+        R_ARM_ALU_SB_G0_NC => "R_ARM_ALU_SB_G0_NC",
+        R_ARM_ALU_SB_G0 => "R_ARM_ALU_SB_G0",
+        R_ARM_ALU_SB_G1_NC => "R_ARM_ALU_SB_G1_NC",
+        R_ARM_ALU_SB_G1 => "R_ARM_ALU_SB_G1",
+        R_ARM_ALU_SB_G2 => "R_ARM_ALU_SB_G2",
+        R_ARM_LDR_SB_G0 => "R_ARM_LDR_SB_G0",
+        R_ARM_LDR_SB_G1 => "R_ARM_LDR_SB_G1",
+        R_ARM_LDR_SB_G2 => "R_ARM_LDR_SB_G2",
+        R_ARM_LDRS_SB_G0 => "R_ARM_LDRS_SB_G0",
+        R_ARM_LDRS_SB_G1 => "R_ARM_LDRS_SB_G1",
+        R_ARM_LDRS_SB_G2 => "R_ARM_LDRS_SB_G2",
+        R_ARM_LDC_SB_G0 => "R_ARM_LDC_SB_G0",
+        R_ARM_LDC_SB_G1 => "R_ARM_LDC_SB_G1",
+        R_ARM_LDC_SB_G2 => "R_ARM_LDC_SB_G2",
+        R_ARM_MOVW_BREL_NC => "R_ARM_MOVW_BREL_NC",
+        R_ARM_MOVT_BREL => "R_ARM_MOVT_BREL",
+        R_ARM_MOVW_BREL => "R_ARM_MOVW_BREL",
+        R_ARM_THM_MOVW_BREL_NC => "R_ARM_THM_MOVW_BREL_NC",
+        R_ARM_THM_MOVT_BREL => "R_ARM_THM_MOVT_BREL",
+        R_ARM_THM_MOVW_BREL => "R_ARM_THM_MOVW_BREL",
+        R_ARM_TLS_GOTDESC => "R_ARM_TLS_GOTDESC",
+        R_ARM_TLS_CALL => "R_ARM_TLS_CALL",
+        R_ARM_TLS_DESCSEQ => "R_ARM_TLS_DESCSEQ",
+        R_ARM_THM_TLS_CALL => "R_ARM_THM_TLS_CALL",
+        R_ARM_PLT32_ABS => "R_ARM_PLT32_ABS",
+        R_ARM_GOT_ABS => "R_ARM_GOT_ABS",
+        R_ARM_GOT_PREL => "R_ARM_GOT_PREL",
+        R_ARM_GOT_BREL12 => "R_ARM_GOT_BREL12",
+        R_ARM_GOTOFF12 => "R_ARM_GOTOFF12",
+        R_ARM_GOTRELAX => "R_ARM_GOTRELAX",
+        R_ARM_GNU_VTENTRY => "R_ARM_GNU_VTENTRY",
+        R_ARM_GNU_VTINHERIT => "R_ARM_GNU_VTINHERIT",
+        R_ARM_THM_PC11 => "R_ARM_THM_PC11",
+        R_ARM_THM_PC9 => "R_ARM_THM_PC9",
+        R_ARM_TLS_GD32 => "R_ARM_TLS_GD32",
+        R_ARM_TLS_LDM32 => "R_ARM_TLS_LDM32",
+        R_ARM_TLS_LDO32 => "R_ARM_TLS_LDO32",
+        R_ARM_TLS_IE32 => "R_ARM_TLS_IE32",
+        R_ARM_TLS_LE32 => "R_ARM_TLS_LE32",
+        R_ARM_TLS_LDO12 => "R_ARM_TLS_LDO12",
+        R_ARM_TLS_LE12 => "R_ARM_TLS_LE12",
+        R_ARM_TLS_IE12GP => "R_ARM_TLS_IE12GP",
+        R_ARM_ME_TOO => "R_ARM_ME_TOO",
+        R_ARM_THM_TLS_DESCSEQ16 => "R_ARM_THM_TLS_DESCSEQ16",
+        R_ARM_THM_TLS_DESCSEQ32 => "R_ARM_THM_TLS_DESCSEQ32",
+        R_ARM_THM_GOT_BREL12 => "R_ARM_THM_GOT_BREL12",
+        R_ARM_IRELATIVE => "R_ARM_IRELATIVE",
+        R_ARM_RXPC25 => "R_ARM_RXPC25",
+        R_ARM_RSBREL32 => "R_ARM_RSBREL32",
+        R_ARM_THM_RPC22 => "R_ARM_THM_RPC22",
+        R_ARM_RREL32 => "R_ARM_RREL32",
+        R_ARM_RABS22 => "R_ARM_RABS22",
+        R_ARM_RPC24 => "R_ARM_RPC24",
+        R_ARM_RBASE => "R_ARM_RBASE",
          _ => "R_UNKNOWN_ARM",
         }},
         // MIPS
@@ -1243,6 +1370,64 @@ pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
         R_MIPS_COPY => "R_MIPS_COPY",
         R_MIPS_JUMP_SLOT => "R_MIPS_JUMP_SLOT",
         _ => "R_UNKNOWN_MIPS",
-        }} _ => "R_UNKNOWN"
+        }},
+        // risc-v
+        EM_RISCV => { match typ {
+        R_RISCV_NONE => "R_RISCV_NONE",
+        R_RISCV_32 => "R_RISCV_32",
+        R_RISCV_64 => "R_RISCV_64",
+        R_RISCV_RELATIVE => "R_RISCV_RELATIVE",
+        R_RISCV_COPY => "R_RISCV_COPY",
+        R_RISCV_JUMP_SLOT => "R_RISCV_JUMP_SLOT",
+        R_RISCV_TLS_DTPMOD32 => "R_RISCV_TLS_DTPMOD32",
+        R_RISCV_TLS_DTPMOD64 => "R_RISCV_TLS_DTPMOD64",
+        R_RISCV_TLS_DTPREL32 => "R_RISCV_TLS_DTPREL32",
+        R_RISCV_TLS_DTPREL64 => "R_RISCV_TLS_DTPREL64",
+        R_RISCV_TLS_TPREL32 => "R_RISCV_TLS_TPREL32",
+        R_RISCV_TLS_TPREL64 => "R_RISCV_TLS_TPREL64",
+        R_RISCV_TLSDESC => "R_RISCV_TLSDESC",
+        R_RISCV_BRANCH => "R_RISCV_BRANCH",
+        R_RISCV_JAL => "R_RISCV_JAL",
+        R_RISCV_CALL => "R_RISCV_CALL",
+        R_RISCV_CALL_PLT => "R_RISCV_CALL_PLT",
+        R_RISCV_GOT_HI20 => "R_RISCV_GOT_HI20",
+        R_RISCV_TLS_GOT_HI20 => "R_RISCV_TLS_GOT_HI20",
+        R_RISCV_TLS_GD_HI20 => "R_RISCV_TLS_GD_HI20",
+        R_RISCV_PCREL_HI20 => "R_RISCV_PCREL_HI20",
+        R_RISCV_PCREL_LO12_I => "R_RISCV_PCREL_LO12_I",
+        R_RISCV_PCREL_LO12_S => "R_RISCV_PCREL_LO12_S",
+        R_RISCV_HI20 => "R_RISCV_HI20",
+        R_RISCV_LO12_I => "R_RISCV_LO12_I",
+        R_RISCV_LO12_S => "R_RISCV_LO12_S",
+        R_RISCV_TPREL_HI20 => "R_RISCV_TPREL_HI20",
+        R_RISCV_TPREL_LO12_I => "R_RISCV_TPREL_LO12_I",
+        R_RISCV_TPREL_LO12_S => "R_RISCV_TPREL_LO12_S",
+        R_RISCV_TPREL_ADD => "R_RISCV_TPREL_ADD",
+        R_RISCV_ADD8 => "R_RISCV_ADD8",
+        R_RISCV_ADD16 => "R_RISCV_ADD16",
+        R_RISCV_ADD32 => "R_RISCV_ADD32",
+        R_RISCV_ADD64 => "R_RISCV_ADD64",
+        R_RISCV_SUB8 => "R_RISCV_SUB8",
+        R_RISCV_SUB16 => "R_RISCV_SUB16",
+        R_RISCV_SUB32 => "R_RISCV_SUB32",
+        R_RISCV_SUB64 => "R_RISCV_SUB64",
+        R_RISCV_GOT32_PCREL => "R_RISCV_GOT32_PCREL",
+        R_RISCV_ALIGN => "R_RISCV_ALIGN",
+        R_RISCV_RVC_BRANCH => "R_RISCV_RVC_BRANCH",
+        R_RISCV_RVC_JUMP => "R_RISCV_RVC_JUMP",
+        R_RISCV_RELAX => "R_RISCV_RELAX",
+        R_RISCV_SUB6 => "R_RISCV_SUB6",
+        R_RISCV_SET6 => "R_RISCV_SET6",
+        R_RISCV_SET8 => "R_RISCV_SET8",
+        R_RISCV_SET16 => "R_RISCV_SET16",
+        R_RISCV_SET32 => "R_RISCV_SET32",
+        R_RISCV_32_PCREL => "R_RISCV_32_PCREL",
+        R_RISCV_IRELATIVE => "R_RISCV_IRELATIVE",
+        R_RISCV_PLT32 => "R_RISCV_PLT32",
+        R_RISCV_SET_ULEB128 => "R_RISCV_SET_ULEB128",
+        R_RISCV_SUB_ULEB128 => "R_RISCV_SUB_ULEB128",
+        _ => "R_UNKNOWN_RISCV",
+        }},
+        _ => "R_UNKNOWN"
     }
 }
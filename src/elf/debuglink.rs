@@ -0,0 +1,84 @@
+//! The links of a stripped binary to its separate debug info: `.gnu_debuglink`, as added by `objcopy --add-gnu-debuglink`,
+//! and `.gnu_debugaltlink`, which names the supplementary debug file `dwz` moves the debug info shared by several binaries into.
+//!
+//! A `.gnu_debuglink` is the file name, NUL padded to 4 bytes, then the CRC32 of the whole debug file;
+//! a `.gnu_debugaltlink` is the file name, a NUL, then the build id of the supplementary file.
+//!
+//! See: https://sourceware.org/gdb/onlinedocs/gdb/Separate-Debug-Files.html
+
+if_alloc! {
+    use scroll::{Pread, Endian};
+    use error;
+    use hash::{Crc32, HashAlgo};
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// The contents of a `.gnu_debuglink` section
+    pub struct DebugLink<'a> {
+        /// The file name of the debug file, to be searched for next to the binary, in its `.debug` subdirectory, and in the global debug directory
+        pub filename: &'a str,
+        /// The CRC32 of the debug file, see `crc32`
+        pub crc: u32,
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// The contents of a `.gnu_debugaltlink` section
+    pub struct DebugAltLink<'a> {
+        /// The path of the supplementary debug file, relative to the debug file which has the link, unless absolute
+        pub filename: &'a str,
+        /// The build id of the supplementary debug file, i.e., its `NT_GNU_BUILD_ID` note; it is found by this, rather than a CRC
+        pub build_id: &'a [u8],
+    }
+
+    /// Reads the NUL terminated file name at the start of the link section `bytes`, returning it and the offset past its NUL
+    fn filename<'a>(bytes: &'a [u8], section: &str) -> error::Result<(&'a str, usize)> {
+        match bytes.iter().position(|&byte| byte == 0) {
+            Some(end) => Ok((bytes.pread_with::<&str>(0, ::scroll::ctx::StrCtx::Length(end))?, end + 1)),
+            None => Err(error::Error::Malformed(format!("{} file name is not NUL terminated", section))),
+        }
+    }
+
+    impl<'a> DebugLink<'a> {
+        /// Parses the `.gnu_debuglink` section `bytes`, whose CRC is in the byte order `le` of the binary
+        pub fn parse(bytes: &'a [u8], le: Endian) -> error::Result<Self> {
+            let (filename, end) = filename(bytes, ".gnu_debuglink")?;
+            let crc = bytes.pread_with((end + 3) & !3, le)?;
+            Ok(DebugLink { filename, crc })
+        }
+        /// Whether `debug_file`, the contents of a candidate file found by `filename`, is the debug file this link refers to
+        pub fn matches(&self, debug_file: &[u8]) -> bool {
+            crc32(debug_file) == self.crc
+        }
+    }
+
+    impl<'a> DebugAltLink<'a> {
+        /// Parses the `.gnu_debugaltlink` section `bytes`
+        pub fn parse(bytes: &'a [u8]) -> error::Result<Self> {
+            let (filename, end) = filename(bytes, ".gnu_debugaltlink")?;
+            Ok(DebugAltLink { filename, build_id: &bytes[end..] })
+        }
+    }
+
+    /// The CRC32 of `bytes` which `.gnu_debuglink` records, i.e., that of zlib, see `hash::Crc32`
+    pub fn crc32(bytes: &[u8]) -> u32 {
+        let mut crc = Crc32::new();
+        crc.update(bytes);
+        crc.crc()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parse_links() {
+            assert_eq!(crc32(b""), 0);
+            assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+            let link = DebugLink::parse(b"a.debug\0\x26\x15\xd0\xb7", ::scroll::LE).unwrap();
+            assert_eq!(link, DebugLink { filename: "a.debug", crc: 0xb7d0_1526 });
+            assert!(DebugLink::parse(b"a.debug\0\x26\x15", ::scroll::LE).is_err());
+            assert!(DebugLink::parse(b"a.debug", ::scroll::LE).is_err());
+            let link = DebugAltLink::parse(b"../dwz/common.debug\0\x01\x23").unwrap();
+            assert_eq!(link, DebugAltLink { filename: "../dwz/common.debug", build_id: &[0x01, 0x23] });
+        }
+    }
+}
@@ -95,6 +95,14 @@ pub const DT_PREINIT_ARRAYSZ: u64 = 33;
 pub const DT_NUM: u64 = 34;
 /// Start of OS-specific
 pub const DT_LOOS: u64 = 0x6000000d;
+/// Address of the Android packed relocations without addends
+pub const DT_ANDROID_REL: u64 = DT_LOOS + 2;
+/// Size in bytes of DT_ANDROID_REL
+pub const DT_ANDROID_RELSZ: u64 = DT_LOOS + 3;
+/// Address of the Android packed relocations with addends
+pub const DT_ANDROID_RELA: u64 = DT_LOOS + 4;
+/// Size in bytes of DT_ANDROID_RELA
+pub const DT_ANDROID_RELASZ: u64 = DT_LOOS + 5;
 /// End of OS-specific
 pub const DT_HIOS: u64 = 0x6ffff000;
 /// Start of processor-specific
@@ -192,6 +200,10 @@ pub fn tag_to_str(tag: u64) -> &'static str {
         DT_PREINIT_ARRAYSZ => "DT_PREINIT_ARRAYSZ",
         DT_NUM => "DT_NUM",
         DT_LOOS => "DT_LOOS",
+        DT_ANDROID_REL => "DT_ANDROID_REL",
+        DT_ANDROID_RELSZ => "DT_ANDROID_RELSZ",
+        DT_ANDROID_RELA => "DT_ANDROID_RELA",
+        DT_ANDROID_RELASZ => "DT_ANDROID_RELASZ",
         DT_HIOS => "DT_HIOS",
         DT_LOPROC => "DT_LOPROC",
         DT_HIPROC => "DT_HIPROC",
@@ -270,6 +282,80 @@ pub const DF_1_SYMINTPOSE: u64 = 0x00800000;
 pub const DF_1_GLOBAUDIT: u64 = 0x01000000;
 /// Singleton dyn are used.
 pub const DF_1_SINGLETON: u64 = 0x02000000;
+/// Object is a stub.
+pub const DF_1_STUB: u64 = 0x04000000;
+/// Object is a position-independent executable.
+pub const DF_1_PIE: u64 = 0x08000000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The `DF_*` flags in the `DT_FLAGS` entry
+pub struct DynFlags(pub u64);
+
+impl DynFlags {
+    /// The raw flags
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+    /// Whether all of the `DF_*` bits in `flags` are set
+    pub fn contains(&self, flags: u64) -> bool {
+        self.0 & flags == flags
+    }
+    /// Whether the object needs `$ORIGIN` processing (`DF_ORIGIN`)
+    pub fn is_origin(&self) -> bool {
+        self.contains(DF_ORIGIN)
+    }
+    /// Whether symbol resolution starts with the object itself (`DF_SYMBOLIC`)
+    pub fn is_symbolic(&self) -> bool {
+        self.contains(DF_SYMBOLIC)
+    }
+    /// Whether the object has text relocations (`DF_TEXTREL`)
+    pub fn is_textrel(&self) -> bool {
+        self.contains(DF_TEXTREL)
+    }
+    /// Whether all relocations are processed at load time (`DF_BIND_NOW`)
+    pub fn is_bind_now(&self) -> bool {
+        self.contains(DF_BIND_NOW)
+    }
+    /// Whether the object uses the static TLS model (`DF_STATIC_TLS`)
+    pub fn is_static_tls(&self) -> bool {
+        self.contains(DF_STATIC_TLS)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The `DF_1_*` flags in the `DT_FLAGS_1` entry
+pub struct DynFlags1(pub u64);
+
+impl DynFlags1 {
+    /// The raw flags
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+    /// Whether all of the `DF_1_*` bits in `flags` are set
+    pub fn contains(&self, flags: u64) -> bool {
+        self.0 & flags == flags
+    }
+    /// Whether all relocations are processed at load time (`DF_1_NOW`)
+    pub fn is_now(&self) -> bool {
+        self.contains(DF_1_NOW)
+    }
+    /// Whether the object's symbols are made available for all subsequent loads (`DF_1_GLOBAL`)
+    pub fn is_global(&self) -> bool {
+        self.contains(DF_1_GLOBAL)
+    }
+    /// Whether the object may not be unloaded (`DF_1_NODELETE`)
+    pub fn is_nodelete(&self) -> bool {
+        self.contains(DF_1_NODELETE)
+    }
+    /// Whether the object may not be `dlopen`ed (`DF_1_NOOPEN`)
+    pub fn is_noopen(&self) -> bool {
+        self.contains(DF_1_NOOPEN)
+    }
+    /// Whether the object is a position-independent executable (`DF_1_PIE`)
+    pub fn is_pie(&self) -> bool {
+        self.contains(DF_1_PIE)
+    }
+}
 
 if_alloc! {
     use core::fmt;
@@ -405,6 +491,227 @@ if_alloc! {
             }
             needed
         }
+
+        /// Returns the `DT_RPATH` search paths, split on `:`
+        pub fn get_rpaths<'a>(&self, strtab: &Strtab<'a>) -> Vec<&'a str> {
+            self.get_search_paths(DT_RPATH, strtab)
+        }
+
+        /// Returns the `DT_RUNPATH` search paths, split on `:`
+        pub fn get_runpaths<'a>(&self, strtab: &Strtab<'a>) -> Vec<&'a str> {
+            self.get_search_paths(DT_RUNPATH, strtab)
+        }
+
+        /// Returns the `DT_FLAGS` entry, or no flags if there isn't one
+        pub fn flags(&self) -> DynFlags {
+            DynFlags(self.get_tag(DT_FLAGS).unwrap_or(0))
+        }
+
+        /// Returns the `DT_FLAGS_1` entry, or no flags if there isn't one
+        pub fn flags_1(&self) -> DynFlags1 {
+            DynFlags1(self.get_tag(DT_FLAGS_1).unwrap_or(0))
+        }
+
+        fn get_tag(&self, tag: u64) -> Option<u64> {
+            self.dyns.iter().find(|dyn| dyn.d_tag as u64 == tag).map(|dyn| dyn.d_val)
+        }
+
+        fn get_search_paths<'a>(&self, tag: u64, strtab: &Strtab<'a>) -> Vec<&'a str> {
+            let mut paths = Vec::new();
+            for dyn in &self.dyns {
+                if dyn.d_tag as u64 == tag {
+                    match strtab.get(dyn.d_val as usize) {
+                        Some(Ok(path)) => paths.extend(path.split(':')),
+                        // FIXME: warn! here
+                        _ => (),
+                    }
+                }
+            }
+            paths
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// A dynamic entry, decoded by its tag; the string valued entries are resolved against the dynamic string table
+    pub enum DynEntry<'a> {
+        /// `DT_NEEDED`, a library this binary depends on
+        Needed(&'a str),
+        /// `DT_SONAME`
+        SoName(&'a str),
+        /// `DT_RPATH`, the `:` separated library search paths
+        RPath(&'a str),
+        /// `DT_RUNPATH`, the `:` separated library search paths, which are searched after `LD_LIBRARY_PATH`
+        RunPath(&'a str),
+        /// `DT_FLAGS`, see the `DF_*` values
+        Flags(u64),
+        /// `DT_FLAGS_1`, see the `DF_1_*` values
+        Flags1(u64),
+        Init(u64),
+        Fini(u64),
+        InitArray(u64),
+        InitArraySz(u64),
+        FiniArray(u64),
+        FiniArraySz(u64),
+        PreinitArray(u64),
+        PreinitArraySz(u64),
+        Hash(u64),
+        GnuHash(u64),
+        StrTab(u64),
+        StrSz(u64),
+        SymTab(u64),
+        SymEnt(u64),
+        Rela(u64),
+        RelaSz(u64),
+        RelaEnt(u64),
+        RelaCount(u64),
+        Rel(u64),
+        RelSz(u64),
+        RelEnt(u64),
+        RelCount(u64),
+        PltGot(u64),
+        PltRelSz(u64),
+        /// `DT_PLTREL`, whether the plt relocations are `DT_REL` or `DT_RELA`
+        PltRel(u64),
+        JmpRel(u64),
+        Debug(u64),
+        VerSym(u64),
+        VerDef(u64),
+        VerDefNum(u64),
+        VerNeed(u64),
+        VerNeedNum(u64),
+        TextRel,
+        Symbolic,
+        BindNow,
+        /// Any other tag and its value, or a string valued tag whose string is out of bounds
+        Unknown(u64, u64),
+    }
+
+    impl<'a> DynEntry<'a> {
+        /// Decodes `dyn`, resolving string values in `strtab`
+        pub fn new(dyn: &Dyn, strtab: &Strtab<'a>) -> Self {
+            let val = dyn.d_val;
+            let string = || match strtab.get(val as usize) {
+                Some(Ok(string)) => Some(string),
+                _ => None,
+            };
+            let entry = match dyn.d_tag {
+                DT_NEEDED => string().map(DynEntry::Needed),
+                DT_SONAME => string().map(DynEntry::SoName),
+                DT_RPATH => string().map(DynEntry::RPath),
+                DT_RUNPATH => string().map(DynEntry::RunPath),
+                DT_FLAGS => Some(DynEntry::Flags(val)),
+                DT_FLAGS_1 => Some(DynEntry::Flags1(val)),
+                DT_INIT => Some(DynEntry::Init(val)),
+                DT_FINI => Some(DynEntry::Fini(val)),
+                DT_INIT_ARRAY => Some(DynEntry::InitArray(val)),
+                DT_INIT_ARRAYSZ => Some(DynEntry::InitArraySz(val)),
+                DT_FINI_ARRAY => Some(DynEntry::FiniArray(val)),
+                DT_FINI_ARRAYSZ => Some(DynEntry::FiniArraySz(val)),
+                DT_PREINIT_ARRAY => Some(DynEntry::PreinitArray(val)),
+                DT_PREINIT_ARRAYSZ => Some(DynEntry::PreinitArraySz(val)),
+                DT_HASH => Some(DynEntry::Hash(val)),
+                DT_GNU_HASH => Some(DynEntry::GnuHash(val)),
+                DT_STRTAB => Some(DynEntry::StrTab(val)),
+                DT_STRSZ => Some(DynEntry::StrSz(val)),
+                DT_SYMTAB => Some(DynEntry::SymTab(val)),
+                DT_SYMENT => Some(DynEntry::SymEnt(val)),
+                DT_RELA => Some(DynEntry::Rela(val)),
+                DT_RELASZ => Some(DynEntry::RelaSz(val)),
+                DT_RELAENT => Some(DynEntry::RelaEnt(val)),
+                DT_RELACOUNT => Some(DynEntry::RelaCount(val)),
+                DT_REL => Some(DynEntry::Rel(val)),
+                DT_RELSZ => Some(DynEntry::RelSz(val)),
+                DT_RELENT => Some(DynEntry::RelEnt(val)),
+                DT_RELCOUNT => Some(DynEntry::RelCount(val)),
+                DT_PLTGOT => Some(DynEntry::PltGot(val)),
+                DT_PLTRELSZ => Some(DynEntry::PltRelSz(val)),
+                DT_PLTREL => Some(DynEntry::PltRel(val)),
+                DT_JMPREL => Some(DynEntry::JmpRel(val)),
+                DT_DEBUG => Some(DynEntry::Debug(val)),
+                DT_VERSYM => Some(DynEntry::VerSym(val)),
+                DT_VERDEF => Some(DynEntry::VerDef(val)),
+                DT_VERDEFNUM => Some(DynEntry::VerDefNum(val)),
+                DT_VERNEED => Some(DynEntry::VerNeed(val)),
+                DT_VERNEEDNUM => Some(DynEntry::VerNeedNum(val)),
+                DT_TEXTREL => Some(DynEntry::TextRel),
+                DT_SYMBOLIC => Some(DynEntry::Symbolic),
+                DT_BIND_NOW => Some(DynEntry::BindNow),
+                _ => None,
+            };
+            entry.unwrap_or(DynEntry::Unknown(dyn.d_tag, val))
+        }
+    }
+
+    /// An iterator over the decoded entries of a `Dynamic`, up to the terminating `DT_NULL`
+    pub struct DynIterator<'a, 'b> {
+        dyns: ::core::slice::Iter<'b, Dyn>,
+        strtab: &'b Strtab<'a>,
+    }
+
+    impl<'a, 'b> Iterator for DynIterator<'a, 'b> {
+        type Item = DynEntry<'a>;
+        fn next(&mut self) -> Option<Self::Item> {
+            match self.dyns.next() {
+                Some(dyn) if dyn.d_tag != DT_NULL => Some(DynEntry::new(dyn, self.strtab)),
+                _ => None,
+            }
+        }
+    }
+
+    impl Dynamic {
+        /// Iterates over the decoded dynamic entries, resolving their strings in `strtab`, which should be the dynamic string table
+        pub fn iter<'a, 'b>(&'b self, strtab: &'b Strtab<'a>) -> DynIterator<'a, 'b> {
+            DynIterator { dyns: self.dyns.iter(), strtab: strtab }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn flags() {
+            let dyns = vec![
+                Dyn { d_tag: DT_FLAGS, d_val: DF_BIND_NOW | DF_ORIGIN },
+                Dyn { d_tag: DT_FLAGS_1, d_val: DF_1_NOW | DF_1_PIE },
+                Dyn { d_tag: DT_NULL, d_val: 0 },
+            ];
+            let dynamic = Dynamic { dyns: dyns, info: DynamicInfo::default(), count: 3 };
+            let flags = dynamic.flags();
+            assert!(flags.is_bind_now() && flags.is_origin());
+            assert!(!flags.is_textrel());
+            assert!(flags.contains(DF_BIND_NOW | DF_ORIGIN));
+            let flags_1 = dynamic.flags_1();
+            assert!(flags_1.is_now() && flags_1.is_pie());
+            assert!(!flags_1.is_nodelete());
+            let dynamic = Dynamic { dyns: vec![], info: DynamicInfo::default(), count: 0 };
+            assert_eq!(dynamic.flags(), DynFlags(0));
+            assert_eq!(dynamic.flags_1().bits(), 0);
+        }
+
+        #[test]
+        fn entries() {
+            let strtab = Strtab::new(b"\0libc.so.6\0", 0);
+            let dyns = vec![
+                Dyn { d_tag: DT_NEEDED, d_val: 1 },
+                Dyn { d_tag: DT_NEEDED, d_val: 100 },
+                Dyn { d_tag: DT_FLAGS, d_val: DF_BIND_NOW },
+                Dyn { d_tag: DT_BIND_NOW, d_val: 0 },
+                Dyn { d_tag: 0x6fff_fe00, d_val: 7 },
+                Dyn { d_tag: DT_NULL, d_val: 0 },
+                Dyn { d_tag: DT_INIT, d_val: 0x1000 },
+            ];
+            let dynamic = Dynamic { dyns: dyns, info: DynamicInfo::default(), count: 7 };
+            let entries: Vec<_> = dynamic.iter(&strtab).collect();
+            assert_eq!(entries, vec![
+                DynEntry::Needed("libc.so.6"),
+                // the out of bounds name degrades to the raw entry
+                DynEntry::Unknown(DT_NEEDED, 100),
+                DynEntry::Flags(DF_BIND_NOW),
+                DynEntry::BindNow,
+                DynEntry::Unknown(0x6fff_fe00, 7),
+            ]);
+        }
     }
 }
 
@@ -559,6 +866,10 @@ macro_rules! elf_dyn_std_impl {
             pub relsz: usize,
             pub relent: $size,
             pub relcount: usize,
+            pub android_rela: usize,
+            pub android_relasz: usize,
+            pub android_rel: usize,
+            pub android_relsz: usize,
             pub gnu_hash: Option<$size>,
             pub hash: Option<$size>,
             pub strtab: usize,
@@ -597,6 +908,10 @@ macro_rules! elf_dyn_std_impl {
                     DT_RELSZ => self.relsz = dyn.d_val as usize,
                     DT_RELENT => self.relent = dyn.d_val as _,
                     DT_RELCOUNT => self.relcount = dyn.d_val as usize,
+                    DT_ANDROID_RELA => self.android_rela = dyn.d_val.wrapping_add(bias as _) as usize,
+                    DT_ANDROID_RELASZ => self.android_relasz = dyn.d_val as usize,
+                    DT_ANDROID_REL => self.android_rel = dyn.d_val.wrapping_add(bias as _) as usize,
+                    DT_ANDROID_RELSZ => self.android_relsz = dyn.d_val as usize,
                     DT_GNU_HASH => self.gnu_hash = Some(dyn.d_val.wrapping_add(bias as _)),
                     DT_HASH => self.hash = Some(dyn.d_val.wrapping_add(bias as _)) as _,
                     DT_STRTAB => self.strtab = dyn.d_val.wrapping_add(bias as _) as usize,
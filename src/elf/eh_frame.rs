@@ -0,0 +1,233 @@
+//! The `.eh_frame_hdr` section (`PT_GNU_EH_FRAME`), which holds a pointer to `.eh_frame`, and a table of the FDEs sorted by the
+//! first address they cover, so that an unwinder can find the FDE for a pc with a binary search.
+//!
+//! The header is a version byte, followed by the DWARF pointer encodings of the `.eh_frame` pointer, the FDE count and the table;
+//! then the `.eh_frame` pointer, the FDE count, and `fde_count` pairs of `(initial_location, fde_address)`.
+//!
+//! See: https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/ehframechpt.html
+
+/// The only version of the header
+pub const EH_FRAME_HDR_VERSION: u8 = 1;
+
+/// A pointer sized unsigned value
+pub const DW_EH_PE_ABSPTR: u8 = 0x00;
+pub const DW_EH_PE_ULEB128: u8 = 0x01;
+pub const DW_EH_PE_UDATA2: u8 = 0x02;
+pub const DW_EH_PE_UDATA4: u8 = 0x03;
+pub const DW_EH_PE_UDATA8: u8 = 0x04;
+pub const DW_EH_PE_SLEB128: u8 = 0x09;
+pub const DW_EH_PE_SDATA2: u8 = 0x0a;
+pub const DW_EH_PE_SDATA4: u8 = 0x0b;
+pub const DW_EH_PE_SDATA8: u8 = 0x0c;
+/// The value is relative to the address of the value itself
+pub const DW_EH_PE_PCREL: u8 = 0x10;
+pub const DW_EH_PE_TEXTREL: u8 = 0x20;
+/// The value is relative to the start of `.eh_frame_hdr`
+pub const DW_EH_PE_DATAREL: u8 = 0x30;
+pub const DW_EH_PE_FUNCREL: u8 = 0x40;
+pub const DW_EH_PE_ALIGNED: u8 = 0x50;
+/// The value is the address of the actual value
+pub const DW_EH_PE_INDIRECT: u8 = 0x80;
+/// There is no value
+pub const DW_EH_PE_OMIT: u8 = 0xff;
+
+/// The mask of the encoding's value format
+const DW_EH_PE_FORMAT: u8 = 0x0f;
+/// The mask of the encoding's application, i.e., what the value is relative to
+const DW_EH_PE_APPLICATION: u8 = 0x70;
+
+if_alloc! {
+    use scroll::{Pread, Uleb128, Sleb128};
+    use container::Ctx;
+    use error;
+
+    /// The size of the values in the `encoding` format, or `None` if it has no fixed size
+    fn encoded_size(encoding: u8, ctx: Ctx) -> Option<usize> {
+        match encoding & DW_EH_PE_FORMAT {
+            DW_EH_PE_ABSPTR => Some(ctx.size()),
+            DW_EH_PE_UDATA2 | DW_EH_PE_SDATA2 => Some(2),
+            DW_EH_PE_UDATA4 | DW_EH_PE_SDATA4 => Some(4),
+            DW_EH_PE_UDATA8 | DW_EH_PE_SDATA8 => Some(8),
+            _ => None,
+        }
+    }
+
+    /// Reads a pointer with `encoding` at `offset` in `bytes`, which start at the virtual address `address`;
+    /// only the encodings which are relative to the pc or to `.eh_frame_hdr` itself can be resolved without the loaded image
+    pub fn read_encoded(bytes: &[u8], offset: &mut usize, encoding: u8, address: u64, ctx: Ctx) -> error::Result<u64> {
+        let pc = address.wrapping_add(*offset as u64);
+        let value = match encoding & DW_EH_PE_FORMAT {
+            DW_EH_PE_ABSPTR => if ctx.is_big() { bytes.gread_with::<u64>(offset, ctx.le)? } else { bytes.gread_with::<u32>(offset, ctx.le)? as u64 },
+            DW_EH_PE_ULEB128 => Uleb128::read(bytes, offset)?,
+            DW_EH_PE_UDATA2 => bytes.gread_with::<u16>(offset, ctx.le)? as u64,
+            DW_EH_PE_UDATA4 => bytes.gread_with::<u32>(offset, ctx.le)? as u64,
+            DW_EH_PE_UDATA8 => bytes.gread_with::<u64>(offset, ctx.le)?,
+            DW_EH_PE_SLEB128 => Sleb128::read(bytes, offset)? as u64,
+            DW_EH_PE_SDATA2 => bytes.gread_with::<i16>(offset, ctx.le)? as i64 as u64,
+            DW_EH_PE_SDATA4 => bytes.gread_with::<i32>(offset, ctx.le)? as i64 as u64,
+            DW_EH_PE_SDATA8 => bytes.gread_with::<i64>(offset, ctx.le)? as u64,
+            format => return Err(error::Error::Malformed(format!("Unknown DWARF pointer format {:#x}", format))),
+        };
+        if encoding & DW_EH_PE_INDIRECT != 0 {
+            return Err(error::Error::Malformed(format!("Indirect DWARF pointer encoding {:#x} needs the loaded image", encoding)));
+        }
+        match encoding & DW_EH_PE_APPLICATION {
+            0 => Ok(value),
+            DW_EH_PE_PCREL => Ok(pc.wrapping_add(value)),
+            DW_EH_PE_DATAREL => Ok(address.wrapping_add(value)),
+            application => Err(error::Error::Malformed(format!("Unsupported DWARF pointer application {:#x}", application))),
+        }
+    }
+
+    #[derive(Debug)]
+    /// A parsed `.eh_frame_hdr`
+    pub struct EhFrameHdr<'a> {
+        pub version: u8,
+        /// The encoding of `eh_frame_ptr`
+        pub eh_frame_ptr_enc: u8,
+        /// The encoding of `fde_count`
+        pub fde_count_enc: u8,
+        /// The encoding of the table entries
+        pub table_enc: u8,
+        /// The virtual address of `.eh_frame`
+        pub eh_frame_ptr: u64,
+        /// The number of entries in the table, which is 0 if there is no table
+        pub fde_count: usize,
+        bytes: &'a [u8],
+        table_offset: usize,
+        entry_size: usize,
+        address: u64,
+        ctx: Ctx,
+    }
+
+    impl<'a> EhFrameHdr<'a> {
+        /// Parses the `.eh_frame_hdr` in `bytes`, which is loaded at the virtual address `address`
+        pub fn parse(bytes: &'a [u8], address: u64, ctx: Ctx) -> error::Result<Self> {
+            let mut offset = 0;
+            let version: u8 = bytes.gread(&mut offset)?;
+            if version != EH_FRAME_HDR_VERSION {
+                return Err(error::Error::Malformed(format!("Unknown .eh_frame_hdr version {}", version)));
+            }
+            let eh_frame_ptr_enc: u8 = bytes.gread(&mut offset)?;
+            let fde_count_enc: u8 = bytes.gread(&mut offset)?;
+            let table_enc: u8 = bytes.gread(&mut offset)?;
+            let eh_frame_ptr = read_encoded(bytes, &mut offset, eh_frame_ptr_enc, address, ctx)?;
+            // the table is only usable for a binary search if its entries have a fixed size
+            let entry_size = encoded_size(table_enc, ctx);
+            let (fde_count, entry_size) = match (fde_count_enc, table_enc, entry_size) {
+                (DW_EH_PE_OMIT, _, _) | (_, DW_EH_PE_OMIT, _) | (_, _, None) => (0, 0),
+                (_, _, Some(size)) => (read_encoded(bytes, &mut offset, fde_count_enc, address, ctx)? as usize, size * 2),
+            };
+            let table_size = fde_count.checked_mul(entry_size);
+            match table_size.and_then(|size| offset.checked_add(size)) {
+                Some(end) if end <= bytes.len() => (),
+                _ => return Err(error::Error::Malformed(format!(".eh_frame_hdr of size {:#x} cannot hold {} FDEs", bytes.len(), fde_count))),
+            };
+            Ok(EhFrameHdr {
+                version,
+                eh_frame_ptr_enc,
+                fde_count_enc,
+                table_enc,
+                eh_frame_ptr,
+                fde_count,
+                bytes,
+                table_offset: offset,
+                entry_size,
+                address,
+                ctx,
+            })
+        }
+
+        /// The `(initial_location, fde_address)` entry at `index` of the table, as virtual addresses
+        pub fn get(&self, index: usize) -> Option<(u64, u64)> {
+            if index >= self.fde_count {
+                return None;
+            }
+            let mut offset = self.table_offset + index * self.entry_size;
+            let initial_location = read_encoded(self.bytes, &mut offset, self.table_enc, self.address, self.ctx).ok();
+            let fde_address = read_encoded(self.bytes, &mut offset, self.table_enc, self.address, self.ctx).ok();
+            match (initial_location, fde_address) {
+                (Some(initial_location), Some(fde_address)) => Some((initial_location, fde_address)),
+                _ => None,
+            }
+        }
+
+        /// Finds the virtual address of the FDE covering `pc`, i.e., of the entry with the greatest initial location not above `pc`.
+        ///
+        /// **NB**: the table doesn't record where an FDE's range ends, so a `pc` past the last function still finds the last FDE
+        pub fn lookup(&self, pc: u64) -> Option<u64> {
+            let (mut lo, mut hi) = (0, self.fde_count);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                let (initial_location, _) = self.get(mid)?;
+                if initial_location <= pc { lo = mid + 1 } else { hi = mid }
+            }
+            if lo == 0 {
+                return None;
+            }
+            self.get(lo - 1).map(|(_, fde_address)| fde_address)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use scroll::{self, Pwrite};
+        use container::Container;
+
+        const TABLE_ENC: u8 = DW_EH_PE_DATAREL | DW_EH_PE_SDATA4;
+
+        fn header(fde_count: u32, entries: &[(i32, i32)]) -> Vec<u8> {
+            let mut bytes = vec![0u8; 12 + entries.len() * 8];
+            let mut offset = 0;
+            for byte in &[EH_FRAME_HDR_VERSION, DW_EH_PE_PCREL | DW_EH_PE_SDATA4, DW_EH_PE_UDATA4, TABLE_ENC] {
+                bytes.gwrite(*byte, &mut offset).unwrap();
+            }
+            bytes.gwrite_with(0x20i32, &mut offset, scroll::LE).unwrap();
+            bytes.gwrite_with(fde_count, &mut offset, scroll::LE).unwrap();
+            for &(initial_location, fde) in entries {
+                bytes.gwrite_with(initial_location, &mut offset, scroll::LE).unwrap();
+                bytes.gwrite_with(fde, &mut offset, scroll::LE).unwrap();
+            }
+            bytes
+        }
+
+        #[test]
+        fn parse_and_lookup() {
+            let ctx = Ctx::new(Container::Big, scroll::LE);
+            let bytes = header(3, &[(-0x100, 0x40), (-0x80, 0x58), (0x10, 0x70)]);
+            let hdr = EhFrameHdr::parse(&bytes, 0x1000, ctx).unwrap();
+            // the .eh_frame pointer is pc relative, and sits at offset 4
+            assert_eq!(hdr.eh_frame_ptr, 0x1024);
+            assert_eq!(hdr.fde_count, 3);
+            assert_eq!(hdr.get(0), Some((0xf00, 0x1040)));
+            assert_eq!(hdr.get(3), None);
+            assert_eq!(hdr.lookup(0xeff), None);
+            assert_eq!(hdr.lookup(0xf00), Some(0x1040));
+            assert_eq!(hdr.lookup(0xf7f), Some(0x1040));
+            assert_eq!(hdr.lookup(0xf80), Some(0x1058));
+            assert_eq!(hdr.lookup(0x2000), Some(0x1070));
+        }
+
+        #[test]
+        fn malformed_header() {
+            let ctx = Ctx::new(Container::Big, scroll::LE);
+            // more FDEs than fit in the section
+            assert!(EhFrameHdr::parse(&header(4, &[(0, 0)]), 0, ctx).is_err());
+            assert!(EhFrameHdr::parse(&header(0xffff_ffff, &[]), 0, ctx).is_err());
+            let mut bytes = header(1, &[(0, 0)]);
+            bytes[0] = 2;
+            assert!(EhFrameHdr::parse(&bytes, 0, ctx).is_err());
+            // without a table encoding there's nothing to search
+            let mut bytes = header(1, &[(0, 0)]);
+            bytes[3] = DW_EH_PE_OMIT;
+            let hdr = EhFrameHdr::parse(&bytes, 0, ctx).unwrap();
+            assert_eq!(hdr.fde_count, 0);
+            assert_eq!(hdr.lookup(0), None);
+            let mut offset = 0;
+            assert!(read_encoded(&[0; 8], &mut offset, DW_EH_PE_TEXTREL | DW_EH_PE_UDATA4, 0, ctx).is_err());
+            let mut offset = 0;
+            assert!(read_encoded(&[0; 8], &mut offset, DW_EH_PE_INDIRECT | DW_EH_PE_UDATA4, 0, ctx).is_err());
+        }
+    }
+}
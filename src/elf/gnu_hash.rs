@@ -14,6 +14,17 @@
 //!
 //! See: https://blogs.oracle.com/ali/entry/gnu_hash_elf_sections
 
+/// GNU hash function: takes a string and returns the u32 hash of that string
+pub fn hash(symbol: &str) -> u32 {
+    let bytes = symbol.as_bytes();
+    const HASH_SEED: u32 = 5381;
+    let mut hash = HASH_SEED;
+    for b in bytes {
+        hash = hash.wrapping_mul(32).wrapping_add(*b as u32).wrapping_add(hash);
+    }
+    hash
+}
+
 macro_rules! elf_gnu_hash_impl {
     ($size:ty) => {
 
@@ -22,16 +33,7 @@ macro_rules! elf_gnu_hash_impl {
         use strtab::Strtab;
         use super::sym;
 
-        /// GNU hash function: takes a string and returns the u32 hash of that string
-        pub fn hash(symbol: &str) -> u32 {
-            let bytes = symbol.as_bytes();
-            const HASH_SEED: u32 = 5381;
-            let mut hash = HASH_SEED;
-            for b in bytes {
-                hash = hash.wrapping_mul(32).wrapping_add(*b as u32).wrapping_add(hash);
-            }
-            hash
-        }
+        pub use elf::gnu_hash::hash;
 
         pub struct GnuHash<'process> {
             nbuckets: u32,
@@ -127,3 +129,144 @@ macro_rules! elf_gnu_hash_impl {
         }
     }
 }
+
+if_alloc! {
+    use scroll::Pread;
+    use container::Ctx;
+    use strtab::Strtab;
+    use error;
+    use elf::sym::Symtab;
+    use elf::section_header::SHN_UNDEF;
+
+    #[derive(Debug)]
+    /// A `.gnu.hash` table read from a binary on disk, bounds checked on every access
+    pub struct GnuHash<'a> {
+        nbuckets: u32,
+        symindex: u32,
+        shift2: u32,
+        /// The bloom filter, as 32 or 64-bit words, depending on the container
+        bloom: &'a [u8],
+        buckets: &'a [u8],
+        /// The hash values of `symindex..`; the table does not record its length, so this runs to the end of `bytes`
+        chains: &'a [u8],
+        ctx: Ctx,
+    }
+
+    impl<'a> GnuHash<'a> {
+        /// Parses the hash table at `offset`; fails if the header, the bloom filter or the buckets do not fit in `bytes`,
+        /// so pass only the bytes up to the end of the `SHT_GNU_HASH` section, if the binary has section headers
+        pub fn parse(bytes: &'a [u8], offset: usize, ctx: Ctx) -> error::Result<GnuHash<'a>> {
+            let mut offset = offset;
+            let nbuckets: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let symindex: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let maskwords: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let shift2: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            // the bloom filter is indexed modulo its size, which the linkers always make a power of two
+            if !maskwords.is_power_of_two() {
+                return Err(error::Error::Malformed(format!("GNU hash table at {:#x} has {} bloom words, which is not a power of two", offset, maskwords)));
+            }
+            let bloom_size = (maskwords as usize).checked_mul(ctx.size());
+            let buckets_size = (nbuckets as usize).checked_mul(4);
+            let (bloom_size, buckets_size) = match (bloom_size, buckets_size) {
+                (Some(bloom_size), Some(buckets_size)) => (bloom_size, buckets_size),
+                _ => return Err(error::Error::Malformed(format!("GNU hash table at {:#x} has an overflowing size: {} buckets, {} bloom words", offset, nbuckets, maskwords))),
+            };
+            let bloom: &'a [u8] = bytes.gread_with(&mut offset, bloom_size)?;
+            let buckets: &'a [u8] = bytes.gread_with(&mut offset, buckets_size)?;
+            let chains = &bytes[offset..];
+            Ok(GnuHash { nbuckets, symindex, shift2, bloom, buckets, chains, ctx })
+        }
+
+        /// The index of the first symbol in the dynamic symbol table which is covered by this hash table
+        pub fn symindex(&self) -> usize {
+            self.symindex as usize
+        }
+
+        /// The number of symbols in the dynamic symbol table, which (unlike the SysV hash table) the table does not record:
+        /// it is one past the end of the chain of the highest bucket, or `symindex` if every bucket is empty.
+        ///
+        /// Returns `None` if that chain runs off the end of the table
+        pub fn dynsym_count(&self) -> Option<usize> {
+            let mut max = 0;
+            for i in 0..self.nbuckets as usize {
+                match self.buckets.pread_with::<u32>(i * 4, self.ctx.le) {
+                    Ok(idx) => max = max.max(idx as usize),
+                    Err(_) => return None,
+                }
+            }
+            if max < self.symindex() {
+                return Some(self.symindex());
+            }
+            let mut idx = max;
+            loop {
+                let h2: u32 = match self.chains.pread_with((idx - self.symindex()).saturating_mul(4), self.ctx.le) {
+                    Ok(h2) => h2,
+                    Err(_) => return None,
+                };
+                idx += 1;
+                if h2 & 1 == 1 {
+                    return Some(idx);
+                }
+            }
+        }
+
+        /// Whether the bloom filter rules out `hash`
+        fn filtered(&self, hash: u32) -> bool {
+            let bits = (self.ctx.size() * 8) as u32;
+            let nwords = self.bloom.len() / self.ctx.size();
+            if nwords == 0 {
+                return true;
+            }
+            let offset = ((hash / bits) as usize % nwords) * self.ctx.size();
+            let word: u64 = if self.ctx.is_big() {
+                self.bloom.pread_with(offset, self.ctx.le).unwrap_or(0)
+            } else {
+                self.bloom.pread_with::<u32>(offset, self.ctx.le).unwrap_or(0) as u64
+            };
+            let mask = (1u64 << (hash % bits)) | (1u64 << ((hash >> self.shift2) % bits));
+            word & mask != mask
+        }
+
+        /// Finds the index of the defined symbol `name` in `dynsyms`, using `dynstrtab` for the symbol names
+        pub fn find(&self, name: &str, dynsyms: &Symtab, dynstrtab: &Strtab) -> Option<usize> {
+            if name.is_empty() || self.nbuckets == 0 {
+                return None;
+            }
+            let hash = hash(name);
+            if self.filtered(hash) {
+                return None;
+            }
+            let bucket = (hash % self.nbuckets) as usize * 4;
+            let mut idx = match self.buckets.pread_with::<u32>(bucket, self.ctx.le) {
+                Ok(idx) => idx as usize,
+                Err(_) => return None,
+            };
+            // an empty bucket is 0, and symbols below `symindex` aren't hashed
+            if idx == 0 || idx < self.symindex() {
+                return None;
+            }
+            loop {
+                let chain = (idx - self.symindex()).saturating_mul(4);
+                let h2: u32 = match self.chains.pread_with(chain, self.ctx.le) {
+                    Ok(h2) => h2,
+                    Err(_) => return None,
+                };
+                if hash | 1 == h2 | 1 {
+                    let sym = match dynsyms.get(idx) {
+                        Some(sym) => sym,
+                        None => return None,
+                    };
+                    let matches = dynstrtab.get_at(sym.st_name).ok() == Some(name);
+                    if matches && sym.st_shndx != SHN_UNDEF as usize {
+                        return Some(idx);
+                    }
+                }
+                // the low bit marks the end of the chain
+                if h2 & 1 == 1 {
+                    return None;
+                }
+                idx += 1;
+            }
+        }
+    }
+}
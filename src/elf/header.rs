@@ -114,6 +114,22 @@ pub const ELFDATA2MSB: u8 = 2;
 /// Number of bytes in an identifier.
 pub const SIZEOF_IDENT: usize = 16;
 
+// ARM specific values for e_flags
+/// Mask of the ARM EABI version.
+pub const EF_ARM_EABIMASK: u32 = 0xff000000;
+/// The code uses the soft-float ABI.
+pub const EF_ARM_ABI_FLOAT_SOFT: u32 = 0x00000200;
+/// The code uses the hard-float (VFP register) ABI.
+pub const EF_ARM_ABI_FLOAT_HARD: u32 = 0x00000400;
+/// The code is BE8, i.e., big endian data with little endian instructions.
+pub const EF_ARM_BE8: u32 = 0x00800000;
+
+/// The ARM EABI version encoded in `e_flags`, 0 if unknown
+#[inline]
+pub fn arm_eabi_version(e_flags: u32) -> u32 {
+    (e_flags & EF_ARM_EABIMASK) >> 24
+}
+
 /// Convert a ELF class byte to the associated string.
 #[inline]
 pub fn class_to_str(et: u8) -> &'static str {
@@ -37,7 +37,8 @@
 //! `endian_fd` features if you disable `default`.
 
 #[macro_use]
-mod gnu_hash;
+pub mod gnu_hash;
+pub mod sysv_hash;
 
 // These are shareable values for the 32/64 bit implementations.
 //
@@ -52,6 +53,14 @@ pub mod dynamic;
 #[macro_use]
 pub mod reloc;
 pub mod note;
+pub mod arm_attributes;
+pub mod versym;
+pub mod eh_frame;
+pub mod debuglink;
+#[cfg(all(feature = "elf32", feature = "elf64", feature = "std"))]
+pub mod builder;
+#[cfg(all(feature = "elf32", feature = "elf64", feature = "std"))]
+pub use self::builder::Builder;
 
 macro_rules! if_sylvan {
     ($($i:item)*) => ($(
@@ -61,11 +70,15 @@ macro_rules! if_sylvan {
 }
 
 if_sylvan! {
-    use scroll::{self, ctx, Pread, Endian};
+    use scroll::{self, ctx, Pread, Pwrite, Endian};
     use strtab::Strtab;
     use error;
     use container::{Container, Ctx};
     use alloc::vec::Vec;
+    use alloc::collections::{BTreeMap, BTreeSet};
+    use anomaly::{self, Anomaly, Region};
+    use options::{ParseOptions, Recovery};
+    use core::cell::{Ref, RefCell};
 
     pub type Header = header::Header;
     pub type ProgramHeader = program_header::ProgramHeader;
@@ -75,11 +88,46 @@ if_sylvan! {
     pub type Dyn = dynamic::Dyn;
     pub type Dynamic = dynamic::Dynamic;
     pub type Reloc = reloc::Reloc;
+    pub type GnuHash<'a> = gnu_hash::GnuHash<'a>;
+    pub type SysvHash<'a> = sysv_hash::SysvHash<'a>;
+    pub type VersymSection<'a> = versym::VersymSection<'a>;
+    pub type VerneedSection = versym::VerneedSection;
+    pub type VerdefSection = versym::VerdefSection;
 
     pub type ProgramHeaders = Vec<ProgramHeader>;
     pub type SectionHeaders = Vec<SectionHeader>;
     pub type ShdrIdx = usize;
 
+    #[derive(Debug, Clone, PartialEq)]
+    /// Everything needed to find a binary's dependencies on disk; see `Elf::dependency_query`
+    pub struct DependencyQuery<'a> {
+        /// The `DT_NEEDED` libraries, in load order
+        pub needed: Vec<&'a str>,
+        /// The `DT_RPATH` search paths
+        pub rpaths: Vec<&'a str>,
+        /// The `DT_RUNPATH` search paths
+        pub runpaths: Vec<&'a str>,
+        /// The binary's own soname, which is how other binaries refer to it
+        pub soname: Option<&'a str>,
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// A part of an ELF binary which a lenient parse could not read, and so left empty; see `Elf::parse_with`
+    pub enum Component {
+        /// The program interpreter, so `interpreter` is `None`
+        Interpreter,
+        /// The section header table, so `section_headers` is empty, and there are no section based components either
+        SectionHeaders,
+        /// The section at this index: the section header string table, the symbol table or its string table, or a relocation section
+        Section(ShdrIdx),
+        /// The `PT_DYNAMIC` array (`dynamic` is `None`) or the dynamic string table it locates (`soname`, `libraries`, `rpaths` and `runpaths` are empty)
+        Dynamic,
+        /// The dynamic symbol table, so `dynsyms` is empty
+        DynamicSymbols,
+        /// One of the dynamic relocation tables, so `dynrelas`, `dynrels` or `pltrelocs` lack its relocations
+        DynamicRelocations,
+    }
+
     #[derive(Debug)]
     /// An ELF binary. The underlying data structures are read according to the headers byte order and container size (32 or 64).
     pub struct Elf<'a> {
@@ -103,6 +151,16 @@ if_sylvan! {
         pub syms: Symtab<'a>,
         /// The string table for the symbol table
         pub strtab: Strtab<'a>,
+        /// The GNU hash table for the dynamic symbols, if it has one
+        pub gnu_hash: Option<GnuHash<'a>>,
+        /// The SysV hash table for the dynamic symbols, if it has one
+        pub sysv_hash: Option<SysvHash<'a>>,
+        /// The `.gnu.version` section, with the version index of every dynamic symbol, if it has one
+        pub versym: Option<VersymSection<'a>>,
+        /// The `.gnu.version_r` section, with the versions required from the dependencies, if it has one
+        pub verneed: Option<VerneedSection>,
+        /// The `.gnu.version_d` section, with the versions this binary defines, if it has one
+        pub verdef: Option<VerdefSection>,
         /// Contains dynamic linking information, with the _DYNAMIC array + a preprocessed DynamicInfo for that array
         pub dynamic: Option<Dynamic>,
         /// The dynamic relocation entries (strings, copy-data, etc.) with an addend
@@ -119,6 +177,10 @@ if_sylvan! {
         pub interpreter: Option<&'a str>,
         /// A list of this binary's dynamic libraries it uses, if there are any
         pub libraries: Vec<&'a str>,
+        /// The `DT_RPATH` library search paths, if there are any
+        pub rpaths: Vec<&'a str>,
+        /// The `DT_RUNPATH` library search paths, if there are any
+        pub runpaths: Vec<&'a str>,
         pub is_64: bool,
         /// Whether this is a shared object or not
         pub is_lib: bool,
@@ -128,22 +190,62 @@ if_sylvan! {
         pub bias: u64,
         /// Whether the binary is little endian or not
         pub little_endian: bool,
+        /// The components a lenient parse could not read, and the reason; always empty unless parsed with `ParseOptions::fail_fast` unset
+        pub errors: Vec<(Component, error::Error)>,
         ctx: Ctx,
+        symbol_index: Option<BTreeMap<&'a str, usize>>,
+        address_index: Option<Vec<(&'a str, u64, u64)>>,
+    }
+
+    impl Reloc {
+        /// The name of the symbol this relocation refers to, or `None` if it refers to no symbol (`r_sym` is 0) or the name is malformed.
+        ///
+        /// `section` is the index of the relocation section this relocation was read from, whose `sh_link` selects either the symbol table or the dynamic symbol table;
+        /// pass `None` for the dynamic relocations (`dynrelas`, `dynrels` and `pltrelocs`), which always refer to the dynamic symbol table.
+        /// Section symbols have an empty name
+        pub fn symbol<'a>(&self, elf: &Elf<'a>, section: Option<ShdrIdx>) -> Option<&'a str> {
+            if self.r_sym == 0 {
+                return None;
+            }
+            let (syms, strtab) = match section {
+                None => (&elf.dynsyms, &elf.dynstrtab),
+                Some(idx) => {
+                    let link = match elf.section_headers.get(idx) {
+                        Some(shdr) => shdr.sh_link as usize,
+                        None => return None,
+                    };
+                    match elf.section_headers.get(link).map(|shdr| shdr.sh_type) {
+                        Some(section_header::SHT_SYMTAB) => (&elf.syms, &elf.strtab),
+                        Some(section_header::SHT_DYNSYM) => (&elf.dynsyms, &elf.dynstrtab),
+                        _ => return None,
+                    }
+                }
+            };
+            syms.get(self.r_sym).and_then(|sym| strtab.get_at(sym.st_name).ok())
+        }
     }
 
     impl<'a> Elf<'a> {
         /// Try to iterate notes in PT_NOTE program headers; returns `None` if there aren't any note headers in this binary
         pub fn iter_note_headers(&self, data: &'a [u8]) -> Option<note::NoteIterator<'a>> {
+            self.iter_note_headers_with(data, program_header::PT_NOTE, None)
+        }
+        /// Like `iter_note_headers`, but pads the notes to `alignment` (4 or 8) whatever the `p_align` of their headers,
+        /// since some toolchains record the wrong alignment, e.g., 8 for the 4 byte aligned notes of 64-bit core dumps
+        pub fn iter_note_headers_aligned(&self, data: &'a [u8], alignment: usize) -> Option<note::NoteIterator<'a>> {
+            self.iter_note_headers_with(data, program_header::PT_NOTE, Some(alignment))
+        }
+        fn iter_note_headers_with(&self, data: &'a [u8], p_type: u32, alignment: Option<usize>) -> Option<note::NoteIterator<'a>> {
             let mut iters = vec![];
             for phdr in &self.program_headers {
-                if phdr.p_type == program_header::PT_NOTE {
+                if phdr.p_type == p_type {
                     let offset = phdr.p_offset as usize;
-                    let alignment = phdr.p_align as usize;
+                    let alignment = alignment.unwrap_or(phdr.p_align as usize);
 
                     iters.push(note::NoteDataIterator {
                         data,
                         offset,
-                        size: offset + phdr.p_filesz as usize,
+                        size: offset.saturating_add(phdr.p_filesz as usize),
                         ctx: (alignment, self.ctx)
                     });
                 }
@@ -183,7 +285,7 @@ if_sylvan! {
                 iters.push(note::NoteDataIterator {
                     data,
                     offset,
-                    size: offset + sect.sh_size as usize,
+                    size: offset.saturating_add(sect.sh_size as usize),
                     ctx: (alignment, self.ctx)
                 });
             }
@@ -197,14 +299,410 @@ if_sylvan! {
                 })
             }
         }
+        /// The properties of the `NT_GNU_PROPERTY_TYPE_0` note, e.g., whether the binary supports Intel CET or AArch64 BTI,
+        /// from the `.note.gnu.property` section, or else the `PT_GNU_PROPERTY` segment; empty if there is neither. `data` must be the original binary
+        pub fn gnu_properties(&self, data: &'a [u8]) -> error::Result<Vec<note::GnuProperty>> {
+            let notes = self.iter_note_sections(data, Some(".note.gnu.property"))
+                .or_else(|| self.iter_note_headers_with(data, program_header::PT_GNU_PROPERTY, None));
+            let mut properties = Vec::new();
+            for note in notes.into_iter().flat_map(|notes| notes) {
+                let note = note?;
+                if note.name == "GNU" && note.n_type == note::NT_GNU_PROPERTY_TYPE_0 {
+                    properties.extend(note::GnuProperty::parse(note.desc, self.ctx)?);
+                }
+            }
+            Ok(properties)
+        }
+        /// The name of the dynamic relocation `reloc` refers to, i.e., one of `dynrelas`, `dynrels` or `pltrelocs`, resolved against the dynamic symbol table;
+        /// see `reloc_symbol_in` for the relocations of `shdr_relocs`, and `Reloc::symbol`
+        pub fn reloc_symbol(&self, reloc: &Reloc) -> Option<&'a str> {
+            reloc.symbol(self, None)
+        }
+        /// The name of the symbol `reloc`, read from the relocation section at `section_idx` (as paired in `shdr_relocs`), refers to,
+        /// resolved against the symbol table that section's `sh_link` selects
+        pub fn reloc_symbol_in(&self, reloc: &Reloc, section_idx: ShdrIdx) -> Option<&'a str> {
+            reloc.symbol(self, Some(section_idx))
+        }
+        /// Looks up the defined dynamic symbol `name`, returning its index in `dynsyms` and the symbol itself.
+        ///
+        /// Uses the GNU hash table if there is one, then the SysV hash table, and otherwise scans `dynsyms`.
+        /// Undefined symbols (i.e., imports) and the null symbol never match.
+        pub fn lookup_dynamic_symbol(&self, name: &str) -> Option<(usize, Sym)> {
+            if name.is_empty() {
+                return None;
+            }
+            let idx = if let Some(ref gnu_hash) = self.gnu_hash {
+                gnu_hash.find(name, &self.dynsyms, &self.dynstrtab)
+            } else if let Some(ref sysv_hash) = self.sysv_hash {
+                sysv_hash.find(name, &self.dynsyms, &self.dynstrtab)
+            } else {
+                self.dynsyms.iter().skip(1).position(|sym| {
+                    sym.st_shndx != section_header::SHN_UNDEF as usize &&
+                        self.dynstrtab.get_at(sym.st_name).ok() == Some(name)
+                }).map(|idx| idx + 1)
+            };
+            match idx {
+                Some(idx) => self.dynsyms.get(idx).map(|sym| (idx, sym)),
+                None => None,
+            }
+        }
+        /// Finds the symbol `name` in the symbol table (`.symtab`), returning its index and the symbol;
+        /// if several symbols share the name, a defined one is preferred over undefined ones, and otherwise the first one wins.
+        ///
+        /// This scans the whole table, unless `build_symbol_index` has been called; use `lookup_dynamic_symbol` for the dynamic symbols
+        pub fn symbol_by_name(&self, name: &str) -> Option<(usize, Sym)> {
+            if name.is_empty() {
+                return None;
+            }
+            let idx = match self.symbol_index {
+                Some(ref index) => index.get(name).cloned(),
+                None => {
+                    let mut found = None;
+                    for (idx, sym) in self.syms.iter().enumerate() {
+                        if self.strtab.get_at(sym.st_name).ok() == Some(name) {
+                            if sym.st_shndx != section_header::SHN_UNDEF as usize {
+                                found = Some(idx);
+                                break;
+                            } else if found.is_none() {
+                                found = Some(idx);
+                            }
+                        }
+                    }
+                    found
+                }
+            };
+            match idx {
+                Some(idx) => self.syms.get(idx).map(|sym| (idx, sym)),
+                None => None,
+            }
+        }
+        /// The name of the version of the dynamic symbol at `index`, e.g., `GLIBC_2.14` for `memcpy@GLIBC_2.14`, whether it is required from a dependency or defined by this binary.
+        ///
+        /// Returns `None` if the symbol is local or unversioned, or if the binary has no (or malformed) versioning sections; the hidden bit is ignored, see `versym::Versym::is_hidden`
+        pub fn symbol_version(&self, index: usize) -> Option<&'a str> {
+            let version = match self.versym.as_ref().and_then(|versym| versym.get(index)) {
+                Some(versym) => versym.version(),
+                None => return None,
+            };
+            if version == versym::VER_NDX_LOCAL || version == versym::VER_NDX_GLOBAL {
+                return None;
+            }
+            let name = if let Some((_, vernaux)) = self.verneed.as_ref().and_then(|verneed| verneed.find(version)) {
+                vernaux.vna_name
+            } else {
+                match self.verdef.as_ref().and_then(|verdef| verdef.find(version)).and_then(|verdef| verdef.aux.first()) {
+                    Some(verdaux) => verdaux.vda_name,
+                    None => return None,
+                }
+            };
+            self.dynstrtab.get(name as usize).and_then(|name| name.ok())
+        }
+        /// Builds a map from name to index of the symbol table, which makes every subsequent `symbol_by_name` a map lookup instead of a scan
+        pub fn build_symbol_index(&mut self) {
+            let mut index = BTreeMap::new();
+            let mut defined = BTreeMap::new();
+            for (idx, sym) in self.syms.iter().enumerate() {
+                let name = match self.strtab.get_at(sym.st_name) {
+                    Ok(name) if !name.is_empty() => name,
+                    _ => continue,
+                };
+                if sym.st_shndx != section_header::SHN_UNDEF as usize {
+                    if !defined.contains_key(name) {
+                        defined.insert(name, idx);
+                    }
+                } else if !index.contains_key(name) {
+                    index.insert(name, idx);
+                }
+            }
+            index.extend(defined);
+            self.symbol_index = Some(index);
+        }
+        /// The symbols of the symbol table, `.symtab`, with their names in `strtab`; these include the local symbols, but are empty if the binary is stripped,
+        /// which leaves only the `dynamic_symbols`
+        pub fn static_symbols<'b>(&'b self) -> sym::NamedSymIterator<'a, 'b> {
+            sym::NamedSymIterator::new(&self.syms, &self.strtab)
+        }
+        /// The symbols of the dynamic symbol table, `.dynsym`, with their names in `dynstrtab`: only the imports and exports, which are kept by `strip`
+        pub fn dynamic_symbols<'b>(&'b self) -> sym::NamedSymIterator<'a, 'b> {
+            sym::NamedSymIterator::new(&self.dynsyms, &self.dynstrtab)
+        }
+        /// The `static_symbols`, then the `dynamic_symbols` which are not among them, i.e., without a static symbol of the same name at the same address.
+        ///
+        /// A symbol in both tables is thus the one of `.symtab`; its size and binding are the same in either, but a static symbol may also be local
+        pub fn all_symbols_deduped(&self) -> Vec<(&'a str, Sym)> {
+            let mut symbols = self.static_symbols().collect::<Vec<_>>();
+            let seen = symbols.iter().map(|&(name, ref sym)| (name, sym.st_value)).collect::<BTreeSet<_>>();
+            symbols.extend(self.dynamic_symbols().filter(|&(name, ref sym)| !seen.contains(&(name, sym.st_value))));
+            symbols
+        }
+        /// The named functions and objects of the symbol table and the dynamic symbol table, as `(name, address, size)`, sorted by address;
+        /// a symbol whose `st_size` is 0 is sized up to the next symbol, or to the end of the section (or else the `PT_LOAD` segment) containing it, if that is closer.
+        ///
+        /// Undefined, absolute and thread local symbols have no address, and are left out; in a relocatable object, addresses are relative to each symbol's section
+        pub fn sorted_symbols(&self) -> Vec<(&'a str, u64, u64)> {
+            if let Some(ref index) = self.address_index {
+                return index.clone();
+            }
+            let mut symbols = Vec::new();
+            for &(ref syms, ref strtab) in [(&self.syms, &self.strtab), (&self.dynsyms, &self.dynstrtab)].iter() {
+                for sym in syms.iter() {
+                    let st_type = sym.st_type();
+                    if sym.st_shndx == section_header::SHN_UNDEF as usize || sym.st_shndx >= section_header::SHN_LORESERVE as usize
+                        || st_type == sym::STT_SECTION || st_type == sym::STT_FILE || st_type == sym::STT_TLS {
+                        continue;
+                    }
+                    match strtab.get_at(sym.st_name) {
+                        Ok(name) if !name.is_empty() => symbols.push((name, sym.st_value, sym.st_size)),
+                        _ => (),
+                    }
+                }
+            }
+            let sections = self.section_headers.iter()
+                .filter(|shdr| shdr.sh_flags & section_header::SHF_ALLOC as u64 != 0 && shdr.sh_flags & section_header::SHF_TLS as u64 == 0)
+                .map(|shdr| (shdr.sh_addr, shdr.sh_size));
+            let segments = self.program_headers.iter()
+                .filter(|phdr| phdr.p_type == program_header::PT_LOAD)
+                .map(|phdr| (phdr.p_vaddr, phdr.p_memsz));
+            let regions = sections.chain(segments).collect::<Vec<_>>();
+            ::symbol::sort_by_address(&mut symbols, |address| {
+                regions.iter()
+                    .find(|&&(start, size)| start <= address && address - start < size)
+                    .map(|&(start, size)| start + size)
+            });
+            symbols
+        }
+        /// Finds the symbol of `sorted_symbols` containing `address`, returning its name and the offset of `address` into it.
+        ///
+        /// This sorts the symbols on every call, unless `build_address_index` has been called
+        pub fn symbolicate(&self, address: u64) -> Option<(&'a str, u64)> {
+            match self.address_index {
+                Some(ref index) => ::symbol::containing(index, address).map(|(&name, offset)| (name, offset)),
+                None => ::symbol::containing(&self.sorted_symbols(), address).map(|(&name, offset)| (name, offset)),
+            }
+        }
+        /// Sorts the symbols once, which makes every subsequent `symbolicate` a binary search, and `sorted_symbols` a copy
+        pub fn build_address_index(&mut self) {
+            self.address_index = None;
+            self.address_index = Some(self.sorted_symbols());
+        }
+        /// Parses the build attributes of the `.ARM.attributes` section, if this is an ARM binary which has one; `data` must be the original binary
+        pub fn arm_attributes(&self, data: &'a [u8]) -> error::Result<Option<arm_attributes::ArmAttributes<'a>>> {
+            if self.header.e_machine != header::EM_ARM {
+                return Ok(None);
+            }
+            for shdr in &self.section_headers {
+                if shdr.sh_type == section_header::SHT_ARM_ATTRIBUTES {
+                    shdr.check_size(data.len())?;
+                    let offset = shdr.sh_offset as usize;
+                    let bytes = &data[offset..offset + shdr.sh_size as usize];
+                    return arm_attributes::ArmAttributes::parse(bytes, self.ctx.le).map(Some);
+                }
+            }
+            Ok(None)
+        }
+        /// Parses the `.eh_frame_hdr` FDE search table of the `PT_GNU_EH_FRAME` segment, if there is one; `data` must be the original binary
+        pub fn eh_frame_hdr(&self, data: &'a [u8]) -> error::Result<Option<eh_frame::EhFrameHdr<'a>>> {
+            for ph in &self.program_headers {
+                if ph.p_type == program_header::PT_GNU_EH_FRAME {
+                    let offset = ph.p_offset as usize;
+                    let bytes = match (ph.p_filesz as usize).checked_add(offset).and_then(|end| data.get(offset..end)) {
+                        Some(bytes) => bytes,
+                        None => return Err(error::Error::Malformed(format!("PT_GNU_EH_FRAME at {:#x} of size {:#x} is out of bounds", offset, ph.p_filesz))),
+                    };
+                    return eh_frame::EhFrameHdr::parse(bytes, ph.p_vaddr, self.ctx).map(Some);
+                }
+            }
+            Ok(None)
+        }
+        #[cfg(feature = "compression")]
+        /// Decompresses the xz compressed ELF in the `.gnu_debugdata` section (aka MiniDebugInfo), if there is one; `data` must be the original binary.
+        ///
+        /// The embedded ELF usually contains only a `.symtab` with the function symbols stripped from this binary; parse it with `Elf::parse` to get at them.
+        pub fn mini_debug_info(&self, data: &[u8]) -> error::Result<Option<Vec<u8>>> {
+            for shdr in &self.section_headers {
+                let is_debugdata = self.shdr_strtab.get(shdr.sh_name).map_or(false, |r| r.ok() == Some(".gnu_debugdata"));
+                if is_debugdata && shdr.sh_type != section_header::SHT_NOBITS {
+                    shdr.check_size(data.len())?;
+                    let offset = shdr.sh_offset as usize;
+                    let compressed = &data[offset..offset + shdr.sh_size as usize];
+                    return ::compression::decompress_xz(compressed).map(Some);
+                }
+            }
+            Ok(None)
+        }
+        /// Parses the `.gnu_debuglink` section, which names the separate debug file of a stripped binary, if there is one; `data` must be the original binary
+        pub fn debuglink(&self, data: &'a [u8]) -> error::Result<Option<debuglink::DebugLink<'a>>> {
+            match self.section_data_by_name(data, ".gnu_debuglink")? {
+                Some(bytes) => debuglink::DebugLink::parse(bytes, self.ctx.le).map(Some),
+                None => Ok(None),
+            }
+        }
+        /// Parses the `.gnu_debugaltlink` section, which names the supplementary debug file `dwz` split out of this one, if there is one; `data` must be the original binary
+        pub fn debugaltlink(&self, data: &'a [u8]) -> error::Result<Option<debuglink::DebugAltLink<'a>>> {
+            match self.section_data_by_name(data, ".gnu_debugaltlink")? {
+                Some(bytes) => debuglink::DebugAltLink::parse(bytes).map(Some),
+                None => Ok(None),
+            }
+        }
+        fn section_data_by_name(&self, data: &'a [u8], name: &str) -> error::Result<Option<&'a [u8]>> {
+            for shdr in &self.section_headers {
+                if self.shdr_strtab.get(shdr.sh_name).map_or(false, |r| r.ok() == Some(name)) {
+                    return shdr.data(data).map(Some);
+                }
+            }
+            Ok(None)
+        }
+        /// Bundles what a resolver needs to find this binary's dependencies, since goblin itself never touches the filesystem.
+        ///
+        /// To compute the full dependency closure the way glibc's `ld.so` does, for every `needed` library that isn't already loaded (by soname):
+        ///
+        /// 1. if the name contains a `/`, it is a path, and is used as is
+        /// 2. otherwise, if there are no `runpaths`, search the `rpaths` of this binary, then those of the binary that loaded it, and so on up to the executable
+        /// 3. then `LD_LIBRARY_PATH`
+        /// 4. then this binary's `runpaths` (which, unlike `rpaths`, are not inherited by dependencies)
+        /// 5. then `/etc/ld.so.cache` and the default directories, e.g., `/lib` and `/usr/lib`
+        ///
+        /// `$ORIGIN` in a search path expands to the directory of the binary which has that path; parse each library found and recurse.
+        pub fn dependency_query(&self) -> DependencyQuery<'a> {
+            DependencyQuery {
+                needed: self.libraries.clone(),
+                rpaths: self.rpaths.clone(),
+                runpaths: self.runpaths.clone(),
+                soname: self.soname,
+            }
+        }
         pub fn is_object_file(&self) -> bool {
             self.header.e_type == header::ET_REL
         }
-        /// Parses the contents of the byte stream in `bytes`, and maybe returns a unified binary
-        pub fn parse(bytes: &'a [u8]) -> error::Result<Self> {
+        /// Translates the virtual address `vaddr` into an offset in the file, by the `PT_LOAD` segment which contains it.
+        ///
+        /// The segments are searched in the order of the program header table, and the first whose memory image contains `vaddr` wins, even if segments overlap;
+        /// empty segments contain nothing. Returns `None` if no segment contains `vaddr`, or if it lies past the segment's file image, i.e., in its bss
+        pub fn vm_to_file_offset(&self, vaddr: u64) -> Option<u64> {
+            for phdr in &self.program_headers {
+                if phdr.p_type == program_header::PT_LOAD && vaddr >= phdr.p_vaddr && vaddr - phdr.p_vaddr < phdr.p_memsz {
+                    let delta = vaddr - phdr.p_vaddr;
+                    return if delta < phdr.p_filesz { phdr.p_offset.checked_add(delta) } else { None };
+                }
+            }
+            None
+        }
+        /// Translates the file `offset` into a virtual address, by the first `PT_LOAD` segment whose file image contains it; the inverse of `vm_to_file_offset`,
+        /// with the same (first match wins) treatment of overlapping and empty segments
+        pub fn file_offset_to_vm(&self, offset: u64) -> Option<u64> {
+            for phdr in &self.program_headers {
+                if phdr.p_type == program_header::PT_LOAD && offset >= phdr.p_offset && offset - phdr.p_offset < phdr.p_filesz {
+                    return phdr.p_vaddr.checked_add(offset - phdr.p_offset);
+                }
+            }
+            None
+        }
+        /// Applies the `R_*_RELATIVE` dynamic relocations of `dynrelas`, `dynrels` and `pltrelocs` to `image`, this binary loaded at `load_bias`:
+        /// each writes `load_bias` plus its addend at its offset, where a `Rel`'s addend is the value already there.
+        ///
+        /// `image` is the memory image of the `PT_LOAD` segments, starting at the lowest `p_vaddr` rounded down to its `p_align`; every write is bounds checked against it.
+        /// Only x86, x86-64, ARM and AArch64 relocations are known. Symbolic binds are the caller's job, see `apply_relocations_with`, as are `R_*_IRELATIVE`s,
+        /// whose value is what the resolver function at `load_bias` plus the addend returns
+        pub fn apply_relocations(&self, image: &mut [u8], load_bias: u64) -> error::Result<()> {
+            self.apply_relocations_with(image, load_bias, |_| Ok(None))
+        }
+        /// Like `apply_relocations`, but calls `resolve` with every other relocation, which returns the pointer sized value to write at its offset, or `None` to leave it be
+        pub fn apply_relocations_with<F: FnMut(&Reloc) -> error::Result<Option<u64>>>(&self, image: &mut [u8], load_bias: u64, mut resolve: F) -> error::Result<()> {
+            let relative = match self.header.e_machine {
+                header::EM_X86_64 => Some(reloc::R_X86_64_RELATIVE),
+                header::EM_386 => Some(reloc::R_386_RELATIVE),
+                header::EM_AARCH64 => Some(reloc::R_AARCH64_RELATIVE),
+                header::EM_ARM => Some(reloc::R_ARM_RELATIVE),
+                _ => None,
+            };
+            let base = self.program_headers.iter()
+                .filter(|phdr| phdr.p_type == program_header::PT_LOAD)
+                .map(|phdr| phdr.p_vaddr - phdr.p_vaddr % ::core::cmp::max(phdr.p_align, 1))
+                .min()
+                .unwrap_or(0);
+            let size = self.ctx.size();
+            for reloc in self.dynrelas.iter().chain(&self.dynrels).chain(&self.pltrelocs) {
+                let len = image.len();
+                let location = || -> error::Result<usize> {
+                    match reloc.r_offset.checked_sub(base) {
+                        Some(offset) if len >= size && offset <= (len - size) as u64 => Ok(offset as usize),
+                        _ => Err(error::Error::Malformed(format!("relocation at {:#x} is outside of the {:#x} byte image at {:#x}", reloc.r_offset, len, base))),
+                    }
+                };
+                let value = if Some(reloc.r_type) == relative {
+                    let addend = match reloc.r_addend {
+                        Some(addend) => addend as u64,
+                        None if self.is_64 => image.pread_with::<u64>(location()?, self.ctx.le)?,
+                        None => image.pread_with::<u32>(location()?, self.ctx.le)? as u64,
+                    };
+                    load_bias.wrapping_add(addend)
+                } else {
+                    match resolve(reloc)? {
+                        Some(value) => value,
+                        None => continue,
+                    }
+                };
+                let offset = location()?;
+                if self.is_64 {
+                    image.pwrite_with(value, offset, self.ctx.le)?;
+                } else {
+                    image.pwrite_with(value as u32, offset, self.ctx.le)?;
+                }
+            }
+            Ok(())
+        }
+        /// Inspects the parsed section and program header tables for structural inconsistencies, like overlapping sections, or an entry point outside of any executable segment; `data` must be the original binary
+        pub fn anomalies(&self, data: &[u8]) -> Vec<Anomaly> {
+            let file_size = data.len() as u64;
+            let mut anomalies = Vec::new();
+            let mut loads = Vec::new();
+            for (i, phdr) in self.program_headers.iter().enumerate() {
+                let range = anomaly::push_out_of_bounds(&mut anomalies, Region::Segment(i), phdr.p_offset, phdr.p_filesz, file_size);
+                if let Some(range) = range {
+                    if phdr.p_type == program_header::PT_LOAD {
+                        loads.push((Region::Segment(i), range));
+                    }
+                }
+            }
+            let mut sections = Vec::new();
+            for (i, shdr) in self.section_headers.iter().enumerate() {
+                // NOBITS sections occupy no bytes in the file, whatever their offset and size claim
+                if shdr.sh_type == section_header::SHT_NOBITS || shdr.sh_type == section_header::SHT_NULL {
+                    continue;
+                }
+                if let Some(range) = anomaly::push_out_of_bounds(&mut anomalies, Region::Section(i), shdr.sh_offset, shdr.sh_size, file_size) {
+                    sections.push((Region::Section(i), range));
+                }
+            }
+            anomaly::push_overlaps(&mut anomalies, &loads);
+            anomaly::push_overlaps(&mut anomalies, &sections);
+            for (i, phdr) in self.program_headers.iter().enumerate() {
+                if phdr.p_type != program_header::PT_LOAD || phdr.p_memsz != 0 {
+                    continue;
+                }
+                for (j, shdr) in self.section_headers.iter().enumerate() {
+                    if shdr.is_alloc() && shdr.sh_size != 0 && shdr.sh_addr == phdr.p_vaddr {
+                        anomalies.push(Anomaly::EmptySegment { segment: i, section: j });
+                    }
+                }
+            }
+            let mut has_load = false;
+            let mut entry_is_executable = false;
+            for phdr in &self.program_headers {
+                if phdr.p_type == program_header::PT_LOAD {
+                    has_load = true;
+                    entry_is_executable |= phdr.is_executable() && phdr.contains_vaddr(self.entry);
+                }
+            }
+            // relocatable objects (and other unloadable files) have no meaningful entry point
+            if has_load && self.entry != 0 && !entry_is_executable {
+                anomalies.push(Anomaly::EntryNotExecutable { entry: self.entry });
+            }
+            anomalies
+        }
+        /// Parses only the ELF header, deferring everything else to the accessors of the returned `LazyElf`
+        pub fn lazy_parse(bytes: &'a [u8]) -> error::Result<LazyElf<'a>> {
             let header = bytes.pread::<Header>(0)?;
-            let entry = header.e_entry as usize;
-            let is_lib = header.e_type == header::ET_DYN;
             let is_lsb = header.e_ident[header::EI_DATA] == header::ELFDATA2LSB;
             let endianness = scroll::Endian::from(is_lsb);
             let class = header.e_ident[header::EI_CLASS];
@@ -216,6 +714,34 @@ if_sylvan! {
             let is_64 = class == header::ELFCLASS64;
             let container = if is_64 { Container::Big } else { Container::Little };
             let ctx = Ctx::new(container, endianness);
+            Ok(LazyElf {
+                header,
+                is_64,
+                little_endian: is_lsb,
+                ctx,
+                program_headers: RefCell::new(None),
+                section_headers: RefCell::new(None),
+                shdr_strtab: RefCell::new(None),
+                syms: RefCell::new(None),
+                dynamic: RefCell::new(None),
+            })
+        }
+
+        /// Parses the contents of the given binary
+        pub fn parse(bytes: &'a [u8]) -> error::Result<Self> {
+            Self::parse_with(bytes, ParseOptions::default())
+        }
+
+        /// Parses the contents of the given binary according to `opts`.
+        ///
+        /// Unless `opts.fail_fast`, a malformed component is left empty rather than failing the parse, and recorded in `errors`;
+        /// see `Component` for which fields it leaves empty. The header and program headers are always required
+        pub fn parse_with(bytes: &'a [u8], opts: ParseOptions) -> error::Result<Self> {
+            let mut recovery = Recovery::new(&opts);
+            let LazyElf { header, is_64, little_endian: is_lsb, ctx, .. } = Elf::lazy_parse(bytes)?;
+            let entry = header.e_entry as usize;
+            let is_lib = header.e_type == header::ET_DYN;
+            let container = ctx.container;
 
             let program_headers = ProgramHeader::parse(bytes, header.e_phoff as usize, header.e_phnum as usize, ctx)?;
 
@@ -243,13 +769,16 @@ if_sylvan! {
             let mut interpreter = None;
             for ph in &program_headers {
                 if ph.p_type == program_header::PT_INTERP && ph.p_filesz != 0 {
-                    let count = (ph.p_filesz - 1) as usize;
-                    let offset = ph.p_offset as usize;
-                    interpreter = Some(bytes.pread_with::<&str>(offset, ::scroll::ctx::StrCtx::Length(count))?);
+                    // the path is NUL terminated, but the segment may be padded past it, or, malformed, not hold the NUL at all
+                    let path = match bytes.get(ph.p_offset as usize..(ph.p_offset as usize).saturating_add(ph.p_filesz as usize)) {
+                        Some(segment) => segment.pread::<&str>(0).map(Some).map_err(Into::into),
+                        None => Err(error::Error::Malformed(format!("PT_INTERP segment at {:#x} of size {:#x} is out of bounds", ph.p_offset, ph.p_filesz))),
+                    };
+                    interpreter = recovery.recover(Component::Interpreter, path)?;
                 }
             }
 
-            let section_headers = SectionHeader::parse(bytes, header.e_shoff as usize, header.e_shnum as usize, ctx)?;
+            let section_headers = recovery.recover(Component::SectionHeaders, SectionHeader::parse(bytes, header.e_shoff as usize, header.e_shnum as usize, ctx))?;
 
             let get_strtab = |section_headers: &[SectionHeader], section_idx: usize| {
                 if section_idx >= section_headers.len() {
@@ -263,33 +792,37 @@ if_sylvan! {
             };
 
             let strtab_idx = header.e_shstrndx as usize;
-            let shdr_strtab = get_strtab(&section_headers, strtab_idx)?;
+            let shdr_strtab = recovery.recover(Component::Section(strtab_idx), get_strtab(&section_headers, strtab_idx))?;
 
             let mut syms = Symtab::default();
             let mut strtab = Strtab::default();
-            for shdr in &section_headers {
+            for (idx, shdr) in section_headers.iter().enumerate() {
                 if shdr.sh_type as u32 == section_header::SHT_SYMTAB {
                     let size = shdr.sh_entsize;
                     let count = if size == 0 { 0 } else { shdr.sh_size / size };
-                    syms = Symtab::parse(bytes, shdr.sh_offset as usize, count as usize, ctx)?;
-                    strtab = get_strtab(&section_headers, shdr.sh_link as usize)?;
+                    syms = recovery.recover(Component::Section(idx), Symtab::parse(bytes, shdr.sh_offset as usize, count as usize, ctx))?;
+                    strtab = recovery.recover(Component::Section(shdr.sh_link as usize), get_strtab(&section_headers, shdr.sh_link as usize))?;
                 }
             }
 
             let mut soname = None;
             let mut libraries = vec![];
+            let mut rpaths = vec![];
+            let mut runpaths = vec![];
             let mut dynsyms = Symtab::default();
             let mut dynrelas = vec![];
             let mut dynrels = vec![];
             let mut pltrelocs = vec![];
             let mut dynstrtab = Strtab::default();
-            let dynamic = Dynamic::parse(bytes, &program_headers, bias, ctx)?;
+            let mut gnu_hash = None;
+            let mut sysv_hash = None;
+            let dynamic = recovery.recover(Component::Dynamic, Dynamic::parse(bytes, &program_headers, bias, ctx))?;
             if let Some(ref dynamic) = dynamic {
                 let dyn_info = &dynamic.info;
-                dynstrtab = Strtab::parse(bytes,
+                dynstrtab = recovery.recover(Component::Dynamic, Strtab::parse(bytes,
                                           dyn_info.strtab,
                                           dyn_info.strsz,
-                                          0x0)?;
+                                          0x0))?;
 
                 if dyn_info.soname != 0 {
                     // FIXME: warn! here
@@ -298,13 +831,41 @@ if_sylvan! {
                 if dyn_info.needed_count > 0 {
                     libraries = dynamic.get_libraries(&dynstrtab);
                 }
-                let num_syms = if dyn_info.syment == 0 { 0 } else { if dyn_info.strtab <= dyn_info.symtab { 0 } else { (dyn_info.strtab - dyn_info.symtab) / dyn_info.syment }};
-                dynsyms = Symtab::parse(bytes, dyn_info.symtab, num_syms, ctx)?;
+                rpaths = dynamic.get_rpaths(&dynstrtab);
+                runpaths = dynamic.get_runpaths(&dynstrtab);
+                // a malformed hash table only costs us the fast symbol lookup, so don't fail the parse over it
+                if let Some(offset) = dyn_info.gnu_hash {
+                    let offset = offset as usize;
+                    // the table doesn't record its own size, so bound it by its section, if there is one
+                    let end = section_headers.iter()
+                        .find(|shdr| shdr.sh_type == section_header::SHT_GNU_HASH && shdr.sh_offset == offset as u64)
+                        .map_or(bytes.len(), |shdr| (shdr.sh_offset.saturating_add(shdr.sh_size) as usize).min(bytes.len()));
+                    gnu_hash = GnuHash::parse(&bytes[..end], offset, ctx).ok();
+                }
+                if let Some(offset) = dyn_info.hash {
+                    sysv_hash = SysvHash::parse(bytes, offset as usize, ctx).ok();
+                }
+                // the hash tables know how many dynamic symbols there are; otherwise, assume the string table follows the symbol table, as it usually does
+                let hash_count = match (&sysv_hash, &gnu_hash) {
+                    (&Some(ref sysv_hash), _) => Some(sysv_hash.nchain()),
+                    (_, &Some(ref gnu_hash)) => gnu_hash.dynsym_count(),
+                    _ => None,
+                };
+                dynsyms = match hash_count.map(|count| Symtab::parse(bytes, dyn_info.symtab, count, ctx)) {
+                    Some(Ok(dynsyms)) => dynsyms,
+                    _ => {
+                        let num_syms = if dyn_info.syment == 0 { 0 } else { if dyn_info.strtab <= dyn_info.symtab { 0 } else { (dyn_info.strtab - dyn_info.symtab) / dyn_info.syment }};
+                        recovery.recover(Component::DynamicSymbols, Symtab::parse(bytes, dyn_info.symtab, num_syms, ctx))?
+                    }
+                };
                 // parse the dynamic relocations
-                dynrelas = Reloc::parse(bytes, dyn_info.rela, dyn_info.relasz, true, ctx)?;
-                dynrels = Reloc::parse(bytes, dyn_info.rel, dyn_info.relsz, false, ctx)?;
+                dynrelas = recovery.recover(Component::DynamicRelocations, Reloc::parse(bytes, dyn_info.rela, dyn_info.relasz, true, ctx))?;
+                dynrels = recovery.recover(Component::DynamicRelocations, Reloc::parse(bytes, dyn_info.rel, dyn_info.relsz, false, ctx))?;
+                // Android linkers may pack the dynamic relocations instead, or in addition
+                dynrelas.extend(recovery.recover(Component::DynamicRelocations, Reloc::parse_android(bytes, dyn_info.android_rela, dyn_info.android_relasz, true, ctx))?);
+                dynrels.extend(recovery.recover(Component::DynamicRelocations, Reloc::parse_android(bytes, dyn_info.android_rel, dyn_info.android_relsz, false, ctx))?);
                 let is_rela = dyn_info.pltrel as u64 == dynamic::DT_RELA;
-                pltrelocs = Reloc::parse(bytes, dyn_info.jmprel, dyn_info.pltrelsz, is_rela, ctx)?;
+                pltrelocs = recovery.recover(Component::DynamicRelocations, Reloc::parse(bytes, dyn_info.jmprel, dyn_info.pltrelsz, is_rela, ctx))?;
             }
 
             // iterate through shdrs again iff we're an ET_REL
@@ -312,20 +873,38 @@ if_sylvan! {
                 let mut relocs = vec![];
                 if header.e_type == header::ET_REL {
                     for (idx, section) in section_headers.iter().enumerate() {
-                        if section.sh_type == section_header::SHT_REL {
-                            section.check_size(bytes.len())?;
-                            let sh_relocs = Reloc::parse(bytes, section.sh_offset as usize, section.sh_size as usize, false, ctx)?;
-                            relocs.push((idx, sh_relocs));
-                        }
-                        if section.sh_type == section_header::SHT_RELA {
-                            section.check_size(bytes.len())?;
-                            let sh_relocs = Reloc::parse(bytes, section.sh_offset as usize, section.sh_size as usize, true, ctx)?;
+                        let sh_relocs = match section.sh_type {
+                            section_header::SHT_REL | section_header::SHT_RELA => {
+                                let is_rela = section.sh_type == section_header::SHT_RELA;
+                                section.check_size(bytes.len()).and_then(|_| Reloc::parse(bytes, section.sh_offset as usize, section.sh_size as usize, is_rela, ctx))
+                            },
+                            section_header::SHT_ANDROID_REL | section_header::SHT_ANDROID_RELA => {
+                                let is_rela = section.sh_type == section_header::SHT_ANDROID_RELA;
+                                section.check_size(bytes.len()).and_then(|_| Reloc::parse_android(bytes, section.sh_offset as usize, section.sh_size as usize, is_rela, ctx))
+                            },
+                            _ => continue,
+                        };
+                        // a malformed relocation section is skipped, rather than recorded as empty
+                        if let Some(sh_relocs) = recovery.recover(Component::Section(idx), sh_relocs.map(Some))? {
                             relocs.push((idx, sh_relocs));
                         }
                     }
                 }
                 relocs
             };
+
+            // like the hash tables, malformed versioning only costs us the symbol versions
+            let mut versym = None;
+            let mut verneed = None;
+            let mut verdef = None;
+            for shdr in &section_headers {
+                match shdr.sh_type {
+                    section_header::SHT_GNU_VERSYM => versym = VersymSection::parse(bytes, shdr, ctx).ok(),
+                    section_header::SHT_GNU_VERNEED => verneed = VerneedSection::parse(bytes, shdr, ctx).ok(),
+                    section_header::SHT_GNU_VERDEF => verdef = VerdefSection::parse(bytes, shdr, ctx).ok(),
+                    _ => (),
+                }
+            }
             Ok(Elf {
                 header: header,
                 program_headers: program_headers,
@@ -336,6 +915,11 @@ if_sylvan! {
                 dynstrtab: dynstrtab,
                 syms: syms,
                 strtab: strtab,
+                gnu_hash: gnu_hash,
+                sysv_hash: sysv_hash,
+                versym: versym,
+                verneed: verneed,
+                verdef: verdef,
                 dynrelas: dynrelas,
                 dynrels: dynrels,
                 pltrelocs: pltrelocs,
@@ -343,12 +927,139 @@ if_sylvan! {
                 soname: soname,
                 interpreter: interpreter,
                 libraries: libraries,
+                rpaths: rpaths,
+                runpaths: runpaths,
                 is_64: is_64,
                 is_lib: is_lib,
                 entry: entry as u64,
                 bias: bias as u64,
                 little_endian: is_lsb,
+                errors: recovery.errors,
                 ctx,
+                symbol_index: None,
+                address_index: None,
+            })
+        }
+    }
+
+    impl Ctx {
+        /// The container size and byte order `elf` was parsed with
+        pub fn from_elf(elf: &Elf) -> Self {
+            elf.ctx
+        }
+    }
+
+    #[derive(Debug)]
+    /// An ELF binary of which only the header has been parsed, see `Elf::lazy_parse`.
+    ///
+    /// Every accessor parses its region of `bytes`, which must be the bytes the header was parsed from, on its first call,
+    /// and returns that region again on later calls; a region which fails to parse is parsed anew on the next call.
+    pub struct LazyElf<'a> {
+        /// The ELF header, which locates the program and section header tables
+        pub header: Header,
+        /// Whether the binary is 64-bit (`ELFCLASS64`) or not
+        pub is_64: bool,
+        /// Whether the binary is little endian or not
+        pub little_endian: bool,
+        ctx: Ctx,
+        program_headers: RefCell<Option<ProgramHeaders>>,
+        section_headers: RefCell<Option<SectionHeaders>>,
+        shdr_strtab: RefCell<Option<Strtab<'a>>>,
+        syms: RefCell<Option<(Symtab<'a>, Strtab<'a>)>>,
+        dynamic: RefCell<Option<Option<Dynamic>>>,
+    }
+
+    /// Returns the region cached in `cache`, parsing it with `parse` if this is the first call, or the last one failed
+    fn cached<'c, T, F: FnOnce() -> error::Result<T>>(cache: &'c RefCell<Option<T>>, parse: F) -> error::Result<Ref<'c, T>> {
+        if cache.borrow().is_none() {
+            let region = parse()?;
+            *cache.borrow_mut() = Some(region);
+        }
+        Ok(Ref::map(cache.borrow(), |region| region.as_ref().unwrap()))
+    }
+
+    impl<'a> LazyElf<'a> {
+        /// The container size and byte order of this binary
+        pub fn ctx(&self) -> Ctx {
+            self.ctx
+        }
+
+        /// The program headers
+        pub fn program_headers<'s>(&'s self, bytes: &'a [u8]) -> error::Result<Ref<'s, ProgramHeaders>> {
+            cached(&self.program_headers, || {
+                ProgramHeader::parse(bytes, self.header.e_phoff as usize, self.header.e_phnum as usize, self.ctx)
+            })
+        }
+
+        /// The section headers
+        pub fn section_headers<'s>(&'s self, bytes: &'a [u8]) -> error::Result<Ref<'s, SectionHeaders>> {
+            cached(&self.section_headers, || {
+                SectionHeader::parse(bytes, self.header.e_shoff as usize, self.header.e_shnum as usize, self.ctx)
+            })
+        }
+
+        /// The section header string table
+        pub fn shdr_strtab<'s>(&'s self, bytes: &'a [u8]) -> error::Result<Ref<'s, Strtab<'a>>> {
+            cached(&self.shdr_strtab, || {
+                let section_headers = self.section_headers(bytes)?;
+                match section_headers.get(self.header.e_shstrndx as usize) {
+                    Some(shdr) => {
+                        shdr.check_size(bytes.len())?;
+                        Strtab::parse(bytes, shdr.sh_offset as usize, shdr.sh_size as usize, 0x0)
+                    },
+                    None => Ok(Strtab::default()),
+                }
+            })
+        }
+
+        /// Finds the first section named `name`, parsing only the section headers and their string table
+        pub fn section_by_name(&self, bytes: &'a [u8], name: &str) -> error::Result<Option<SectionHeader>> {
+            let section_headers = self.section_headers(bytes)?;
+            let shdr_strtab = self.shdr_strtab(bytes)?;
+            Ok(section_headers.iter().find(|shdr| {
+                shdr_strtab.get(shdr.sh_name).map_or(false, |r| r.ok() == Some(name))
+            }).cloned())
+        }
+
+        /// The debugging symbol table and its string table, which are empty if the binary has none
+        pub fn syms<'s>(&'s self, bytes: &'a [u8]) -> error::Result<Ref<'s, (Symtab<'a>, Strtab<'a>)>> {
+            cached(&self.syms, || {
+                let section_headers = self.section_headers(bytes)?;
+                for shdr in section_headers.iter() {
+                    if shdr.sh_type as u32 == section_header::SHT_SYMTAB {
+                        let size = shdr.sh_entsize;
+                        let count = if size == 0 { 0 } else { shdr.sh_size / size };
+                        let syms = Symtab::parse(bytes, shdr.sh_offset as usize, count as usize, self.ctx)?;
+                        let strtab = match section_headers.get(shdr.sh_link as usize) {
+                            Some(strtab) => {
+                                strtab.check_size(bytes.len())?;
+                                Strtab::parse(bytes, strtab.sh_offset as usize, strtab.sh_size as usize, 0x0)?
+                            },
+                            None => Strtab::default(),
+                        };
+                        return Ok((syms, strtab));
+                    }
+                }
+                Ok((Symtab::default(), Strtab::default()))
+            })
+        }
+
+        /// The `_DYNAMIC` array, if the binary has one
+        pub fn dynamic<'s>(&'s self, bytes: &'a [u8]) -> error::Result<Ref<'s, Option<Dynamic>>> {
+            cached(&self.dynamic, || {
+                let program_headers = self.program_headers(bytes)?;
+                let mut bias: usize = 0;
+                for ph in program_headers.iter() {
+                    if ph.p_type == program_header::PT_LOAD {
+                        // the same first load address bias as `Elf::parse`
+                        bias = match self.ctx.container {
+                            Container::Little => (::core::u32::MAX - (ph.p_vaddr as u32)).wrapping_add(1) as usize,
+                            Container::Big    => (::core::u64::MAX - ph.p_vaddr).wrapping_add(1) as usize,
+                        };
+                        break;
+                    }
+                }
+                Dynamic::parse(bytes, &program_headers, bias, self.ctx)
             })
         }
     }
@@ -430,4 +1141,522 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn lookup_dynamic_symbol() {
+        for lib in &[include!("../../etc/libgnu.rs"), include!("../../etc/libsysv.rs")] {
+            let mut binary = Elf::parse(lib).unwrap();
+            assert!(binary.gnu_hash.is_some() || binary.sysv_hash.is_some());
+            for _ in 0..2 {
+                for name in &["goblin_answer", "goblin_hello", "goblin_value"] {
+                    let (idx, sym) = binary.lookup_dynamic_symbol(name).unwrap();
+                    assert_eq!(&binary.dynstrtab[sym.st_name], *name);
+                    assert_eq!(binary.dynsyms.get(idx), Some(sym));
+                }
+                // undefined, missing, and the null symbol's empty name
+                assert_eq!(binary.lookup_dynamic_symbol("goblin_import"), None);
+                assert_eq!(binary.lookup_dynamic_symbol("goblin_missing"), None);
+                assert_eq!(binary.lookup_dynamic_symbol(""), None);
+                // and again with a linear scan
+                binary.gnu_hash = None;
+                binary.sysv_hash = None;
+            }
+        }
+    }
+
+    #[test]
+    fn iter_note_headers() {
+        let lib: Vec<u8> = include!("../../etc/libgnu.rs");
+        let mut binary = Elf::parse(&lib).unwrap();
+        assert!(binary.iter_note_headers(&lib).is_none());
+        // a 64-bit core note, 4 byte aligned, in a segment which claims 8 byte alignment
+        let data = [5, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, b'C', b'O', b'R', b'E', 0, 0, 0, 0, 1, 2, 3, 4];
+        let mut phdr = ProgramHeader::new();
+        phdr.p_type = program_header::PT_NOTE;
+        phdr.p_filesz = data.len() as u64;
+        phdr.p_align = 8;
+        binary.program_headers = vec![phdr];
+        assert!(binary.iter_note_headers(&data).unwrap().next().unwrap().is_err());
+        let mut notes = binary.iter_note_headers_aligned(&data, 4).unwrap();
+        let note = notes.next().unwrap().unwrap();
+        assert_eq!((note.name, note.n_type, note.desc), ("CORE", 1, &[1, 2, 3, 4][..]));
+        assert!(notes.next().is_none());
+    }
+
+    #[test]
+    fn dynsym_count() {
+        use scroll::Pwrite;
+        for lib in &[include!("../../etc/libgnu.rs"), include!("../../etc/libsysv.rs")] {
+            let binary = Elf::parse(lib).unwrap();
+            let dynsym = binary.section_headers.iter().find(|shdr| shdr.sh_type == section_header::SHT_DYNSYM).unwrap();
+            let count = (dynsym.sh_size / dynsym.sh_entsize) as usize;
+            assert_eq!(binary.dynsyms.len(), count);
+            if let Some(ref gnu_hash) = binary.gnu_hash {
+                assert_eq!(gnu_hash.dynsym_count(), Some(count));
+            }
+            // without section headers, the count must come from the hash table alone
+            let mut stripped = lib.clone();
+            stripped.pwrite_with(0u16, 0x3c, scroll::LE).unwrap();
+            let stripped = Elf::parse(&stripped).unwrap();
+            assert!(stripped.section_headers.is_empty());
+            assert_eq!(stripped.dynsyms.len(), count);
+        }
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn mini_debug_info() {
+        let lib: Vec<u8> = include!("../../etc/libminidebug.rs");
+        let binary = Elf::parse(&lib).unwrap();
+        let debug_info = binary.mini_debug_info(&lib).unwrap().unwrap();
+        let embedded = Elf::parse(&debug_info).unwrap();
+        assert!(embedded.lookup_dynamic_symbol("goblin_answer").is_some());
+        let crt1: Vec<u8> = include!("../../etc/crt1.rs");
+        assert_eq!(Elf::parse(&crt1).unwrap().mini_debug_info(&crt1).unwrap(), None);
+    }
+
+    #[test]
+    fn dependency_query() {
+        let lib: Vec<u8> = include!("../../etc/libdeps.rs");
+        let binary = Elf::parse(&lib).unwrap();
+        assert_eq!(binary.dependency_query(), DependencyQuery {
+            needed: vec!["libc.so.6"],
+            rpaths: vec![],
+            runpaths: vec!["$ORIGIN/lib", "/opt/goblin/lib"],
+            soname: Some("libdeps.so.1"),
+        });
+    }
+
+    #[test]
+    fn lenient_parse() {
+        use scroll::Pwrite;
+        let mut crt1: Vec<u8> = include!("../../etc/crt1.rs");
+        let binary = Elf::parse(&crt1).unwrap();
+        assert!(binary.errors.is_empty());
+        let shoff = binary.header.e_shoff as usize;
+        let shentsize = binary.header.e_shentsize as usize;
+        let rela = binary.shdr_relocs[0].0;
+        let symtab = binary.section_headers.iter().position(|shdr| shdr.sh_type == section_header::SHT_SYMTAB).unwrap();
+        let nrelocs = binary.shdr_relocs.len();
+        // move the first relocation section past the end of the file
+        crt1.pwrite_with(0xffff_ffffu64, shoff + rela * shentsize + 24, scroll::LE).unwrap();
+        assert!(Elf::parse(&crt1).is_err());
+        let lenient = ParseOptions { fail_fast: false, .. ParseOptions::default() };
+        let binary = Elf::parse_with(&crt1, lenient).unwrap();
+        assert_eq!(binary.errors.iter().map(|&(component, _)| component).collect::<Vec<_>>(), vec![Component::Section(rela)]);
+        assert_eq!(binary.shdr_relocs.len(), nrelocs - 1);
+        assert!(binary.syms.len() != 0);
+        // and then the symbol table too, which fails independently
+        crt1.pwrite_with(0xffff_ffffu64, shoff + symtab * shentsize + 24, scroll::LE).unwrap();
+        let binary = Elf::parse_with(&crt1, lenient).unwrap();
+        assert_eq!(binary.errors.iter().map(|&(component, _)| component).collect::<Vec<_>>(), vec![Component::Section(symtab), Component::Section(rela)]);
+        assert_eq!(binary.syms.len(), 0);
+        assert_eq!(binary.shdr_relocs.len(), nrelocs - 1);
+    }
+
+    #[test]
+    fn anomalies_crt1() {
+        use anomaly::{Anomaly, Region};
+        let crt1: Vec<u8> = include!("../../etc/crt1.rs");
+        let mut binary = Elf::parse(&crt1).unwrap();
+        assert_eq!(binary.anomalies(&crt1), vec![]);
+        let (first, second) = {
+            let mut progbits = binary.section_headers.iter().enumerate()
+                .filter(|&(_, shdr)| shdr.sh_type == section_header::SHT_PROGBITS && shdr.sh_size != 0)
+                .map(|(i, _)| i);
+            (progbits.next().unwrap(), progbits.next().unwrap())
+        };
+        binary.section_headers[second].sh_offset = binary.section_headers[first].sh_offset;
+        binary.section_headers[first].sh_size = crt1.len() as u64;
+        let anomalies = binary.anomalies(&crt1);
+        assert!(anomalies.contains(&Anomaly::Overlap { first: Region::Section(first), second: Region::Section(second) }));
+        assert!(anomalies.iter().any(|anomaly| match *anomaly {
+            Anomaly::OutOfBounds { region, .. } => region == Region::Section(first),
+            _ => false,
+        }));
+    }
+
+    #[test]
+    fn symbol_by_name() {
+        use scroll::Pwrite;
+        let mut crt1: Vec<u8> = include!("../../etc/crt1.rs");
+        // rename the undefined `__libc_csu_fini` before it to `_start` too
+        let (offset, st_name) = {
+            let binary = Elf::parse(&crt1).unwrap();
+            let symtab = binary.section_headers.iter().find(|shdr| shdr.sh_type == section_header::SHT_SYMTAB).unwrap();
+            assert_eq!(binary.symbol_by_name("_start").map(|(idx, _)| idx), Some(11));
+            (symtab.sh_offset as usize + 10 * symtab.sh_entsize as usize, binary.syms.get(11).unwrap().st_name as u32)
+        };
+        crt1.pwrite_with(st_name, offset, scroll::LE).unwrap();
+        let mut binary = Elf::parse(&crt1).unwrap();
+        for _ in 0..2 {
+            assert_eq!(binary.symbol_by_name("_start").map(|(idx, sym)| (idx, sym.st_size)), Some((11, 43)));
+            assert_eq!(binary.symbol_by_name("main").map(|(idx, _)| idx), Some(13));
+            assert_eq!(binary.symbol_by_name("__libc_csu_fini"), None);
+            assert_eq!(binary.symbol_by_name(""), None);
+            binary.build_symbol_index();
+        }
+    }
+
+    #[test]
+    fn reloc_symbol() {
+        let crt1: Vec<u8> = include!("../../etc/crt1.rs");
+        let binary = Elf::parse(&crt1).unwrap();
+        let mut names = Vec::new();
+        for &(idx, ref relocs) in &binary.shdr_relocs {
+            // a copy resolves just as well as the original
+            names.extend(relocs.iter().cloned().map(|reloc| binary.reloc_symbol_in(&reloc, idx)));
+        }
+        assert_eq!(names, vec![Some("__libc_csu_fini"), Some("__libc_csu_init"), Some("main"), Some("__libc_start_main"), Some("")]);
+        let lib: Vec<u8> = include!("../../etc/libgnu.rs");
+        let binary = Elf::parse(&lib).unwrap();
+        assert_eq!(binary.pltrelocs[0].symbol(&binary, None), Some("goblin_import"));
+        let jump_slot = binary.pltrelocs[0];
+        assert_eq!(binary.reloc_symbol(&jump_slot), Some("goblin_import"));
+        assert_eq!(jump_slot.r_type_name(binary.header.e_machine), "R_X86_64_JUMP_SLOT");
+        assert_eq!(reloc::r_to_str(jump_slot.r_type, binary.header.e_machine), "X86_64_JUMP_SLOT");
+        assert!(binary.pltrelocs[0].r_addend.is_some());
+        assert_eq!(Reloc::default().symbol(&binary, None), None);
+    }
+
+    #[test]
+    fn symbol_version() {
+        use scroll::Pwrite;
+        let lib: Vec<u8> = include!("../../etc/libgnu.rs");
+        let mut binary = Elf::parse(&lib).unwrap();
+        assert!(binary.versym.is_none() && binary.verneed.is_none() && binary.verdef.is_none());
+        assert_eq!(binary.symbol_version(1), None);
+        // borrow a dynamic string as the version name, since the fixture is unversioned
+        let name = binary.dynsyms.get(1).unwrap().st_name;
+        let mut bytes = [0u8; 40];
+        // versyms: local, global, hidden version 2, version 3
+        bytes.pwrite_with(versym::VERSYM_HIDDEN | 2, 4, scroll::LE).unwrap();
+        bytes.pwrite_with(3u16, 6, scroll::LE).unwrap();
+        // a single dependency, requiring version 2
+        bytes.pwrite_with(1u16, 8, scroll::LE).unwrap();
+        bytes.pwrite_with(1u16, 10, scroll::LE).unwrap();
+        bytes.pwrite_with(versym::SIZEOF_VERNEED as u32, 16, scroll::LE).unwrap();
+        bytes.pwrite_with(2u16, 30, scroll::LE).unwrap();
+        bytes.pwrite_with(name as u32, 32, scroll::LE).unwrap();
+        let mut shdr = SectionHeader::new();
+        shdr.sh_size = 8;
+        binary.versym = Some(VersymSection::parse(&bytes, &shdr, binary.ctx).unwrap());
+        shdr.sh_offset = 8;
+        shdr.sh_size = 32;
+        shdr.sh_info = 1;
+        binary.verneed = Some(VerneedSection::parse(&bytes, &shdr, binary.ctx).unwrap());
+        let name = binary.dynstrtab.get(name).unwrap().ok();
+        assert!(name.is_some());
+        assert_eq!(binary.symbol_version(0), None);
+        assert_eq!(binary.symbol_version(1), None);
+        assert_eq!(binary.symbol_version(2), name);
+        assert_eq!(binary.symbol_version(3), None);
+        assert_eq!(binary.symbol_version(4), None);
+    }
+
+    #[test]
+    fn symtab_to_bytes() {
+        for bin in &[include!("../../etc/crt1.rs"), include!("../../etc/crt132.rs")] {
+            let binary = Elf::parse(bin).unwrap();
+            let shdr = binary.section_headers.iter().find(|shdr| shdr.sh_type == section_header::SHT_SYMTAB).unwrap();
+            let offset = shdr.sh_offset as usize;
+            let bytes = binary.syms.to_bytes(binary.ctx);
+            assert_eq!(&bytes[..], &bin[offset..offset + shdr.sh_size as usize]);
+            // swapping the endianness must not disturb the symbols, in particular the packed st_info
+            let ctx = Ctx::new(binary.ctx.container, if binary.little_endian { Endian::Big } else { Endian::Little });
+            let swapped = binary.syms.to_bytes(ctx);
+            assert!(swapped != bytes);
+            let syms = Symtab::parse(&swapped, 0, binary.syms.len(), ctx).unwrap();
+            assert_eq!(syms.to_vec(), binary.syms.to_vec());
+            assert_eq!(syms.to_bytes(binary.ctx), bytes);
+        }
+        assert!(Symtab::default().to_bytes(Ctx::default()).is_empty());
+    }
+
+    #[test]
+    fn lazy_parse() {
+        for bin in &[include!("../../etc/crt1.rs"), include!("../../etc/crt132.rs"), include!("../../etc/libgnu.rs")] {
+            let binary = Elf::parse(bin).unwrap();
+            let lazy = Elf::lazy_parse(bin).unwrap();
+            assert!(lazy.header == binary.header);
+            assert_eq!(lazy.is_64, binary.is_64);
+            assert_eq!(lazy.little_endian, binary.little_endian);
+            assert_eq!(*lazy.program_headers(bin).unwrap(), binary.program_headers);
+            assert_eq!(*lazy.section_headers(bin).unwrap(), binary.section_headers);
+            {
+                let syms = lazy.syms(bin).unwrap();
+                let (ref syms, ref strtab) = *syms;
+                assert_eq!(syms.to_vec(), binary.syms.to_vec());
+                fn names<'a>(syms: &Symtab, strtab: &Strtab<'a>) -> Vec<Option<&'a str>> {
+                    syms.iter().map(|sym| strtab.get_at(sym.st_name).ok()).collect()
+                }
+                assert_eq!(names(syms, strtab), names(&binary.syms, &binary.strtab));
+            }
+            assert_eq!(lazy.dynamic(bin).unwrap().as_ref().map(|dynamic| &dynamic.dyns), binary.dynamic.as_ref().map(|dynamic| &dynamic.dyns));
+            // later calls return the region parsed by the first
+            assert_eq!(lazy.section_headers(bin).unwrap().as_ptr(), lazy.section_headers(bin).unwrap().as_ptr());
+            let text = lazy.section_by_name(bin, ".text").unwrap().unwrap();
+            assert_eq!(binary.shdr_strtab.get(text.sh_name).unwrap().unwrap(), ".text");
+            assert!(lazy.section_by_name(bin, ".no_such_section").unwrap().is_none());
+        }
+        let mut bad: Vec<u8> = include!("../../etc/crt1.rs");
+        bad[header::EI_CLASS] = 0;
+        assert!(Elf::lazy_parse(&bad).is_err());
+    }
+
+    #[test]
+    fn section_data() {
+        let mut nobits = 0;
+        for bin in &[include!("../../etc/crt1.rs"), include!("../../etc/libgnu.rs")] {
+            let binary = Elf::parse(bin).unwrap();
+            for shdr in &binary.section_headers {
+                if shdr.sh_type == section_header::SHT_NOBITS {
+                    assert!(shdr.data(bin).is_err());
+                    nobits += 1;
+                } else {
+                    assert_eq!(shdr.data(bin).unwrap(), &bin[shdr.file_range()]);
+                }
+            }
+            let text = binary.section_headers.iter().find(|shdr| binary.shdr_strtab.get(shdr.sh_name).unwrap().unwrap() == ".text").unwrap();
+            assert_eq!(text.data(bin).unwrap().len(), text.sh_size as usize);
+            assert!(text.data(&bin[..text.sh_offset as usize]).is_err());
+        }
+        assert!(nobits > 0);
+    }
+
+    #[test]
+    fn eh_frame_hdr() {
+        let bin: Vec<u8> = include!("../../etc/libgnu.rs");
+        let binary = Elf::parse(&bin).unwrap();
+        let section = |name| binary.section_headers.iter().find(|shdr| binary.shdr_strtab.get(shdr.sh_name).unwrap().unwrap() == name).unwrap();
+        let eh_frame = section(".eh_frame");
+        let hdr = binary.eh_frame_hdr(&bin).unwrap().unwrap();
+        assert_eq!(hdr.version, eh_frame::EH_FRAME_HDR_VERSION);
+        assert_eq!(hdr.eh_frame_ptr, eh_frame.sh_addr);
+        assert!(hdr.fde_count > 0);
+        let eh_frame = eh_frame.sh_addr..eh_frame.sh_addr + eh_frame.sh_size;
+        let mut previous = 0;
+        for i in 0..hdr.fde_count {
+            let (initial_location, fde) = hdr.get(i).unwrap();
+            assert!(initial_location >= previous);
+            assert!(eh_frame.contains(&fde));
+            assert_eq!(hdr.lookup(initial_location), Some(fde));
+            previous = initial_location;
+        }
+        let (first, _) = hdr.get(0).unwrap();
+        assert_eq!(hdr.lookup(first - 1), None);
+        let crt1: Vec<u8> = include!("../../etc/crt1.rs");
+        assert!(Elf::parse(&crt1).unwrap().eh_frame_hdr(&crt1).unwrap().is_none());
+    }
+
+    #[test]
+    fn dynamic_entries() {
+        use elf::dynamic::DynEntry;
+        let bin: Vec<u8> = include!("../../etc/libdeps.rs");
+        let binary = Elf::parse(&bin).unwrap();
+        let dynamic = binary.dynamic.as_ref().unwrap();
+        let needed: Vec<_> = dynamic.iter(&binary.dynstrtab).filter_map(|entry| match entry { DynEntry::Needed(lib) => Some(lib), _ => None }).collect();
+        assert_eq!(needed, vec!["libc.so.6"]);
+        let soname = dynamic.iter(&binary.dynstrtab).filter_map(|entry| match entry { DynEntry::SoName(soname) => Some(soname), _ => None }).next();
+        assert_eq!(soname, Some("libdeps.so.1"));
+        assert!(dynamic.iter(&binary.dynstrtab).any(|entry| entry == DynEntry::RunPath("$ORIGIN/lib:/opt/goblin/lib")));
+        assert!(dynamic.iter(&binary.dynstrtab).any(|entry| match entry { DynEntry::GnuHash(_) => true, _ => false }));
+        assert_eq!(dynamic.iter(&binary.dynstrtab).count(), dynamic.dyns.len() - 1);
+    }
+
+    #[test]
+    fn compressed_section() {
+        use scroll::Pwrite;
+        use elf::compression_header::{CompressionHeader, ELFCOMPRESS_ZLIB};
+        // zlib.compress(b"goblin" * 4)
+        const ZLIB: [u8; 16] = [0x78, 0x9c, 0x4b, 0xcf, 0x4f, 0xca, 0xc9, 0xcc, 0x4b, 0xc7, 0x20, 0x01, 0x7b, 0xe8, 0x09, 0xed];
+        let ctx = Ctx::new(Container::Big, Endian::Little);
+        let chdr = CompressionHeader { ch_type: ELFCOMPRESS_ZLIB, ch_size: 24, ch_addralign: 1 };
+        let mut bytes = vec![0u8; CompressionHeader::size(&ctx) + ZLIB.len()];
+        let offset = bytes.pwrite_with(chdr.clone(), 0, ctx).unwrap();
+        bytes[offset..].copy_from_slice(&ZLIB);
+        let mut shdr = SectionHeader { sh_type: section_header::SHT_PROGBITS, sh_size: bytes.len() as u64, .. Default::default() };
+        assert!(!shdr.is_compressed());
+        assert_eq!(shdr.compression_header(&bytes, ctx).unwrap(), None);
+        assert_eq!(shdr.decompress(&bytes, ctx).unwrap(), bytes);
+        shdr.sh_flags = section_header::SHF_COMPRESSED as u64;
+        assert!(shdr.is_compressed());
+        assert_eq!(shdr.compression_header(&bytes, ctx).unwrap(), Some(chdr));
+        #[cfg(feature = "compression")]
+        assert_eq!(shdr.decompress(&bytes, ctx).unwrap(), b"goblingoblingoblingoblin".to_vec());
+        #[cfg(not(feature = "compression"))]
+        assert!(shdr.decompress(&bytes, ctx).is_err());
+        // too short for the compression header
+        shdr.sh_size = 8;
+        assert!(shdr.decompress(&bytes, ctx).is_err());
+    }
+
+    #[test]
+    fn vm_to_file_offset() {
+        use elf::program_header::{ProgramHeader, PT_LOAD, PT_DYNAMIC};
+        let bin: Vec<u8> = include!("../../etc/libdeps.rs");
+        let mut binary = Elf::parse(&bin).unwrap();
+        let load = |p_offset, p_vaddr, p_filesz, p_memsz| ProgramHeader { p_type: PT_LOAD, p_offset, p_vaddr, p_filesz, p_memsz, .. Default::default() };
+        binary.program_headers = vec![
+            ProgramHeader { p_type: PT_DYNAMIC, p_offset: 0x3000, p_vaddr: 0x1000, p_filesz: 0x100, p_memsz: 0x100, .. Default::default() },
+            load(0, 0, 0, 0),
+            load(0x1000, 0x1000, 0x800, 0x1000),
+            // overlaps the segment before it
+            load(0x2000, 0x1400, 0x1000, 0x1000),
+        ];
+        assert_eq!(binary.vm_to_file_offset(0), None);
+        assert_eq!(binary.vm_to_file_offset(0x1010), Some(0x1010));
+        // the bss of the first segment shadows the second
+        assert_eq!(binary.vm_to_file_offset(0x1900), None);
+        assert_eq!(binary.vm_to_file_offset(0x2100), Some(0x2d00));
+        assert_eq!(binary.vm_to_file_offset(0x2400), None);
+        assert_eq!(binary.file_offset_to_vm(0x1010), Some(0x1010));
+        assert_eq!(binary.file_offset_to_vm(0x1900), None);
+        assert_eq!(binary.file_offset_to_vm(0x2d00), Some(0x2100));
+        assert_eq!(binary.file_offset_to_vm(0x3000), None);
+    }
+
+    #[test]
+    fn interpreter() {
+        use scroll::Pwrite;
+        let mut pie: Vec<u8> = include!("../../etc/pie.rs");
+        assert_eq!(Elf::parse(&pie).unwrap().interpreter, Some("/lib64/ld-linux-x86-64.so.2"));
+        let bin: Vec<u8> = include!("../../etc/static.rs");
+        assert_eq!(Elf::parse(&bin).unwrap().interpreter, None);
+        // the `PT_INTERP` header is the second; past the NUL, its segment may hold anything
+        pie.pwrite_with(0x40u64, 0x78 + 32, scroll::LE).unwrap();
+        assert_eq!(Elf::parse(&pie).unwrap().interpreter, Some("/lib64/ld-linux-x86-64.so.2"));
+        pie.pwrite_with(pie.len() as u64 - 0x10, 0x78 + 8, scroll::LE).unwrap();
+        assert!(Elf::parse(&pie).is_err());
+    }
+
+    #[test]
+    fn symbolicate() {
+        use scroll::Pwrite;
+        let mut bin: Vec<u8> = include!("../../etc/libgnu.rs");
+        let expected = vec![("goblin_answer", 0x2b0, 14), ("goblin_hello", 0x2be, 7), ("goblin_value", 0x488, 4)];
+        assert_eq!(Elf::parse(&bin).unwrap().sorted_symbols(), expected);
+        // without sizes, the functions are sized by each other and the end of .text, and the object by the end of .data
+        for idx in 2..5 {
+            bin.pwrite_with(0u64, 0x1c0 + idx * 24 + 16, scroll::LE).unwrap();
+        }
+        let mut binary = Elf::parse(&bin).unwrap();
+        for _ in 0..2 {
+            assert_eq!(binary.sorted_symbols(), expected);
+            assert_eq!(binary.symbolicate(0x2b0), Some(("goblin_answer", 0)));
+            assert_eq!(binary.symbolicate(0x2c4), Some(("goblin_hello", 6)));
+            assert_eq!(binary.symbolicate(0x48b), Some(("goblin_value", 3)));
+            assert_eq!(binary.symbolicate(0x2c5), None);
+            assert_eq!(binary.symbolicate(0x290), None);
+            binary.build_address_index();
+        }
+    }
+
+    #[test]
+    fn gnu_properties() {
+        use scroll::Pwrite;
+        let mut bin: Vec<u8> = include!("../../etc/cet.rs");
+        let cet = vec![note::GnuProperty::X86Feature1 { ibt: true, shstk: true }];
+        assert_eq!(Elf::parse(&bin).unwrap().gnu_properties(&bin).unwrap(), cet);
+        // without section headers, the segment still has the note
+        bin.pwrite_with(0u16, 0x3c, scroll::LE).unwrap();
+        bin.pwrite_with(0u16, 0x3e, scroll::LE).unwrap();
+        let binary = Elf::parse(&bin).unwrap();
+        assert!(binary.section_headers.is_empty());
+        assert_eq!(binary.gnu_properties(&bin).unwrap(), cet);
+        let pie: Vec<u8> = include!("../../etc/pie.rs");
+        assert_eq!(Elf::parse(&pie).unwrap().gnu_properties(&pie).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn debuglink() {
+        // `static`, after `objcopy --add-gnu-debuglink=static.debug --add-section .gnu_debugaltlink=...` with a copy of itself as the debug file
+        let bin: Vec<u8> = include!("../../etc/debuglink.rs");
+        let debug_file: Vec<u8> = include!("../../etc/static.rs");
+        let binary = Elf::parse(&bin).unwrap();
+        let link = binary.debuglink(&bin).unwrap().unwrap();
+        assert_eq!(link, debuglink::DebugLink { filename: "static.debug", crc: 0xb7d0_1526 });
+        assert!(link.matches(&debug_file));
+        assert!(!link.matches(&bin));
+        let altlink = binary.debugaltlink(&bin).unwrap().unwrap();
+        assert_eq!(altlink.filename, "dwz/common.debug");
+        assert_eq!(altlink.build_id, &[0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67][..]);
+        let binary = Elf::parse(&debug_file).unwrap();
+        assert_eq!(binary.debuglink(&debug_file).unwrap(), None);
+        assert_eq!(binary.debugaltlink(&debug_file).unwrap(), None);
+    }
+
+    #[test]
+    fn apply_relocations() {
+        use scroll::Pwrite;
+        // a shared object whose `goblin_ptr` at 0x2f8 points to a local function at 0x208, and `goblin_import_ptr` at 0x2f0 to an import
+        let bin: Vec<u8> = include!("../../etc/librel.rs");
+        let binary = Elf::parse(&bin).unwrap();
+        let mut image = vec![0u8; 0x300];
+        for phdr in binary.program_headers.iter().filter(|phdr| phdr.p_type == program_header::PT_LOAD) {
+            let (offset, size) = (phdr.p_offset as usize, phdr.p_filesz as usize);
+            image[phdr.p_vaddr as usize..][..size].copy_from_slice(&bin[offset..offset + size]);
+        }
+        let mut relocated = image.clone();
+        binary.apply_relocations(&mut relocated, 0x7f00_0000_0000).unwrap();
+        let mut expected = image.clone();
+        expected.pwrite_with(0x7f00_0000_0208u64, 0x2f8, scroll::LE).unwrap();
+        assert_eq!(relocated, expected);
+        // the import is left to the resolver
+        let mut names = Vec::new();
+        binary.apply_relocations_with(&mut relocated, 0x7f00_0000_0000, |reloc| {
+            names.push(binary.reloc_symbol(reloc));
+            Ok(Some(0x7f00_1000_0000))
+        }).unwrap();
+        assert_eq!(names, vec![Some("goblin_import")]);
+        expected.pwrite_with(0x7f00_1000_0000u64, 0x2f0, scroll::LE).unwrap();
+        assert_eq!(relocated, expected);
+        // an image too small for the data segment
+        assert!(binary.apply_relocations(&mut image[..0x2fc], 0).is_err());
+    }
+
+    #[test]
+    fn section_and_segment_flags() {
+        let lib: Vec<u8> = include!("../../etc/libgnu.rs");
+        let binary = Elf::parse(&lib).unwrap();
+        let flags = |name| binary.section_headers.iter().find(|shdr| &binary.shdr_strtab[shdr.sh_name] == name).unwrap().flags();
+        let text = flags(".text");
+        assert!(text.is_exec() && text.is_read() && !text.is_write());
+        let data = flags(".data");
+        assert!(!data.is_exec() && data.is_read() && data.is_write());
+        // not mapped at all
+        let shstrtab = flags(".shstrtab");
+        assert!(!shstrtab.is_exec() && !shstrtab.is_read() && !shstrtab.is_write());
+        // 64-bit `sh_flags` can set bits past the 32 of the `SHF_*` constants
+        let wide = section_header::SectionFlags(1 << 40 | section_header::SHF_ALLOC as u64);
+        assert!(wide.contains(1 << 40) && wide.contains(1 << 40 | section_header::SHF_ALLOC as u64) && !wide.contains(1 << 41));
+        let loads = binary.program_headers.iter().filter(|phdr| phdr.p_type == program_header::PT_LOAD).map(|phdr| phdr.flags()).collect::<Vec<_>>();
+        assert!(loads.iter().any(|flags| flags.is_exec() && flags.is_read() && !flags.is_write()));
+        assert!(loads.iter().any(|flags| !flags.is_exec() && flags.is_read() && flags.is_write()));
+    }
+
+    #[test]
+    fn symbols_deduped() {
+        // a shared object with both tables, where `goblin_helper` is local, and only in `.symtab`
+        let lib: Vec<u8> = include!("../../etc/libboth.rs");
+        let binary = Elf::parse(&lib).unwrap();
+        fn names<'a>(symbols: &[(&'a str, Sym)]) -> Vec<&'a str> {
+            symbols.iter().map(|&(name, _)| name).filter(|name| name.starts_with("goblin_")).collect()
+        }
+        let statics = binary.static_symbols().collect::<Vec<_>>();
+        assert_eq!(names(&statics), vec!["goblin_helper", "goblin_import", "goblin_answer", "goblin_caller"]);
+        let mut iter = binary.dynamic_symbols();
+        iter.next();
+        assert_eq!(iter.size_hint(), (binary.dynsyms.len() - 1, Some(binary.dynsyms.len() - 1)));
+        let dynamics = binary.dynamic_symbols().collect::<Vec<_>>();
+        assert_eq!(names(&dynamics), vec!["goblin_import", "goblin_caller", "goblin_answer"]);
+        let all = binary.all_symbols_deduped();
+        assert_eq!(all, statics);
+        assert_eq!(all.iter().filter(|&&(name, _)| name == "goblin_answer").count(), 1);
+        // a stripped binary has only dynamic symbols
+        let lib: Vec<u8> = include!("../../etc/libgnu.rs");
+        let binary = Elf::parse(&lib).unwrap();
+        assert_eq!(binary.static_symbols().len(), 0);
+        assert_eq!(binary.all_symbols_deduped(), binary.dynamic_symbols().collect::<Vec<_>>());
+    }
 }
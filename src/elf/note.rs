@@ -31,6 +31,22 @@ pub const NT_GNU_BUILD_ID: u32 = 3;
 // Version note generated by GNU gold containing a version string.
 pub const NT_GNU_GOLD_VERSION: u32 = 4;
 
+// Program property note, as generated by ld from the properties of its inputs.
+//    The descriptor is an array of properties, each a word for the type, a word
+//    for the size of its data, and the data, padded to 8 bytes in ELFCLASS64
+//    and to 4 bytes in ELFCLASS32.
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+// The Intel CET features every input supports: a word of GNU_PROPERTY_X86_FEATURE_1_* bits.
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc000_0002;
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 1 << 0;
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 1 << 1;
+
+// The AArch64 branch protection features every input supports: a word of GNU_PROPERTY_AARCH64_FEATURE_1_* bits.
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc000_0000;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 1 << 0;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 1 << 1;
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "alloc", derive(Pread, Pwrite, IOread, IOwrite, SizeWith))]
 #[repr(C)]
@@ -78,9 +94,15 @@ if_alloc! {
                 None
             } else {
                 debug!("NoteIterator - {:#x}", self.offset);
-                match self.data.gread_with(&mut self.offset, self.ctx) {
+                // a note may not run past the end of its section or segment
+                let data = &self.data[..self.size.min(self.data.len())];
+                match data.gread_with(&mut self.offset, self.ctx) {
                     Ok(res) => Some(Ok(res)),
-                    Err(e) => Some(Err(e.into()))
+                    Err(e) => {
+                        // the rest of the notes can't be found without this one's size
+                        self.offset = self.size;
+                        Some(Err(e))
+                    }
                 }
             }
         }
@@ -159,11 +181,69 @@ if_alloc! {
                 NT_GNU_HWCAP => "NT_GNU_HWCAP",
                 NT_GNU_BUILD_ID => "NT_GNU_BUILD_ID",
                 NT_GNU_GOLD_VERSION => "NT_GNU_GOLD_VERSION",
+                NT_GNU_PROPERTY_TYPE_0 => "NT_GNU_PROPERTY_TYPE_0",
                 _ => "NT_UNKNOWN"
             }
         }
     }
 
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// A property of an `NT_GNU_PROPERTY_TYPE_0` note which goblin decodes
+    pub enum GnuProperty {
+        /// The Intel CET features of `GNU_PROPERTY_X86_FEATURE_1_AND`
+        X86Feature1 {
+            /// Indirect branch tracking: every indirect branch target starts with an `endbr`
+            ibt: bool,
+            /// Shadow stack: returns are checked against a second, protected stack
+            shstk: bool,
+        },
+        /// The AArch64 branch protection features of `GNU_PROPERTY_AARCH64_FEATURE_1_AND`
+        Aarch64Feature1 {
+            /// Branch target identification: every indirect branch target starts with a `bti`
+            bti: bool,
+            /// Pointer authentication of return addresses
+            pac: bool,
+        },
+    }
+
+    impl GnuProperty {
+        /// Decodes the properties in the descriptor of an `NT_GNU_PROPERTY_TYPE_0` note, skipping those of other types
+        pub fn parse(desc: &[u8], ctx: container::Ctx) -> error::Result<Vec<Self>> {
+            let alignment = if ctx.container == container::Container::Big { 8 } else { 4 };
+            let mut properties = Vec::new();
+            let mut offset = 0;
+            while offset < desc.len() {
+                let pr_type: u32 = desc.gread_with(&mut offset, ctx.le)?;
+                let pr_datasz: u32 = desc.gread_with(&mut offset, ctx.le)?;
+                let data = match desc[offset..].get(..pr_datasz as usize) {
+                    Some(data) => data,
+                    None => return Err(error::Error::Malformed(format!("GNU property {:#x} of size {:#x} runs past the end of its note", pr_type, pr_datasz))),
+                };
+                offset += data.len();
+                align(alignment, &mut offset);
+                let property = match pr_type {
+                    GNU_PROPERTY_X86_FEATURE_1_AND => {
+                        let bits: u32 = data.pread_with(0, ctx.le)?;
+                        GnuProperty::X86Feature1 {
+                            ibt: bits & GNU_PROPERTY_X86_FEATURE_1_IBT != 0,
+                            shstk: bits & GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0,
+                        }
+                    },
+                    GNU_PROPERTY_AARCH64_FEATURE_1_AND => {
+                        let bits: u32 = data.pread_with(0, ctx.le)?;
+                        GnuProperty::Aarch64Feature1 {
+                            bti: bits & GNU_PROPERTY_AARCH64_FEATURE_1_BTI != 0,
+                            pac: bits & GNU_PROPERTY_AARCH64_FEATURE_1_PAC != 0,
+                        }
+                    },
+                    _ => continue,
+                };
+                properties.push(property);
+            }
+            Ok(properties)
+        }
+    }
+
     impl<'a> ctx::TryFromCtx<'a, (usize, container::Ctx)> for Note<'a> {
         type Error = error::Error;
         type Size = usize;
@@ -180,9 +260,18 @@ if_alloc! {
                 }
             };
             debug!("{:?} - {:#x}", header, *offset);
-            // -1 because includes \0 terminator
-            let name = bytes.gread_with::<&'a str>(offset, ctx::StrCtx::Length(header.n_namesz - 1))?;
-            *offset += 1;
+            if header.n_namesz > bytes.len() || header.n_descsz > bytes.len() {
+                return Err(error::Error::Malformed(format!("Note has a name size {:#x} or descriptor size {:#x} larger than its {:#x} bytes", header.n_namesz, header.n_descsz, bytes.len())));
+            }
+            // a note without a name has a zero `namesz`, rather than a lone \0
+            let name = if header.n_namesz == 0 {
+                ""
+            } else {
+                // -1 because includes \0 terminator
+                let name = bytes.gread_with::<&'a str>(offset, ctx::StrCtx::Length(header.n_namesz - 1))?;
+                *offset += 1;
+                name
+            };
             align(alignment, offset);
             debug!("note name {} - {:#x}", name, *offset);
             let desc = bytes.gread_with::<&'a [u8]>(offset, header.n_descsz)?;
@@ -264,6 +353,47 @@ if_alloc! {
             assert!(notes.next().is_none());
         }
 
+        #[test]
+        fn malformed_notes() {
+            // a note without a name, followed by one whose descriptor runs past the end of the segment
+            let mut data = [0u8; 24];
+            data[4] = 4;
+            data[8] = 7;
+            data[16] = 0xff;
+            data[17] = 0xff;
+            data[18] = 0xff;
+            data[19] = 0xff;
+            let mut notes = NoteDataIterator { data: &data, size: data.len(), offset: 0, ctx: CONTEXT };
+            let note = notes.next().unwrap().unwrap();
+            assert_eq!((note.name, note.n_type, note.desc), ("", 7, &[0u8; 4][..]));
+            assert!(notes.next().unwrap().is_err());
+            assert!(notes.next().is_none());
+            // the build id note mustn't be read past a segment which ends in its middle
+            let mut notes = make_note_iter(32, 60);
+            assert!(notes.next().unwrap().is_err());
+            assert!(notes.next().is_none());
+        }
+
+        #[test]
+        fn gnu_properties() {
+            use scroll::Pwrite;
+            // an unknown property, padded to 8 bytes, then BTI; and the same, padded to 4 bytes, in a 32-bit binary
+            let mut desc = [0u8; 24];
+            for &(offset, value) in [(0, 0xc000_8002u32), (4, 4), (8, 1), (16, GNU_PROPERTY_AARCH64_FEATURE_1_AND), (20, 4)].iter() {
+                desc.pwrite_with(value, offset, ::scroll::LE).unwrap();
+            }
+            let mut bti = desc.to_vec();
+            bti.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+            assert_eq!(GnuProperty::parse(&bti, CONTEXT.1).unwrap(), vec![GnuProperty::Aarch64Feature1 { bti: true, pac: false }]);
+            let ctx = container::Ctx { container: container::Container::Little, .. CONTEXT.1 };
+            let mut bti = desc[..12].to_vec();
+            bti.extend_from_slice(&desc[16..]);
+            bti.extend_from_slice(&[3, 0, 0, 0]);
+            assert_eq!(GnuProperty::parse(&bti, ctx).unwrap(), vec![GnuProperty::Aarch64Feature1 { bti: true, pac: true }]);
+            // the last property lacks its data
+            assert!(GnuProperty::parse(&desc, CONTEXT.1).is_err());
+        }
+
         #[test]
         fn ignore_no_sections() {
             let mut notes = NoteIterator { iters: vec![], index: 0 };
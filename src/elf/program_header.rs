@@ -24,6 +24,8 @@ pub const PT_GNU_EH_FRAME: u32 = 0x6474e550;
 pub const PT_GNU_STACK: u32 = 0x6474e551;
 /// Read-only after relocation
 pub const PT_GNU_RELRO: u32 = 0x6474e552;
+/// GNU property notes, e.g., the CET or BTI support of the binary
+pub const PT_GNU_PROPERTY: u32 = 0x6474e553;
 /// Sun Specific segment
 pub const PT_LOSUNW: u32 = 0x6ffffffa;
 /// Sun Specific segment
@@ -50,6 +52,33 @@ pub const PF_W: u32 = 1 << 1;
 /// Segment is readable
 pub const PF_R: u32 = 1 << 2;
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+/// The `p_flags` of a program header, i.e., the permissions its segment is mapped with
+pub struct SegmentFlags(pub u32);
+
+impl SegmentFlags {
+    /// The raw `PF_*` bits
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+    /// Whether every bit of `flags` is set
+    pub fn contains(&self, flags: u32) -> bool {
+        self.0 & flags == flags
+    }
+    /// Whether the segment is executable, i.e., has `PF_X`
+    pub fn is_exec(&self) -> bool {
+        self.contains(PF_X)
+    }
+    /// Whether the segment is writable, i.e., has `PF_W`
+    pub fn is_write(&self) -> bool {
+        self.contains(PF_W)
+    }
+    /// Whether the segment is readable, i.e., has `PF_R`
+    pub fn is_read(&self) -> bool {
+        self.contains(PF_R)
+    }
+}
+
 pub fn pt_to_str(pt: u32) -> &'static str {
     match pt {
         PT_NULL => "PT_NULL",
@@ -65,6 +94,7 @@ pub fn pt_to_str(pt: u32) -> &'static str {
         PT_GNU_EH_FRAME => "PT_GNU_EH_FRAME",
         PT_GNU_STACK => "PT_GNU_STACK",
         PT_GNU_RELRO => "PT_GNU_RELRO",
+        PT_GNU_PROPERTY => "PT_GNU_PROPERTY",
         PT_SUNWBSS => "PT_SUNWBSS",
         PT_SUNWSTACK => "PT_SUNWSTACK",
         PT_HIOS => "PT_HIOS",
@@ -121,9 +151,13 @@ if_alloc! {
         pub fn file_range(&self) -> Range<usize> {
             (self.p_offset as usize..self.p_offset as usize + self.p_filesz as usize)
         }
-        /// Returns this program header's virtual memory range
-        pub fn vm_range(&self) -> Range<usize> {
-            (self.p_vaddr as usize..self.p_vaddr as usize + self.p_memsz as usize)
+        /// Returns this program header's virtual memory range, `p_vaddr..p_vaddr + p_memsz`, which includes the bss
+        pub fn vm_range(&self) -> Range<u64> {
+            self.p_vaddr..self.p_vaddr.saturating_add(self.p_memsz)
+        }
+        /// Whether the virtual memory address `addr` lies within this program header's `p_vaddr..p_vaddr + p_memsz`
+        pub fn contains_vaddr(&self, addr: u64) -> bool {
+            addr >= self.p_vaddr && addr - self.p_vaddr < self.p_memsz
         }
         /// Sets the executable flag
         pub fn executable(&mut self) {
@@ -137,6 +171,10 @@ if_alloc! {
         pub fn read(&mut self) {
             self.p_flags |= PF_R;
         }
+        /// The typed `p_flags` of this program header
+        pub fn flags(&self) -> SegmentFlags {
+            SegmentFlags(self.p_flags)
+        }
         /// Whether this program header is executable
         pub fn is_executable(&self) -> bool {
             self.p_flags & PF_X != 0
@@ -225,6 +263,39 @@ if_alloc! {
             }
         }
     }
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn contains_vaddr() {
+            let mut phdr = ProgramHeader::new();
+            phdr.p_vaddr = 0x1000;
+            phdr.p_filesz = 0x10;
+            phdr.p_memsz = 0x20;
+            assert!(!phdr.contains_vaddr(0xfff));
+            assert!(phdr.contains_vaddr(0x1000));
+            // bss, past the end of the file contents
+            assert!(phdr.contains_vaddr(0x101f));
+            assert!(!phdr.contains_vaddr(0x1020));
+            assert_eq!(phdr.vm_range(), 0x1000..0x1020);
+            assert_eq!(phdr.file_range(), 0..0x10);
+            phdr.p_vaddr = ::core::u64::MAX;
+            assert!(phdr.contains_vaddr(::core::u64::MAX));
+        }
+
+        #[test]
+        fn flags() {
+            let mut phdr = ProgramHeader::new();
+            phdr.p_flags = PF_R | PF_X;
+            let flags = phdr.flags();
+            assert!(flags.is_exec() && flags.is_read() && !flags.is_write());
+            assert_eq!(flags.bits(), phdr.p_flags);
+            phdr.write();
+            assert!(phdr.flags().is_write());
+            assert!(phdr.flags().contains(PF_R | PF_W));
+        }
+    }
 } // end if_alloc
 
 macro_rules! elf_program_header_std_impl { ($size:ty) => {
@@ -46,6 +46,17 @@
 
 include!("constants_relocation.rs");
 
+/// The magic number starting the Android packed relocations of an `SHT_ANDROID_REL(A)` section
+pub const ANDROID_PACKED_RELOCS_MAGIC: &'static [u8; 4] = b"APS2";
+/// Every relocation in the group has the same `r_info`
+pub const RELOCATION_GROUPED_BY_INFO_FLAG: u64 = 1;
+/// Every relocation in the group is the same distance from the previous one
+pub const RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG: u64 = 2;
+/// Every relocation in the group has the same addend
+pub const RELOCATION_GROUPED_BY_ADDEND_FLAG: u64 = 4;
+/// The relocations in the group have addends; otherwise their addends are 0
+pub const RELOCATION_GROUP_HAS_ADDEND_FLAG: u64 = 8;
+
 macro_rules! elf_reloc {
     ($size:ident, $isize:ty) => {
         use core::fmt;
@@ -190,7 +201,7 @@ macro_rules! elf_rela_std_impl { ($size:ident, $isize:ty) => {
                 }
                 Ok(relocs)
             }
-        } // end if_alloc
+} // end if_alloc
     };
 }
 
@@ -278,6 +289,26 @@ if_alloc! {
             use scroll::ctx::SizeWith;
             Reloc::size_with(&(is_rela, ctx))
         }
+        /// The name of this relocation's type on `machine` (the binary's `e_machine`), e.g., `R_X86_64_GLOB_DAT`; see `r_type_name`
+        pub fn r_type_name(&self, machine: u16) -> &'static str {
+            r_type_name(self.r_type, machine)
+        }
+        /// What this relocation writes on `machine`, for the common types of x86, x86-64, ARM and AArch64; any other is `RelocKind::Other(r_type)`
+        pub fn kind(&self, machine: u16) -> ::relocation::RelocKind {
+            use elf::header::{EM_386, EM_X86_64, EM_ARM, EM_AARCH64};
+            use relocation::RelocKind;
+            match (machine, self.r_type) {
+                (EM_X86_64, R_X86_64_RELATIVE) | (EM_386, R_386_RELATIVE) | (EM_AARCH64, R_AARCH64_RELATIVE) | (EM_ARM, R_ARM_RELATIVE) => RelocKind::Relative,
+                  (EM_X86_64, R_X86_64_64) | (EM_X86_64, R_X86_64_GLOB_DAT)
+                | (EM_386, R_386_32) | (EM_386, R_386_GLOB_DAT)
+                | (EM_AARCH64, R_AARCH64_ABS64) | (EM_AARCH64, R_AARCH64_GLOB_DAT)
+                | (EM_ARM, R_ARM_ABS32) | (EM_ARM, R_ARM_GLOB_DAT) => RelocKind::Absolute,
+                (EM_X86_64, R_X86_64_PC32) | (EM_386, R_386_PC32) | (EM_AARCH64, R_AARCH64_PREL32) | (EM_ARM, R_ARM_REL32) => RelocKind::PcRelative,
+                (EM_X86_64, R_X86_64_JUMP_SLOT) | (EM_386, R_386_JMP_SLOT) | (EM_AARCH64, R_AARCH64_JUMP_SLOT) | (EM_ARM, R_ARM_JUMP_SLOT) => RelocKind::JumpSlot,
+                (EM_X86_64, R_X86_64_COPY) | (EM_386, R_386_COPY) | (EM_AARCH64, R_AARCH64_COPY) | (EM_ARM, R_ARM_COPY) => RelocKind::Copy,
+                (_, r_type) => RelocKind::Other(r_type),
+            }
+        }
         #[cfg(feature = "endian_fd")]
         pub fn parse(bytes: &[u8], mut offset: usize, filesz: usize, is_rela: bool, ctx: Ctx) -> ::error::Result<Vec<Reloc>> {
             use scroll::Pread;
@@ -290,6 +321,81 @@ if_alloc! {
             }
             Ok(relocs)
         }
+        /// Decodes the `filesz` bytes of Android packed relocations at `offset`, as found in `SHT_ANDROID_REL(A)` sections, or at `DT_ANDROID_REL(A)`.
+        ///
+        /// The relocations are a stream of sleb128s: the count and the initial `r_offset`, followed by groups of relocations,
+        /// where the flags of each group say which of the offset delta, `r_info` and addend are shared by the whole group.
+        #[cfg(feature = "endian_fd")]
+        pub fn parse_android(bytes: &[u8], offset: usize, filesz: usize, is_rela: bool, ctx: Ctx) -> ::error::Result<Vec<Reloc>> {
+            use scroll::Sleb128;
+            use error::Error;
+            if filesz == 0 {
+                return Ok(Vec::new());
+            }
+            let bytes = match offset.checked_add(filesz).and_then(|end| bytes.get(offset..end)) {
+                Some(bytes) => bytes,
+                None => return Err(Error::Malformed(format!("Android packed relocations at {:#x} of size {:#x} are out of bounds", offset, filesz))),
+            };
+            if !bytes.starts_with(ANDROID_PACKED_RELOCS_MAGIC) {
+                return Err(Error::Malformed(format!("Android packed relocations at {:#x} have bad magic {:?}", offset, &bytes[..::core::cmp::min(4, bytes.len())])));
+            }
+            let mut cursor = ANDROID_PACKED_RELOCS_MAGIC.len();
+            let count = Sleb128::read(bytes, &mut cursor)?;
+            if count < 0 {
+                return Err(Error::Malformed(format!("Android packed relocations at {:#x} have a negative count {}", offset, count)));
+            }
+            let mut remaining = count as u64;
+            let mut r_offset = Sleb128::read(bytes, &mut cursor)? as u64;
+            let mut r_info = 0u64;
+            let mut r_addend = 0i64;
+            // a group needs at least two bytes, but may consist of any number of relocations
+            let mut relocs = Vec::with_capacity(::core::cmp::min(remaining as usize, bytes.len()));
+            while remaining > 0 {
+                let group_size = Sleb128::read(bytes, &mut cursor)?;
+                if group_size <= 0 || group_size as u64 > remaining {
+                    return Err(Error::Malformed(format!("Android packed relocations at {:#x} have a bad group size {} with {} relocations remaining", offset, group_size, remaining)));
+                }
+                let flags = Sleb128::read(bytes, &mut cursor)? as u64;
+                let grouped_by_info = flags & RELOCATION_GROUPED_BY_INFO_FLAG != 0;
+                let grouped_by_offset_delta = flags & RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG != 0;
+                let grouped_by_addend = flags & RELOCATION_GROUPED_BY_ADDEND_FLAG != 0;
+                let has_addend = flags & RELOCATION_GROUP_HAS_ADDEND_FLAG != 0;
+                if has_addend && !is_rela {
+                    return Err(Error::Malformed(format!("Android packed relocations at {:#x} have addends, but are not SHT_ANDROID_RELA", offset)));
+                }
+                let group_offset_delta = if grouped_by_offset_delta { Sleb128::read(bytes, &mut cursor)? } else { 0 };
+                if grouped_by_info {
+                    r_info = Sleb128::read(bytes, &mut cursor)? as u64;
+                }
+                if has_addend && grouped_by_addend {
+                    r_addend = r_addend.wrapping_add(Sleb128::read(bytes, &mut cursor)?);
+                } else if !has_addend {
+                    r_addend = 0;
+                }
+                for _ in 0..group_size {
+                    let delta = if grouped_by_offset_delta { group_offset_delta } else { Sleb128::read(bytes, &mut cursor)? };
+                    r_offset = r_offset.wrapping_add(delta as u64);
+                    if !grouped_by_info {
+                        r_info = Sleb128::read(bytes, &mut cursor)? as u64;
+                    }
+                    if has_addend && !grouped_by_addend {
+                        r_addend = r_addend.wrapping_add(Sleb128::read(bytes, &mut cursor)?);
+                    }
+                    let (offset, sym, typ) = match ctx.container {
+                        Container::Little => (r_offset as u32 as u64, reloc32::r_sym(r_info as u32) as usize, reloc32::r_type(r_info as u32)),
+                        Container::Big => (r_offset, reloc64::r_sym(r_info) as usize, reloc64::r_type(r_info)),
+                    };
+                    relocs.push(Reloc {
+                        r_offset: offset,
+                        r_addend: if is_rela { Some(r_addend) } else { None },
+                        r_sym: sym,
+                        r_type: typ,
+                    });
+                }
+                remaining -= group_size as u64;
+            }
+            Ok(relocs)
+        }
     }
 
     type RelocCtx = (bool, Ctx);
@@ -392,3 +498,78 @@ if_alloc! {
         }
     }
 } // end if_alloc
+
+#[cfg(all(test, feature = "endian_fd"))]
+mod tests {
+    use super::*;
+    use container::{Ctx, Container};
+    use elf::header::*;
+    use scroll;
+
+    fn sleb(bytes: &mut Vec<u8>, mut value: i64) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+                bytes.push(byte);
+                return;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    #[test]
+    fn parse_android_packed_relocs() {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let mut bytes = ANDROID_PACKED_RELOCS_MAGIC.to_vec();
+        // 3 relocations, starting at 0x1000
+        for value in &[3, 0x1000] { sleb(&mut bytes, *value); }
+        // 2 R_X86_64_RELATIVEs, 8 bytes apart, with an addend of 0x10
+        for value in &[2, 0xf, 8, 8, 0x10] { sleb(&mut bytes, *value); }
+        // 1 R_X86_64_GLOB_DAT against symbol 1, with its own offset delta and addend
+        for value in &[1, 0x8, 0x10, (1 << 32) | 6, -0x10] { sleb(&mut bytes, *value); }
+        let relocs = Reloc::parse_android(&bytes, 0, bytes.len(), true, ctx).unwrap();
+        let relocs = relocs.iter().map(|r| (r.r_offset, r.r_sym, r.r_type, r.r_addend)).collect::<Vec<_>>();
+        assert_eq!(relocs, vec![
+            (0x1008, 0, R_X86_64_RELATIVE, Some(0x10)),
+            (0x1010, 0, R_X86_64_RELATIVE, Some(0x10)),
+            (0x1020, 1, R_X86_64_GLOB_DAT, Some(0)),
+        ]);
+        // addends need SHT_ANDROID_RELA, and the stream may not end early
+        assert!(Reloc::parse_android(&bytes, 0, bytes.len(), false, ctx).is_err());
+        assert!(Reloc::parse_android(&bytes, 0, bytes.len() - 1, true, ctx).is_err());
+        assert!(Reloc::parse_android(&bytes[1..], 0, bytes.len() - 1, true, ctx).is_err());
+    }
+
+    #[test]
+    fn relocation_names() {
+        let names = [
+            (R_386_JMP_SLOT, EM_386, "R_386_JMP_SLOT"),
+            (R_X86_64_GLOB_DAT, EM_X86_64, "R_X86_64_GLOB_DAT"),
+            (R_AARCH64_NONE, EM_AARCH64, "R_AARCH64_NONE"),
+            (R_AARCH64_GLOB_DAT, EM_AARCH64, "R_AARCH64_GLOB_DAT"),
+            (R_AARCH64_RELATIVE, EM_AARCH64, "R_AARCH64_RELATIVE"),
+            (R_ARM_NONE, EM_ARM, "R_ARM_NONE"),
+            (R_ARM_GLOB_DAT, EM_ARM, "R_ARM_GLOB_DAT"),
+            (R_RISCV_JUMP_SLOT, EM_RISCV, "R_RISCV_JUMP_SLOT"),
+            (R_RISCV_IRELATIVE, EM_RISCV, "R_RISCV_IRELATIVE"),
+            (R_RISCV_SUB_ULEB128, EM_RISCV, "R_RISCV_SUB_ULEB128"),
+            // the same value names a different relocation on each machine
+            (5, EM_ARM, "R_ARM_ABS16"),
+            (1027, EM_X86_64, "R_UNKNOWN_X86_64"),
+            (42, EM_RISCV, "R_UNKNOWN_RISCV"),
+            (1, EM_BPF, "R_UNKNOWN"),
+        ];
+        for &(typ, machine, name) in names.iter() {
+            assert_eq!(r_type_name(typ, machine), name);
+        }
+        // `r_to_str` keeps its historical spelling, without the prefix, but for MIPS and unknown types
+        assert_eq!(r_to_str(R_X86_64_GLOB_DAT, EM_X86_64), "X86_64_GLOB_DAT");
+        assert_eq!(r_to_str(R_386_JMP_SLOT, EM_386), "386_JMP_SLOT");
+        assert_eq!(r_to_str(R_AARCH64_RELATIVE, EM_AARCH64), "AARCH64_RELATIVE");
+        assert_eq!(r_to_str(R_ARM_GLOB_DAT, EM_ARM), "ARM_GLOB_DAT");
+        assert_eq!(r_to_str(R_RISCV_JUMP_SLOT, EM_RISCV), "RISCV_JUMP_SLOT");
+        assert_eq!(r_to_str(R_MIPS_REL32, EM_MIPS), "R_MIPS_REL32");
+        assert_eq!(r_to_str(1027, EM_X86_64), "R_UNKNOWN_X86_64");
+    }
+}
@@ -117,6 +117,18 @@ pub const SHT_SYMTAB_SHNDX: u32 = 18;
 pub const SHT_NUM: u32 = 19;
 /// Start OS-specific.
 pub const SHT_LOOS: u32 = 0x60000000;
+/// Android packed relocations without addends.
+pub const SHT_ANDROID_REL: u32 = 0x60000001;
+/// Android packed relocations with addends.
+pub const SHT_ANDROID_RELA: u32 = 0x60000002;
+/// LLVM ODR table.
+pub const SHT_LLVM_ODRTAB: u32 = 0x6fff4c00;
+/// LLVM linker options.
+pub const SHT_LLVM_LINKER_OPTIONS: u32 = 0x6fff4c01;
+/// LLVM address-significance table.
+pub const SHT_LLVM_ADDRSIG: u32 = 0x6fff4c03;
+/// Android relative relocations.
+pub const SHT_ANDROID_RELR: u32 = 0x6fffff00;
 /// Object attributes.
 pub const SHT_GNU_ATTRIBUTES: u32 = 0x6ffffff5;
 /// GNU-style hash table.
@@ -144,6 +156,28 @@ pub const SHT_HIOS: u32 = 0x6fffffff;
 pub const SHT_LOPROC: u32 = 0x70000000;
 /// End of processor-specific.
 pub const SHT_HIPROC: u32 = 0x7fffffff;
+/// ARM unwind table.
+pub const SHT_ARM_EXIDX: u32 = 0x70000001;
+/// ARM preemption map.
+pub const SHT_ARM_PREEMPTMAP: u32 = 0x70000002;
+/// ARM build attributes.
+pub const SHT_ARM_ATTRIBUTES: u32 = 0x70000003;
+/// ARM debug overlay.
+pub const SHT_ARM_DEBUGOVERLAY: u32 = 0x70000004;
+/// ARM overlay section.
+pub const SHT_ARM_OVERLAYSECTION: u32 = 0x70000005;
+/// AArch64 build attributes.
+pub const SHT_AARCH64_ATTRIBUTES: u32 = 0x70000003;
+/// x86-64 unwind table.
+pub const SHT_X86_64_UNWIND: u32 = 0x70000001;
+/// MIPS register usage information.
+pub const SHT_MIPS_REGINFO: u32 = 0x70000006;
+/// MIPS miscellaneous options.
+pub const SHT_MIPS_OPTIONS: u32 = 0x7000000d;
+/// MIPS DWARF debugging information.
+pub const SHT_MIPS_DWARF: u32 = 0x7000001e;
+/// MIPS ABI flags.
+pub const SHT_MIPS_ABIFLAGS: u32 = 0x7000002a;
 /// Start of application-specific.
 pub const SHT_LOUSER: u32 = 0x80000000;
 /// End of application-specific.
@@ -198,6 +232,33 @@ pub const SHF_FLAGS: [u32; SHF_NUM_REGULAR_FLAGS] = [
     SHF_ORDERED,
 ];
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+/// The `sh_flags` of a section header
+pub struct SectionFlags(pub u64);
+
+impl SectionFlags {
+    /// The raw `SHF_*` bits
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+    /// Whether every bit of `flags` is set
+    pub fn contains(&self, flags: u64) -> bool {
+        self.0 & flags == flags
+    }
+    /// Whether the section holds instructions, i.e., has `SHF_EXECINSTR`
+    pub fn is_exec(&self) -> bool {
+        self.contains(SHF_EXECINSTR as u64)
+    }
+    /// Whether the section is writable at run time, i.e., has `SHF_WRITE`
+    pub fn is_write(&self) -> bool {
+        self.contains(SHF_WRITE as u64)
+    }
+    /// Whether the section is readable at run time, i.e., it is mapped, with `SHF_ALLOC`; ELF has no flag for reading
+    pub fn is_read(&self) -> bool {
+        self.contains(SHF_ALLOC as u64)
+    }
+}
+
 pub fn sht_to_str(sht: u32) -> &'static str {
     match sht {
         SHT_NULL => "SHT_NULL",
@@ -219,6 +280,12 @@ pub fn sht_to_str(sht: u32) -> &'static str {
         SHT_SYMTAB_SHNDX => "SHT_SYMTAB_SHNDX",
         SHT_NUM => "SHT_NUM",
         SHT_LOOS => "SHT_LOOS",
+        SHT_ANDROID_REL => "SHT_ANDROID_REL",
+        SHT_ANDROID_RELA => "SHT_ANDROID_RELA",
+        SHT_LLVM_ODRTAB => "SHT_LLVM_ODRTAB",
+        SHT_LLVM_LINKER_OPTIONS => "SHT_LLVM_LINKER_OPTIONS",
+        SHT_LLVM_ADDRSIG => "SHT_LLVM_ADDRSIG",
+        SHT_ANDROID_RELR => "SHT_ANDROID_RELR",
         SHT_GNU_ATTRIBUTES => "SHT_GNU_ATTRIBUTES",
         SHT_GNU_HASH => "SHT_GNU_HASH",
         SHT_GNU_LIBLIST => "SHT_GNU_LIBLIST",
@@ -233,10 +300,32 @@ pub fn sht_to_str(sht: u32) -> &'static str {
         SHT_HIPROC => "SHT_HIPROC",
         SHT_LOUSER => "SHT_LOUSER",
         SHT_HIUSER => "SHT_HIUSER",
+        _ if sht >= SHT_LOOS && sht <= SHT_HIOS => "UNKNOWN_SHT_OS",
+        _ if sht >= SHT_LOPROC && sht <= SHT_HIPROC => "UNKNOWN_SHT_PROC",
+        _ if sht >= SHT_LOUSER && sht <= SHT_HIUSER => "UNKNOWN_SHT_USER",
         _ => "UNKNOWN_SHT",
     }
 }
 
+/// Converts a section type to its string representation, naming the processor-specific types of `machine`, e.g., `SHT_ARM_EXIDX` for `EM_ARM`
+pub fn sht_to_str_for_machine(sht: u32, machine: u16) -> &'static str {
+    use elf::header::*;
+    match (machine, sht) {
+        (EM_ARM, SHT_ARM_EXIDX) => "SHT_ARM_EXIDX",
+        (EM_ARM, SHT_ARM_PREEMPTMAP) => "SHT_ARM_PREEMPTMAP",
+        (EM_ARM, SHT_ARM_ATTRIBUTES) => "SHT_ARM_ATTRIBUTES",
+        (EM_ARM, SHT_ARM_DEBUGOVERLAY) => "SHT_ARM_DEBUGOVERLAY",
+        (EM_ARM, SHT_ARM_OVERLAYSECTION) => "SHT_ARM_OVERLAYSECTION",
+        (EM_AARCH64, SHT_AARCH64_ATTRIBUTES) => "SHT_AARCH64_ATTRIBUTES",
+        (EM_X86_64, SHT_X86_64_UNWIND) => "SHT_X86_64_UNWIND",
+        (EM_MIPS, SHT_MIPS_REGINFO) => "SHT_MIPS_REGINFO",
+        (EM_MIPS, SHT_MIPS_OPTIONS) => "SHT_MIPS_OPTIONS",
+        (EM_MIPS, SHT_MIPS_DWARF) => "SHT_MIPS_DWARF",
+        (EM_MIPS, SHT_MIPS_ABIFLAGS) => "SHT_MIPS_ABIFLAGS",
+        _ => sht_to_str(sht),
+    }
+}
+
 pub fn shf_to_str(shf: u32) -> &'static str {
     match shf {
         SHF_WRITE => "SHF_WRITE",
@@ -371,6 +460,10 @@ if_alloc! {
 
     #[cfg(feature = "endian_fd")]
     use alloc::vec::Vec;
+    #[cfg(feature = "endian_fd")]
+    use elf::compression_header::CompressionHeader;
+    #[cfg(all(feature = "endian_fd", feature = "compression"))]
+    use elf::compression_header::{ELFCOMPRESS_ZLIB, ELFCOMPRESS_ZSTD};
 
     #[derive(Default, PartialEq, Clone)]
     /// A unified SectionHeader - convertable to and from 32-bit and 64-bit variants
@@ -449,6 +542,49 @@ if_alloc! {
             }
             Ok(())
         }
+        /// The contents of this section given `bytes`, which must be the original binary; a `SHT_NOBITS` section, e.g., `.bss`, has no contents in the file, and is an error
+        pub fn data<'a>(&self, bytes: &'a [u8]) -> error::Result<&'a [u8]> {
+            if self.sh_type == SHT_NOBITS {
+                return Err(error::Error::Malformed(format!("Section {} is SHT_NOBITS, and has no data in the file", self.sh_name)));
+            }
+            self.check_size(bytes.len())?;
+            Ok(&bytes[self.sh_offset as usize..(self.sh_offset + self.sh_size) as usize])
+        }
+        /// Whether this section's contents start with a compression header, and are compressed, i.e., it has `SHF_COMPRESSED`
+        pub fn is_compressed(&self) -> bool {
+            self.sh_flags as u32 & SHF_COMPRESSED == SHF_COMPRESSED
+        }
+        /// Parses the compression header at the start of this section's data, or returns `None` if it isn't compressed
+        #[cfg(feature = "endian_fd")]
+        pub fn compression_header(&self, bytes: &[u8], ctx: Ctx) -> error::Result<Option<CompressionHeader>> {
+            if !self.is_compressed() {
+                return Ok(None);
+            }
+            CompressionHeader::parse(self.data(bytes)?, 0, ctx).map(Some)
+        }
+        /// The decompressed contents of this section, or a copy of them if it isn't compressed; `bytes` must be the original binary.
+        ///
+        /// Decompressing `ELFCOMPRESS_ZLIB` and `ELFCOMPRESS_ZSTD` sections requires the `compression` feature; without it, a compressed section is an error,
+        /// and only its raw contents are available, via `data`
+        #[cfg(feature = "endian_fd")]
+        pub fn decompress(&self, bytes: &[u8], ctx: Ctx) -> error::Result<Vec<u8>> {
+            let data = self.data(bytes)?;
+            let chdr = match self.compression_header(bytes, ctx)? {
+                Some(chdr) => chdr,
+                None => return Ok(data.to_vec()),
+            };
+            let compressed = &data[CompressionHeader::size(&ctx)..];
+            // the decompression stops at `ch_size`, so a small section can't claim an arbitrarily large buffer
+            let decompressed = decompress_section(chdr.ch_type, compressed, chdr.ch_size)?;
+            if decompressed.len() as u64 != chdr.ch_size {
+                return Err(error::Error::Malformed(format!("Section {} decompressed to {:#x} bytes, but its header says {:#x}", self.sh_name, decompressed.len(), chdr.ch_size)));
+            }
+            Ok(decompressed)
+        }
+        /// The typed `sh_flags` of this section header
+        pub fn flags(&self) -> SectionFlags {
+            SectionFlags(self.sh_flags)
+        }
         pub fn is_relocation(&self) -> bool {
             self.sh_type == SHT_RELA
         }
@@ -463,6 +599,20 @@ if_alloc! {
         }
     }
 
+    #[cfg(all(feature = "endian_fd", feature = "compression"))]
+    fn decompress_section(ch_type: u32, compressed: &[u8], ch_size: u64) -> error::Result<Vec<u8>> {
+        match ch_type {
+            ELFCOMPRESS_ZLIB => ::compression::decompress_zlib_limited(compressed, ch_size),
+            ELFCOMPRESS_ZSTD => ::compression::decompress_zstd_limited(compressed, ch_size),
+            _ => Err(error::Error::Malformed(format!("Unknown section compression type {}", ch_type))),
+        }
+    }
+
+    #[cfg(all(feature = "endian_fd", not(feature = "compression")))]
+    fn decompress_section(ch_type: u32, _compressed: &[u8], _ch_size: u64) -> error::Result<Vec<u8>> {
+        Err(error::Error::Malformed(format!("Section is compressed with type {}, but goblin was built without the `compression` feature", ch_type)))
+    }
+
     impl fmt::Debug for SectionHeader {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             write!(f,
@@ -279,6 +279,7 @@ if_alloc! {
     use container::{Ctx, Container};
     use error::Result;
     use alloc::vec::Vec;
+    use strtab::Strtab;
 
     #[derive(Default, PartialEq, Clone)]
     /// A unified Sym definition - convertable to and from 32-bit and 64-bit variants
@@ -351,6 +352,25 @@ if_alloc! {
         }
     }
 
+    impl<'a> ::symbol::Symbol for (&'a str, Sym) {
+        fn name(&self) -> &str {
+            self.0
+        }
+        fn address(&self) -> u64 {
+            self.1.st_value
+        }
+        fn is_function(&self) -> bool {
+            self.1.is_function()
+        }
+        /// Weak symbols are global too
+        fn is_global(&self) -> bool {
+            self.1.st_bind() != STB_LOCAL
+        }
+        fn is_undefined(&self) -> bool {
+            self.1.st_shndx == ::elf::section_header::SHN_UNDEF as usize
+        }
+    }
+
     impl ctx::SizeWith<Ctx> for Sym {
         type Units = usize;
         #[inline]
@@ -477,6 +497,18 @@ if_alloc! {
         pub fn to_vec(&self) -> Vec<Sym> {
             self.iter().collect()
         }
+
+        /// Serializes every symbol of the table with `ctx`, which may differ from the context the table was parsed with;
+        /// with the same context, this reproduces the table's bytes exactly
+        pub fn to_bytes(&self, ctx: Ctx) -> Vec<u8> {
+            use scroll::Pwrite;
+            let size = Sym::size_with(&ctx);
+            let mut bytes = vec![0u8; self.count * size];
+            for (i, sym) in self.iter().enumerate() {
+                bytes.pwrite_with(sym, i * size, ctx).unwrap();
+            }
+            bytes
+        }
     }
 
     impl<'a, 'b> IntoIterator for &'b Symtab<'a> {
@@ -516,6 +548,12 @@ if_alloc! {
                 Some(self.bytes.gread_with(&mut self.offset, self.ctx).unwrap())
             }
         }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = self.count - self.index;
+            (len, Some(len))
+        }
     }
 
     impl<'a> ExactSizeIterator for SymIterator<'a> {
@@ -524,4 +562,38 @@ if_alloc! {
             self.count - self.index
         }
     }
+
+    /// An iterator over the symbols of a symbol table, with their names in its string table; a name out of the string table's bounds is empty
+    pub struct NamedSymIterator<'a, 'b> {
+        syms: SymIterator<'a>,
+        strtab: &'b Strtab<'a>,
+    }
+
+    impl<'a, 'b> NamedSymIterator<'a, 'b> {
+        /// Iterates `symtab`, naming its symbols from `strtab`, which must be the string table its section links to
+        pub fn new(symtab: &Symtab<'a>, strtab: &'b Strtab<'a>) -> Self {
+            NamedSymIterator { syms: symtab.iter(), strtab }
+        }
+    }
+
+    impl<'a, 'b> Iterator for NamedSymIterator<'a, 'b> {
+        type Item = (&'a str, Sym);
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.syms.next().map(|sym| (self.strtab.get_at(sym.st_name).unwrap_or(""), sym))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.syms.size_hint()
+        }
+    }
+
+    impl<'a, 'b> ExactSizeIterator for NamedSymIterator<'a, 'b> {
+        #[inline]
+        fn len(&self) -> usize {
+            self.syms.len()
+        }
+    }
 } // end if_alloc
@@ -0,0 +1,92 @@
+//! The legacy SysV `.hash` table, found in binaries linked with `--hash-style=sysv` (or `both`).
+//!
+//! The table is an array of u32s: `nbucket`, `nchain`, then `nbucket` buckets and `nchain` chain entries,
+//! where `nchain` is always the number of symbols in the dynamic symbol table.
+//!
+//! See: http://www.sco.com/developers/gabi/latest/ch5.dynamic.html#hash
+
+/// SysV ELF hash function: takes a string and returns the u32 hash of that string
+pub fn hash(symbol: &str) -> u32 {
+    let mut hash: u32 = 0;
+    for b in symbol.as_bytes() {
+        hash = (hash << 4).wrapping_add(*b as u32);
+        let g = hash & 0xf000_0000;
+        if g != 0 {
+            hash ^= g >> 24;
+        }
+        hash &= !g;
+    }
+    hash
+}
+
+if_alloc! {
+    use scroll::Pread;
+    use container::Ctx;
+    use strtab::Strtab;
+    use error;
+    use elf::sym::Symtab;
+    use elf::section_header::SHN_UNDEF;
+
+    #[derive(Debug)]
+    /// A SysV `.hash` table read from a binary on disk, bounds checked on every access
+    pub struct SysvHash<'a> {
+        nbucket: u32,
+        nchain: u32,
+        buckets: &'a [u8],
+        chains: &'a [u8],
+        ctx: Ctx,
+    }
+
+    impl<'a> SysvHash<'a> {
+        /// Parses the hash table at `offset`; fails if the buckets or the chains do not fit in `bytes`
+        pub fn parse(bytes: &'a [u8], offset: usize, ctx: Ctx) -> error::Result<SysvHash<'a>> {
+            let mut offset = offset;
+            let nbucket: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let nchain: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let buckets_size = (nbucket as usize).checked_mul(4);
+            let chains_size = (nchain as usize).checked_mul(4);
+            let (buckets_size, chains_size) = match (buckets_size, chains_size) {
+                (Some(buckets_size), Some(chains_size)) => (buckets_size, chains_size),
+                _ => return Err(error::Error::Malformed(format!("SysV hash table at {:#x} has an overflowing size: {} buckets, {} chains", offset, nbucket, nchain))),
+            };
+            let buckets: &'a [u8] = bytes.gread_with(&mut offset, buckets_size)?;
+            let chains: &'a [u8] = bytes.gread_with(&mut offset, chains_size)?;
+            Ok(SysvHash { nbucket, nchain, buckets, chains, ctx })
+        }
+
+        /// The number of chain entries, which is the number of symbols in the dynamic symbol table
+        pub fn nchain(&self) -> usize {
+            self.nchain as usize
+        }
+
+        /// Finds the index of the defined symbol `name` in `dynsyms`, using `dynstrtab` for the symbol names
+        pub fn find(&self, name: &str, dynsyms: &Symtab, dynstrtab: &Strtab) -> Option<usize> {
+            if name.is_empty() || self.nbucket == 0 {
+                return None;
+            }
+            let bucket = (hash(name) % self.nbucket) as usize * 4;
+            let mut idx = match self.buckets.pread_with::<u32>(bucket, self.ctx.le) {
+                Ok(idx) => idx as usize,
+                Err(_) => return None,
+            };
+            // a well formed chain visits every symbol at most once, so anything longer is a loop
+            for _ in 0..self.nchain {
+                // `STN_UNDEF` terminates the chain
+                if idx == 0 {
+                    break;
+                }
+                if let Some(sym) = dynsyms.get(idx) {
+                    let matches = dynstrtab.get_at(sym.st_name).ok() == Some(name);
+                    if matches && sym.st_shndx != SHN_UNDEF as usize {
+                        return Some(idx);
+                    }
+                }
+                idx = match self.chains.pread_with::<u32>(idx.saturating_mul(4), self.ctx.le) {
+                    Ok(idx) => idx as usize,
+                    Err(_) => break,
+                };
+            }
+            None
+        }
+    }
+}
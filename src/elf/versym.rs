@@ -0,0 +1,335 @@
+//! GNU symbol versioning: the `.gnu.version` (`SHT_GNU_versym`), `.gnu.version_r` (`SHT_GNU_verneed`) and `.gnu.version_d` (`SHT_GNU_verdef`) sections.
+//!
+//! `.gnu.version` holds one u16 per dynamic symbol, indexing a version either required from a dependency (in `.gnu.version_r`),
+//! or defined by this binary (in `.gnu.version_d`); this is how `memcpy@GLIBC_2.14` is told apart from an unversioned `memcpy`.
+//! The entries of the other two sections are linked lists, threaded through the `vn_next`/`vna_next` and `vd_next`/`vda_next` byte offsets.
+//!
+//! See: https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/symversion.html
+
+/// The symbol is local, i.e., not available outside of this binary
+pub const VER_NDX_LOCAL: u16 = 0;
+/// The symbol is global, but has no version
+pub const VER_NDX_GLOBAL: u16 = 1;
+/// Set in a versym entry if the symbol is hidden, i.e., it must not be used to satisfy an unversioned reference
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+/// Masks the version index out of a versym entry
+pub const VERSYM_VERSION: u16 = 0x7fff;
+
+/// The version definition describes the binary itself
+pub const VER_FLG_BASE: u16 = 0x1;
+/// The version dependency is weak
+pub const VER_FLG_WEAK: u16 = 0x2;
+/// The version is informational only
+pub const VER_FLG_INFO: u16 = 0x4;
+
+/// The size of a `Verneed` entry in the section; the same for 32 and 64-bit binaries
+pub const SIZEOF_VERNEED: usize = 16;
+/// The size of a `Vernaux` entry in the section
+pub const SIZEOF_VERNAUX: usize = 16;
+/// The size of a `Verdef` entry in the section
+pub const SIZEOF_VERDEF: usize = 20;
+/// The size of a `Verdaux` entry in the section
+pub const SIZEOF_VERDAUX: usize = 8;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// An entry of the `.gnu.version` section
+pub struct Versym {
+    pub vs_val: u16,
+}
+
+impl Versym {
+    /// The version index, with the hidden bit masked off
+    pub fn version(&self) -> u16 {
+        self.vs_val & VERSYM_VERSION
+    }
+    /// Whether the hidden bit is set
+    pub fn is_hidden(&self) -> bool {
+        self.vs_val & VERSYM_HIDDEN != 0
+    }
+    /// Whether the symbol is local (`VER_NDX_LOCAL`)
+    pub fn is_local(&self) -> bool {
+        self.version() == VER_NDX_LOCAL
+    }
+    /// Whether the symbol is global and unversioned (`VER_NDX_GLOBAL`)
+    pub fn is_global(&self) -> bool {
+        self.version() == VER_NDX_GLOBAL
+    }
+}
+
+if_alloc! {
+    use scroll::Pread;
+    use alloc::vec::Vec;
+    use container::Ctx;
+    use error;
+    use elf::section_header::SectionHeader;
+
+    /// Returns the bytes of the section `shdr` in `bytes`
+    fn section_bytes<'a>(bytes: &'a [u8], shdr: &SectionHeader) -> error::Result<&'a [u8]> {
+        shdr.check_size(bytes.len())?;
+        let offset = shdr.sh_offset as usize;
+        Ok(&bytes[offset..offset + shdr.sh_size as usize])
+    }
+
+    /// Returns the offset of the next entry of a linked list, which is `next` bytes after `offset`,
+    /// or an error if it doesn't advance (and so would loop) or overflows
+    fn next_offset(offset: usize, next: u32, what: &str) -> error::Result<usize> {
+        match offset.checked_add(next as usize) {
+            Some(next_offset) if next != 0 => Ok(next_offset),
+            _ => Err(error::Error::Malformed(format!("{} at {:#x} has a bad next offset {:#x}", what, offset, next))),
+        }
+    }
+
+    #[derive(Debug)]
+    /// The `.gnu.version` section, with one entry per dynamic symbol; bounds checked on every access
+    pub struct VersymSection<'a> {
+        bytes: &'a [u8],
+        ctx: Ctx,
+    }
+
+    impl<'a> VersymSection<'a> {
+        /// Parses the `SHT_GNU_versym` section `shdr` of the binary `bytes`
+        pub fn parse(bytes: &'a [u8], shdr: &SectionHeader, ctx: Ctx) -> error::Result<VersymSection<'a>> {
+            let bytes = section_bytes(bytes, shdr)?;
+            Ok(VersymSection { bytes, ctx })
+        }
+        /// The number of entries
+        pub fn len(&self) -> usize {
+            self.bytes.len() / 2
+        }
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+        /// The entry for the dynamic symbol at `index`
+        pub fn get(&self, index: usize) -> Option<Versym> {
+            match index.checked_mul(2) {
+                Some(offset) => self.bytes.pread_with(offset, self.ctx.le).ok().map(|vs_val| Versym { vs_val }),
+                None => None,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// A version required from a dependency
+    pub struct Vernaux {
+        /// The ELF hash of the version name
+        pub vna_hash: u32,
+        /// `VER_FLG_WEAK`, if the dependency is weak
+        pub vna_flags: u16,
+        /// The version index the versym entries refer to this version by
+        pub vna_other: u16,
+        /// The version name, an offset into the dynamic string table
+        pub vna_name: u32,
+        pub vna_next: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// A dependency, along with the versions required from it
+    pub struct Verneed {
+        pub vn_version: u16,
+        pub vn_cnt: u16,
+        /// The file name of the dependency, an offset into the dynamic string table
+        pub vn_file: u32,
+        pub vn_aux: u32,
+        pub vn_next: u32,
+        /// The `vn_cnt` versions required from this dependency
+        pub aux: Vec<Vernaux>,
+    }
+
+    #[derive(Debug, Default)]
+    /// The `.gnu.version_r` section
+    pub struct VerneedSection {
+        pub needed: Vec<Verneed>,
+    }
+
+    impl VerneedSection {
+        /// Parses the `SHT_GNU_verneed` section `shdr` of the binary `bytes`, which has `sh_info` entries
+        pub fn parse(bytes: &[u8], shdr: &SectionHeader, ctx: Ctx) -> error::Result<VerneedSection> {
+            let bytes = section_bytes(bytes, shdr)?;
+            let le = ctx.le;
+            let count = shdr.sh_info as usize;
+            let mut needed = Vec::new();
+            let mut offset = 0;
+            // every entry takes at least 16 bytes, so `count` can't exceed what fits in the section
+            for i in 0..count.min(bytes.len() / SIZEOF_VERNEED) {
+                let mut o = offset;
+                let vn_version: u16 = bytes.gread_with(&mut o, le)?;
+                let vn_cnt: u16 = bytes.gread_with(&mut o, le)?;
+                let vn_file: u32 = bytes.gread_with(&mut o, le)?;
+                let vn_aux: u32 = bytes.gread_with(&mut o, le)?;
+                let vn_next: u32 = bytes.gread_with(&mut o, le)?;
+                let mut aux = Vec::new();
+                let mut aux_offset = next_offset(offset, vn_aux, "Verneed")?;
+                for j in 0..vn_cnt as usize {
+                    let mut o = aux_offset;
+                    let vna_hash: u32 = bytes.gread_with(&mut o, le)?;
+                    let vna_flags: u16 = bytes.gread_with(&mut o, le)?;
+                    let vna_other: u16 = bytes.gread_with(&mut o, le)?;
+                    let vna_name: u32 = bytes.gread_with(&mut o, le)?;
+                    let vna_next: u32 = bytes.gread_with(&mut o, le)?;
+                    aux.push(Vernaux { vna_hash, vna_flags, vna_other, vna_name, vna_next });
+                    if j + 1 < vn_cnt as usize {
+                        aux_offset = next_offset(aux_offset, vna_next, "Vernaux")?;
+                    }
+                }
+                needed.push(Verneed { vn_version, vn_cnt, vn_file, vn_aux, vn_next, aux });
+                if i + 1 < count {
+                    offset = next_offset(offset, vn_next, "Verneed")?;
+                }
+            }
+            Ok(VerneedSection { needed })
+        }
+        /// Finds the dependency and required version whose index is `version`
+        pub fn find(&self, version: u16) -> Option<(&Verneed, &Vernaux)> {
+            for verneed in &self.needed {
+                for vernaux in &verneed.aux {
+                    if vernaux.vna_other == version {
+                        return Some((verneed, vernaux));
+                    }
+                }
+            }
+            None
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// A name of a version definition; the first is the version's own name, any others are its predecessors
+    pub struct Verdaux {
+        /// The version name, an offset into the dynamic string table
+        pub vda_name: u32,
+        pub vda_next: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// A version defined by this binary
+    pub struct Verdef {
+        pub vd_version: u16,
+        /// `VER_FLG_BASE` if this version describes the binary itself
+        pub vd_flags: u16,
+        /// The version index the versym entries refer to this version by
+        pub vd_ndx: u16,
+        pub vd_cnt: u16,
+        /// The ELF hash of the version name
+        pub vd_hash: u32,
+        pub vd_aux: u32,
+        pub vd_next: u32,
+        /// The `vd_cnt` names of this version
+        pub aux: Vec<Verdaux>,
+    }
+
+    #[derive(Debug, Default)]
+    /// The `.gnu.version_d` section
+    pub struct VerdefSection {
+        pub defined: Vec<Verdef>,
+    }
+
+    impl VerdefSection {
+        /// Parses the `SHT_GNU_verdef` section `shdr` of the binary `bytes`, which has `sh_info` entries
+        pub fn parse(bytes: &[u8], shdr: &SectionHeader, ctx: Ctx) -> error::Result<VerdefSection> {
+            let bytes = section_bytes(bytes, shdr)?;
+            let le = ctx.le;
+            let count = shdr.sh_info as usize;
+            let mut defined = Vec::new();
+            let mut offset = 0;
+            for i in 0..count.min(bytes.len() / SIZEOF_VERDEF) {
+                let mut o = offset;
+                let vd_version: u16 = bytes.gread_with(&mut o, le)?;
+                let vd_flags: u16 = bytes.gread_with(&mut o, le)?;
+                let vd_ndx: u16 = bytes.gread_with(&mut o, le)?;
+                let vd_cnt: u16 = bytes.gread_with(&mut o, le)?;
+                let vd_hash: u32 = bytes.gread_with(&mut o, le)?;
+                let vd_aux: u32 = bytes.gread_with(&mut o, le)?;
+                let vd_next: u32 = bytes.gread_with(&mut o, le)?;
+                let mut aux = Vec::new();
+                let mut aux_offset = next_offset(offset, vd_aux, "Verdef")?;
+                for j in 0..vd_cnt as usize {
+                    let mut o = aux_offset;
+                    let vda_name: u32 = bytes.gread_with(&mut o, le)?;
+                    let vda_next: u32 = bytes.gread_with(&mut o, le)?;
+                    aux.push(Verdaux { vda_name, vda_next });
+                    if j + 1 < vd_cnt as usize {
+                        aux_offset = next_offset(aux_offset, vda_next, "Verdaux")?;
+                    }
+                }
+                defined.push(Verdef { vd_version, vd_flags, vd_ndx, vd_cnt, vd_hash, vd_aux, vd_next, aux });
+                if i + 1 < count {
+                    offset = next_offset(offset, vd_next, "Verdef")?;
+                }
+            }
+            Ok(VerdefSection { defined })
+        }
+        /// Finds the version definition whose index is `version`
+        pub fn find(&self, version: u16) -> Option<&Verdef> {
+            self.defined.iter().find(|verdef| verdef.vd_ndx == version)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::{self, Pwrite};
+    use container::{Container, Ctx};
+    use elf::section_header::{SectionHeader, SHT_GNU_VERNEED, SHT_GNU_VERDEF};
+
+    fn shdr(sh_type: u32, size: usize, count: u32) -> SectionHeader {
+        let mut shdr = SectionHeader::new();
+        shdr.sh_type = sh_type;
+        shdr.sh_size = size as u64;
+        shdr.sh_info = count;
+        shdr
+    }
+
+    fn vernaux(bytes: &mut [u8], offset: usize, other: u16, name: u32, next: u32) {
+        bytes.pwrite_with(0u32, offset, scroll::LE).unwrap();
+        bytes.pwrite_with(0u16, offset + 4, scroll::LE).unwrap();
+        bytes.pwrite_with(other, offset + 6, scroll::LE).unwrap();
+        bytes.pwrite_with(name, offset + 8, scroll::LE).unwrap();
+        bytes.pwrite_with(next, offset + 12, scroll::LE).unwrap();
+    }
+
+    #[test]
+    fn verneed_chain() {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let mut bytes = [0u8; 48];
+        bytes.pwrite_with(1u16, 0, scroll::LE).unwrap();
+        bytes.pwrite_with(2u16, 2, scroll::LE).unwrap();
+        bytes.pwrite_with(0x10u32, 4, scroll::LE).unwrap();
+        bytes.pwrite_with(SIZEOF_VERNEED as u32, 8, scroll::LE).unwrap();
+        vernaux(&mut bytes, 16, 2, 0x20, SIZEOF_VERNAUX as u32);
+        vernaux(&mut bytes, 32, 3, 0x30, 0);
+        let verneed = VerneedSection::parse(&bytes, &shdr(SHT_GNU_VERNEED, bytes.len(), 1), ctx).unwrap();
+        assert_eq!(verneed.needed.len(), 1);
+        assert_eq!(verneed.needed[0].vn_file, 0x10);
+        let (_, vernaux) = verneed.find(3).unwrap();
+        assert_eq!(vernaux.vna_name, 0x30);
+        assert!(verneed.find(4).is_none());
+
+        // a second version which points back at itself must not be followed forever
+        let mut looping = bytes;
+        looping.pwrite_with(0u32, 28, scroll::LE).unwrap();
+        assert!(VerneedSection::parse(&looping, &shdr(SHT_GNU_VERNEED, bytes.len(), 1), ctx).is_err());
+        // nor may a dependency claim more entries than fit in the section
+        let verneed = VerneedSection::parse(&bytes, &shdr(SHT_GNU_VERNEED, bytes.len(), 0xffff), ctx);
+        assert!(verneed.is_err());
+    }
+
+    #[test]
+    fn verdef_and_versym() {
+        let ctx = Ctx::new(Container::Little, scroll::LE);
+        let mut bytes = [0u8; 28];
+        bytes.pwrite_with(1u16, 0, scroll::LE).unwrap();
+        bytes.pwrite_with(VER_FLG_BASE, 2, scroll::LE).unwrap();
+        bytes.pwrite_with(1u16, 4, scroll::LE).unwrap();
+        bytes.pwrite_with(1u16, 6, scroll::LE).unwrap();
+        bytes.pwrite_with(SIZEOF_VERDEF as u32, 12, scroll::LE).unwrap();
+        bytes.pwrite_with(0x42u32, 20, scroll::LE).unwrap();
+        let verdef = VerdefSection::parse(&bytes, &shdr(SHT_GNU_VERDEF, bytes.len(), 1), ctx).unwrap();
+        assert_eq!(verdef.find(1).unwrap().aux, vec![Verdaux { vda_name: 0x42, vda_next: 0 }]);
+        assert!(verdef.find(2).is_none());
+
+        let versym = Versym { vs_val: VERSYM_HIDDEN | 2 };
+        assert!(versym.is_hidden());
+        assert_eq!(versym.version(), 2);
+        assert!(Versym { vs_val: VER_NDX_GLOBAL }.is_global());
+    }
+}
@@ -0,0 +1,126 @@
+//! Streaming hashes, as fed the loadable contents of a binary by `Object::content_hash`.
+//!
+//! `Crc32` needs nothing, `Sha256` the `sha256` feature; any other algorithm can be plugged in by implementing `HashAlgo`.
+
+/// A hash function, fed its input piecewise
+pub trait HashAlgo {
+    /// The digest, e.g., `[u8; 32]` for SHA-256
+    type Output;
+    /// Hashes `bytes` after everything hashed so far
+    fn update(&mut self, bytes: &[u8]);
+    /// The digest of everything hashed
+    fn finish(self) -> Self::Output;
+}
+
+#[derive(Clone)]
+/// The CRC32 of zlib, with the reflected `0xedb88320` polynomial; its digest is the CRC in big endian
+pub struct Crc32 {
+    table: [u32; 256],
+    crc: u32,
+}
+
+impl Crc32 {
+    pub fn new() -> Self {
+        let mut table = [0u32; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            let mut crc = i as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+            }
+            *entry = crc;
+        }
+        Crc32 { table, crc: !0 }
+    }
+    /// The CRC of everything hashed so far
+    pub fn crc(&self) -> u32 {
+        !self.crc
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Crc32::new()
+    }
+}
+
+impl ::core::fmt::Debug for Crc32 {
+    fn fmt(&self, fmt: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(fmt, "Crc32({:#010x})", self.crc())
+    }
+}
+
+impl HashAlgo for Crc32 {
+    type Output = [u8; 4];
+    fn update(&mut self, bytes: &[u8]) {
+        let table = &self.table;
+        self.crc = bytes.iter().fold(self.crc, |crc, &byte| table[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8));
+    }
+    fn finish(self) -> [u8; 4] {
+        let crc = self.crc();
+        [(crc >> 24) as u8, (crc >> 16) as u8, (crc >> 8) as u8, crc as u8]
+    }
+}
+
+#[cfg(feature = "sha256")]
+#[derive(Clone, Default)]
+/// SHA-256, as computed by the `sha2` crate
+pub struct Sha256(::sha2::Sha256);
+
+#[cfg(feature = "sha256")]
+impl Sha256 {
+    pub fn new() -> Self {
+        Sha256::default()
+    }
+}
+
+#[cfg(feature = "sha256")]
+impl ::core::fmt::Debug for Sha256 {
+    fn fmt(&self, fmt: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(fmt, "Sha256")
+    }
+}
+
+#[cfg(feature = "sha256")]
+impl HashAlgo for Sha256 {
+    type Output = [u8; 32];
+    fn update(&mut self, bytes: &[u8]) {
+        ::sha2::Digest::input(&mut self.0, bytes);
+    }
+    fn finish(self) -> [u8; 32] {
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&::sha2::Digest::result(self.0));
+        digest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest<H: HashAlgo>(mut algo: H, pieces: &[&[u8]]) -> H::Output {
+        for piece in pieces {
+            algo.update(piece);
+        }
+        algo.finish()
+    }
+
+    #[test]
+    fn crc32() {
+        assert_eq!(digest(Crc32::new(), &[]), [0; 4]);
+        assert_eq!(digest(Crc32::new(), &[b"123456789"]), [0xcb, 0xf4, 0x39, 0x26]);
+        assert_eq!(digest(Crc32::new(), &[b"1234", b"", b"56789"]), [0xcb, 0xf4, 0x39, 0x26]);
+    }
+
+    #[cfg(feature = "sha256")]
+    #[test]
+    fn sha256() {
+        fn hex(digest: [u8; 32]) -> String {
+            digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+        }
+        assert_eq!(hex(digest(Sha256::new(), &[])), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(hex(digest(Sha256::new(), &[b"abc"])), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        // two blocks, fed across their boundary
+        let message = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        assert_eq!(hex(digest(Sha256::new(), &[&message[..3], &message[3..40], &message[40..]])), "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+    }
+}
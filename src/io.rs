@@ -0,0 +1,508 @@
+//! Parsing from a `Read + Seek` stream, rather than a slice of the whole file.
+//!
+//! `Object::parse` borrows everything from its bytes, so the entire binary must be in memory first, which hurts for multi-gigabyte files,
+//! or when scanning through large archives or memory dumps. `Object::from_reader` instead reads the headers, then seeks to, and reads,
+//! only the tables and strings it needs, returning an `OwnedObject` which owns all of its data.
+//!
+//! The tradeoff is IO: every table and string is a seek and a read of its own, so for a file which is already in memory (or in the page cache),
+//! the slice API is faster. An `OwnedObject` also knows less than its borrowed counterpart: the headers, the section, segment and program header tables,
+//! and the names of things, like the libraries a binary depends on, but no symbols, relocations, imports or exports.
+//! Every region is bounds checked against the length of the stream before it is allocated, so a malformed header can't make it allocate more than the file holds.
+
+use std::io::{Read, Seek, SeekFrom};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use scroll::{self, Pread};
+
+use error;
+use strtab::Strtab;
+use container::Container;
+use elf;
+use mach;
+use pe;
+use archive;
+use {peek, Hint, Object};
+
+/// A stream, and its length
+struct Source<R> {
+    reader: R,
+    len: u64,
+}
+
+impl<R: Read + Seek> Source<R> {
+    fn new(mut reader: R) -> error::Result<Self> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        Ok(Source { reader, len })
+    }
+    /// Reads the `size` bytes at `offset`, which must lie inside the stream
+    fn read(&mut self, offset: u64, size: u64) -> error::Result<Vec<u8>> {
+        match offset.checked_add(size) {
+            Some(end) if end <= self.len => (),
+            _ => return Err(error::Error::Malformed(format!("{:#x} bytes at {:#x} are past the end of the stream ({:#x} bytes)", size, offset, self.len))),
+        }
+        self.reader.seek(SeekFrom::Start(offset))?;
+        let mut bytes = vec![0u8; size as usize];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+    /// Reads up to `size` bytes at `offset`, stopping at the end of the stream
+    fn read_some(&mut self, offset: u64, size: u64) -> error::Result<Vec<u8>> {
+        let size = ::core::cmp::min(size, self.len.saturating_sub(offset));
+        self.read(offset, size)
+    }
+    /// Reads the NUL terminated string at `offset`, which may be at most `max` bytes long
+    fn read_str(&mut self, offset: u64, max: u64) -> error::Result<String> {
+        let bytes = self.read_some(offset, max + 1)?;
+        match bytes.iter().position(|byte| *byte == 0) {
+            Some(len) => Ok(String::from_utf8_lossy(&bytes[..len]).into_owned()),
+            None => Err(error::Error::Malformed(format!("string at {:#x} is unterminated, or longer than {:#x} bytes", offset, max))),
+        }
+    }
+}
+
+/// The longest name read from a PE, e.g., of an imported dll
+const MAX_NAME: u64 = 0x1000;
+
+#[derive(Debug)]
+/// An `Object` which owns its data, as parsed by `Object::from_reader`
+pub enum OwnedObject {
+    Elf(OwnedElf),
+    PE(OwnedPE),
+    Mach(OwnedMach),
+    /// A multi-architecture Mach-o, with each of its architectures
+    MachFat(Vec<(mach::fat::FatArch, OwnedMach)>),
+    Archive(OwnedArchive),
+    /// None of the above, with the given magic value
+    Unknown(u64),
+}
+
+#[derive(Debug)]
+/// The headers of an ELF binary, and the names in them
+pub struct OwnedElf {
+    pub header: elf::Header,
+    pub program_headers: Vec<elf::ProgramHeader>,
+    pub section_headers: Vec<elf::SectionHeader>,
+    /// The name of every section, in the order of `section_headers`
+    pub section_names: Vec<String>,
+    pub interpreter: Option<String>,
+    pub soname: Option<String>,
+    /// The `DT_NEEDED` libraries
+    pub libraries: Vec<String>,
+    pub is_64: bool,
+    pub little_endian: bool,
+}
+
+impl OwnedElf {
+    fn parse<R: Read + Seek>(source: &mut Source<R>) -> error::Result<Self> {
+        let ident = source.read_some(0, elf::header::header64::SIZEOF_EHDR as u64)?;
+        let lazy = elf::Elf::lazy_parse(&ident)?;
+        let (header, is_64, little_endian, ctx) = (lazy.header, lazy.is_64, lazy.little_endian, lazy.ctx());
+        let phentsize = elf::ProgramHeader::size(&ctx) as u64;
+        let bytes = source.read(header.e_phoff, header.e_phnum as u64 * phentsize)?;
+        let program_headers = elf::ProgramHeader::parse(&bytes, 0, header.e_phnum as usize, ctx)?;
+        let shentsize = elf::SectionHeader::size(&ctx) as u64;
+        let bytes = source.read(header.e_shoff, header.e_shnum as u64 * shentsize)?;
+        let section_headers = elf::SectionHeader::parse(&bytes, 0, header.e_shnum as usize, ctx)?;
+        let mut section_names = Vec::with_capacity(section_headers.len());
+        if let Some(shdr) = section_headers.get(header.e_shstrndx as usize) {
+            let bytes = source.read(shdr.sh_offset, shdr.sh_size)?;
+            let strtab = Strtab::new(&bytes, 0);
+            for shdr in &section_headers {
+                section_names.push(strtab.get(shdr.sh_name).and_then(|name| name.ok()).unwrap_or("").to_string());
+            }
+        }
+        let mut interpreter = None;
+        for phdr in &program_headers {
+            if phdr.p_type == elf::program_header::PT_INTERP && phdr.p_filesz != 0 {
+                let bytes = source.read(phdr.p_offset, phdr.p_filesz - 1)?;
+                interpreter = Some(String::from_utf8_lossy(&bytes).into_owned());
+            }
+        }
+        let mut soname = None;
+        let mut libraries = Vec::new();
+        // the dynamic section links to its string table, which spares mapping the addresses in the dynamic array
+        for shdr in &section_headers {
+            if shdr.sh_type != elf::section_header::SHT_DYNAMIC {
+                continue;
+            }
+            let strtab = match section_headers.get(shdr.sh_link as usize) {
+                Some(strtab) => source.read(strtab.sh_offset, strtab.sh_size)?,
+                None => return Err(error::Error::Malformed(format!("dynamic section links to section {}, but there are only {}", shdr.sh_link, section_headers.len()))),
+            };
+            let strtab = Strtab::new(&strtab, 0);
+            let bytes = source.read(shdr.sh_offset, shdr.sh_size)?;
+            let mut offset = 0;
+            while offset < bytes.len() {
+                let dyn: elf::Dyn = bytes.gread_with(&mut offset, ctx)?;
+                let name = || strtab.get(dyn.d_val as usize).and_then(|name| name.ok()).unwrap_or("").to_string();
+                match dyn.d_tag {
+                    elf::dynamic::DT_NULL => break,
+                    elf::dynamic::DT_NEEDED => libraries.push(name()),
+                    elf::dynamic::DT_SONAME => soname = Some(name()),
+                    _ => (),
+                }
+            }
+        }
+        Ok(OwnedElf { header, program_headers, section_headers, section_names, interpreter, soname, libraries, is_64, little_endian })
+    }
+}
+
+#[derive(Debug)]
+/// The header and load commands of a (single architecture) Mach-o binary, and the names in them
+pub struct OwnedMach {
+    pub header: mach::header::Header,
+    /// The load commands; their offsets are relative to the start of this Mach-o, as with `MachO::load_commands`
+    pub load_commands: Vec<mach::load_command::LoadCommand>,
+    /// The install name of this dylib, if it is one
+    pub name: Option<String>,
+    /// The dylibs this binary depends on, starting with `"self"`, or the install name of this dylib, as with `MachO::libs`
+    pub libs: Vec<String>,
+    pub is_64: bool,
+    pub little_endian: bool,
+}
+
+impl OwnedMach {
+    /// Parses the Mach-o starting at `base` in the stream
+    fn parse<R: Read + Seek>(source: &mut Source<R>, base: u64) -> error::Result<Self> {
+        use scroll::ctx::SizeWith;
+        let bytes = source.read_some(base, mach::header::SIZEOF_HEADER_64 as u64)?;
+        let (magic, maybe_ctx) = mach::parse_magic_and_ctx(&bytes, 0)?;
+        let ctx = match maybe_ctx {
+            Some(ctx) => ctx,
+            None => return Err(error::Error::BadMagic(magic as u64)),
+        };
+        let header: mach::header::Header = bytes.pread_with(0, ctx)?;
+        let size = mach::header::Header::size_with(&ctx.container);
+        // the load commands directly follow the header; reading them along with it keeps their offsets relative to the start of the Mach-o
+        let bytes = source.read(base, size as u64 + header.sizeofcmds as u64)?;
+        let mut offset = size;
+        let mut load_commands = Vec::with_capacity(header.ncmds);
+        let mut name = None;
+        let mut libs = vec!["self".to_string()];
+        for _ in 0..header.ncmds {
+            let cmd = mach::load_command::LoadCommand::parse(&bytes, &mut offset, ctx.le)?;
+            match cmd.command {
+                  mach::load_command::CommandVariant::LoadDylib      (command)
+                | mach::load_command::CommandVariant::LoadUpwardDylib(command)
+                | mach::load_command::CommandVariant::ReexportDylib  (command)
+                | mach::load_command::CommandVariant::LoadWeakDylib  (command)
+                | mach::load_command::CommandVariant::LazyLoadDylib  (command) => {
+                    libs.push(bytes.pread::<&str>(cmd.offset + command.dylib.name as usize)?.to_string());
+                },
+                mach::load_command::CommandVariant::IdDylib(command) => {
+                    let id = bytes.pread::<&str>(cmd.offset + command.dylib.name as usize)?.to_string();
+                    libs[0] = id.clone();
+                    name = Some(id);
+                },
+                _ => (),
+            }
+            load_commands.push(cmd);
+        }
+        Ok(OwnedMach { header, load_commands, name, libs, is_64: ctx.container.is_big(), little_endian: ctx.le.is_little() })
+    }
+}
+
+#[derive(Debug)]
+/// The headers and section table of a PE binary, and the names in them
+pub struct OwnedPE {
+    pub header: pe::header::Header,
+    pub sections: Vec<pe::section_table::SectionTable>,
+    /// The name of this dll, from its export directory, if it has one
+    pub name: Option<String>,
+    /// The dlls this binary imports from, in the order of the import directory
+    pub libraries: Vec<String>,
+    pub is_64: bool,
+    pub entry: usize,
+    pub image_base: usize,
+}
+
+/// Maps `rva` to a file offset, by the section which contains it
+fn rva_to_offset(rva: u32, sections: &[pe::section_table::SectionTable]) -> Option<u64> {
+    for section in sections {
+        let size = ::core::cmp::max(section.virtual_size, section.size_of_raw_data);
+        if rva >= section.virtual_address && rva - section.virtual_address < size {
+            return Some((rva - section.virtual_address) as u64 + section.pointer_to_raw_data as u64);
+        }
+    }
+    None
+}
+
+impl OwnedPE {
+    fn parse<R: Read + Seek>(source: &mut Source<R>) -> error::Result<Self> {
+        let dos = source.read_some(0, pe::header::PE_POINTER_OFFSET as u64 + 4)?;
+        let pe_pointer = pe::header::DosHeader::parse(&dos)?.pe_pointer as u64;
+        let mut offset = pe_pointer as usize;
+        let bytes = source.read(0, pe_pointer + pe::header::SIZEOF_COFF_HEADER as u64)?;
+        let coff_header = pe::header::CoffHeader::parse(&bytes, &mut offset)?;
+        let nsections = coff_header.number_of_sections as usize;
+        let size = offset as u64 + coff_header.size_of_optional_header as u64 + (nsections * pe::section_table::SIZEOF_SECTION_TABLE) as u64;
+        let mut bytes = source.read(0, size)?;
+        let header = pe::header::Header::parse(&bytes)?;
+        // long section names are offsets into the COFF string table, so append it, and point the section table parser at it
+        let string_table_offset = bytes.len();
+        if coff_header.pointer_to_symbol_table != 0 {
+            let strtab = coff_header.pointer_to_symbol_table as u64 + coff_header.number_of_symbol_table as u64 * 18;
+            let len: u32 = source.read_some(strtab, 4)?.pread_with(0, scroll::LE).unwrap_or(0);
+            bytes.extend(source.read_some(strtab, len as u64)?);
+        }
+        let mut offset = offset + coff_header.size_of_optional_header as usize;
+        let mut sections = Vec::with_capacity(nsections);
+        for _ in 0..nsections {
+            sections.push(pe::section_table::SectionTable::parse(&bytes, &mut offset, string_table_offset)?);
+        }
+        let mut name = None;
+        let mut libraries = Vec::new();
+        let mut is_64 = false;
+        let mut entry = 0;
+        let mut image_base = 0;
+        if let Some(optional_header) = header.optional_header {
+            is_64 = optional_header.container()? == Container::Big;
+            entry = optional_header.standard_fields.address_of_entry_point as usize;
+            image_base = optional_header.windows_fields.image_base as usize;
+            if let &Some(export_table) = optional_header.data_directories.get_export_table() {
+                if let Some(offset) = rva_to_offset(export_table.virtual_address, &sections) {
+                    let table = source.read(offset, pe::export::SIZEOF_EXPORT_DIRECTORY_TABLE as u64)?;
+                    let table = pe::export::ExportDirectoryTable::parse(&table, 0)?;
+                    if let Some(offset) = rva_to_offset(table.name_rva, &sections) {
+                        name = Some(source.read_str(offset, MAX_NAME)?);
+                    }
+                }
+            }
+            if let &Some(import_table) = optional_header.data_directories.get_import_table() {
+                let mut offset = match rva_to_offset(import_table.virtual_address, &sections) {
+                    Some(offset) => offset,
+                    None => return Err(error::Error::Malformed(format!("Cannot map import table rva {:#x} into offset", import_table.virtual_address))),
+                };
+                // the directory ends with a zeroed entry, which the stream must hold
+                loop {
+                    let entry = source.read(offset, pe::import::SIZEOF_IMPORT_DIRECTORY_ENTRY as u64)?;
+                    let entry: pe::import::ImportDirectoryEntry = entry.pread_with(0, scroll::LE)?;
+                    if entry.is_null() {
+                        break;
+                    }
+                    match rva_to_offset(entry.name_rva, &sections) {
+                        Some(name) => libraries.push(source.read_str(name, MAX_NAME)?),
+                        None => return Err(error::Error::Malformed(format!("Cannot map import name rva {:#x} into offset", entry.name_rva))),
+                    }
+                    offset += pe::import::SIZEOF_IMPORT_DIRECTORY_ENTRY as u64;
+                }
+            }
+        }
+        Ok(OwnedPE { header, sections, name, libraries, is_64, entry, image_base })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A member of an archive: where it is, but not its contents
+pub struct OwnedMember {
+    /// The member name, with the SysV and BSD extended names resolved, as with `Member::extended_name`
+    pub name: String,
+    /// The offset of the member header in the stream
+    pub header_offset: u64,
+    /// The offset of the member's contents in the stream
+    pub offset: u64,
+    pub size: usize,
+    /// Whether this member is from a thin archive, and so its contents are in the file at `name`, rather than the stream
+    pub thin: bool,
+}
+
+impl OwnedMember {
+    /// Reads this member's contents from `reader`, which must be the stream it was parsed from
+    pub fn read<R: Read + Seek>(&self, reader: &mut R) -> error::Result<Vec<u8>> {
+        if self.thin {
+            return Err(error::Error::Malformed(format!("member {:?} of a thin archive is not in the archive", self.name)));
+        }
+        reader.seek(SeekFrom::Start(self.offset))?;
+        let mut bytes = vec![0u8; self.size];
+        reader.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// The members of a Unix archive; the symbol index is skipped
+pub struct OwnedArchive {
+    pub members: Vec<OwnedMember>,
+}
+
+impl OwnedArchive {
+    fn parse<R: Read + Seek>(source: &mut Source<R>) -> error::Result<Self> {
+        let mut members = Vec::new();
+        // the SysV extended names, `/123` in a member header being the name at 123
+        let mut names = Vec::new();
+        let thin = &source.read(0, archive::SIZEOF_MAGIC as u64)?[..] == archive::THIN_MAGIC;
+        let mut offset = archive::SIZEOF_MAGIC as u64;
+        while offset < source.len {
+            // members are 2 byte aligned
+            offset += offset & 1;
+            if offset >= source.len {
+                break;
+            }
+            let mut bytes = source.read(offset, archive::SIZEOF_HEADER as u64)?;
+            let name = bytes.pread_with::<&str>(0, scroll::ctx::StrCtx::Length(16))?.to_string();
+            // a BSD name follows the header, so read it along, and let `Member::parse` handle it
+            if name.starts_with("#1/") {
+                let len = name[3..].trim_right().parse::<u64>().map_err(|_| error::Error::Malformed(format!("bad BSD name {:?} at {:#x}", name, offset)))?;
+                bytes.extend(source.read(offset + archive::SIZEOF_HEADER as u64, len)?);
+            }
+            let mut parsed = 0;
+            let member = archive::Member::parse(&bytes, &mut parsed)?;
+            let header_offset = offset;
+            let data = offset + member.offset;
+            let raw = member.raw_name().to_string();
+            let extended = member.extended_name().to_string();
+            let size = member.size();
+            offset = data + size as u64;
+            if raw.trim_right() == "//" {
+                names = source.read(data, size as u64)?;
+            } else if raw.trim_right() == "/" || raw.trim_right() == "/SYM64/" || extended.starts_with("__.SYMDEF") {
+                // the symbol index
+            } else {
+                // the contents of a thin archive's members are elsewhere, so the next header follows this one
+                if thin {
+                    offset = data;
+                }
+                let name = if raw.starts_with('/') && raw.len() > 1 {
+                    let idx = raw[1..].trim_right().parse::<usize>().map_err(|_| error::Error::Malformed(format!("bad SysV name {:?} at {:#x}", raw, offset)))?;
+                    let name = match names.get(idx..) {
+                        Some(name) => name,
+                        None => return Err(error::Error::Malformed(format!("SysV name {:?} is out of range of the {} byte name table", raw, names.len()))),
+                    };
+                    let len = name.iter().position(|byte| *byte == b'\n').unwrap_or(name.len());
+                    String::from_utf8_lossy(&name[..len]).trim_right_matches('/').to_string()
+                } else {
+                    extended
+                };
+                members.push(OwnedMember { name, header_offset, offset: data, size, thin });
+            }
+        }
+        Ok(OwnedArchive { members })
+    }
+    /// The member called `name`, if there is one
+    pub fn get(&self, name: &str) -> Option<&OwnedMember> {
+        self.members.iter().find(|member| member.name == name)
+    }
+}
+
+impl<'a> Object<'a> {
+    /// Parses an `OwnedObject` from `reader`, seeking to and reading only the regions it needs; see the [module documentation](io/index.html)
+    /// for when this pays off over `Object::parse`
+    pub fn from_reader<R: Read + Seek>(mut reader: R) -> error::Result<OwnedObject> {
+        let hint = peek(&mut reader)?;
+        let mut source = Source::new(reader)?;
+        match hint {
+            Hint::Elf(_) => Ok(OwnedObject::Elf(OwnedElf::parse(&mut source)?)),
+            Hint::Mach(_) => Ok(OwnedObject::Mach(OwnedMach::parse(&mut source, 0)?)),
+            Hint::MachFat(narches) => {
+                let bytes = source.read(mach::fat::SIZEOF_FAT_HEADER as u64, (narches * mach::fat::SIZEOF_FAT_ARCH) as u64)?;
+                let mut arches = Vec::with_capacity(narches);
+                for i in 0..narches {
+                    let arch = mach::fat::FatArch::parse(&bytes, i * mach::fat::SIZEOF_FAT_ARCH)?;
+                    arches.push((arch, OwnedMach::parse(&mut source, arch.offset as u64)?));
+                }
+                Ok(OwnedObject::MachFat(arches))
+            },
+            Hint::PE => Ok(OwnedObject::PE(OwnedPE::parse(&mut source)?)),
+            Hint::Archive => Ok(OwnedObject::Archive(OwnedArchive::parse(&mut source)?)),
+            Hint::Unknown(magic) => Ok(OwnedObject::Unknown(magic)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use scroll::Pwrite;
+
+    #[test]
+    fn elf_from_reader() {
+        let bytes: Vec<u8> = include!("../etc/libdeps.rs");
+        let elf = elf::Elf::parse(&bytes).unwrap();
+        let owned = match Object::from_reader(Cursor::new(&bytes)).unwrap() {
+            OwnedObject::Elf(owned) => owned,
+            object => panic!("{:?} is not an ELF", object),
+        };
+        assert_eq!(owned.header, elf.header);
+        assert_eq!(owned.program_headers, elf.program_headers);
+        assert_eq!(owned.section_headers, elf.section_headers);
+        let names = elf.section_headers.iter().map(|shdr| elf.shdr_strtab.get(shdr.sh_name).unwrap().unwrap()).collect::<Vec<_>>();
+        assert_eq!(owned.section_names, names);
+        assert_eq!(owned.soname.as_ref().map(|soname| soname.as_str()), elf.soname);
+        assert_eq!(owned.libraries, vec!["libc.so.6"]);
+        assert_eq!(owned.interpreter.as_ref().map(|interpreter| interpreter.as_str()), elf.interpreter);
+        // a truncated file is malformed, rather than a short read of garbage
+        let truncated = &bytes[..elf.header.e_shoff as usize + 8];
+        assert!(Object::from_reader(Cursor::new(truncated)).is_err());
+    }
+
+    #[test]
+    fn archive_from_reader() {
+        let bytes: Vec<u8> = include!("../etc/crt1a.rs");
+        let archive = archive::Archive::parse(&bytes).unwrap();
+        let mut reader = Cursor::new(&bytes);
+        let owned = match Object::from_reader(&mut reader).unwrap() {
+            OwnedObject::Archive(owned) => owned,
+            object => panic!("{:?} is not an archive", object),
+        };
+        assert_eq!(owned.members.iter().map(|member| member.name.as_str()).collect::<Vec<_>>(), archive.members());
+        for member in &owned.members {
+            assert_eq!(member.read(&mut reader).unwrap(), archive.extract(&member.name, &bytes).unwrap());
+        }
+        assert!(owned.get("crt1.o").is_some());
+        assert!(!owned.members[0].thin);
+        // a thin archive's members are only named
+        let bytes: Vec<u8> = include!("../etc/thin.rs");
+        let mut reader = Cursor::new(&bytes);
+        let owned = match Object::from_reader(&mut reader).unwrap() {
+            OwnedObject::Archive(owned) => owned,
+            object => panic!("{:?} is not an archive", object),
+        };
+        assert_eq!(owned.members.iter().map(|member| member.name.as_str()).collect::<Vec<_>>(), vec!["a.o", "sub/b.o"]);
+        assert!(owned.members[1].thin);
+        assert_eq!(owned.members[1].header_offset, 0xe4);
+        assert!(owned.members[1].read(&mut reader).is_err());
+    }
+
+    #[test]
+    fn pe_from_reader() {
+        // a PE32+ with a single section, at rva 0x1000 and offset 0x200, holding the import directory and the name of its dll
+        let mut bytes = vec![0u8; 0x400];
+        bytes.pwrite_with(pe::header::DOS_MAGIC, 0, scroll::LE).unwrap();
+        bytes.pwrite_with(0x40u32, pe::header::PE_POINTER_OFFSET as usize, scroll::LE).unwrap();
+        bytes.pwrite_with(pe::header::COFF_MAGIC, 0x40, scroll::LE).unwrap();
+        bytes.pwrite_with(pe::header::COFF_MACHINE_X86_64, 0x44, scroll::LE).unwrap();
+        bytes.pwrite_with(1u16, 0x46, scroll::LE).unwrap();
+        bytes.pwrite_with(0xf0u16, 0x54, scroll::LE).unwrap();
+        let optional_header = 0x58;
+        bytes.pwrite_with(pe::optional_header::MAGIC_64, optional_header, scroll::LE).unwrap();
+        bytes.pwrite_with(0x1010u32, optional_header + 16, scroll::LE).unwrap();
+        bytes.pwrite_with(0x1_4000_0000u64, optional_header + 24, scroll::LE).unwrap();
+        bytes.pwrite_with(0x200u32, optional_header + 36, scroll::LE).unwrap();
+        bytes.pwrite_with(16u32, optional_header + 108, scroll::LE).unwrap();
+        // the import table is the second data directory
+        bytes.pwrite_with(0x1000u32, optional_header + 112 + 8, scroll::LE).unwrap();
+        bytes.pwrite_with(0x28u32, optional_header + 112 + 12, scroll::LE).unwrap();
+        let section = optional_header + 0xf0;
+        bytes[section..section + 6].copy_from_slice(b".idata");
+        for (i, value) in [0x1000u32, 0x1000, 0x200, 0x200].iter().enumerate() {
+            bytes.pwrite_with(*value, section + 8 + i * 4, scroll::LE).unwrap();
+        }
+        bytes.pwrite_with(0x1100u32, 0x200 + 12, scroll::LE).unwrap();
+        bytes[0x300..0x30c].copy_from_slice(b"KERNEL32.dll");
+        let owned = match Object::from_reader(Cursor::new(&bytes)).unwrap() {
+            OwnedObject::PE(owned) => owned,
+            object => panic!("{:?} is not a PE", object),
+        };
+        assert!(owned.is_64);
+        assert_eq!(owned.entry, 0x1010);
+        assert_eq!(owned.image_base, 0x1_4000_0000);
+        assert_eq!(owned.sections.len(), 1);
+        assert_eq!(owned.sections[0].name().unwrap(), ".idata");
+        assert_eq!(owned.libraries, vec!["KERNEL32.dll"]);
+        assert_eq!(owned.name, None);
+        // a stream which ends inside the import directory is malformed
+        bytes.truncate(0x20a);
+        assert!(Object::from_reader(Cursor::new(&bytes)).is_err());
+    }
+}
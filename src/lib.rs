@@ -51,6 +51,9 @@
 //!                 Object::Archive(archive) => {
 //!                     println!("archive: {:#?}", &archive);
 //!                 },
+//!                 Object::Custom(object) => {
+//!                     println!("{}: {:#?}", object.format_name(), &object);
+//!                 },
 //!                 Object::Unknown(magic) => { println!("unknown magic: {:#x}", magic) }
 //!             }
 //!         }
@@ -92,6 +95,21 @@ extern crate log;
 #[cfg(feature = "std")]
 extern crate core;
 
+#[cfg(feature = "compression")]
+extern crate flate2;
+#[cfg(feature = "compression")]
+extern crate xz2;
+#[cfg(feature = "compression")]
+extern crate zstd;
+#[cfg(feature = "demangle")]
+extern crate rustc_demangle;
+#[cfg(feature = "demangle")]
+extern crate cpp_demangle;
+#[cfg(feature = "demangle")]
+extern crate msvc_demangler;
+#[cfg(feature = "sha256")]
+extern crate sha2;
+
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 #[macro_use]
 extern crate alloc;
@@ -128,8 +146,28 @@ macro_rules! if_alloc {
 #[cfg(feature = "alloc")]
 pub mod error;
 
+#[cfg(feature = "alloc")]
+pub mod anomaly;
+
 pub mod strtab;
 
+pub mod options;
+
+pub mod symbol;
+pub use symbol::Symbol;
+
+pub mod relocation;
+
+pub mod hash;
+
+#[cfg(feature = "compression")]
+pub mod compression;
+
+#[cfg(feature = "demangle")]
+pub mod demangle;
+#[cfg(feature = "demangle")]
+pub use demangle::demangle;
+
 /// Binary container size information and byte-order context
 pub mod container {
     use scroll;
@@ -147,6 +185,17 @@ pub mod container {
         pub fn is_big(&self) -> bool {
             *self == Container::Big
         }
+        /// The container size of the Mach-o `magic`, read as big endian, i.e., `Big` for `MH_MAGIC_64` and `MH_CIGAM_64`, `Little` for `MH_MAGIC` and `MH_CIGAM`,
+        /// and `None` for anything else, a `FAT_MAGIC` included; this doesn't need the `mach32` or `mach64` features
+        pub fn from_magic(magic: u32) -> Option<Self> {
+            match magic {
+                // `MH_MAGIC_64` and `MH_CIGAM_64`
+                0xfeed_facf | 0xcffa_edfe => Some(Container::Big),
+                // `MH_MAGIC` and `MH_CIGAM`
+                0xfeed_face | 0xcefa_edfe => Some(Container::Little),
+                _ => None,
+            }
+        }
     }
 
     #[cfg(not(target_pointer_width = "64"))]
@@ -184,6 +233,14 @@ pub mod container {
         pub fn new (container: Container, le: scroll::Endian) -> Self {
             Ctx { container: container, le: le }
         }
+        /// Create a little endian binary container context
+        pub fn le(container: Container) -> Self {
+            Ctx { container: container, le: scroll::LE }
+        }
+        /// Create a big endian binary container context
+        pub fn be(container: Container) -> Self {
+            Ctx { container: container, le: scroll::BE }
+        }
         /// Return a dubious pointer/address byte size for the container
         pub fn size(&self) -> usize {
             match self.container {
@@ -256,7 +313,7 @@ if_everything! {
                 } else { None };
 
             Ok(Hint::Elf(HintData { is_lsb, is_64 }))
-        } else if &bytes[0..archive::SIZEOF_MAGIC] == archive::MAGIC {
+        } else if &bytes[0..archive::SIZEOF_MAGIC] == archive::MAGIC || &bytes[0..archive::SIZEOF_MAGIC] == archive::THIN_MAGIC {
             Ok(Hint::Archive)
         } else if (&bytes[0..2]).pread_with::<u16>(0, LE)? == pe::header::DOS_MAGIC {
             Ok(Hint::PE)
@@ -303,25 +360,618 @@ if_everything! {
         Mach(mach::Mach<'a>),
         /// A Unix archive
         Archive(archive::Archive<'a>),
+        /// An out-of-tree format, recognized by one of the detectors of a `Registry`
+        Custom(::alloc::boxed::Box<CustomObject>),
         /// None of the above, with the given magic value
         Unknown(u64),
     }
 
+    /// A binary in a format goblin does not know about, like Plan 9 a.out, or a raw firmware image
+    pub trait CustomObject: ::core::fmt::Debug {
+        /// The name of this object's format, e.g., `"plan9"`
+        fn format_name(&self) -> &'static str;
+    }
+
+    /// Recognizes, and parses, an out-of-tree format in `bytes`, returning `None` if they are in some other format
+    pub type Detector = fn(&[u8]) -> Option<::alloc::boxed::Box<CustomObject>>;
+
+    #[derive(Default)]
+    /// The formats `Registry::parse` recognizes beyond goblin's built-in ones.
+    ///
+    /// The built-in formats always come first; the detectors only see the bytes goblin itself does not recognize, in the order they were registered.
+    pub struct Registry {
+        detectors: ::alloc::vec::Vec<Detector>,
+    }
+
+    impl Registry {
+        /// Creates a registry without any detectors, which parses exactly like `Object::parse`
+        pub fn new() -> Self {
+            Registry::default()
+        }
+        /// Adds `detector` after all the previously registered ones
+        pub fn register(&mut self, detector: Detector) -> &mut Self {
+            self.detectors.push(detector);
+            self
+        }
+        /// Tries to parse an `Object` from `bytes` with the built-in formats, then with the registered detectors
+        #[cfg(feature = "std")]
+        pub fn parse<'a>(&self, bytes: &'a [u8]) -> error::Result<Object<'a>> {
+            match Object::parse(bytes)? {
+                Object::Unknown(magic) => {
+                    for detector in &self.detectors {
+                        if let Some(object) = detector(bytes) {
+                            return Ok(Object::Custom(object));
+                        }
+                    }
+                    Ok(Object::Unknown(magic))
+                },
+                object => Ok(object),
+            }
+        }
+    }
+
+    impl ::core::fmt::Debug for Registry {
+        fn fmt(&self, fmt: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            fmt.debug_struct("Registry").field("detectors", &self.detectors.len()).finish()
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// The format and architecture of a binary, as told by `Object::parse_kind` from its headers alone
+    pub enum ObjectKind {
+        /// An ELF binary, with its `e_machine`, e.g., `EM_X86_64`
+        Elf { machine: u16, is_64: bool, is_lsb: bool },
+        /// A Mach-o binary, with its `cputype`, e.g., `CPU_TYPE_ARM64`
+        Mach { cputype: u32, is_64: bool, is_lsb: bool },
+        /// A multi-architecture Mach-o binary, with the number of architectures in it
+        MachFat(usize),
+        /// A PE32/PE32+, with the COFF header's `machine`, e.g., `COFF_MACHINE_X86_64`
+        PE { machine: u16, is_64: bool },
+        /// A Unix archive
+        Archive,
+        /// None of the above, with the given magic value
+        Unknown(u64),
+    }
+
+    impl<'a> Object<'a> {
+        /// Iterates the symbols of this binary, whatever its format: the symbol table and then the dynamic symbol table of an ELF,
+        /// the symbol table of a (single architecture) Mach-o, and the exports and then the imports of a PE; archives have none
+        pub fn symbols<'b>(&'b self) -> ::alloc::boxed::Box<Iterator<Item = ::alloc::boxed::Box<Symbol + 'b>> + 'b> {
+            use alloc::boxed::Box;
+            fn boxed<'b, T: Symbol + 'b>(symbol: T) -> Box<Symbol + 'b> {
+                Box::new(symbol)
+            }
+            match *self {
+                Object::Elf(ref elf) => {
+                    let syms = elf.syms.iter().map(move |sym| (elf.strtab.get_at(sym.st_name).unwrap_or(""), sym));
+                    let dynsyms = elf.dynsyms.iter().map(move |sym| (elf.dynstrtab.get_at(sym.st_name).unwrap_or(""), sym));
+                    Box::new(syms.chain(dynsyms).map(boxed))
+                },
+                Object::Mach(mach::Mach::Binary(ref macho)) => Box::new(macho.symbols().filter_map(|symbol| symbol.ok()).map(boxed)),
+                Object::PE(ref pe) => Box::new(pe.exports.iter().map(boxed).chain(pe.imports.iter().map(boxed))),
+                _ => Box::new(::core::iter::empty()),
+            }
+        }
+        /// Iterates the relocations of this binary, whatever its format, see `relocation::GenericReloc`: the dynamic relocations (`dynrelas`, `dynrels`
+        /// and `pltrelocs`) and then the section relocations of an ELF; the binds of a (single architecture) Mach-o, as its `BindInterpreter` runs them for `imports`,
+        /// then its external and local `LC_DYSYMTAB` relocations, then its section relocations; and the base relocations of a PE. Other objects have none.
+        ///
+        /// The Mach-o and PE relocations are parsed up front, so that a malformed table is an error, rather than the end of the iteration
+        pub fn relocations<'b>(&'b self) -> error::Result<::alloc::boxed::Box<Iterator<Item = relocation::GenericReloc<'b>> + 'b>> {
+            use alloc::boxed::Box;
+            use relocation::GenericReloc;
+            match *self {
+                Object::Elf(ref elf) => {
+                    let machine = elf.header.e_machine;
+                    let generic = move |reloc: &elf::Reloc, section| GenericReloc {
+                        offset: reloc.r_offset,
+                        kind: reloc.kind(machine),
+                        symbol: reloc.symbol(elf, section),
+                        addend: reloc.r_addend.unwrap_or(0),
+                    };
+                    let dynamic = elf.dynrelas.iter().chain(&elf.dynrels).chain(&elf.pltrelocs).map(move |reloc| generic(reloc, None));
+                    let sections = elf.shdr_relocs.iter().flat_map(move |&(idx, ref relocs)| relocs.iter().map(move |reloc| generic(reloc, Some(idx))));
+                    Ok(Box::new(dynamic.chain(sections)))
+                },
+                Object::Mach(mach::Mach::Binary(ref macho)) => {
+                    let mut relocs: Vec<GenericReloc> = macho.imports()?.into_iter().map(|import| GenericReloc {
+                        offset: import.address,
+                        kind: import.kind(),
+                        symbol: Some(import.name),
+                        addend: import.addend,
+                    }).collect();
+                    let symbol = |info: &mach::relocation::RelocationInfo| {
+                        if info.is_extern() {
+                            macho.symbols.as_ref().and_then(|symbols| symbols.get(info.r_symbolnum()).ok()).map(|(name, _)| name)
+                        } else {
+                            None
+                        }
+                    };
+                    if let Some(dysymtab) = macho.dysymtab() {
+                        let base = macho.relocation_base().unwrap_or(0);
+                        for info in dysymtab.external_relocations()?.iter().chain(&dysymtab.local_relocations()?) {
+                            relocs.push(GenericReloc { offset: base.wrapping_add(info.r_address as i64 as u64), kind: info.kind(), symbol: symbol(info), addend: 0 });
+                        }
+                    }
+                    for (_, infos, _) in macho.relocations()? {
+                        for info in infos {
+                            let info = info?;
+                            relocs.push(GenericReloc { offset: info.r_address as u32 as u64, kind: info.kind(), symbol: symbol(&info), addend: 0 });
+                        }
+                    }
+                    Ok(Box::new(relocs.into_iter()))
+                },
+                Object::PE(ref pe) => {
+                    let mut relocs = Vec::new();
+                    if let Some(ref data) = pe.relocation_data {
+                        for reloc in data.relocations() {
+                            let reloc = reloc?;
+                            relocs.push(GenericReloc { offset: reloc.rva as u64, kind: reloc.kind(), symbol: None, addend: 0 });
+                        }
+                    }
+                    Ok(Box::new(relocs.into_iter()))
+                },
+                _ => Ok(Box::new(::core::iter::empty())),
+            }
+        }
+        /// The entry point of this binary, as a virtual memory address, or 0 if it has none; for a PE, the image base plus its `entry` RVA.
+        ///
+        /// `None` for the objects without a single entry point: multi-architecture Mach-o binaries, archives, and unknown or custom formats
+        pub fn entry(&self) -> Option<u64> {
+            match *self {
+                Object::Elf(ref elf) => Some(elf.entry),
+                Object::Mach(mach::Mach::Binary(ref macho)) => Some(macho.entry),
+                Object::PE(ref pe) if pe.entry == 0 => Some(0),
+                Object::PE(ref pe) => Some(pe.image_base as u64 + pe.entry as u64),
+                _ => None,
+            }
+        }
+        /// Whether this is a 64-bit binary; `None` for the same objects as `entry`
+        pub fn is_64(&self) -> Option<bool> {
+            match *self {
+                Object::Elf(ref elf) => Some(elf.is_64),
+                Object::Mach(mach::Mach::Binary(ref macho)) => Some(macho.is_64),
+                Object::PE(ref pe) => Some(pe.is_64),
+                _ => None,
+            }
+        }
+        /// Whether this is a little endian binary, which a PE always is; `None` for the same objects as `entry`
+        pub fn is_little_endian(&self) -> Option<bool> {
+            match *self {
+                Object::Elf(ref elf) => Some(elf.little_endian),
+                Object::Mach(mach::Mach::Binary(ref macho)) => Some(macho.little_endian),
+                Object::PE(_) => Some(true),
+                _ => None,
+            }
+        }
+        /// Whether this binary is a library: an ELF shared object, but not a position independent executable (which is `ET_DYN` too),
+        /// a Mach-o dylib or bundle, or a DLL; `None` for the same objects as `entry`.
+        ///
+        /// An ELF is a PIE if it has `DF_1_PIE`, or, for linkers which don't set it, if it has an interpreter but no soname;
+        /// an interpreter alone doesn't tell, since runnable libraries, like libc, have one too
+        pub fn is_lib(&self) -> Option<bool> {
+            match *self {
+                Object::Elf(ref elf) => {
+                    let is_pie = elf.dynamic.as_ref().map_or(false, |dynamic| dynamic.flags_1().is_pie()) || (elf.interpreter.is_some() && elf.soname.is_none());
+                    Some(elf.is_lib && !is_pie)
+                },
+                Object::Mach(mach::Mach::Binary(ref macho)) => Some(macho.header.filetype == mach::header::MH_DYLIB || macho.header.filetype == mach::header::MH_BUNDLE),
+                Object::PE(ref pe) => Some(pe.is_lib),
+                _ => None,
+            }
+        }
+        /// The virtual memory address this binary prefers to be loaded at: the lowest `PT_LOAD` address of an ELF (0 if it has none),
+        /// the `__TEXT` segment's address of a Mach-o (0 if it has none), or a PE's image base; `None` for the same objects as `entry`
+        pub fn image_base(&self) -> Option<u64> {
+            match *self {
+                Object::Elf(ref elf) => Some(elf.program_headers.iter()
+                    .filter(|phdr| phdr.p_type == elf::program_header::PT_LOAD)
+                    .map(|phdr| phdr.p_vaddr)
+                    .min()
+                    .unwrap_or(0)),
+                Object::Mach(mach::Mach::Binary(ref macho)) => Some(macho.segments.iter()
+                    .find(|segment| segment.name().ok() == Some("__TEXT"))
+                    .map_or(0, |segment| segment.vmaddr)),
+                Object::PE(ref pe) => Some(pe.image_base as u64),
+                _ => None,
+            }
+        }
+        /// Hashes the loadable contents of this binary with `algo`, e.g., `hash::Crc32::new()`, as a fingerprint which ignores its debug info, symbol tables, and padding;
+        /// `bytes` must be the binary this was parsed from.
+        ///
+        /// Exactly these file contents are hashed, with nothing between them, in ascending order of virtual address, then file offset, then size:
+        ///
+        /// * ELF: the `p_offset..p_offset + p_filesz` of every `PT_LOAD`, so the first usually includes the ELF header and program headers
+        /// * Mach-o: the `fileoff..fileoff + filesize` of every segment with a `filesize`, i.e., all but the zero-fill ones, like `__PAGEZERO`, except `__LINKEDIT`,
+        ///   whose symbol tables, dyld info and code signature (which re-signing changes) aren't loaded contents; this includes the headers in `__TEXT`
+        /// * PE: the raw data of every section with `IMAGE_SCN_MEM_READ`, from `pointer_to_raw_data`, of `size_of_raw_data` bytes,
+        ///   or only `virtual_size` if that is smaller (and not 0), which leaves out the file alignment padding
+        ///
+        /// Contents shared by several ranges are hashed once for each.
+        /// `None` for the same objects as `entry`; a range past the end of `bytes` is `Malformed`
+        #[cfg(feature = "content_hash")]
+        pub fn content_hash<H: hash::HashAlgo>(&self, bytes: &[u8], mut algo: H) -> error::Result<Option<H::Output>> {
+            use core::cmp::min;
+            let mut ranges: ::alloc::vec::Vec<(u64, u64, u64)> = match *self {
+                Object::Elf(ref elf) => elf.program_headers.iter()
+                    .filter(|phdr| phdr.p_type == elf::program_header::PT_LOAD)
+                    .map(|phdr| (phdr.p_vaddr, phdr.p_offset, phdr.p_filesz))
+                    .collect(),
+                Object::Mach(mach::Mach::Binary(ref macho)) => macho.segments.iter()
+                    .filter(|segment| segment.filesize != 0 && segment.name().ok() != Some("__LINKEDIT"))
+                    .map(|segment| (segment.vmaddr, segment.fileoff, segment.filesize))
+                    .collect(),
+                Object::PE(ref pe) => pe.sections.iter()
+                    .filter(|section| section.flags().is_read())
+                    .map(|section| {
+                        let size = if section.virtual_size == 0 { section.size_of_raw_data } else { min(section.virtual_size, section.size_of_raw_data) };
+                        (section.virtual_address as u64, section.pointer_to_raw_data as u64, size as u64)
+                    })
+                    .collect(),
+                _ => return Ok(None),
+            };
+            ranges.sort();
+            for (address, offset, size) in ranges {
+                match offset.checked_add(size) {
+                    Some(end) if end <= bytes.len() as u64 => algo.update(&bytes[offset as usize..end as usize]),
+                    _ => return Err(error::Error::Malformed(format!("loadable contents at {:#x}, at file offset {:#x} of size {:#x}, are past the end of the {:#x} byte file", address, offset, size, bytes.len()))),
+                }
+            }
+            Ok(Some(algo.finish()))
+        }
+        /// Tells the format and architecture of the binary in `bytes` from its headers alone, without parsing (or allocating) anything else;
+        /// much cheaper than `Object::parse` when triaging many files.
+        ///
+        /// Every format needs at least 16 bytes, ELF needs 20, and PE needs its DOS header, plus the COFF header and the optional header's magic it points to;
+        /// fewer bytes are `Malformed`, as is a PE whose optional header magic is neither PE32 nor PE32+
+        pub fn parse_kind(bytes: &[u8]) -> error::Result<ObjectKind> {
+            use scroll::{Pread, LE, BE};
+            let need = |size: usize, format: &str| -> error::Result<()> {
+                if bytes.len() < size {
+                    Err(error::Error::Malformed(format!("{} bytes are too few for a {} header, which needs {}", bytes.len(), format, size)))
+                } else {
+                    Ok(())
+                }
+            };
+            need(16, "binary")?;
+            let mut magic = [0u8; 16];
+            magic.copy_from_slice(&bytes[..16]);
+            match peek_bytes(&magic)? {
+                Hint::Elf(HintData { is_lsb, is_64 }) => {
+                    let is_64 = match is_64 {
+                        Some(is_64) => is_64,
+                        None => return Err(error::Error::Malformed(format!("ELF class {} is neither 32 nor 64-bit", bytes[elf::header::EI_CLASS]))),
+                    };
+                    // `e_machine` follows `e_ident` and `e_type`
+                    need(elf::header::SIZEOF_IDENT + 4, "ELF")?;
+                    let machine = bytes.pread_with::<u16>(elf::header::SIZEOF_IDENT + 2, if is_lsb { LE } else { BE })?;
+                    Ok(ObjectKind::Elf { machine, is_64, is_lsb })
+                },
+                Hint::Mach(HintData { is_lsb, is_64 }) => {
+                    let cputype = bytes.pread_with::<u32>(4, if is_lsb { LE } else { BE })?;
+                    Ok(ObjectKind::Mach { cputype, is_64: is_64.unwrap_or(false), is_lsb })
+                },
+                Hint::MachFat(narchitectures) => Ok(ObjectKind::MachFat(narchitectures)),
+                Hint::PE => {
+                    let pe_pointer_offset = pe::header::PE_POINTER_OFFSET as usize;
+                    need(pe_pointer_offset + 4, "DOS")?;
+                    let pe_pointer = bytes.pread_with::<u32>(pe_pointer_offset, LE)? as usize;
+                    // the PE signature and COFF header, then the optional header's magic
+                    need(pe_pointer.saturating_add(pe::header::SIZEOF_COFF_HEADER + 2), "PE")?;
+                    if bytes.pread_with::<u32>(pe_pointer, LE)? != pe::header::COFF_MAGIC {
+                        return Err(error::Error::Malformed(format!("PE header at {:#x} does not start with the PE signature", pe_pointer)));
+                    }
+                    let machine = bytes.pread_with::<u16>(pe_pointer + 4, LE)?;
+                    let magic = bytes.pread_with::<u16>(pe_pointer + pe::header::SIZEOF_COFF_HEADER, LE)?;
+                    let is_64 = match magic {
+                        pe::optional_header::MAGIC_64 => true,
+                        pe::optional_header::MAGIC_32 => false,
+                        _ => return Err(error::Error::Malformed(format!("PE optional header magic {:#x} is neither PE32 nor PE32+", magic))),
+                    };
+                    Ok(ObjectKind::PE { machine, is_64 })
+                },
+                Hint::Archive => Ok(ObjectKind::Archive),
+                Hint::Unknown(magic) => Ok(ObjectKind::Unknown(magic)),
+            }
+        }
+    }
+
+    impl container::Ctx {
+        /// Infers the container size and byte order of `bytes` from the magic it starts with, as `Object::parse_kind` sniffs it:
+        /// the ELF `EI_CLASS` and `EI_DATA`, the Mach-o magic, or the PE optional header's magic (a PE is always little endian).
+        ///
+        /// Anything else, including an archive or a fat Mach-o, which have no single context, is `Malformed`
+        pub fn probe(bytes: &[u8]) -> error::Result<Self> {
+            use container::{Container, Ctx};
+            let container = |is_64| if is_64 { Container::Big } else { Container::Little };
+            match Object::parse_kind(bytes)? {
+                ObjectKind::Elf { is_64, is_lsb, .. } => {
+                    let data = bytes[elf::header::EI_DATA];
+                    if data != elf::header::ELFDATA2LSB && data != elf::header::ELFDATA2MSB {
+                        return Err(error::Error::Malformed(format!("ELF data encoding {} is neither little nor big endian", data)));
+                    }
+                    Ok(Ctx::new(container(is_64), scroll::Endian::from(is_lsb)))
+                },
+                ObjectKind::Mach { is_64, is_lsb, .. } => Ok(Ctx::new(container(is_64), scroll::Endian::from(is_lsb))),
+                ObjectKind::PE { is_64, .. } => Ok(Ctx::le(container(is_64))),
+                ObjectKind::MachFat(_) => Err(error::Error::Malformed("a fat Mach-o has a context per architecture, not one".into())),
+                ObjectKind::Archive => Err(error::Error::Malformed("an archive has a context per member, not one".into())),
+                ObjectKind::Unknown(magic) => Err(error::Error::Malformed(format!("no ELF, Mach-o or PE magic in {:#x}", magic))),
+            }
+        }
+    }
+
     // TODO: this could avoid std using peek_bytes
     #[cfg(feature = "std")]
     impl<'a> Object<'a> {
         /// Tries to parse an `Object` from `bytes`
         pub fn parse(bytes: &[u8]) -> error::Result<Object> {
+            Self::parse_with(bytes, options::ParseOptions::default())
+        }
+        /// Tries to parse an `Object` from `bytes`, parsing ELF and Mach-o binaries according to `opts`; see `Elf::parse_with` and `MachO::parse_with`
+        pub fn parse_with(bytes: &[u8], opts: options::ParseOptions) -> error::Result<Object> {
             use std::io::Cursor;
             match peek(&mut Cursor::new(&bytes))? {
-                Hint::Elf(_) => Ok(Object::Elf(elf::Elf::parse(bytes)?)),
-                Hint::Mach(_) | Hint::MachFat(_) => Ok(Object::Mach(mach::Mach::parse(bytes)?)),
+                Hint::Elf(_) => Ok(Object::Elf(elf::Elf::parse_with(bytes, opts)?)),
+                Hint::Mach(_) | Hint::MachFat(_) => Ok(Object::Mach(mach::Mach::parse_with(bytes, opts)?)),
                 Hint::Archive => Ok(Object::Archive(archive::Archive::parse(bytes)?)),
                 Hint::PE => Ok(Object::PE(pe::PE::parse(bytes)?)),
                 Hint::Unknown(magic) => Ok(Object::Unknown(magic))
             }
         }
     }
+
+    /// Tries to parse an `Object` from `bytes`, transparently decompressing them first if they are gzip, xz, or zstd compressed.
+    ///
+    /// The decompressed bytes are stored in `buffer`, which the returned `Object` borrows from; if `bytes` were not compressed, `buffer` is left as `None` and the `Object` borrows from `bytes` directly.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let bytes = std::fs::read("libfoo.so.gz").unwrap();
+    /// let mut buffer = None;
+    /// let object = goblin::parse_maybe_compressed(&bytes, &mut buffer).unwrap();
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn parse_maybe_compressed<'a>(bytes: &'a [u8], buffer: &'a mut Option<Vec<u8>>) -> error::Result<Object<'a>> {
+        *buffer = compression::decompress(bytes)?;
+        match *buffer {
+            Some(ref decompressed) => Object::parse(decompressed),
+            None => Object::parse(bytes),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub mod io;
+
+    #[cfg(all(test, feature = "std"))]
+    mod tests {
+        use super::*;
+
+        #[derive(Debug)]
+        struct Plan9 { text: u32 }
+
+        impl CustomObject for Plan9 {
+            fn format_name(&self) -> &'static str { "plan9" }
+        }
+
+        fn plan9(bytes: &[u8]) -> Option<Box<CustomObject>> {
+            use scroll::{Pread, BE};
+            // the amd64 a.out magic, followed by the size of the text segment
+            match bytes.pread_with::<u32>(0, BE) {
+                Ok(0x8a97) => bytes.pread_with::<u32>(4, BE).ok().map(|text| Box::new(Plan9 { text }) as Box<CustomObject>),
+                _ => None,
+            }
+        }
+
+        #[test]
+        fn parse_kind() {
+            let crt1: Vec<u8> = include!("../etc/crt1.rs");
+            assert_eq!(Object::parse_kind(&crt1).unwrap(), ObjectKind::Elf { machine: elf::header::EM_X86_64, is_64: true, is_lsb: true });
+            assert!(Object::parse_kind(&crt1[..19]).is_err());
+            let crt1a: Vec<u8> = include!("../etc/crt1a.rs");
+            assert_eq!(Object::parse_kind(&crt1a).unwrap(), ObjectKind::Archive);
+            let mut pe = [0u8; 0x80];
+            pe[0] = b'M';
+            pe[1] = b'Z';
+            pe[0x3c] = 0x40;
+            pe[0x40..0x46].copy_from_slice(&[b'P', b'E', 0, 0, 0x64, 0x86]);
+            pe[0x58..0x5a].copy_from_slice(&[0x0b, 0x02]);
+            assert_eq!(Object::parse_kind(&pe).unwrap(), ObjectKind::PE { machine: pe::header::COFF_MACHINE_X86_64, is_64: true });
+            assert!(Object::parse_kind(&pe[..0x59]).is_err());
+            assert!(Object::parse_kind(&pe[..15]).is_err());
+        }
+
+        #[test]
+        fn accessors() {
+            use scroll::{Pwrite, LE};
+            // a position independent executable is `ET_DYN`, but not a library
+            let pie: Vec<u8> = include!("../etc/pie.rs");
+            let object = Object::parse(&pie).unwrap();
+            assert_eq!(object.entry(), Some(0x1b1));
+            assert_eq!(object.is_64(), Some(true));
+            assert_eq!(object.is_little_endian(), Some(true));
+            assert_eq!(object.is_lib(), Some(false));
+            assert_eq!(object.image_base(), Some(0));
+            let libgnu: Vec<u8> = include!("../etc/libgnu.rs");
+            assert_eq!(Object::parse(&libgnu).unwrap().is_lib(), Some(true));
+            // a library with an interpreter, like libc, which can be run, is still a library, told by its soname
+            let mut libc = Object::parse(&libgnu).unwrap();
+            if let Object::Elf(ref mut elf) = libc {
+                elf.interpreter = Some("/lib64/ld-linux-x86-64.so.2");
+                elf.soname = Some("libc.so.6");
+            }
+            assert_eq!(libc.is_lib(), Some(true));
+            if let Object::Elf(ref mut elf) = libc {
+                elf.soname = None;
+            }
+            assert_eq!(libc.is_lib(), Some(false));
+            // a 32-bit dll, without sections
+            let mut dll = vec![0u8; 0x200];
+            dll.pwrite_with(pe::header::DOS_MAGIC, 0, LE).unwrap();
+            dll.pwrite_with(0x40u32, pe::header::PE_POINTER_OFFSET as usize, LE).unwrap();
+            dll.pwrite_with(pe::header::COFF_MAGIC, 0x40, LE).unwrap();
+            dll.pwrite_with(pe::header::COFF_MACHINE_X86, 0x44, LE).unwrap();
+            dll.pwrite_with(0xe0u16, 0x54, LE).unwrap();
+            dll.pwrite_with(pe::characteristic::IMAGE_FILE_DLL, 0x56, LE).unwrap();
+            dll.pwrite_with(pe::optional_header::MAGIC_32, 0x58, LE).unwrap();
+            dll.pwrite_with(0x1010u32, 0x58 + 16, LE).unwrap();
+            dll.pwrite_with(0x1000_0000u32, 0x58 + 28, LE).unwrap();
+            dll.pwrite_with(0x200u32, 0x58 + 36, LE).unwrap();
+            let object = Object::parse(&dll).unwrap();
+            assert_eq!(object.entry(), Some(0x1000_1010));
+            assert_eq!(object.is_64(), Some(false));
+            assert_eq!(object.is_little_endian(), Some(true));
+            assert_eq!(object.is_lib(), Some(true));
+            assert_eq!(object.image_base(), Some(0x1000_0000));
+            // a big endian dylib, without load commands; the size check is against the larger, 64-bit, header
+            let mut dylib = vec![0u8; mach::header::SIZEOF_HEADER_64];
+            for (offset, value) in [mach::header::MH_MAGIC, mach::cputype::CPU_TYPE_POWERPC, 0, mach::header::MH_DYLIB].iter().enumerate() {
+                dylib.pwrite_with(*value, offset * 4, scroll::BE).unwrap();
+            }
+            let object = Object::parse(&dylib).unwrap();
+            assert_eq!(object.entry(), Some(0));
+            assert_eq!(object.is_64(), Some(false));
+            assert_eq!(object.is_little_endian(), Some(false));
+            assert_eq!(object.is_lib(), Some(true));
+            assert_eq!(object.image_base(), Some(0));
+            // an archive has no single entry point
+            let crt1a: Vec<u8> = include!("../etc/crt1a.rs");
+            let object = Object::parse(&crt1a).unwrap();
+            assert_eq!((object.entry(), object.is_64(), object.is_lib()), (None, None, None));
+        }
+
+        #[test]
+        fn ctx_from_binaries() {
+            use container::{Container, Ctx};
+            assert_eq!(Ctx::le(Container::Big), Ctx::new(Container::Big, scroll::LE));
+            assert!(!Ctx::be(Container::Little).is_little_endian());
+            let crt1: Vec<u8> = include!("../etc/crt1.rs");
+            assert_eq!(Ctx::from_elf(&elf::Elf::parse(&crt1).unwrap()), Ctx::le(Container::Big));
+            let crt132: Vec<u8> = include!("../etc/crt132.rs");
+            assert_eq!(Ctx::from_elf(&elf::Elf::parse(&crt132).unwrap()), Ctx::le(Container::Little));
+        }
+
+        #[test]
+        fn ctx_probe() {
+            use container::{Container, Ctx};
+            let crt1: Vec<u8> = include!("../etc/crt1.rs");
+            assert_eq!(Ctx::probe(&crt1).unwrap(), Ctx::le(Container::Big));
+            let crt132: Vec<u8> = include!("../etc/crt132.rs");
+            assert_eq!(Ctx::probe(&crt132).unwrap(), Ctx::le(Container::Little));
+            let mut bad_data = crt132.clone();
+            bad_data[elf::header::EI_DATA] = 3;
+            assert!(Ctx::probe(&bad_data).is_err());
+            // the Mach-o magics, in the byte order of the binary
+            let mut mach = [0u8; 16];
+            mach[..4].copy_from_slice(&[0xcf, 0xfa, 0xed, 0xfe]);
+            assert_eq!(Ctx::probe(&mach).unwrap(), Ctx::le(Container::Big));
+            mach[..4].copy_from_slice(&[0xfe, 0xed, 0xfa, 0xce]);
+            assert_eq!(Ctx::probe(&mach).unwrap(), Ctx::be(Container::Little));
+            assert_eq!(Container::from_magic(mach::header::MH_CIGAM_64), Some(Container::Big));
+            assert_eq!(Container::from_magic(mach::fat::FAT_MAGIC), None);
+            let mut pe = [0u8; 0x80];
+            pe[0] = b'M';
+            pe[1] = b'Z';
+            pe[0x3c] = 0x40;
+            pe[0x40..0x46].copy_from_slice(&[b'P', b'E', 0, 0, 0x64, 0x86]);
+            pe[0x58..0x5a].copy_from_slice(&[0x0b, 0x02]);
+            assert_eq!(Ctx::probe(&pe).unwrap(), Ctx::le(Container::Big));
+            pe[0x58..0x5a].copy_from_slice(&[0x0b, 0x01]);
+            assert_eq!(Ctx::probe(&pe).unwrap(), Ctx::le(Container::Little));
+            // a ROM image
+            pe[0x58..0x5a].copy_from_slice(&[0x07, 0x01]);
+            assert!(Ctx::probe(&pe).is_err());
+            assert!(Ctx::probe(&[0u8; 16]).is_err());
+            let crt1a: Vec<u8> = include!("../etc/crt1a.rs");
+            assert!(Ctx::probe(&crt1a).is_err());
+        }
+
+        #[test]
+        fn object_symbols() {
+            let crt1: Vec<u8> = include!("../etc/crt1.rs");
+            let object = Object::parse(&crt1).unwrap();
+            let start = object.symbols().find(|sym| sym.name() == "_start").unwrap();
+            assert!(start.is_function() && start.is_global() && !start.is_undefined());
+            assert_eq!(start.address(), 0);
+            let libc_start_main = object.symbols().find(|sym| sym.name() == "__libc_start_main").unwrap();
+            assert!(libc_start_main.is_undefined());
+            assert!(object.symbols().any(|sym| !sym.is_global()));
+            let crt1a: Vec<u8> = include!("../etc/crt1a.rs");
+            assert_eq!(Object::parse(&crt1a).unwrap().symbols().count(), 0);
+        }
+
+        #[test]
+        fn object_relocations() {
+            use relocation::{GenericReloc, RelocKind};
+            let librel: Vec<u8> = include!("../etc/librel.rs");
+            let object = Object::parse(&librel).unwrap();
+            assert_eq!(object.relocations().unwrap().collect::<Vec<_>>(), vec![
+                GenericReloc { offset: 0x2f8, kind: RelocKind::Relative, symbol: None, addend: 0x208 },
+                GenericReloc { offset: 0x2f0, kind: RelocKind::Absolute, symbol: Some("goblin_import"), addend: 0 },
+            ]);
+            // the section relocations of an object file, by the symbol table
+            let crt1: Vec<u8> = include!("../etc/crt1.rs");
+            let object = Object::parse(&crt1).unwrap();
+            let relocs: Vec<_> = object.relocations().unwrap().collect();
+            assert_eq!(relocs.len(), 5);
+            assert_eq!(relocs[3], GenericReloc { offset: 0x26, kind: RelocKind::Other(elf::reloc::R_X86_64_GOTPCRELX), symbol: Some("__libc_start_main"), addend: -4 });
+            assert_eq!(relocs[4].kind, RelocKind::PcRelative);
+            let crt1a: Vec<u8> = include!("../etc/crt1a.rs");
+            assert_eq!(Object::parse(&crt1a).unwrap().relocations().unwrap().count(), 0);
+        }
+
+        #[cfg(feature = "content_hash")]
+        #[test]
+        fn content_hash() {
+            use hash::Crc32;
+            let mut libgnu: Vec<u8> = include!("../etc/libgnu.rs");
+            let crc = Object::parse(&libgnu).unwrap().content_hash(&libgnu, Crc32::new()).unwrap().unwrap();
+            assert_eq!(Object::parse(&libgnu).unwrap().content_hash(&libgnu, Crc32::new()).unwrap(), Some(crc));
+            let (text, comment) = match Object::parse(&libgnu).unwrap() {
+                Object::Elf(elf) => {
+                    let offset = |name| elf.section_headers.iter().find(|shdr| &elf.shdr_strtab[shdr.sh_name] == name).unwrap().sh_offset as usize;
+                    (offset(".text"), offset(".comment"))
+                },
+                _ => unreachable!(),
+            };
+            // the symbol tables and comments are not loaded
+            libgnu[comment] ^= 0xff;
+            assert_eq!(Object::parse(&libgnu).unwrap().content_hash(&libgnu, Crc32::new()).unwrap(), Some(crc));
+            libgnu[text] ^= 0xff;
+            assert_ne!(Object::parse(&libgnu).unwrap().content_hash(&libgnu, Crc32::new()).unwrap(), Some(crc));
+            #[cfg(feature = "sha256")] {
+                use hash::Sha256;
+                let sha = Object::parse(&libgnu).unwrap().content_hash(&libgnu, Sha256::new()).unwrap().unwrap();
+                libgnu[text] ^= 0xff;
+                assert_ne!(Object::parse(&libgnu).unwrap().content_hash(&libgnu, Sha256::new()).unwrap(), Some(sha));
+            }
+            let crt1a: Vec<u8> = include!("../etc/crt1a.rs");
+            assert_eq!(Object::parse(&crt1a).unwrap().content_hash(&crt1a, Crc32::new()).unwrap(), None);
+            assert!(Object::parse(&libgnu).unwrap().content_hash(&libgnu[..0x100], Crc32::new()).is_err());
+        }
+
+        #[test]
+        fn registry_detects_custom_formats() {
+            let mut bytes = [0u8; 32];
+            bytes[2] = 0x8a;
+            bytes[3] = 0x97;
+            bytes[7] = 0x20;
+            match Registry::new().parse(&bytes).unwrap() {
+                Object::Unknown(_) => (),
+                object => panic!("unexpected object {:?}", object),
+            }
+            let mut registry = Registry::new();
+            registry.register(plan9);
+            match registry.parse(&bytes).unwrap() {
+                Object::Custom(object) => {
+                    assert_eq!(object.format_name(), "plan9");
+                    assert_eq!(format!("{:?}", object), "Plan9 { text: 32 }");
+                },
+                object => panic!("unexpected object {:?}", object),
+            }
+        }
+    }
 } // end if_endian_fd
 
 /////////////////////////
@@ -11,6 +11,12 @@
 //! the offset to lazy_bind_off to get the information on what
 //! to bind.
 
+use core::ops::Range;
+use scroll::{Pread, Sleb128, Uleb128};
+
+use error;
+use strtab::Strtab;
+
 pub type Opcode = u8;
 
 // The following are used to encode binding information
@@ -20,6 +26,7 @@ pub const BIND_TYPE_TEXT_PCREL32			: u8     = 3;
 pub const BIND_SPECIAL_DYLIB_SELF			: u8     = 0;
 pub const BIND_SPECIAL_DYLIB_MAIN_EXECUTABLE		: u8     = 0xf; // -1
 pub const BIND_SPECIAL_DYLIB_FLAT_LOOKUP		: u8     = 0xe; // -2
+pub const BIND_SPECIAL_DYLIB_WEAK_LOOKUP		: u8     = 0xd; // -3
 pub const BIND_SYMBOL_FLAGS_WEAK_IMPORT	                : u8   = 0x1;
 pub const BIND_SYMBOL_FLAGS_NON_WEAK_DEFINITION         : u8    = 0x8;
 pub const BIND_OPCODE_MASK				: u8	 = 0xF0;
@@ -37,6 +44,11 @@ pub const BIND_OPCODE_DO_BIND                           : Opcode = 0x90;
 pub const BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB		: Opcode = 0xA0;
 pub const BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED	: Opcode = 0xB0;
 pub const BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB  : Opcode = 0xC0;
+pub const BIND_OPCODE_THREADED                          : Opcode = 0xD0;
+/// The immediate of `BIND_OPCODE_THREADED`: the size of the ordinal table the following `BIND_OPCODE_DO_BIND`s fill in follows as a uleb
+pub const BIND_SUBOPCODE_THREADED_SET_BIND_ORDINAL_TABLE_SIZE_ULEB : u8 = 0x00;
+/// The immediate of `BIND_OPCODE_THREADED`: walks the chain of threaded rebases and binds starting at the current segment and offset
+pub const BIND_SUBOPCODE_THREADED_APPLY                 : u8     = 0x01;
 
 pub fn opcode_to_str(opcode: Opcode) -> &'static str {
     match opcode {
@@ -53,6 +65,165 @@ pub fn opcode_to_str(opcode: Opcode) -> &'static str {
         BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB => "BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB",
         BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED => "BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED",
         BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB => "BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB",
+        BIND_OPCODE_THREADED => "BIND_OPCODE_THREADED",
         _ => "UNKNOWN OPCODE"
     }
 }
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A decoded bind opcode, with its immediate and ULEB/SLEB operands
+pub enum BindOpcode<'a> {
+    Done,
+    SetDylibOrdinalImm(u8),
+    SetDylibOrdinalUleb(u64),
+    /// One of the `BIND_SPECIAL_DYLIB_*`
+    SetDylibSpecialImm(u8),
+    /// The `BIND_SYMBOL_FLAGS_*`, and the symbol name
+    SetSymbolTrailingFlagsImm { flags: u8, name: &'a str },
+    /// One of the `BIND_TYPE_*`
+    SetTypeImm(u8),
+    SetAddendSleb(i64),
+    SetSegmentAndOffsetUleb { segment: u8, offset: u64 },
+    AddAddrUleb(u64),
+    DoBind,
+    DoBindAddAddrUleb(u64),
+    DoBindAddAddrImmScaled(u8),
+    DoBindUlebTimesSkippingUleb { count: u64, skip: u64 },
+    ThreadedSetBindOrdinalTableSizeUleb(u64),
+    ThreadedApply,
+    /// An opcode dyld doesn't know, which has no operands
+    Unknown(Opcode),
+}
+
+impl<'a> BindOpcode<'a> {
+    /// Decodes the opcode at `offset` in `data`, and advances `offset` past its operands
+    pub fn parse(data: &'a [u8], offset: &mut usize) -> error::Result<Self> {
+        let start = *offset;
+        let byte: u8 = data.gread(offset)?;
+        let immediate = byte & BIND_IMMEDIATE_MASK;
+        let opcode = match byte & BIND_OPCODE_MASK {
+            BIND_OPCODE_DONE => BindOpcode::Done,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM => BindOpcode::SetDylibOrdinalImm(immediate),
+            BIND_OPCODE_SET_DYLIB_ORDINAL_ULEB => BindOpcode::SetDylibOrdinalUleb(Uleb128::read(data, offset)?),
+            BIND_OPCODE_SET_DYLIB_SPECIAL_IMM => BindOpcode::SetDylibSpecialImm(immediate),
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM => {
+                // the name must be terminated before the end of `data`, rather than truncated by it
+                let name = Strtab::new(data, 0x0).get_at(*offset)?;
+                *offset += name.len() + 1;
+                BindOpcode::SetSymbolTrailingFlagsImm { flags: immediate, name: name }
+            },
+            BIND_OPCODE_SET_TYPE_IMM => BindOpcode::SetTypeImm(immediate),
+            BIND_OPCODE_SET_ADDEND_SLEB => BindOpcode::SetAddendSleb(Sleb128::read(data, offset)?),
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB => BindOpcode::SetSegmentAndOffsetUleb { segment: immediate, offset: Uleb128::read(data, offset)? },
+            BIND_OPCODE_ADD_ADDR_ULEB => BindOpcode::AddAddrUleb(Uleb128::read(data, offset)?),
+            BIND_OPCODE_DO_BIND => BindOpcode::DoBind,
+            BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB => BindOpcode::DoBindAddAddrUleb(Uleb128::read(data, offset)?),
+            BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED => BindOpcode::DoBindAddAddrImmScaled(immediate),
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB => {
+                let count = Uleb128::read(data, offset)?;
+                let skip = Uleb128::read(data, offset)?;
+                BindOpcode::DoBindUlebTimesSkippingUleb { count: count, skip: skip }
+            },
+            BIND_OPCODE_THREADED => match immediate {
+                BIND_SUBOPCODE_THREADED_SET_BIND_ORDINAL_TABLE_SIZE_ULEB => BindOpcode::ThreadedSetBindOrdinalTableSizeUleb(Uleb128::read(data, offset)?),
+                BIND_SUBOPCODE_THREADED_APPLY => BindOpcode::ThreadedApply,
+                // the operands of an unknown subopcode are unknown too, so the stream can't be decoded any further
+                subopcode => return Err(error::Error::Malformed(format!("unknown BIND_OPCODE_THREADED subopcode {:#x} at {:#x}", subopcode, start))),
+            },
+            _ => BindOpcode::Unknown(byte),
+        };
+        Ok(opcode)
+    }
+    /// The `BIND_OPCODE_*` of this opcode, without its immediate
+    pub fn opcode(&self) -> Opcode {
+        match *self {
+            BindOpcode::Done => BIND_OPCODE_DONE,
+            BindOpcode::SetDylibOrdinalImm(_) => BIND_OPCODE_SET_DYLIB_ORDINAL_IMM,
+            BindOpcode::SetDylibOrdinalUleb(_) => BIND_OPCODE_SET_DYLIB_ORDINAL_ULEB,
+            BindOpcode::SetDylibSpecialImm(_) => BIND_OPCODE_SET_DYLIB_SPECIAL_IMM,
+            BindOpcode::SetSymbolTrailingFlagsImm { .. } => BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM,
+            BindOpcode::SetTypeImm(_) => BIND_OPCODE_SET_TYPE_IMM,
+            BindOpcode::SetAddendSleb(_) => BIND_OPCODE_SET_ADDEND_SLEB,
+            BindOpcode::SetSegmentAndOffsetUleb { .. } => BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB,
+            BindOpcode::AddAddrUleb(_) => BIND_OPCODE_ADD_ADDR_ULEB,
+            BindOpcode::DoBind => BIND_OPCODE_DO_BIND,
+            BindOpcode::DoBindAddAddrUleb(_) => BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB,
+            BindOpcode::DoBindAddAddrImmScaled(_) => BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED,
+            BindOpcode::DoBindUlebTimesSkippingUleb { .. } => BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB,
+            BindOpcode::ThreadedSetBindOrdinalTableSizeUleb(_) | BindOpcode::ThreadedApply => BIND_OPCODE_THREADED,
+            BindOpcode::Unknown(byte) => byte & BIND_OPCODE_MASK,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An iterator over the decoded opcodes of a bind opcode stream, with the file offset of each; see `BindInterpreter::opcodes`.
+/// After the first error, the iterator is exhausted
+pub struct BindOpcodes<'a> {
+    data: &'a [u8],
+    offset: usize,
+    end: usize,
+    done: bool,
+}
+
+impl<'a> BindOpcodes<'a> {
+    /// Decodes the opcodes in `location` of `data`, which is usually the original binary
+    pub fn new(data: &'a [u8], location: Range<usize>) -> Self {
+        BindOpcodes { data: data, offset: location.start, end: location.end, done: false }
+    }
+}
+
+impl<'a> Iterator for BindOpcodes<'a> {
+    type Item = error::Result<(u64, BindOpcode<'a>)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.offset >= self.end {
+            return None;
+        }
+        let offset = self.offset;
+        match BindOpcode::parse(self.data, &mut self.offset) {
+            Ok(opcode) => Some(Ok((offset as u64, opcode))),
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_opcodes() {
+        let data = [
+            0xff,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM | BIND_SYMBOL_FLAGS_WEAK_IMPORT, b'_', b'a', 0,
+            BIND_OPCODE_SET_ADDEND_SLEB, 0x7f,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 2, 0x80, 0x01,
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0x3, 0x8,
+            BIND_OPCODE_THREADED | BIND_SUBOPCODE_THREADED_APPLY,
+            BIND_OPCODE_DONE,
+        ];
+        let opcodes = BindOpcodes::new(&data, 1..data.len()).collect::<error::Result<Vec<_>>>().unwrap();
+        assert_eq!(opcodes, vec![
+            (1, BindOpcode::SetDylibOrdinalImm(1)),
+            (2, BindOpcode::SetSymbolTrailingFlagsImm { flags: BIND_SYMBOL_FLAGS_WEAK_IMPORT, name: "_a" }),
+            (6, BindOpcode::SetAddendSleb(-1)),
+            (8, BindOpcode::SetSegmentAndOffsetUleb { segment: 2, offset: 0x80 }),
+            (11, BindOpcode::DoBindUlebTimesSkippingUleb { count: 3, skip: 8 }),
+            (14, BindOpcode::ThreadedApply),
+            (15, BindOpcode::Done),
+        ]);
+        assert_eq!(opcode_to_str(opcodes[4].1.opcode()), "BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB");
+        assert_eq!(BindOpcode::parse(&data, &mut 0).unwrap(), BindOpcode::Unknown(0xff));
+        // a truncated uleb, and an unknown threaded subopcode, both end the stream
+        let mut opcodes = BindOpcodes::new(&[BIND_OPCODE_ADD_ADDR_ULEB, 0x80], 0..2);
+        assert!(opcodes.next().unwrap().is_err());
+        assert!(opcodes.next().is_none());
+        assert!(BindOpcodes::new(&[BIND_OPCODE_THREADED | 0xf], 0..1).next().unwrap().is_err());
+        // as does a symbol name which runs off the end of the data
+        assert!(BindOpcode::parse(&[BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'a'], &mut 0).is_err());
+    }
+}
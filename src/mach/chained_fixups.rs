@@ -0,0 +1,202 @@
+//! The chained fixups of `LC_DYLD_CHAINED_FIXUPS`, which replace the rebase and bind opcodes in binaries built for macOS 12 and iOS 15 or newer.
+//!
+//! The fixups start with a `dyld_chained_fixups_header`, which locates the page starts of the fixup chains, the imports table, and the symbol pool holding the imports' names.
+//! Every bind in a chain refers to an entry in the imports table by its index.
+
+use core::fmt::{self, Debug};
+use scroll::{Pread, Endian};
+use alloc::vec::Vec;
+
+use error;
+use container;
+use mach::load_command;
+
+/// The imports are `dyld_chained_import`s
+pub const DYLD_CHAINED_IMPORT: u32 = 1;
+/// The imports are `dyld_chained_import_addend`s
+pub const DYLD_CHAINED_IMPORT_ADDEND: u32 = 2;
+/// The imports are `dyld_chained_import_addend64`s
+pub const DYLD_CHAINED_IMPORT_ADDEND64: u32 = 3;
+
+/// The symbol pool is a plain array of nul terminated strings
+pub const DYLD_CHAINED_SYMBOL_UNCOMPRESSED: u32 = 0;
+/// The symbol pool is zlib compressed
+pub const DYLD_CHAINED_SYMBOL_ZLIB: u32 = 1;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Pread, Pwrite, SizeWith)]
+/// The header of the chained fixups, at the start of the `LC_DYLD_CHAINED_FIXUPS` data
+pub struct ChainedFixupsHeader {
+    /// 0
+    pub fixups_version: u32,
+    /// The offset of the `dyld_chained_starts_in_image`, relative to the start of this header
+    pub starts_offset: u32,
+    /// The offset of the imports table, relative to the start of this header
+    pub imports_offset: u32,
+    /// The offset of the symbol pool, relative to the start of this header
+    pub symbols_offset: u32,
+    /// The number of imports
+    pub imports_count: u32,
+    /// A `DYLD_CHAINED_IMPORT*` value
+    pub imports_format: u32,
+    /// A `DYLD_CHAINED_SYMBOL_*` value
+    pub symbols_format: u32,
+}
+
+pub const SIZEOF_CHAINED_FIXUPS_HEADER: usize = 28;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// An entry of the chained imports table
+pub struct ChainedImport<'a> {
+    /// The dylib ordinal of the import; like the bind opcodes, 0 is this image, and -1, -2 and -3 are the main executable, a flat lookup, and a weak lookup
+    pub lib_ordinal: i32,
+    /// Whether the import may be missing at runtime
+    pub weak_import: bool,
+    /// The name of the imported symbol
+    pub name: &'a str,
+    /// The addend, 0 if the imports format has none
+    pub addend: i64,
+}
+
+impl<'a> Debug for ChainedImport<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{} ordinal: {} weak: {} addend: {:#x}", self.name, self.lib_ordinal, self.weak_import, self.addend)
+    }
+}
+
+/// Sign extends the special (negative) dylib ordinals, which occupy the top 16 values of an ordinal of `bits` bits
+fn lib_ordinal(ordinal: u64, bits: u32) -> i32 {
+    let max = 1u64 << bits;
+    if ordinal >= max - 16 {
+        ordinal as i32 - max as i32
+    } else {
+        ordinal as i32
+    }
+}
+
+/// The chained fixups of a binary
+pub struct ChainedFixups<'a> {
+    /// The header of the chained fixups
+    pub header: ChainedFixupsHeader,
+    data: &'a [u8],
+    le: Endian,
+}
+
+impl<'a> Debug for ChainedFixups<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ChainedFixups")
+            .field("header", &self.header)
+            .field("size", &self.data.len())
+            .finish()
+    }
+}
+
+impl<'a> ChainedFixups<'a> {
+    /// Reads the chained fixups header of `command`; `bytes` must be the Mach-o the command was parsed from
+    pub fn parse(bytes: &'a [u8], command: &load_command::LinkeditDataCommand, ctx: &container::Ctx) -> error::Result<Self> {
+        let offset = command.dataoff as usize;
+        let size = command.datasize as usize;
+        let data = match offset.checked_add(size).and_then(|end| bytes.get(offset..end)) {
+            Some(data) => data,
+            None => return Err(error::Error::Malformed(format!("chained fixups at {:#x} of size {:#x} are out of bounds", offset, size))),
+        };
+        let header: ChainedFixupsHeader = data.pread_with(0, ctx.le)?;
+        Ok(ChainedFixups { header, data, le: ctx.le })
+    }
+    /// The entries of the imports table, with their names resolved in the symbol pool
+    pub fn imports(&self) -> error::Result<Vec<ChainedImport<'a>>> {
+        let header = &self.header;
+        if header.symbols_format != DYLD_CHAINED_SYMBOL_UNCOMPRESSED {
+            return Err(error::Error::Malformed(format!("chained fixups have unsupported symbols format {}", header.symbols_format)));
+        }
+        let entry_size = match header.imports_format {
+            DYLD_CHAINED_IMPORT => 4,
+            DYLD_CHAINED_IMPORT_ADDEND => 8,
+            DYLD_CHAINED_IMPORT_ADDEND64 => 16,
+            format => return Err(error::Error::Malformed(format!("chained fixups have unknown imports format {}", format))),
+        };
+        let start = header.imports_offset as usize;
+        let table = (header.imports_count as usize).checked_mul(entry_size)
+            .and_then(|size| start.checked_add(size))
+            .and_then(|end| self.data.get(start..end));
+        let table = match table {
+            Some(table) => table,
+            None => return Err(error::Error::Malformed(format!("chained imports table at {:#x} with {} imports is out of bounds", start, header.imports_count))),
+        };
+        let symbols = self.data.get(header.symbols_offset as usize..).unwrap_or(&[]);
+        let mut imports = Vec::with_capacity(header.imports_count as usize);
+        let offset = &mut 0;
+        for _ in 0..header.imports_count {
+            let (lib_ordinal, weak_import, name_offset, addend) = match header.imports_format {
+                DYLD_CHAINED_IMPORT | DYLD_CHAINED_IMPORT_ADDEND => {
+                    // lib_ordinal: 8, weak_import: 1, name_offset: 23
+                    let raw: u32 = table.gread_with(offset, self.le)?;
+                    let addend = if header.imports_format == DYLD_CHAINED_IMPORT_ADDEND { table.gread_with::<i32>(offset, self.le)? as i64 } else { 0 };
+                    (lib_ordinal((raw & 0xff) as u64, 8), raw & 0x100 != 0, (raw >> 9) as usize, addend)
+                },
+                _ => {
+                    // lib_ordinal: 16, weak_import: 1, reserved: 15, name_offset: 32
+                    let raw: u64 = table.gread_with(offset, self.le)?;
+                    let addend: i64 = table.gread_with(offset, self.le)?;
+                    (lib_ordinal(raw & 0xffff, 16), raw & 0x1_0000 != 0, (raw >> 32) as usize, addend)
+                },
+            };
+            let name: &'a str = symbols.pread(name_offset)?;
+            imports.push(ChainedImport { lib_ordinal, weak_import, name, addend });
+        }
+        Ok(imports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::{self, Pwrite};
+    use container::{Container, Ctx};
+    use mach::load_command::{LinkeditDataCommand, LC_DYLD_CHAINED_FIXUPS, SIZEOF_LINKEDIT_DATA_COMMAND};
+
+    fn fixups(imports_format: u32, table: &[u8]) -> Vec<u8> {
+        let symbols = b"\0_printf\0_objc_msgSend\0";
+        let header = ChainedFixupsHeader {
+            fixups_version: 0,
+            starts_offset: 0,
+            imports_offset: SIZEOF_CHAINED_FIXUPS_HEADER as u32,
+            symbols_offset: (SIZEOF_CHAINED_FIXUPS_HEADER + table.len()) as u32,
+            imports_count: 2,
+            imports_format,
+            symbols_format: DYLD_CHAINED_SYMBOL_UNCOMPRESSED,
+        };
+        let mut bytes = vec![0u8; SIZEOF_CHAINED_FIXUPS_HEADER];
+        bytes.pwrite_with(header, 0, scroll::LE).unwrap();
+        bytes.extend_from_slice(table);
+        bytes.extend_from_slice(symbols);
+        bytes
+    }
+
+    fn imports(bytes: &[u8]) -> error::Result<Vec<(i32, bool, &str, i64)>> {
+        let command = LinkeditDataCommand { cmd: LC_DYLD_CHAINED_FIXUPS, cmdsize: SIZEOF_LINKEDIT_DATA_COMMAND as u32, dataoff: 0, datasize: bytes.len() as u32 };
+        let fixups = ChainedFixups::parse(bytes, &command, &Ctx::new(Container::Big, scroll::LE))?;
+        Ok(fixups.imports()?.iter().map(|import| (import.lib_ordinal, import.weak_import, import.name, import.addend)).collect())
+    }
+
+    #[test]
+    fn chained_imports() {
+        // _printf from dylib 1, and a weak _objc_msgSend via a flat lookup
+        let table = [0x01, 0x02, 0x00, 0x00, 0xfe, 0x13, 0x00, 0x00];
+        assert_eq!(imports(&fixups(DYLD_CHAINED_IMPORT, &table)).unwrap(), vec![
+            (1, false, "_printf", 0),
+            (-2, true, "_objc_msgSend", 0),
+        ]);
+        let table = [
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x02, 0x00, 0x01, 0x00, 0x09, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(imports(&fixups(DYLD_CHAINED_IMPORT_ADDEND64, &table)).unwrap(), vec![
+            (1, false, "_printf", 0),
+            (2, true, "_objc_msgSend", 0x10),
+        ]);
+        let mut bytes = fixups(DYLD_CHAINED_IMPORT, &table[..8]);
+        bytes.truncate(SIZEOF_CHAINED_FIXUPS_HEADER + 4);
+        assert!(imports(&bytes).is_err());
+    }
+}
@@ -190,6 +190,16 @@ pub const SEG_UNIXSTACK: &'static str = "__UNIXSTACK";
 /// the segment for the self (dyld) modifing code stubs that has read, write and execute permissions
 pub const SEG_IMPORT: &'static str = "__IMPORT";
 
+// Virtual memory protection bits, as found in a segment's `maxprot` and `initprot`
+/// no permissions
+pub const VM_PROT_NONE: u32 = 0x0;
+/// read permission
+pub const VM_PROT_READ: u32 = 0x1;
+/// write permission
+pub const VM_PROT_WRITE: u32 = 0x2;
+/// execute permission
+pub const VM_PROT_EXECUTE: u32 = 0x4;
+
 pub mod cputype {
 
     /// An alias for u32
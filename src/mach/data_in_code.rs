@@ -0,0 +1,124 @@
+//! The data in code table of `LC_DATA_IN_CODE`, an array in `__LINKEDIT` of the ranges of `__text` which hold data, like jump tables, rather than instructions
+
+use scroll::{self, Pread};
+
+use error;
+use mach::load_command::{self, DataInCodeEntry};
+
+pub const DICE_KIND_DATA: u16 = 0x0001;
+pub const DICE_KIND_JUMP_TABLE8: u16 = 0x0002;
+pub const DICE_KIND_JUMP_TABLE16: u16 = 0x0003;
+pub const DICE_KIND_JUMP_TABLE32: u16 = 0x0004;
+pub const DICE_KIND_ABS_JUMP_TABLE32: u16 = 0x0005;
+
+pub const SIZEOF_DATA_IN_CODE_ENTRY: usize = 8;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// What a range of data in code holds
+pub enum DataInCodeKind {
+    /// Literal data
+    Data,
+    /// A table of 8-bit jump offsets
+    JumpTable8,
+    /// A table of 16-bit jump offsets
+    JumpTable16,
+    /// A table of 32-bit jump offsets
+    JumpTable32,
+    /// A table of 32-bit absolute jump addresses
+    AbsJumpTable32,
+    Unknown(u16),
+}
+
+impl From<u16> for DataInCodeKind {
+    fn from(kind: u16) -> Self {
+        match kind {
+            DICE_KIND_DATA => DataInCodeKind::Data,
+            DICE_KIND_JUMP_TABLE8 => DataInCodeKind::JumpTable8,
+            DICE_KIND_JUMP_TABLE16 => DataInCodeKind::JumpTable16,
+            DICE_KIND_JUMP_TABLE32 => DataInCodeKind::JumpTable32,
+            DICE_KIND_ABS_JUMP_TABLE32 => DataInCodeKind::AbsJumpTable32,
+            kind => DataInCodeKind::Unknown(kind),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// A range of data in code
+pub struct DataInCode {
+    /// The file offset of the range, from the start of the Mach-o
+    pub offset: u32,
+    /// The length of the range, in bytes
+    pub length: u16,
+    pub kind: DataInCodeKind,
+}
+
+impl From<DataInCodeEntry> for DataInCode {
+    fn from(entry: DataInCodeEntry) -> Self {
+        DataInCode { offset: entry.offset, length: entry.length, kind: entry.kind.into() }
+    }
+}
+
+/// An iterator over the entries of a data in code table
+#[derive(Debug, Clone)]
+pub struct DataInCodeEntries<'a> {
+    data: &'a [u8],
+    offset: usize,
+    le: scroll::Endian,
+}
+
+impl<'a> DataInCodeEntries<'a> {
+    /// Parses the table `command` points to in `bytes`, which must be the original binary; its size must be a whole number of entries
+    pub fn parse(bytes: &'a [u8], command: &load_command::LinkeditDataCommand, le: scroll::Endian) -> error::Result<Self> {
+        let start = command.dataoff as usize;
+        let size = command.datasize as usize;
+        if size % SIZEOF_DATA_IN_CODE_ENTRY != 0 {
+            return Err(error::Error::Malformed(format!("data in code table size {:#x} is not a multiple of the entry size {}", size, SIZEOF_DATA_IN_CODE_ENTRY)));
+        }
+        match bytes.get(start..start.saturating_add(size)) {
+            Some(data) => Ok(DataInCodeEntries { data, offset: 0, le }),
+            None => Err(error::Error::Malformed(format!("data in code table at {:#x} of size {:#x} is out of bounds", start, size))),
+        }
+    }
+    /// The number of entries left in the table
+    pub fn len(&self) -> usize {
+        (self.data.len() - self.offset) / SIZEOF_DATA_IN_CODE_ENTRY
+    }
+    /// Whether there are no entries left in the table
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a> Iterator for DataInCodeEntries<'a> {
+    type Item = DataInCode;
+    fn next(&mut self) -> Option<Self::Item> {
+        // the size is a whole number of entries, so reads only fail at the end
+        self.data.gread_with::<DataInCodeEntry>(&mut self.offset, self.le).ok().map(DataInCode::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn parse_data_in_code() {
+        // 4 bytes of data at 0x1000, and a 16 byte jump table at 0x1f00
+        let bytes = [0xff, 0x00, 0x00, 0x10, 0x00, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x10, 0x00, 0x04, 0x00];
+        let command = load_command::LinkeditDataCommand { cmd: load_command::LC_DATA_IN_CODE, cmdsize: 16, dataoff: 1, datasize: 16 };
+        let entries = DataInCodeEntries::parse(&bytes, &command, scroll::BE).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries.collect::<Vec<_>>(), vec![
+            DataInCode { offset: 0x1000, length: 4, kind: DataInCodeKind::Data },
+            DataInCode { offset: 0x1f00, length: 16, kind: DataInCodeKind::JumpTable32 },
+        ]);
+        assert_eq!(DataInCodeKind::from(9), DataInCodeKind::Unknown(9));
+        let command = load_command::LinkeditDataCommand { datasize: 12, .. command };
+        assert!(DataInCodeEntries::parse(&bytes, &command, scroll::BE).is_err());
+        let command = load_command::LinkeditDataCommand { dataoff: 8, datasize: 16, .. command };
+        assert!(DataInCodeEntries::parse(&bytes, &command, scroll::BE).is_err());
+        let command = load_command::LinkeditDataCommand { dataoff: 0, datasize: 0, .. command };
+        assert!(DataInCodeEntries::parse(&bytes, &command, scroll::BE).unwrap().is_empty());
+    }
+}
@@ -13,9 +13,13 @@ use core::fmt::{self, Debug};
 use mach::load_command;
 use alloc::vec::Vec;
 use alloc::string::String;
+use alloc::collections::btree_set::BTreeSet;
 
 type Flag = u64;
 
+/// The deepest the export trie is walked before it is considered malformed, guarding the recursive walk against crafted tries; far deeper than any real trie
+pub const MAX_TRIE_DEPTH: usize = 512;
+
  // "The following are used on the flags byte of a terminal node
  // in the export information."
 pub const EXPORT_SYMBOL_FLAGS_KIND_MASK         : Flag = 0x03;
@@ -94,7 +98,10 @@ impl<'a> ExportInfo<'a> {
                 tmp.into()
             };
             let lib_symbol_name = bytes.pread::<&str>(offset)?;
-            let lib = libs[lib_ordinal as usize];
+            let lib = match libs.get(lib_ordinal as usize) {
+                Some(lib) => lib,
+                None => return Err(error::Error::Malformed(format!("export trie re-exports from library ordinal {} but there are only {} libraries", lib_ordinal, libs.len()))),
+            };
             let lib_symbol_name = if lib_symbol_name == "" { None } else { Some (lib_symbol_name)};
             Ok(Reexport {
                 lib: lib,
@@ -139,7 +146,7 @@ impl<'a> ExportInfo<'a> {
 #[derive(Debug)]
 /// A finalized symbolic export reconstructed from the export trie
 pub struct Export<'a> {
-    /// The reconsituted export name which dyld matches against
+    /// The reconsituted export name which dyld matches against; it is owned, since the trie spells it out across the edges leading to its node
     pub name: String,
     /// The export info in the node data
     pub info: ExportInfo<'a>,
@@ -158,6 +165,26 @@ impl<'a> Export<'a> {
         };
         Export { name: name, info: info, size: 0, offset: offset }
     }
+    /// The `EXPORT_SYMBOL_FLAGS_*` of this export
+    pub fn flags(&self) -> Flag {
+        match self.info {
+            ExportInfo::Regular { flags, .. } | ExportInfo::Reexport { flags, .. } | ExportInfo::Stub { flags, .. } => flags,
+        }
+    }
+    /// Whether this export is re-exported from another dylib
+    pub fn is_reexport(&self) -> bool {
+        match self.info {
+            ExportInfo::Reexport { .. } => true,
+            _ => false,
+        }
+    }
+    /// The name of the symbol this export re-exports, if it re-exports it under a different name
+    pub fn reexport_name(&self) -> Option<&'a str> {
+        match self.info {
+            ExportInfo::Reexport { lib_symbol_name, .. } => lib_symbol_name,
+            _ => None,
+        }
+    }
 }
 
 /// An export trie efficiently encodes all of the symbols exported by this binary for dynamic linking
@@ -166,12 +193,15 @@ pub struct ExportTrie<'a> {
     location: Range<usize>,
 }
 
+/// The export trie's counterpart to `mach::imports::BindInterpreter`: walking an `ExportTrie` interprets its terminal and edge nodes
+pub type ExportInterpreter<'a> = ExportTrie<'a>;
+
 impl<'a> ExportTrie<'a> {
 
     #[inline]
-    fn walk_nodes(&self, libs: &[&'a str], branches: Vec<(String, usize)>, acc: &mut Vec<Export<'a>>) -> error::Result<()> {
+    fn walk_nodes(&self, libs: &[&'a str], branches: Vec<(String, usize)>, depth: usize, visited: &mut BTreeSet<usize>, acc: &mut Vec<Export<'a>>) -> error::Result<()> {
         for (symbol, next_node) in branches {
-            self.walk_trie(libs, symbol, next_node, acc)?;
+            self.walk_trie(libs, symbol, next_node, depth + 1, visited, acc)?;
         }
         Ok(())
     }
@@ -197,7 +227,14 @@ impl<'a> ExportTrie<'a> {
         Ok(branches)
     }
 
-    fn walk_trie(&self, libs: &[&'a str], current_symbol: String, start: usize, exports: &mut Vec<Export<'a>>) -> error::Result<()> {
+    fn walk_trie(&self, libs: &[&'a str], current_symbol: String, start: usize, depth: usize, visited: &mut BTreeSet<usize>, exports: &mut Vec<Export<'a>>) -> error::Result<()> {
+        if depth > MAX_TRIE_DEPTH {
+            return Err(error::Error::Malformed(format!("export trie is deeper than {} nodes at {:#x}", MAX_TRIE_DEPTH, start)));
+        }
+        // a trie reaches every node exactly once, so a revisit means the edges form a cycle (or share nodes, which is just as bad)
+        if !visited.insert(start) {
+            return Err(error::Error::Malformed(format!("export trie node at {:#x} is reachable twice", start)));
+        }
         if start < self.location.end {
             let offset = &mut start.clone();
             let terminal_size = Uleb128::read(&self.data, offset)?;
@@ -208,7 +245,7 @@ impl<'a> ExportTrie<'a> {
                 let nbranches = Uleb128::read(&self.data, offset)? as usize;
                 //println!("\t@ {:#x} BRAN {}", *offset, nbranches);
                 let branches = self.walk_branches(nbranches, current_symbol, *offset)?;
-                self.walk_nodes(libs, branches, exports)
+                self.walk_nodes(libs, branches, depth, visited, exports)
             } else { // terminal node, but the tricky part is that they can have children...
                 let pos = *offset;
                 let children_start = &mut (pos + terminal_size as usize);
@@ -225,7 +262,7 @@ impl<'a> ExportTrie<'a> {
                 } else {
                     // more branches to walk
                     let branches = self.walk_branches(nchildren, current_symbol, *children_start)?;
-                    self.walk_nodes(libs, branches, exports)
+                    self.walk_nodes(libs, branches, depth, visited, exports)
                 }
             }
         } else { Ok(()) }
@@ -236,14 +273,14 @@ impl<'a> ExportTrie<'a> {
         let offset = self.location.start.clone();
         let current_symbol = String::new();
         let mut exports = Vec::new();
-        self.walk_trie(libs, current_symbol, offset, &mut exports)?;
+        self.walk_trie(libs, current_symbol, offset, 0, &mut BTreeSet::new(), &mut exports)?;
         Ok(exports)
     }
 
     /// Create a new, lazy, zero-copy export trie from the `DyldInfo` `command`
     pub fn new(bytes: &'a [u8], command: &load_command::DyldInfoCommand) -> Self {
         let start = command.export_off as usize;
-        let end = (command.export_size + command.export_off) as usize;
+        let end = start + command.export_size as usize;
         ExportTrie {
             data: bytes.as_ref(),
             location: start..end,
@@ -273,6 +310,34 @@ mod tests {
         println!("trie: {:#?}", &trie);
         let exports = trie.exports(&libs).unwrap();
         println!("len: {} exports: {:#?}", exports.len(), &exports);
-        assert_eq!(exports.len() as usize, 3usize)
+        assert_eq!(exports.len() as usize, 3usize);
+        assert!(exports.iter().all(|export| !export.is_reexport() && export.flags() == EXPORT_SYMBOL_FLAGS_KIND_REGULAR));
+    }
+
+    fn trie<'a>(bytes: &'a [u8], libs: &[&'a str]) -> error::Result<Vec<Export<'a>>> {
+        let mut command = load_command::DyldInfoCommand::default();
+        command.export_size = bytes.len() as u32;
+        ExportInterpreter::new(bytes, &command).exports(libs)
+    }
+
+    #[test]
+    fn reexport() {
+        // `_a` re-exports `_b` from the dylib with ordinal 1
+        let bytes = [0x00, 0x01, b'_', b'a', 0x00, 0x06, 0x05, 0x08, 0x01, b'_', b'b', 0x00, 0x00];
+        let libs = ["self", "/usr/lib/libfoo.dylib"];
+        let exports = trie(&bytes, &libs).unwrap();
+        assert_eq!(exports.len(), 1);
+        assert_eq!(exports[0].name, "_a");
+        assert!(exports[0].is_reexport());
+        assert_eq!(exports[0].flags(), EXPORT_SYMBOL_FLAGS_REEXPORT);
+        assert_eq!(exports[0].reexport_name(), Some("_b"));
+        assert!(trie(&bytes, &libs[..1]).is_err());
+    }
+
+    #[test]
+    fn cyclic_trie() {
+        // the root's only edge leads back to the root
+        let bytes = [0x00, 0x01, b'_', 0x00, 0x00];
+        assert!(trie(&bytes, &[]).is_err());
     }
 }
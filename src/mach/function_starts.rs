@@ -0,0 +1,109 @@
+//! The function starts of `LC_FUNCTION_STARTS`, a stream of ULEB128 deltas in `__LINKEDIT` which reconstructs the address of every function,
+//! even in a stripped binary.
+//!
+//! The first delta is relative to the start of `__TEXT`, each following delta to the previous function; a zero delta ends the stream,
+//! which is then padded with zeros to the pointer size.
+
+use scroll::Uleb128;
+
+use error;
+use mach::load_command;
+
+/// An iterator over the absolute addresses of the functions in a binary
+pub struct FunctionStarts<'a> {
+    data: &'a [u8],
+    offset: usize,
+    address: u64,
+    error: Option<error::Error>,
+    done: bool,
+}
+
+impl<'a> FunctionStarts<'a> {
+    /// Decodes the deltas in `data`, starting from `base`, which is the virtual address of `__TEXT`
+    pub fn new(data: &'a [u8], base: u64) -> Self {
+        FunctionStarts { data, offset: 0, address: base, error: None, done: false }
+    }
+    /// Decodes the deltas `command` points to in `bytes`, which must be the original binary; if they are out of bounds, the iterator yields only that error
+    pub fn parse(bytes: &'a [u8], command: &load_command::LinkeditDataCommand, base: u64) -> Self {
+        let start = command.dataoff as usize;
+        match bytes.get(start..start.saturating_add(command.datasize as usize)) {
+            Some(data) => FunctionStarts::new(data, base),
+            None => {
+                let mut starts = FunctionStarts::new(&[], base);
+                starts.error = Some(error::Error::Malformed(format!("function starts at {:#x} of size {:#x} are out of bounds", start, command.datasize)));
+                starts
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for FunctionStarts<'a> {
+    type Item = error::Result<u64>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if let Some(error) = self.error.take() {
+            self.done = true;
+            return Some(Err(error));
+        }
+        if self.offset >= self.data.len() {
+            self.done = true;
+            return None;
+        }
+        let delta = match Uleb128::read(self.data, &mut self.offset) {
+            Ok(delta) => delta,
+            Err(error) => {
+                self.done = true;
+                return Some(Err(error.into()));
+            }
+        };
+        if delta == 0 {
+            self.done = true;
+            return None;
+        }
+        match self.address.checked_add(delta) {
+            Some(address) => {
+                self.address = address;
+                Some(Ok(address))
+            },
+            None => {
+                self.done = true;
+                Some(Err(error::Error::Malformed(format!("function start delta {:#x} from {:#x} overflows the address space", delta, self.address))))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn decode_deltas() {
+        // 0xf20, then 0x10, then 0x100 (as a two byte ULEB128), then the terminator and padding
+        let data = [0xa0, 0x1e, 0x10, 0x80, 0x02, 0x00, 0x00, 0x00];
+        let starts: Vec<u64> = FunctionStarts::new(&data, 0x1_0000_0000).map(|start| start.unwrap()).collect();
+        assert_eq!(starts, vec![0x1_0000_0f20, 0x1_0000_0f30, 0x1_0000_1030]);
+        // the stream may also just end
+        assert_eq!(FunctionStarts::new(&data[..3], 0).count(), 2);
+        assert_eq!(FunctionStarts::new(&[], 0).count(), 0);
+    }
+
+    #[test]
+    fn malformed_deltas() {
+        // a truncated ULEB128
+        let mut starts = FunctionStarts::new(&[0x10, 0x80], 0);
+        assert_eq!(starts.next().unwrap().unwrap(), 0x10);
+        assert!(starts.next().unwrap().is_err());
+        assert!(starts.next().is_none());
+        let mut starts = FunctionStarts::new(&[0x10], ::core::u64::MAX - 8);
+        assert!(starts.next().unwrap().is_err());
+        assert!(starts.next().is_none());
+        let command = load_command::LinkeditDataCommand { cmd: load_command::LC_FUNCTION_STARTS, cmdsize: 16, dataoff: 4, datasize: 8 };
+        let mut starts = FunctionStarts::parse(&[0x10; 8], &command, 0);
+        assert!(starts.next().unwrap().is_err());
+        assert!(starts.next().is_none());
+    }
+}
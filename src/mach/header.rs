@@ -138,6 +138,8 @@ pub const MH_DYLIB_STUB: u32 = 0x9;
 pub const MH_DSYM: u32 = 0xa;
 /// x86_64 kexts
 pub const MH_KEXT_BUNDLE: u32 = 0xb;
+/// set of Mach-o binaries, e.g., a kernelcache, see `LC_FILESET_ENTRY`
+pub const MH_FILESET: u32 = 0xc;
 
 pub fn filetype_to_str(filetype: u32) -> &'static str {
     match filetype {
@@ -152,6 +154,7 @@ pub fn filetype_to_str(filetype: u32) -> &'static str {
         MH_DYLIB_STUB => "DYLIB_STUB",
         MH_DSYM => "DSYM",
         MH_KEXT_BUNDLE => "KEXT_BUNDLE",
+        MH_FILESET => "FILESET",
         _ => "UNKNOWN FILETYPE",
     }
 }
@@ -6,35 +6,53 @@
 
 use core::ops::Range;
 use core::fmt::{self, Debug};
-use scroll::{Sleb128, Uleb128, Pread};
+use scroll::Pread;
 use alloc::vec::Vec;
+use alloc::collections::btree_set::BTreeSet;
 
 use container;
 use error;
+use options::ParseOptions;
 use mach::load_command;
-use mach::bind_opcodes;
+use mach::bind_opcodes::{self, BindOpcode, BindOpcodes};
 use mach::segment;
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Which of the bind opcode streams of `LC_DYLD_INFO` is being interpreted
+enum BindKind {
+    /// The non-lazy binds, bound at launch
+    NonLazy,
+    /// The lazy binds, bound on first use
+    Lazy,
+    /// The weak binds, which dyld coalesces by name across all loaded images
+    Weak,
+}
+
+#[derive(Debug, Clone)]
 /// Import binding information generated by running the Finite State Automaton programmed via `bind_opcodes`
 struct BindInformation<'a> {
   seg_index:              u8,
   seg_offset:             u64,
   bind_type:              u8,
-  symbol_library_ordinal: u8,
+  symbol_library_ordinal: u64,
   symbol_name:            &'a str,
   symbol_flags:           u8,
   addend:                 i64,
-  special_dylib:          u8, // seeing self = 0 assuming this means the symbol is imported from itself, because its... libSystem.B.dylib?
+  special_dylib:          Option<u8>, // the `BIND_SPECIAL_DYLIB_*` set by `BIND_OPCODE_SET_DYLIB_SPECIAL_IMM`, which overrides the library ordinal
   is_lazy:                bool,
 }
 
 impl<'a> BindInformation<'a> {
-    pub fn new (is_lazy: bool) -> Self {
+    pub fn new (kind: BindKind) -> Self {
         let mut bind_info = BindInformation::default();
-        if is_lazy {
-            bind_info.is_lazy = true;
-            bind_info.bind_type = bind_opcodes::BIND_TYPE_POINTER;
+        match kind {
+            BindKind::Lazy => {
+                bind_info.is_lazy = true;
+                bind_info.bind_type = bind_opcodes::BIND_TYPE_POINTER;
+            },
+            // weak binds have no library ordinals, since they are looked up in every image
+            BindKind::Weak => bind_info.special_dylib = Some(bind_opcodes::BIND_SPECIAL_DYLIB_WEAK_LOOKUP),
+            BindKind::NonLazy => (),
         }
         bind_info
     }
@@ -49,7 +67,7 @@ impl<'a> Default for BindInformation<'a> {
             seg_index:     0,
             seg_offset:    0x0,
             bind_type:     0x0,
-            special_dylib: 1,
+            special_dylib: None,
             symbol_library_ordinal: 0,
             symbol_name: "",
             symbol_flags: 0,
@@ -64,7 +82,9 @@ impl<'a> Default for BindInformation<'a> {
 pub struct Import<'a> {
     /// The symbol name dyld uses to resolve this import
     pub name:    &'a str,
-    /// The library this symbol belongs to (thanks to two-level namespaces)
+    /// The library this symbol belongs to (thanks to two-level namespaces);
+    /// for the special dylibs, `"main-executable"`, `"flat-lookup"` or `"weak"`.
+    /// Ordinal 0 and `BIND_SPECIAL_DYLIB_SELF` are this image, i.e., `"self"`, or the install name of a dylib
     pub dylib:   &'a str,
     ///  Whether the symbol is lazily resolved or not
     pub is_lazy: bool,
@@ -74,35 +94,89 @@ pub struct Import<'a> {
     pub size:    usize,
     /// The virtual memory address at which this import is found
     pub address: u64,
-    /// The addend of this import
+    /// The segment and section the bound pointer is in, e.g., `("__DATA", "__la_symbol_ptr")` for a lazy import, or `("__DATA_CONST", "__got")`;
+    /// `None` if it is in the padding between the segment's sections
+    pub section: Option<(&'a str, &'a str)>,
+    /// The addend of this import, from `BIND_OPCODE_SET_ADDEND_SLEB`; dyld binds the slot to the symbol's address plus this value
     pub addend:  i64,
     /// Whether this import is weak
     pub is_weak: bool,
+    /// How the bound value is written: one of the `BIND_TYPE_*`, e.g., `BIND_TYPE_POINTER` for a plain pointer
+    pub bind_type: u8,
+    /// The `BIND_SYMBOL_FLAGS_*` of this import
+    pub symbol_flags: u8,
     /// The offset in the stream of bind opcodes that caused this import
     pub start_of_sequence_offset: u64
 }
 
 impl<'a> Import<'a> {
+    /// What dyld binds this import as: a lazy import is a `JumpSlot`, a `BIND_TYPE_TEXT_PCREL32` is `PcRelative`, and any other pointer or text bind `Absolute`
+    pub fn kind(&self) -> ::relocation::RelocKind {
+        use relocation::RelocKind;
+        match self.bind_type {
+            _ if self.is_lazy => RelocKind::JumpSlot,
+            bind_opcodes::BIND_TYPE_POINTER | bind_opcodes::BIND_TYPE_TEXT_ABSOLUTE32 => RelocKind::Absolute,
+            bind_opcodes::BIND_TYPE_TEXT_PCREL32 => RelocKind::PcRelative,
+            bind_type => RelocKind::Other(bind_type as u32),
+        }
+    }
     /// Create a new import from the import binding information in `bi`
-    fn new(bi: &BindInformation<'a>, libs: &[&'a str], segments: &[segment::Segment], start_of_sequence_offset: usize) -> Import<'a> {
-        let (offset, address) = {
-            let segment = &segments[bi.seg_index as usize];
-            (
-                segment.fileoff + bi.seg_offset,
-                segment.vmaddr + bi.seg_offset
-            )
-        };
+    fn new(bi: &BindInformation<'a>, libs: &[&'a str], segments: &[segment::Segment<'a>], start_of_sequence_offset: usize) -> error::Result<Import<'a>> {
+        let (offset, address) = segment::resolve(segments, bi.seg_index as usize, bi.seg_offset)?;
         let size = if bi.is_lazy { 8 } else { 0 };
-        Import {
+        // `resolve` has checked the segment index; a section header which fails to parse can't contain the pointer
+        let segment = &segments[bi.seg_index as usize];
+        let section = segment.into_iter()
+            .position(|section| section.map_or(false, |(section, _)| section.addr <= address && address - section.addr < section.size))
+            .and_then(|index| segment.section_names(index).ok());
+        let dylib = match bi.special_dylib {
+            Some(bind_opcodes::BIND_SPECIAL_DYLIB_MAIN_EXECUTABLE) => "main-executable",
+            Some(bind_opcodes::BIND_SPECIAL_DYLIB_FLAT_LOOKUP) => "flat-lookup",
+            Some(bind_opcodes::BIND_SPECIAL_DYLIB_WEAK_LOOKUP) => "weak",
+            // `BIND_SPECIAL_DYLIB_SELF`, and anything else dyld would reject, is this image
+            Some(_) => match libs.get(0) {
+                Some(lib) => lib,
+                None => return Err(error::Error::Malformed("bind opcodes reference this image, but there are no libraries".to_string())),
+            },
+            None => match libs.get(bi.symbol_library_ordinal as usize) {
+                Some(lib) if bi.symbol_library_ordinal < libs.len() as u64 => lib,
+                _ => return Err(error::Error::Malformed(format!("bind opcodes reference library ordinal {} but there are only {} libraries", bi.symbol_library_ordinal, libs.len()))),
+            },
+        };
+        Ok(Import {
             name: bi.symbol_name,
-            dylib: libs[bi.symbol_library_ordinal as usize],
+            dylib: dylib,
             is_lazy: bi.is_lazy,
             offset: offset,
             size: size,
             address: address,
+            section: section,
             addend: bi.addend,
             is_weak: bi.is_weak(),
+            bind_type: bi.bind_type,
+            symbol_flags: bi.symbol_flags,
             start_of_sequence_offset: start_of_sequence_offset as u64
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Which imports `BindInterpreter::imports_with_opts` reports
+pub struct ImportOptions {
+    /// Whether to run the lazy bind opcodes as well as the non-lazy ones; skipping them is faster, but misses most function imports
+    pub lazy: bool,
+    /// Whether to report only the first import of every `(name, dylib)` pair, for when only the referenced symbols matter
+    pub dedup: bool,
+    /// Whether to sort the imports by name, then dylib, then address, instead of reporting them in opcode stream order; handy for diffing two binaries
+    pub sorted: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        ImportOptions {
+            lazy: true,
+            dedup: false,
+            sorted: false,
         }
     }
 }
@@ -114,6 +188,8 @@ pub struct BindInterpreter<'a> {
     data: &'a [u8],
     location: Range<usize>,
     lazy_location: Range<usize>,
+    weak_location: Range<usize>,
+    max_imports: usize,
 }
 
 impl<'a> Debug for BindInterpreter<'a> {
@@ -121,6 +197,7 @@ impl<'a> Debug for BindInterpreter<'a> {
         writeln!(fmt, "BindInterpreter {{")?;
         writeln!(fmt, "  Location: {:#x}..{:#x}", self.location.start, self.location.end)?;
         writeln!(fmt, "  Lazy Location: {:#x}..{:#x}", self.lazy_location.start, self.lazy_location.end)?;
+        writeln!(fmt, "  Weak Location: {:#x}..{:#x}", self.weak_location.start, self.weak_location.end)?;
         writeln!(fmt, "}}")
     }
 }
@@ -128,149 +205,873 @@ impl<'a> Debug for BindInterpreter<'a> {
 
 impl<'a> BindInterpreter<'a> {
     /// Construct a new import binding interpreter from `bytes` and the load `command`
-    pub fn new(bytes: &'a [u8], command: &load_command::DyldInfoCommand) -> Self {
-        let get_pos = |off: u32, size: u32| -> Range<usize> {
-            off as usize..(off + size) as usize
+    pub fn new(bytes: &'a [u8], command: &load_command::DyldInfoCommand) -> error::Result<Self> {
+        Self::with_options(bytes, command, &ParseOptions::default())
+    }
+    /// Construct a new import binding interpreter from `bytes` and the load `command`, bounded by `opts`;
+    /// fails if any of the bind opcode streams of `command` lies outside of `bytes`
+    pub fn with_options(bytes: &'a [u8], command: &load_command::DyldInfoCommand, opts: &ParseOptions) -> error::Result<Self> {
+        let get_pos = |name: &str, off: u32, size: u32| -> error::Result<Range<usize>> {
+            match off.checked_add(size) {
+                Some(end) if end as usize <= bytes.len() => Ok(off as usize..end as usize),
+                // an empty stream is never read
+                _ if size == 0 => Ok(0..0),
+                _ => Err(error::Error::Malformed(format!("{}_off {:#x} with {}_size {:#x} lies outside of the {:#x} byte binary", name, off, name, size, bytes.len()))),
+            }
         };
-        let location = get_pos(command.bind_off, command.bind_size);
-        let lazy_location = get_pos(command.lazy_bind_off, command.lazy_bind_size);
-        BindInterpreter {
+        let location = get_pos("bind", command.bind_off, command.bind_size)?;
+        let lazy_location = get_pos("lazy_bind", command.lazy_bind_off, command.lazy_bind_size)?;
+        let weak_location = get_pos("weak_bind", command.weak_bind_off, command.weak_bind_size)?;
+        Ok(BindInterpreter {
             data: bytes.as_ref(),
             location: location,
             lazy_location: lazy_location,
-        }
+            weak_location: weak_location,
+            max_imports: opts.max_imports,
+        })
     }
     /// Return the imports in this binary
-    pub fn imports(&self, libs: &[&'a str], segments: &[segment::Segment], ctx: &container::Ctx) -> error::Result<Vec<Import<'a>>>{
+    pub fn imports(&self, libs: &[&'a str], segments: &[segment::Segment<'a>], ctx: &container::Ctx) -> error::Result<Vec<Import<'a>>>{
+        self.imports_with_opts(libs, segments, ctx, &ImportOptions::default())
+    }
+    /// Return the imports in this binary selected by `opts`
+    pub fn imports_with_opts(&self, libs: &[&'a str], segments: &[segment::Segment<'a>], ctx: &container::Ctx, opts: &ImportOptions) -> error::Result<Vec<Import<'a>>>{
+        let kinds = if opts.lazy { NON_LAZY_AND_LAZY } else { NON_LAZY };
+        let mut imports = Vec::new();
+        self.for_each_import_of(kinds, libs, segments, ctx, |import| {
+            imports.push(import);
+            Ok(())
+        })?;
+        if opts.dedup {
+            let mut seen = BTreeSet::new();
+            imports.retain(|import| seen.insert((import.name, import.dylib)));
+        }
+        if opts.sorted {
+            imports.sort_by(|a, b| (a.name, a.dylib, a.address).cmp(&(b.name, b.dylib, b.address)));
+        }
+        Ok(imports)
+    }
+    /// Return the weak imports in this binary, i.e., the symbols dyld coalesces by name across every loaded image, like the C++ inline functions and template instantiations;
+    /// as they come from no particular library, their `dylib` is always `"weak"`
+    pub fn weak_imports(&self, libs: &[&'a str], segments: &[segment::Segment<'a>], ctx: &container::Ctx) -> error::Result<Vec<Import<'a>>>{
         let mut imports = Vec::new();
-        self.run(false, libs, segments, ctx, &mut imports)?;
-        self.run( true, libs, segments, ctx, &mut imports)?;
+        self.run(BindKind::Weak, libs, segments, ctx, &mut imports)?;
         Ok(imports)
     }
-    fn run(&self, is_lazy: bool, libs: &[&'a str], segments: &[segment::Segment], ctx: &container::Ctx, imports: &mut Vec<Import<'a>>) -> error::Result<()>{
-        use mach::bind_opcodes::*;
-        let location = if is_lazy {
-            &self.lazy_location
-        } else {
-            &self.location
+    /// Iterates over the imports in this binary one bind at a time, running the non-lazy and then the lazy bind opcodes as the iterator is driven, rather than collecting them all up front;
+    /// every call starts a fresh, independent walk of the opcode streams
+    pub fn iter_imports<'b>(&'b self, libs: &'b [&'a str], segments: &'b [segment::Segment<'a>], ctx: &container::Ctx) -> ImportIterator<'a, 'b> {
+        ImportIterator::new(self, NON_LAZY_AND_LAZY, libs, segments, *ctx)
+    }
+    /// Runs the non-lazy and then the lazy bind opcodes, calling `f` with each import as it is bound, without collecting the imports;
+    /// the first error, whether from the opcodes or returned by `f`, stops the run and is returned, so `f` can also end it early.
+    ///
+    /// No import is allocated, so a `no_std` build with `alloc` can scan a binary of any size this way
+    pub fn for_each_import<F: FnMut(Import<'a>) -> error::Result<()>>(&self, libs: &[&'a str], segments: &[segment::Segment<'a>], ctx: &container::Ctx, f: F) -> error::Result<()> {
+        self.for_each_import_of(NON_LAZY_AND_LAZY, libs, segments, ctx, f)
+    }
+    /// Decodes the non-lazy, or if `is_lazy`, the lazy bind opcode stream without running it, yielding each opcode with its file offset, e.g., for a `dyldinfo -opcodes` style dump
+    pub fn opcodes(&self, is_lazy: bool) -> BindOpcodes<'a> {
+        let location = if is_lazy { &self.lazy_location } else { &self.location };
+        BindOpcodes::new(self.data, location.clone())
+    }
+    fn run(&self, kind: BindKind, libs: &[&'a str], segments: &[segment::Segment<'a>], ctx: &container::Ctx, imports: &mut Vec<Import<'a>>) -> error::Result<()>{
+        let kinds = match kind {
+            BindKind::NonLazy => NON_LAZY,
+            BindKind::Lazy => LAZY,
+            BindKind::Weak => WEAK,
+        };
+        self.for_each_import_of(kinds, libs, segments, ctx, |import| {
+            imports.push(import);
+            Ok(())
+        })
+    }
+    fn for_each_import_of<F: FnMut(Import<'a>) -> error::Result<()>>(&self, kinds: &'static [BindKind], libs: &[&'a str], segments: &[segment::Segment<'a>], ctx: &container::Ctx, mut f: F) -> error::Result<()> {
+        for import in ImportIterator::new(self, kinds, libs, segments, *ctx) {
+            f(import?)?;
+        }
+        Ok(())
+    }
+}
+
+const NON_LAZY: &'static [BindKind] = &[BindKind::NonLazy];
+const LAZY: &'static [BindKind] = &[BindKind::Lazy];
+const WEAK: &'static [BindKind] = &[BindKind::Weak];
+const NON_LAZY_AND_LAZY: &'static [BindKind] = &[BindKind::NonLazy, BindKind::Lazy];
+
+#[derive(Debug, Copy, Clone)]
+/// The binds an opcode has yet to produce, which `ImportIterator` yields before reading the next opcode
+enum Pending {
+    Idle,
+    /// The `count` binds left of a `BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB`, `skip` bytes apart
+    Repeat { count: u64, skip: u64 },
+    /// The next pointer of a `BIND_SUBOPCODE_THREADED_APPLY` chain
+    Threaded { seg_offset: u64 },
+}
+
+/// Counts another import, unless that would exceed the maximum number of imports
+fn count_import(count: &mut usize, max_imports: usize) -> error::Result<()> {
+    if *count >= max_imports {
+        return Err(error::Error::Malformed(format!("bind opcodes bind more than the maximum of {} imports", max_imports)));
+    }
+    *count += 1;
+    Ok(())
+}
+
+/// Moves the bind address at `seg_offset` forward by `delta`, which comes straight from the opcodes
+fn advance(seg_offset: u64, delta: u64) -> error::Result<u64> {
+    seg_offset.checked_add(delta).ok_or_else(|| error::Error::Malformed(format!("bind address {:#x} plus {:#x} overflows", seg_offset, delta)))
+}
+
+#[derive(Debug)]
+/// An iterator over the imports of a `BindInterpreter`, which runs the bind opcodes lazily, one bind at a time; see `BindInterpreter::iter_imports`.
+/// After the first error, the iterator is exhausted
+pub struct ImportIterator<'a: 'b, 'b> {
+    interpreter: &'b BindInterpreter<'a>,
+    libs: &'b [&'a str],
+    segments: &'b [segment::Segment<'a>],
+    ctx: container::Ctx,
+    /// The opcode streams left to run after the current one
+    kinds: &'static [BindKind],
+    kind: BindKind,
+    location: Range<usize>,
+    offset: usize,
+    bind_info: BindInformation<'a>,
+    /// The binds of `BIND_OPCODE_THREADED`, which the threaded rebase and bind chains refer to by their index, and the declared size of the table
+    ordinal_table: Option<(usize, Vec<BindInformation<'a>>)>,
+    start_of_sequence: usize,
+    pending: Pending,
+    count: usize,
+    done: bool,
+}
+
+impl<'a, 'b> ImportIterator<'a, 'b> {
+    fn new(interpreter: &'b BindInterpreter<'a>, kinds: &'static [BindKind], libs: &'b [&'a str], segments: &'b [segment::Segment<'a>], ctx: container::Ctx) -> Self {
+        ImportIterator {
+            interpreter: interpreter,
+            libs: libs,
+            segments: segments,
+            ctx: ctx,
+            kinds: kinds,
+            kind: BindKind::NonLazy,
+            // empty, so that the first call to `next` starts the first stream
+            location: 0..0,
+            offset: 0,
+            bind_info: BindInformation::new(BindKind::NonLazy),
+            ordinal_table: None,
+            start_of_sequence: 0,
+            pending: Pending::Idle,
+            count: 0,
+            done: false,
+        }
+    }
+    /// Starts running the opcode stream of `kind`
+    fn start(&mut self, kind: BindKind) {
+        let location = match kind {
+            BindKind::NonLazy => &self.interpreter.location,
+            BindKind::Lazy => &self.interpreter.lazy_location,
+            BindKind::Weak => &self.interpreter.weak_location,
+        };
+        self.kind = kind;
+        self.location = location.clone();
+        self.offset = location.start;
+        self.bind_info = BindInformation::new(kind);
+        self.ordinal_table = None;
+        self.start_of_sequence = 0;
+    }
+    /// Follows the threaded chain to the pointer at `seg_offset`, returning the import if it is a bind rather than a rebase
+    fn step_threaded(&mut self, seg_offset: u64) -> error::Result<Option<Import<'a>>> {
+        let seg_index = self.bind_info.seg_index as usize;
+        let vmsize = match self.segments.get(seg_index) {
+            Some(segment) => segment.vmsize,
+            None => return Err(error::Error::Malformed(format!("bind opcodes reference segment {} but there are only {} segments", seg_index, self.segments.len()))),
+        };
+        // every step moves forward, so staying inside the segment bounds the walk
+        if seg_offset >= vmsize {
+            return Err(error::Error::Malformed(format!("threaded bind chain runs past the end of segment {} at offset {:#x}", seg_index, seg_offset)));
+        }
+        let (file_offset, _) = segment::resolve(self.segments, seg_index, seg_offset)?;
+        let value: u64 = self.interpreter.data.pread_with(file_offset as usize, self.ctx.le)?;
+        // bits 51..62 are the distance to the next pointer in the chain, in pointer strides; 0 ends the chain
+        let delta = (value >> 51) & 0x7ff;
+        self.pending = if delta == 0 { Pending::Idle } else { Pending::Threaded { seg_offset: advance(seg_offset, delta * 8)? } };
+        // bit 62 tells binds from rebases, and the low 16 bits of a bind are its index in the ordinal table
+        if value & (1 << 62) == 0 {
+            return Ok(None);
+        }
+        let ordinal = (value & 0xffff) as usize;
+        let mut bind = match self.ordinal_table {
+            Some((_, ref table)) => match table.get(ordinal) {
+                Some(bind) => bind.clone(),
+                None => return Err(error::Error::Malformed(format!("threaded bind at {:#x} uses ordinal {} but the ordinal table has only {} entries", file_offset, ordinal, table.len()))),
+            },
+            None => return Err(error::Error::Malformed(format!("threaded bind at {:#x} without an ordinal table", file_offset))),
         };
-        let mut bind_info = BindInformation::new(is_lazy);
-        let offset = &mut location.start.clone();
-        let mut start_of_sequence: usize = 0;
-        while *offset < location.end {
-            let opcode = self.data.gread::<i8>(offset)? as bind_opcodes::Opcode;
-            // let mut input = String::new();
-            // ::std::io::stdin().read_line(&mut input).unwrap();
-            // println!("opcode: {} ({:#x}) offset: {:#x}\n {:?}", opcode_to_str(opcode & BIND_OPCODE_MASK), opcode, *offset - location.start - 1, &bind_info);
-            match opcode & BIND_OPCODE_MASK {
+        bind.seg_index = seg_index as u8;
+        bind.seg_offset = seg_offset;
+        count_import(&mut self.count, self.interpreter.max_imports)?;
+        Ok(Some(Import::new(&bind, self.libs, self.segments, self.start_of_sequence)?))
+    }
+    /// Runs the opcodes up to the next bind
+    fn next_import(&mut self) -> error::Result<Option<Import<'a>>> {
+        loop {
+            match self.pending {
+                Pending::Repeat { count, skip } => {
+                    self.pending = if count > 1 { Pending::Repeat { count: count - 1, skip: skip } } else { Pending::Idle };
+                    count_import(&mut self.count, self.interpreter.max_imports)?;
+                    let import = Import::new(&self.bind_info, self.libs, self.segments, self.start_of_sequence)?;
+                    self.bind_info.seg_offset = advance(self.bind_info.seg_offset, skip)?;
+                    return Ok(Some(import));
+                },
+                Pending::Threaded { seg_offset } => {
+                    match self.step_threaded(seg_offset)? {
+                        Some(import) => return Ok(Some(import)),
+                        None => continue,
+                    }
+                },
+                Pending::Idle => (),
+            }
+            if self.offset >= self.location.end {
+                // this stream is done, so move on to the next one, if any
+                let (kind, kinds) = match self.kinds.split_first() {
+                    Some((kind, kinds)) => (*kind, kinds),
+                    None => return Ok(None),
+                };
+                self.kinds = kinds;
+                self.start(kind);
+                continue;
+            }
+            let ctx = self.ctx;
+            let start = self.offset;
+            let opcode = BindOpcode::parse(self.interpreter.data, &mut self.offset)?;
+            // println!("opcode: {:?} offset: {:#x}\n {:?}", opcode, start - self.location.start, &self.bind_info);
+            match opcode {
                 // we do nothing, don't update our records, and add a new, fresh record
-                BIND_OPCODE_DONE => {
-                    bind_info = BindInformation::new(is_lazy);
-                    start_of_sequence = *offset - location.start;
+                BindOpcode::Done => {
+                    self.bind_info = BindInformation::new(self.kind);
+                    self.start_of_sequence = self.offset - self.location.start;
                 },
-                BIND_OPCODE_SET_DYLIB_ORDINAL_IMM => {
-	            let symbol_library_ordinal = opcode & BIND_IMMEDIATE_MASK;
-	            bind_info.symbol_library_ordinal = symbol_library_ordinal;
+                BindOpcode::SetDylibOrdinalImm(symbol_library_ordinal) => {
+                    self.bind_info.symbol_library_ordinal = symbol_library_ordinal as u64;
+                    self.bind_info.special_dylib = None;
                 },
-                BIND_OPCODE_SET_DYLIB_ORDINAL_ULEB => {
-	            let symbol_library_ordinal = Uleb128::read(&self.data, offset)?;
-	            bind_info.symbol_library_ordinal = symbol_library_ordinal as u8;
+                BindOpcode::SetDylibOrdinalUleb(symbol_library_ordinal) => {
+                    self.bind_info.symbol_library_ordinal = symbol_library_ordinal;
+                    self.bind_info.special_dylib = None;
                 },
-                BIND_OPCODE_SET_DYLIB_SPECIAL_IMM => {
+                BindOpcode::SetDylibSpecialImm(special_dylib) => {
                     // dyld puts the immediate into the symbol_library_ordinal field...
-                    let special_dylib = opcode & BIND_IMMEDIATE_MASK;
-                    // Printf.printf "special_dylib: 0x%x\n" special_dylib
-                    bind_info.special_dylib = special_dylib;
+                    self.bind_info.special_dylib = Some(special_dylib);
                 },
-                BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM => {
-	            let symbol_flags = opcode & BIND_IMMEDIATE_MASK;
-	            let symbol_name = self.data.pread::<&str>(*offset)?;
-                    *offset = *offset + symbol_name.len() + 1; // second time this \0 caused debug woes
-	            bind_info.symbol_name = symbol_name;
-                    bind_info.symbol_flags = symbol_flags;
+                BindOpcode::SetSymbolTrailingFlagsImm { flags, name } => {
+                    self.bind_info.symbol_name = name;
+                    self.bind_info.symbol_flags = flags;
                 },
-                BIND_OPCODE_SET_TYPE_IMM => {
-	            let bind_type = opcode & BIND_IMMEDIATE_MASK;
-	            bind_info.bind_type = bind_type;
+                BindOpcode::SetTypeImm(bind_type) => {
+                    self.bind_info.bind_type = bind_type;
                 },
-                BIND_OPCODE_SET_ADDEND_SLEB => {
-                    let addend = Sleb128::read(&self.data, offset)?;
-                    bind_info.addend = addend;
+                BindOpcode::SetAddendSleb(addend) => {
+                    self.bind_info.addend = addend;
                 },
-                BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB => {
-	            let seg_index = opcode & BIND_IMMEDIATE_MASK;
+                BindOpcode::SetSegmentAndOffsetUleb { segment, offset } => {
                     // dyld sets the address to the segActualLoadAddress(segIndex) + uleb128
                     // address = segActualLoadAddress(segmentIndex) + read_uleb128(p, end);
-	            let seg_offset = Uleb128::read(&self.data, offset)?;
-	            bind_info.seg_index = seg_index;
-                    bind_info.seg_offset = seg_offset;
+                    self.bind_info.seg_index = segment;
+                    self.bind_info.seg_offset = offset;
                 },
-                BIND_OPCODE_ADD_ADDR_ULEB => {
-	            let addr = Uleb128::read(&self.data, offset)?;
-	            let seg_offset = bind_info.seg_offset.wrapping_add(addr);
-	            bind_info.seg_offset = seg_offset;
+                BindOpcode::AddAddrUleb(addr) => {
+                    self.bind_info.seg_offset = advance(self.bind_info.seg_offset, addr)?;
                 },
                 // record the record by placing its value into our list
-                BIND_OPCODE_DO_BIND => {
+                BindOpcode::DoBind => {
                     // from dyld:
                     //      if ( address >= segmentEndAddress ) 
-	            // throwBadBindingAddress(address, segmentEndAddress, segmentIndex, start, end, p);
-	            // (this->*handler)(context, address, type, symbolName, symboFlags, addend, libraryOrdinal, "", &last);
-	            // address += sizeof(intptr_t);
-                    imports.push(Import::new(&bind_info, libs, segments, start_of_sequence));
-                    let seg_offset = bind_info.seg_offset.wrapping_add(ctx.size() as u64);
-                    bind_info.seg_offset = seg_offset;
+                    // throwBadBindingAddress(address, segmentEndAddress, segmentIndex, start, end, p);
+                    // (this->*handler)(context, address, type, symbolName, symboFlags, addend, libraryOrdinal, "", &last);
+                    // address += sizeof(intptr_t);
+                    if let Some((size, ref mut table)) = self.ordinal_table {
+                        // with threaded binds, this only declares the next entry of the ordinal table
+                        if table.len() >= size {
+                            return Err(error::Error::Malformed(format!("bind opcodes declare more threaded binds than the ordinal table size of {}", size)));
+                        }
+                        table.push(self.bind_info.clone());
+                        continue;
+                    }
+                    count_import(&mut self.count, self.interpreter.max_imports)?;
+                    let import = Import::new(&self.bind_info, self.libs, self.segments, self.start_of_sequence)?;
+                    self.bind_info.seg_offset = advance(self.bind_info.seg_offset, ctx.size() as u64)?;
+                    return Ok(Some(import));
                 },
-                BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB => {
+                BindOpcode::DoBindAddAddrUleb(addr) => {
                     // dyld:
-	            // if ( address >= segmentEndAddress ) 
-	            // throwBadBindingAddress(address, segmentEndAddress, segmentIndex, start, end, p);
-	            // (this->*handler)(context, address, type, symbolName, symboFlags, addend, libraryOrdinal, "", &last);
-	            // address += read_uleb128(p, end) + sizeof(intptr_t);
+                    // if ( address >= segmentEndAddress ) 
+                    // throwBadBindingAddress(address, segmentEndAddress, segmentIndex, start, end, p);
+                    // (this->*handler)(context, address, type, symbolName, symboFlags, addend, libraryOrdinal, "", &last);
+                    // address += read_uleb128(p, end) + sizeof(intptr_t);
                     // we bind the old record, then increment bind info address for the next guy, plus the ptr offset *)
-                    imports.push(Import::new(&bind_info, libs, segments, start_of_sequence));
-                    let addr = Uleb128::read(&self.data, offset)?;
-                    let seg_offset = bind_info.seg_offset.wrapping_add(addr).wrapping_add(ctx.size() as u64);
-                    bind_info.seg_offset = seg_offset;
+                    count_import(&mut self.count, self.interpreter.max_imports)?;
+                    let import = Import::new(&self.bind_info, self.libs, self.segments, self.start_of_sequence)?;
+                    self.bind_info.seg_offset = advance(advance(self.bind_info.seg_offset, addr)?, ctx.size() as u64)?;
+                    return Ok(Some(import));
                 },
-                BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED => {
-                    // dyld:				
+                BindOpcode::DoBindAddAddrImmScaled(scale) => {
+                    // dyld:
                     // if ( address >= segmentEndAddress ) 
-	            // throwBadBindingAddress(address, segmentEndAddress, segmentIndex, start, end, p);
-	            // (this->*handler)(context, address, type, symbolName, symboFlags, addend, libraryOrdinal, "", &last);
-	            // address += immediate*sizeof(intptr_t) + sizeof(intptr_t);
-	            // break;
+                    // throwBadBindingAddress(address, segmentEndAddress, segmentIndex, start, end, p);
+                    // (this->*handler)(context, address, type, symbolName, symboFlags, addend, libraryOrdinal, "", &last);
+                    // address += immediate*sizeof(intptr_t) + sizeof(intptr_t);
+                    // break;
                     // similarly, we bind the old record, then perform address manipulation for the next record
-                    imports.push(Import::new(&bind_info, libs, segments, start_of_sequence));
-	            let scale = opcode & BIND_IMMEDIATE_MASK;
+                    count_import(&mut self.count, self.interpreter.max_imports)?;
+                    let import = Import::new(&self.bind_info, self.libs, self.segments, self.start_of_sequence)?;
                     let size = ctx.size() as u64;
-                    let seg_offset = bind_info.seg_offset.wrapping_add(scale as u64 * size).wrapping_add(size);
-                    bind_info.seg_offset = seg_offset;
+                    self.bind_info.seg_offset = advance(self.bind_info.seg_offset, scale as u64 * size + size)?;
+                    return Ok(Some(import));
                 },
-                BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB => {
+                BindOpcode::DoBindUlebTimesSkippingUleb { count, skip } => {
                     // dyld:
                     // count = read_uleb128(p, end);
-	            // skip = read_uleb128(p, end);
-	            // for (uint32_t i=0; i < count; ++i) {
-	            // if ( address >= segmentEndAddress ) 
-	            // throwBadBindingAddress(address, segmentEndAddress, segmentIndex, start, end, p);
-	            // (this->*handler)(context, address, type, symbolName, symboFlags, addend, libraryOrdinal, "", &last);
-	            // address += skip + sizeof(intptr_t);
-	            // }
-	            // break;
-                    let count = Uleb128::read(&self.data, offset)?;
-                    let skip =  Uleb128::read(&self.data, offset)?;
-                    let skip_plus_size = skip + ctx.size() as u64;
-                    for _i  in 0..count {
-                        imports.push(Import::new(&bind_info, libs, segments, start_of_sequence));
-                        let seg_offset = bind_info.seg_offset.wrapping_add(skip_plus_size);
-                        bind_info.seg_offset = seg_offset;
+                    // skip = read_uleb128(p, end);
+                    // for (uint32_t i=0; i < count; ++i) {
+                    // if ( address >= segmentEndAddress ) 
+                    // throwBadBindingAddress(address, segmentEndAddress, segmentIndex, start, end, p);
+                    // (this->*handler)(context, address, type, symbolName, symboFlags, addend, libraryOrdinal, "", &last);
+                    // address += skip + sizeof(intptr_t);
+                    // }
+                    // break;
+                    let skip_plus_size = skip.saturating_add(ctx.size() as u64);
+                    // `count` comes straight from the file, so make sure every bind lands inside the segment before looping on it
+                    let seg_index = self.bind_info.seg_index;
+                    let vmsize = match self.segments.get(seg_index as usize) {
+                        Some(segment) => segment.vmsize,
+                        None => return Err(error::Error::Malformed(format!("bind opcodes reference segment {} but there are only {} segments", seg_index, self.segments.len()))),
+                    };
+                    let remaining = vmsize.saturating_sub(self.bind_info.seg_offset);
+                    let max_count = remaining / skip_plus_size + if remaining % skip_plus_size != 0 { 1 } else { 0 };
+                    if count > max_count {
+                        return Err(error::Error::Malformed(format!("bind opcodes bind {} imports every {:#x} bytes from offset {:#x}, past the end of segment {} ({:#x} bytes)", count, skip_plus_size, self.bind_info.seg_offset, seg_index, vmsize)));
+                    }
+                    if count > 0 {
+                        self.pending = Pending::Repeat { count: count, skip: skip_plus_size };
+                    }
+                },
+                BindOpcode::ThreadedSetBindOrdinalTableSizeUleb(size) => {
+                    if size > self.interpreter.max_imports as u64 {
+                        return Err(error::Error::Malformed(format!("bind opcodes declare {} threaded binds, more than the maximum of {} imports", size, self.interpreter.max_imports)));
                     }
+                    self.ordinal_table = Some((size as usize, Vec::with_capacity(size as usize)));
                 },
-                _ => {
+                BindOpcode::ThreadedApply => {
+                    if self.ordinal_table.is_none() {
+                        return Err(error::Error::Malformed(format!("bind opcodes apply threaded binds at {:#x} without an ordinal table", start)));
+                    }
+                    self.pending = Pending::Threaded { seg_offset: self.bind_info.seg_offset };
+                },
+                BindOpcode::Unknown(_) => {
                 }
             }
-        }        
-        Ok(())
+        }
+    }
+}
+
+impl<'a, 'b> Iterator for ImportIterator<'a, 'b> {
+    type Item = error::Result<Import<'a>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_import() {
+            Ok(Some(import)) => Some(Ok(import)),
+            Ok(None) => {
+                self.done = true;
+                None
+            },
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mach::bind_opcodes::*;
+    use container::{Container, Ctx};
+    use scroll::Pwrite;
+
+    const LIBS: [&'static str; 3] = ["self", "/usr/lib/libSystem.B.dylib", "/usr/lib/libc++.1.dylib"];
+
+    /// A `__TEXT` and `__DATA` segment, laid out like a typical 64-bit executable
+    fn segments(ctx: Ctx) -> Vec<segment::Segment<'static>> {
+        let mut text = segment::Segment::new(ctx, &[]);
+        text.vmaddr = 0x1_0000_0000;
+        text.vmsize = 0x1000;
+        let mut data = segment::Segment::new(ctx, &[]);
+        data.vmaddr = 0x1_0000_1000;
+        data.vmsize = 0x1000;
+        data.fileoff = 0x1000;
+        data.filesize = 0x1000;
+        vec![text, data]
+    }
+
+    /// An interpreter of the bind, lazy bind and weak bind opcodes at `bind`, `lazy` and `weak` in `data`
+    fn bind_interpreter<'a>(data: &'a [u8], bind: Range<usize>, lazy: Range<usize>, weak: Range<usize>) -> BindInterpreter<'a> {
+        BindInterpreter { data: data, location: bind, lazy_location: lazy, weak_location: weak, max_imports: ::options::DEFAULT_MAX_IMPORTS }
+    }
+
+    /// Runs the interpreter over a hand-built `bind` and `lazy_bind` opcode stream
+    fn interpret<'a>(bind: &'a [u8], lazy_bind: &'a [u8]) -> Vec<Import<'a>> {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let mut imports = Vec::new();
+        let interpreter = bind_interpreter(bind, 0..bind.len(), 0..0, 0..0);
+        interpreter.run(BindKind::NonLazy, &LIBS, &segments, &ctx, &mut imports).unwrap();
+        let interpreter = bind_interpreter(lazy_bind, 0..0, 0..lazy_bind.len(), 0..0);
+        interpreter.run(BindKind::Lazy, &LIBS, &segments, &ctx, &mut imports).unwrap();
+        imports
+    }
+
+    #[test]
+    fn bind_and_lazy_bind() {
+        let bind = [
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'f', b'o', b'o', 0,
+            BIND_OPCODE_SET_TYPE_IMM | BIND_TYPE_POINTER,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x10,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM | BIND_SYMBOL_FLAGS_WEAK_IMPORT, b'_', b'b', b'a', b'r', 0,
+            BIND_OPCODE_SET_ADDEND_SLEB, 0x7f,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let lazy_bind = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x20,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 2,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'b', b'a', b'z', 0,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let imports = interpret(&bind, &lazy_bind);
+        let summary = imports.iter().map(|import| (import.name, import.dylib, import.is_lazy, import.offset, import.address)).collect::<Vec<_>>();
+        assert_eq!(summary, vec![
+            ("_foo", LIBS[1], false, 0x1010, 0x1_0000_1010),
+            ("_bar", LIBS[1], false, 0x1018, 0x1_0000_1018),
+            ("_baz", LIBS[2], true, 0x1020, 0x1_0000_1020),
+        ]);
+        assert!(!imports[0].is_weak);
+        assert!(imports[1].is_weak);
+        assert_eq!(imports[0].addend, 0);
+        assert_eq!(imports[1].addend, -1);
+        assert_eq!(imports[2].addend, 0);
+        assert_eq!(imports[2].size, 8);
+        assert_eq!(imports[1].symbol_flags, BIND_SYMBOL_FLAGS_WEAK_IMPORT);
+        assert_eq!(imports[2].bind_type, BIND_TYPE_POINTER);
+    }
+
+    #[test]
+    fn for_each_import() {
+        let bind = [
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'f', b'o', b'o', 0,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x10,
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 3, 0,
+            BIND_OPCODE_DONE,
+        ];
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let interpreter = bind_interpreter(&bind, 0..bind.len(), 0..0, 0..0);
+        let mut addresses = Vec::new();
+        interpreter.for_each_import(&LIBS, &segments, &ctx, |import| {
+            addresses.push(import.address);
+            Ok(())
+        }).unwrap();
+        assert_eq!(addresses, vec![0x1_0000_1010, 0x1_0000_1018, 0x1_0000_1020]);
+        // an error from the callback stops the run
+        let mut count = 0;
+        let result = interpreter.for_each_import(&LIBS, &segments, &ctx, |_| {
+            count += 1;
+            if count == 2 { Err(error::Error::Malformed("enough".to_string())) } else { Ok(()) }
+        });
+        assert!(result.is_err());
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn bind_info_bounds() {
+        let bytes = [BIND_OPCODE_DONE; 0x20];
+        let mut command = load_command::DyldInfoCommand { bind_off: 0x10, bind_size: 0x10, lazy_bind_off: 0x20, .. Default::default() };
+        let interpreter = BindInterpreter::new(&bytes, &command).unwrap();
+        assert_eq!((interpreter.location, interpreter.lazy_location), (0x10..0x20, 0x20..0x20));
+        // an empty stream may be anywhere
+        command.weak_bind_off = ::core::u32::MAX;
+        assert!(BindInterpreter::new(&bytes, &command).is_ok());
+        command.lazy_bind_size = 1;
+        assert!(BindInterpreter::new(&bytes, &command).is_err());
+        // the end mustn't overflow
+        command.lazy_bind_size = 0;
+        command.bind_off = ::core::u32::MAX;
+        match BindInterpreter::new(&bytes, &command) {
+            Err(error::Error::Malformed(message)) => assert!(message.starts_with("bind_off 0xffffffff"), "{}", message),
+            _ => panic!("bind_off past the end"),
+        }
+    }
+
+    #[test]
+    fn import_sections() {
+        use mach::load_command::{SegmentCommand64, LC_SEGMENT_64, SIZEOF_SEGMENT_COMMAND_64};
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        // the `__DATA` of `segments`, with a `__got`, then, past some padding, a `__la_symbol_ptr`
+        let name = |name: &str| {
+            let mut bytes = [0u8; 16];
+            bytes[..name.len()].copy_from_slice(name.as_bytes());
+            bytes
+        };
+        let mut bytes = vec![0u8; 0x2000];
+        let mut offset = SIZEOF_SEGMENT_COMMAND_64;
+        for &(sectname, addr) in [("__got", 0x1_0000_1000), ("__la_symbol_ptr", 0x1_0000_1020)].iter() {
+            let section = segment::Section { sectname: name(sectname), segname: name("__DATA"), addr, size: 0x10, .. Default::default() };
+            bytes.gwrite_with(section, &mut offset, ctx).unwrap();
+        }
+        let command = SegmentCommand64 {
+            cmd: LC_SEGMENT_64, cmdsize: offset as u32, segname: name("__DATA"), vmaddr: 0x1_0000_1000, vmsize: 0x1000, fileoff: 0x1000, filesize: 0x1000,
+            maxprot: 3, initprot: 3, nsects: 2, flags: 0,
+        };
+        let mut segments = segments(ctx);
+        segments[1] = segment::Segment::from_64(&bytes, &command, 0, ctx).unwrap();
+        let bind = [
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'f', b'o', b'o', 0,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x08,
+            BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB, 0x08,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let lazy_bind = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x28,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'b', b'a', b'r', 0,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let interpreter = bind_interpreter(&bind, 0..bind.len(), 0..0, 0..0);
+        let mut imports = interpreter.imports(&LIBS, &segments, &ctx).unwrap();
+        let interpreter = bind_interpreter(&lazy_bind, 0..0, 0..lazy_bind.len(), 0..0);
+        imports.extend(interpreter.imports(&LIBS, &segments, &ctx).unwrap());
+        let sections = imports.iter().map(|import| (import.address, import.section)).collect::<Vec<_>>();
+        assert_eq!(sections, vec![
+            (0x1_0000_1008, Some(("__DATA", "__got"))),
+            // the padding between the sections
+            (0x1_0000_1018, None),
+            (0x1_0000_1028, Some(("__DATA", "__la_symbol_ptr"))),
+        ]);
+        assert_eq!(segments[1].section_names(1).unwrap(), ("__DATA", "__la_symbol_ptr"));
+        assert!(segments[1].section_names(2).is_err());
+        // the original `segments` have no sections at all
+        assert!(interpret(&bind, &lazy_bind).iter().all(|import| import.section.is_none()));
+    }
+
+    #[test]
+    fn weak_binds() {
+        let weak_bind = [
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'w', 0,
+            BIND_OPCODE_SET_TYPE_IMM | BIND_TYPE_POINTER,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x8,
+            BIND_OPCODE_DO_BIND,
+            // a strong definition of `_s` in this image, which only overrides the weak ones elsewhere
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM | BIND_SYMBOL_FLAGS_NON_WEAK_DEFINITION, b'_', b's', 0,
+            BIND_OPCODE_DONE,
+        ];
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let interpreter = bind_interpreter(&weak_bind, 0..0, 0..0, 0..weak_bind.len());
+        assert!(interpreter.imports(&LIBS, &segments, &ctx).unwrap().is_empty());
+        let imports = interpreter.weak_imports(&LIBS, &segments, &ctx).unwrap();
+        let summary = imports.iter().map(|import| (import.name, import.dylib, import.is_lazy, import.offset)).collect::<Vec<_>>();
+        assert_eq!(summary, vec![("_w", "weak", false, 0x1008)]);
+    }
+
+    #[test]
+    fn threaded_binds() {
+        let mut data = vec![
+            BIND_OPCODE_THREADED | BIND_SUBOPCODE_THREADED_SET_BIND_ORDINAL_TABLE_SIZE_ULEB, 0x2,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'a', 0,
+            BIND_OPCODE_SET_TYPE_IMM | BIND_TYPE_POINTER,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 2,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'b', 0,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_THREADED | BIND_SUBOPCODE_THREADED_APPLY,
+            BIND_OPCODE_DONE,
+        ];
+        let bind_size = data.len();
+        // the chain in `__DATA`: a bind of `_b`, a rebase 0x10 bytes on, then a bind of `_a` 0x8 bytes on, which ends it
+        data.resize(0x1000, 0);
+        for value in &[(1u64 << 62) | (2 << 51) | 1, 0, (1 << 51) | 0x1234, (1 << 62) | 0] {
+            data.extend_from_slice(&[0; 8]);
+            let at = data.len() - 8;
+            data.pwrite_with(*value, at, scroll::LE).unwrap();
+        }
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let interpreter = bind_interpreter(&data, 0..bind_size, 0..0, 0..0);
+        let imports = interpreter.imports(&LIBS, &segments, &ctx).unwrap();
+        let summary = imports.iter().map(|import| (import.name, import.dylib, import.offset)).collect::<Vec<_>>();
+        assert_eq!(summary, vec![("_b", LIBS[2], 0x1000), ("_a", LIBS[1], 0x1018)]);
+        // an ordinal past the end of the table
+        data.pwrite_with((1u64 << 62) | 2, 0x1018, scroll::LE).unwrap();
+        let interpreter = bind_interpreter(&data, 0..bind_size, 0..0, 0..0);
+        assert!(interpreter.imports(&LIBS, &segments, &ctx).is_err());
+    }
+
+    #[test]
+    fn iter_imports() {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let bind = [
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'a', 0,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0x2, 0x0,
+            // everything after this bind is broken
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 0xf, 0x0,
+            BIND_OPCODE_DO_BIND,
+        ];
+        let lazy_bind = [BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x20, BIND_OPCODE_DO_BIND];
+        let mut data = bind.to_vec();
+        data.extend_from_slice(&lazy_bind);
+        let interpreter = bind_interpreter(&data, 0..bind.len(), bind.len()..data.len(), 0..0);
+        let mut imports = interpreter.iter_imports(&LIBS, &segments, &ctx);
+        assert_eq!(imports.next().unwrap().unwrap().offset, 0x1000);
+        // a second walk is independent of the first
+        assert_eq!(interpreter.iter_imports(&LIBS, &segments, &ctx).next().unwrap().unwrap().offset, 0x1000);
+        assert_eq!(imports.next().unwrap().unwrap().offset, 0x1008);
+        assert!(imports.next().unwrap().is_err());
+        assert!(imports.next().is_none());
+        assert!(interpreter.imports(&LIBS, &segments, &ctx).is_err());
+    }
+
+    #[test]
+    fn special_dylibs() {
+        let bind = [
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'a', 0,
+            BIND_OPCODE_SET_TYPE_IMM | BIND_TYPE_TEXT_ABSOLUTE32,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_SET_DYLIB_SPECIAL_IMM | BIND_SPECIAL_DYLIB_FLAT_LOOKUP,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_SET_DYLIB_SPECIAL_IMM | BIND_SPECIAL_DYLIB_MAIN_EXECUTABLE,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_SET_DYLIB_SPECIAL_IMM | BIND_SPECIAL_DYLIB_SELF,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 2,
+            BIND_OPCODE_DO_BIND,
+            // ordinal 0 is this image too, while ordinal 1 is the first dylib
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 0,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_ULEB, 0x1,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_SET_DYLIB_SPECIAL_IMM | BIND_SPECIAL_DYLIB_WEAK_LOOKUP,
+            BIND_OPCODE_DO_BIND,
+            // an ordinal overrides the special dylib before it
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let imports = interpret(&bind, &[]);
+        let dylibs = imports.iter().map(|import| import.dylib).collect::<Vec<_>>();
+        assert_eq!(dylibs, vec!["flat-lookup", "main-executable", LIBS[0], LIBS[2], LIBS[0], LIBS[1], "weak", LIBS[1]]);
+        assert!(imports.iter().all(|import| import.bind_type == BIND_TYPE_TEXT_ABSOLUTE32));
+        // without even a `libs[0]`, this image can't be named
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let interpreter = bind_interpreter(&bind, 0..bind.len(), 0..0, 0..0);
+        assert!(interpreter.imports(&[], &segments, &ctx).is_err());
+    }
+
+    #[test]
+    fn wide_dylib_ordinal() {
+        let bind = [
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'a', 0,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            // ordinal 256, which doesn't fit the immediate, nor a byte
+            BIND_OPCODE_SET_DYLIB_ORDINAL_ULEB, 0x80, 0x02,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let names = (0..258).map(|i| format!("/usr/lib/lib{}.dylib", i)).collect::<Vec<_>>();
+        let libs = names.iter().map(|name| name.as_str()).collect::<Vec<_>>();
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let interpreter = bind_interpreter(&bind, 0..bind.len(), 0..0, 0..0);
+        assert_eq!(interpreter.imports(&libs, &segments, &ctx).unwrap()[0].dylib, "/usr/lib/lib256.dylib");
+        assert!(interpreter.imports(&libs[..256], &segments, &ctx).is_err());
+    }
+
+    #[test]
+    fn bind_address_arithmetic() {
+        let bind = [
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'x', 0,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            // bind at 0x0, then skip a pointer plus 0x8: next at 0x10
+            BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB, 0x8,
+            // bind at 0x10, then skip a pointer plus 2 more: next at 0x28
+            BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED | 2,
+            // bind at 0x28 and 0x38, skipping 0x8 after each
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0x2, 0x8,
+            // 0x48 + 0x78
+            BIND_OPCODE_ADD_ADDR_ULEB, 0x78,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let imports = interpret(&bind, &[]);
+        let offsets = imports.iter().map(|import| import.offset - 0x1000).collect::<Vec<_>>();
+        assert_eq!(offsets, vec![0x0, 0x10, 0x28, 0x38, 0xc0]);
+        assert!(imports.iter().all(|import| import.name == "_x" && !import.is_lazy));
+    }
+
+    #[test]
+    fn bind_uleb_times_skipping_uleb() {
+        let bind = [
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'x', 0,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x10,
+            // three binds, each followed by a pointer and 0x10 bytes of padding
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0x3, 0x10,
+            // the next bind starts after the last skip
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let imports = interpret(&bind, &[]);
+        let offsets = imports.iter().map(|import| import.offset - 0x1000).collect::<Vec<_>>();
+        assert_eq!(offsets, vec![0x10, 0x28, 0x40, 0x58]);
+    }
+
+    #[test]
+    fn lazy_records_reset_after_done() {
+        let lazy_bind = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'a', 0,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x8,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 2,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'b', 0,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let imports = interpret(&[], &lazy_bind);
+        assert_eq!(imports.len(), 2);
+        assert_eq!(imports[0].start_of_sequence_offset, 0);
+        // the second sequence starts right after the first `BIND_OPCODE_DONE`
+        assert_eq!(imports[1].start_of_sequence_offset, 9);
+        assert_eq!((imports[1].name, imports[1].dylib, imports[1].offset), ("_b", LIBS[2], 0x1008));
+    }
+
+    #[test]
+    fn bind_count_is_bounded() {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let run = |bind: &[u8], max_imports: usize| {
+            let mut imports = Vec::new();
+            let mut interpreter = bind_interpreter(bind, 0..bind.len(), 0..0, 0..0);
+            interpreter.max_imports = max_imports;
+            interpreter.run(BindKind::NonLazy, &LIBS, &segments, &ctx, &mut imports).map(|()| imports.len())
+        };
+        // 2^40 binds, 8 bytes apart, from the start of a 0x1000 byte segment
+        let huge = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0x80, 0x80, 0x80, 0x80, 0x80, 0x20, 0x0,
+        ];
+        assert!(run(&huge, ::options::DEFAULT_MAX_IMPORTS).is_err());
+        // u64::MAX binds must fail promptly, rather than loop (almost) forever
+        let endless = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x1, 0x0,
+        ];
+        assert!(run(&endless, ::core::usize::MAX).is_err());
+        // an address that wraps around would land back inside the segment
+        let wrapping = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x10,
+            BIND_OPCODE_ADD_ADDR_ULEB, 0xf8, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x1,
+            BIND_OPCODE_DO_BIND,
+        ];
+        assert!(run(&wrapping, ::options::DEFAULT_MAX_IMPORTS).is_err());
+        // exactly fills the segment
+        let fill = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0x80, 0x4, 0x0,
+        ];
+        assert_eq!(run(&fill, ::options::DEFAULT_MAX_IMPORTS).unwrap(), 0x200);
+        assert!(run(&fill, 0x1ff).is_err());
+        let missing_segment = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 2, 0x0,
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0x1, 0x0,
+        ];
+        assert!(run(&missing_segment, ::options::DEFAULT_MAX_IMPORTS).is_err());
+        // a plain bind into a missing segment is an error too, rather than a panic
+        let missing_segment = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 2, 0x0,
+            BIND_OPCODE_DO_BIND,
+        ];
+        assert!(run(&missing_segment, ::options::DEFAULT_MAX_IMPORTS).is_err());
+        let bogus_segment = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 0xf, 0x0,
+            BIND_OPCODE_DO_BIND,
+        ];
+        assert!(run(&bogus_segment, ::options::DEFAULT_MAX_IMPORTS).is_err());
+        let bogus_ordinal = [
+            BIND_OPCODE_SET_DYLIB_ORDINAL_ULEB, 0xff, 0x1,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_DO_BIND,
+        ];
+        assert!(run(&bogus_ordinal, ::options::DEFAULT_MAX_IMPORTS).is_err());
+        assert_eq!(segment::resolve(&segments, 1, 0x10).unwrap(), (0x1010, 0x1_0000_1010));
+    }
+
+    #[test]
+    fn import_options() {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let segments = segments(ctx);
+        let bind = [
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'f', b'o', b'o', 0,
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x0,
+            BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB, 0x3, 0x0,
+            BIND_OPCODE_DONE,
+        ];
+        let lazy_bind = [
+            BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x20,
+            BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 2,
+            BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM, b'_', b'b', b'a', b'r', 0,
+            BIND_OPCODE_DO_BIND,
+            BIND_OPCODE_DONE,
+        ];
+        let mut data = bind.to_vec();
+        data.extend_from_slice(&lazy_bind);
+        let interpreter = bind_interpreter(&data, 0..bind.len(), bind.len()..data.len(), 0..0);
+        let names = |opts: ImportOptions| interpreter.imports_with_opts(&LIBS, &segments, &ctx, &opts).unwrap().iter().map(|import| import.name).collect::<Vec<_>>();
+        assert_eq!(names(ImportOptions::default()), vec!["_foo", "_foo", "_foo", "_bar"]);
+        assert_eq!(names(ImportOptions { lazy: false, dedup: false, sorted: false }), vec!["_foo", "_foo", "_foo"]);
+        assert_eq!(names(ImportOptions { lazy: true, dedup: true, sorted: false }), vec!["_foo", "_bar"]);
+        assert_eq!(names(ImportOptions { lazy: true, dedup: false, sorted: true }), vec!["_bar", "_foo", "_foo", "_foo"]);
+    }
+
+    #[test]
+    fn opcodes() {
+        let bind = [BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1, BIND_OPCODE_DO_BIND, BIND_OPCODE_DONE];
+        let lazy_bind = [BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1, 0x20, BIND_OPCODE_DO_BIND];
+        let mut data = bind.to_vec();
+        data.extend_from_slice(&lazy_bind);
+        let interpreter = bind_interpreter(&data, 0..bind.len(), bind.len()..data.len(), 0..0);
+        let opcodes = interpreter.opcodes(false).collect::<error::Result<Vec<_>>>().unwrap();
+        assert_eq!(opcodes, vec![(0, BindOpcode::SetDylibOrdinalImm(1)), (1, BindOpcode::DoBind), (2, BindOpcode::Done)]);
+        // the offsets are file offsets, not offsets into the stream
+        let opcodes = interpreter.opcodes(true).collect::<error::Result<Vec<_>>>().unwrap();
+        assert_eq!(opcodes, vec![(3, BindOpcode::SetSegmentAndOffsetUleb { segment: 1, offset: 0x20 }), (5, BindOpcode::DoBind)]);
     }
 }
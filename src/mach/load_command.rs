@@ -2,6 +2,9 @@
 
 use error;
 use core::fmt::{self, Display};
+use core::marker::PhantomData;
+use core::slice;
+use alloc::vec::Vec;
 use scroll::{self, ctx, Endian, Pread};
 
 ///////////////////////////////////////
@@ -845,6 +848,8 @@ pub struct TwolevelHintsCommand {
     pub nhints: u32,
 }
 
+pub const SIZEOF_TWOLEVEL_HINTS_COMMAND: usize = 16;
+
 /// The entries in the two-level namespace lookup hints table are twolevel_hint
 /// structs.  These provide hints to the dynamic link editor where to start
 /// looking for an undefined symbol in a two-level namespace image.  The
@@ -859,13 +864,50 @@ pub struct TwolevelHintsCommand {
 /// primary library.  The table of contents index is an index into the
 /// library's table of contents.  This is used as the starting point of the
 /// binary search or a directed linear search.
-#[repr(C)]
-#[derive(Debug, Clone, Copy, Pread, Pwrite, IOread, IOwrite, SizeWith)]
+///
+/// On disk, a hint is a single 32-bit word holding the 8 bit `isub_image` and the 24 bit `itoc` bitfields,
+/// laid out in the bit order of the binary's endianness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct TwolevelHint {
     /// index into the sub images
-    pub isub_image: u64,
+    pub isub_image: u8,
     /// 24 bit field index into the table of contents
-    pub itoc: [u8; 24],
+    pub itoc: u32,
+}
+
+pub const SIZEOF_TWOLEVEL_HINT: usize = 4;
+
+impl<'a> ctx::TryFromCtx<'a, Endian> for TwolevelHint {
+    type Error = ::error::Error;
+    type Size = usize;
+    fn try_from_ctx(bytes: &'a [u8], le: Endian) -> error::Result<(Self, Self::Size)> {
+        let raw = bytes.pread_with::<u32>(0, le)?;
+        // bitfields are allocated from the least significant bit on little endian targets, and from the most significant bit on big endian ones
+        let hint = if le.is_little() {
+            TwolevelHint { isub_image: raw as u8, itoc: raw >> 8 }
+        } else {
+            TwolevelHint { isub_image: (raw >> 24) as u8, itoc: raw & 0x00ff_ffff }
+        };
+        Ok((hint, SIZEOF_TWOLEVEL_HINT))
+    }
+}
+
+impl TwolevelHintsCommand {
+    /// Reads the `nhints` hints at `offset` in `bytes`, which must be the Mach-o this command was parsed from
+    pub fn hints(&self, bytes: &[u8], le: Endian) -> error::Result<Vec<TwolevelHint>> {
+        let offset = self.offset as usize;
+        let size = (self.nhints as usize).checked_mul(SIZEOF_TWOLEVEL_HINT);
+        let table = match size.and_then(|size| offset.checked_add(size).and_then(|end| bytes.get(offset..end))) {
+            Some(table) => table,
+            None => return Err(error::Error::Malformed(format!("two-level hints table at {:#x} with {} hints is out of bounds", offset, self.nhints))),
+        };
+        let mut hints = Vec::with_capacity(self.nhints as usize);
+        let mut offset = 0;
+        for _ in 0..self.nhints {
+            hints.push(table.gread_with(&mut offset, le)?);
+        }
+        Ok(hints)
+    }
 }
 
 /// The prebind_cksum_command contains the value of the original check sum for
@@ -901,6 +943,22 @@ pub struct UuidCommand {
     pub uuid: [u8; 16],
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+/// The 128-bit uuid of `LC_UUID`, which displays canonically, in upper case hex, e.g., `8A3C0CD5-E3D2-3B4E-9F1A-5C0D3E6B7A21`, as `dwarfdump --uuid` prints it
+pub struct Uuid(pub [u8; 16]);
+
+impl Display for Uuid {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i == 4 || i == 6 || i == 8 || i == 10 {
+                write!(fmt, "-")?;
+            }
+            write!(fmt, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 pub const SIZEOF_UUID_COMMAND: usize = 24;
 
 /// The rpath_command contains a path which at runtime should be added to
@@ -918,12 +976,43 @@ pub struct RpathCommand {
 
 pub const SIZEOF_RPATH_COMMAND: usize = 12;
 
+/// The fileset_entry_command locates one of the Mach-o binaries an `MH_FILESET`, e.g., a kernelcache, is made of.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pread, Pwrite, IOread, IOwrite, SizeWith)]
+pub struct FilesetEntryCommand {
+    /// LC_FILESET_ENTRY
+    pub cmd: u32,
+    /// includes entry_id string
+    pub cmdsize: u32,
+    /// memory address of the entry's Mach-o header
+    pub vmaddr: u64,
+    /// file offset of the entry's Mach-o header in the fileset
+    pub fileoff: u64,
+    /// contained entry id, e.g., the bundle identifier of a kext
+    pub entry_id: LcStr,
+    /// reserved
+    pub reserved: u32,
+}
+
+pub const SIZEOF_FILESET_ENTRY_COMMAND: usize = 32;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A binary of an `MH_FILESET`, from its `LC_FILESET_ENTRY`, see `MachO::fileset_entries`
+pub struct FilesetEntry<'a> {
+    /// The address of the binary's Mach-o header
+    pub vmaddr: u64,
+    /// The offset of the binary's Mach-o header in the fileset; the file offsets of the binary are relative to the fileset as well, see `MachO::parse_fileset_entry`
+    pub fileoff: u64,
+    /// The id of the binary, e.g., `com.apple.kernel`, or the bundle identifier of a kext
+    pub entry_id: &'a str,
+}
+
 /// The linkedit_data_command contains the offsets and sizes of a blob
 /// of data in the __LINKEDIT segment.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Pread, Pwrite, IOread, IOwrite, SizeWith)]
 pub struct LinkeditDataCommand {
-    /// LC_CODE_SIGNATURE, LC_SEGMENT_SPLIT_INFO, LC_FUNCTION_STARTS, LC_DATA_IN_CODE, LC_DYLIB_CODE_SIGN_DRS or LC_LINKER_OPTIMIZATION_HINT.
+    /// LC_CODE_SIGNATURE, LC_SEGMENT_SPLIT_INFO, LC_FUNCTION_STARTS, LC_DATA_IN_CODE, LC_DYLIB_CODE_SIGN_DRS, LC_LINKER_OPTIMIZATION_HINT or LC_DYLD_CHAINED_FIXUPS.
     pub cmd: u32,
     /// sizeof(struct linkedit_data_command)
     pub cmdsize: u32,
@@ -1003,6 +1092,151 @@ impl VersionMinCommand {
 
 pub const SIZEOF_VERSION_MIN_COMMAND: usize = 16;
 
+pub const PLATFORM_MACOS: u32 = 1;
+pub const PLATFORM_IOS: u32 = 2;
+pub const PLATFORM_TVOS: u32 = 3;
+pub const PLATFORM_WATCHOS: u32 = 4;
+pub const PLATFORM_BRIDGEOS: u32 = 5;
+pub const PLATFORM_MACCATALYST: u32 = 6;
+pub const PLATFORM_IOSSIMULATOR: u32 = 7;
+pub const PLATFORM_TVOSSIMULATOR: u32 = 8;
+pub const PLATFORM_WATCHOSSIMULATOR: u32 = 9;
+pub const PLATFORM_DRIVERKIT: u32 = 10;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The platform a binary was built for, from the `platform` of `LC_BUILD_VERSION`
+pub enum Platform {
+    MacOS,
+    IOS,
+    TvOS,
+    WatchOS,
+    BridgeOS,
+    /// An iOS app running on macOS (Mac Catalyst, formerly UIKit for Mac)
+    MacCatalyst,
+    IOSSimulator,
+    TvOSSimulator,
+    WatchOSSimulator,
+    DriverKit,
+    Unknown(u32),
+}
+
+impl From<u32> for Platform {
+    fn from(platform: u32) -> Self {
+        match platform {
+            PLATFORM_MACOS => Platform::MacOS,
+            PLATFORM_IOS => Platform::IOS,
+            PLATFORM_TVOS => Platform::TvOS,
+            PLATFORM_WATCHOS => Platform::WatchOS,
+            PLATFORM_BRIDGEOS => Platform::BridgeOS,
+            PLATFORM_MACCATALYST => Platform::MacCatalyst,
+            PLATFORM_IOSSIMULATOR => Platform::IOSSimulator,
+            PLATFORM_TVOSSIMULATOR => Platform::TvOSSimulator,
+            PLATFORM_WATCHOSSIMULATOR => Platform::WatchOSSimulator,
+            PLATFORM_DRIVERKIT => Platform::DriverKit,
+            platform => Platform::Unknown(platform),
+        }
+    }
+}
+
+impl Platform {
+    /// Whether this is one of the simulator platforms; Mac Catalyst apps are real macOS binaries, and are not
+    pub fn is_simulator(&self) -> bool {
+        match *self {
+            Platform::IOSSimulator | Platform::TvOSSimulator | Platform::WatchOSSimulator => true,
+            _ => false,
+        }
+    }
+}
+
+/// The platform, minimum OS version and SDK version the binary was built for; newer toolchains emit this instead of the `LC_VERSION_MIN_*` commands
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pread, Pwrite, IOread, IOwrite, SizeWith)]
+pub struct BuildVersionCommand {
+    /// LC_BUILD_VERSION
+    pub cmd: u32,
+    /// sizeof(struct build_version_command) plus ntools * sizeof(struct build_tool_version)
+    pub cmdsize: u32,
+    /// One of the PLATFORM_* values
+    pub platform: u32,
+    /// X.Y.Z is encoded in nibbles xxxx.yy.zz
+    pub minos: u32,
+    /// X.Y.Z is encoded in nibbles xxxx.yy.zz
+    pub sdk: u32,
+    /// The number of tool entries following this
+    pub ntools: u32,
+}
+
+pub const SIZEOF_BUILD_VERSION_COMMAND: usize = 24;
+
+impl BuildVersionCommand {
+    /// Reads the `ntools` tools following this command, which was parsed at `offset` in `bytes`
+    pub fn tools(&self, bytes: &[u8], offset: usize, le: Endian) -> error::Result<Vec<BuildToolVersion>> {
+        let size = (self.ntools as usize).checked_mul(SIZEOF_BUILD_TOOL_VERSION);
+        if size.map_or(true, |size| size > (self.cmdsize as usize).saturating_sub(SIZEOF_BUILD_VERSION_COMMAND)) {
+            return Err(error::Error::Malformed(format!("LC_BUILD_VERSION at {:#x} of size {} cannot hold {} tools", offset, self.cmdsize, self.ntools)));
+        }
+        let mut tools = Vec::with_capacity(self.ntools as usize);
+        let mut offset = offset + SIZEOF_BUILD_VERSION_COMMAND;
+        for _ in 0..self.ntools {
+            tools.push(bytes.gread_with(&mut offset, le)?);
+        }
+        Ok(tools)
+    }
+}
+
+pub const TOOL_CLANG: u32 = 1;
+pub const TOOL_SWIFT: u32 = 2;
+pub const TOOL_LD: u32 = 3;
+pub const TOOL_LLD: u32 = 4;
+
+/// A tool, and its version, which built the binary; `ntools` of these follow the `LC_BUILD_VERSION` command
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pread, Pwrite, IOread, IOwrite, SizeWith)]
+pub struct BuildToolVersion {
+    /// One of the TOOL_* values
+    pub tool: u32,
+    /// The version of the tool
+    pub version: u32,
+}
+
+pub const SIZEOF_BUILD_TOOL_VERSION: usize = 8;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+/// An X.Y.Z version, as packed into the nibbles xxxx.yy.zz of the Mach-o version fields
+pub struct Version {
+    pub major: u16,
+    pub minor: u8,
+    pub patch: u8,
+}
+
+impl From<u32> for Version {
+    fn from(version: u32) -> Self {
+        Version {
+            major: (version >> 16) as u16,
+            minor: (version >> 8) as u8,
+            patch: version as u8,
+        }
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The decoded platform and versions of either `LC_BUILD_VERSION` or one of the `LC_VERSION_MIN_*` commands, see `MachO::build_version`
+pub struct BuildVersion {
+    pub platform: Platform,
+    /// The minimum OS version, i.e., the deployment target
+    pub minos: Version,
+    /// The version of the SDK the binary was built against
+    pub sdk: Version,
+    /// The tools which built the binary; always empty for the `LC_VERSION_MIN_*` commands
+    pub tools: Vec<BuildToolVersion>,
+}
+
 #[repr(C)]
 #[derive(Default, Debug, Clone, Copy, Pread, Pwrite, SizeWith)]
 pub struct DyldInfoCommand {
@@ -1034,6 +1268,26 @@ pub struct DyldInfoCommand {
 
 pub const SIZEOF_DYLIB_INFO_COMMAND: usize = 48;
 
+impl DyldInfoCommand {
+    /// Checks that the rebase, bind, weak bind, lazy bind and export info lie within a binary of `size` bytes
+    pub fn check_bounds(&self, size: usize) -> error::Result<()> {
+        let infos = [
+            ("rebase", self.rebase_off, self.rebase_size),
+            ("bind", self.bind_off, self.bind_size),
+            ("weak bind", self.weak_bind_off, self.weak_bind_size),
+            ("lazy bind", self.lazy_bind_off, self.lazy_bind_size),
+            ("export", self.export_off, self.export_size),
+        ];
+        for &(name, offset, len) in infos.iter() {
+            if len != 0 && offset as u64 + len as u64 > size as u64 {
+                return Err(error::Error::Malformed(format!("{} info at {:#x} of size {:#x} lies past the end of the {:#x} byte binary; \
+                    if it was extracted from the dyld shared cache, its linkedit offsets are relative to the cache, see `ParseOptions::linkedit_base`", name, offset, len, size)));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// The linker_option_command contains linker options embedded in object files.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Pread, Pwrite, IOread, IOwrite, SizeWith)]
@@ -1132,6 +1386,15 @@ pub struct SourceVersionCommand {
     pub version: u64,
 }
 
+impl SourceVersionCommand {
+    /// The `A.B.C.D.E` version, unpacked from its 24 and 10-bit fields
+    pub fn unpacked(&self) -> (u32, u32, u32, u32, u32) {
+        let version = self.version;
+        let field = |shift: u32| (version >> shift) as u32 & 0x3ff;
+        ((version >> 40) as u32 & 0xff_ffff, field(30), field(20), field(10), field(0))
+    }
+}
+
 /// The LC_DATA_IN_CODE load commands uses a linkedit_data_command
 /// to point to an array of data_in_code_entry entries. Each entry
 /// describes a range of data in a code section.
@@ -1157,6 +1420,8 @@ pub const LC_REEXPORT_DYLIB: u32 = 0x1f | LC_REQ_DYLD;
 pub const LC_DYLD_INFO_ONLY: u32 = 0x22 | LC_REQ_DYLD;
 pub const LC_LOAD_UPWARD_DYLIB: u32 = 0x23 | LC_REQ_DYLD;
 pub const LC_MAIN: u32 = 0x28 | LC_REQ_DYLD;
+pub const LC_DYLD_CHAINED_FIXUPS: u32 = 0x34 | LC_REQ_DYLD;
+pub const LC_FILESET_ENTRY: u32 = 0x35 | LC_REQ_DYLD;
 pub const LC_SEGMENT: u32 = 0x1;
 pub const LC_SYMTAB: u32 = 0x2;
 pub const LC_SYMSEG: u32 = 0x3;
@@ -1198,6 +1463,9 @@ pub const LC_DYLIB_CODE_SIGN_DRS: u32 = 0x2B;
 pub const LC_ENCRYPTION_INFO_64: u32 = 0x2C;
 pub const LC_LINKER_OPTION: u32 = 0x2D;
 pub const LC_LINKER_OPTIMIZATION_HINT: u32 = 0x2E;
+pub const LC_VERSION_MIN_TVOS: u32 = 0x2F;
+pub const LC_VERSION_MIN_WATCHOS: u32 = 0x30;
+pub const LC_BUILD_VERSION: u32 = 0x32;
 
 pub fn cmd_to_str(cmd: u32) -> &'static str {
     match cmd {
@@ -1248,6 +1516,11 @@ pub fn cmd_to_str(cmd: u32) -> &'static str {
         LC_ENCRYPTION_INFO_64 => "LC_ENCRYPTION_INFO_64",
         LC_LINKER_OPTION => "LC_LINKER_OPTION",
         LC_LINKER_OPTIMIZATION_HINT => "LC_LINKER_OPTIMIZATION_HINT",
+        LC_VERSION_MIN_TVOS => "LC_VERSION_MIN_TVOS",
+        LC_VERSION_MIN_WATCHOS => "LC_VERSION_MIN_WATCHOS",
+        LC_BUILD_VERSION => "LC_BUILD_VERSION",
+        LC_DYLD_CHAINED_FIXUPS => "LC_DYLD_CHAINED_FIXUPS",
+        LC_FILESET_ENTRY => "LC_FILESET_ENTRY",
         _ => "LC_UNKNOWN",
     }
 }
@@ -1306,6 +1579,11 @@ pub enum CommandVariant {
     DylibCodeSignDrs       (LinkeditDataCommand),
     LinkerOption           (LinkeditDataCommand),
     LinkerOptimizationHint (LinkeditDataCommand),
+    VersionMinTvos         (VersionMinCommand),
+    VersionMinWatchos      (VersionMinCommand),
+    BuildVersion           (BuildVersionCommand),
+    DyldChainedFixups      (LinkeditDataCommand),
+    FilesetEntry           (FilesetEntryCommand),
     Unimplemented          (LoadCommandHeader),
 }
 
@@ -1367,12 +1645,76 @@ impl<'a> ctx::TryFromCtx<'a, Endian> for CommandVariant {
             LC_DYLIB_CODE_SIGN_DRS => {     let comm = bytes.pread_with::<LinkeditDataCommand>    (0, le)?;  Ok((DylibCodeSignDrs       (comm), size))},
             LC_LINKER_OPTION => {           let comm = bytes.pread_with::<LinkeditDataCommand>    (0, le)?;  Ok((LinkerOption           (comm), size))},
             LC_LINKER_OPTIMIZATION_HINT => {let comm = bytes.pread_with::<LinkeditDataCommand>    (0, le)?;  Ok((LinkerOptimizationHint (comm), size))},
+            LC_VERSION_MIN_TVOS => {        let comm = bytes.pread_with::<VersionMinCommand>      (0, le)?;  Ok((VersionMinTvos         (comm), size))},
+            LC_VERSION_MIN_WATCHOS => {     let comm = bytes.pread_with::<VersionMinCommand>      (0, le)?;  Ok((VersionMinWatchos      (comm), size))},
+            LC_BUILD_VERSION => {           let comm = bytes.pread_with::<BuildVersionCommand>    (0, le)?;  Ok((BuildVersion           (comm), size))},
+            LC_DYLD_CHAINED_FIXUPS => {     let comm = bytes.pread_with::<LinkeditDataCommand>    (0, le)?;  Ok((DyldChainedFixups      (comm), size))},
+            LC_FILESET_ENTRY => {           let comm = bytes.pread_with::<FilesetEntryCommand>    (0, le)?;  Ok((FilesetEntry           (comm), size))},
             _ =>                                                                                             Ok((Unimplemented          (lc.clone()), size)),
         }
     }
 }
 
+/// Moves the linkedit `offset` from `from` to `to`; 0 means there is no data, and stays so
+fn rebase_linkedit_offset(offset: u64, from: u64, to: u64) -> error::Result<u64> {
+    if offset == 0 {
+        return Ok(0);
+    }
+    match offset.checked_sub(from).and_then(|delta| delta.checked_add(to)) {
+        Some(offset) => Ok(offset),
+        None => Err(error::Error::Malformed(format!("linkedit offset {:#x} is not in __LINKEDIT at {:#x}", offset, from))),
+    }
+}
+
+/// `rebase_linkedit_offset`, for the 32-bit offsets most load commands have
+fn rebase_linkedit_offset32(offset: u32, from: u64, to: u64) -> error::Result<u32> {
+    let rebased = rebase_linkedit_offset(offset as u64, from, to)?;
+    if rebased > ::core::u32::MAX as u64 {
+        return Err(error::Error::Malformed(format!("linkedit offset {:#x} rebased to {:#x} does not fit in 32 bits", offset, rebased)));
+    }
+    Ok(rebased as u32)
+}
+
 impl CommandVariant {
+    /// Rebases the file offsets into `__LINKEDIT` of this command, or if it is the `__LINKEDIT` segment, its `fileoff`, from `from` to `to`;
+    /// e.g., for a binary extracted from the dyld shared cache, from `__LINKEDIT`'s offset in the cache to its offset in the binary.
+    /// Commands without linkedit offsets are left alone
+    pub fn rebase_linkedit(&mut self, from: u64, to: u64) -> error::Result<()> {
+        use self::CommandVariant::*;
+        let rebase = |offset: u32| rebase_linkedit_offset32(offset, from, to);
+        match *self {
+            Segment32(ref mut comm) if comm.name().ok() == Some("__LINKEDIT") => comm.fileoff = rebase(comm.fileoff)?,
+            Segment64(ref mut comm) if comm.name().ok() == Some("__LINKEDIT") => comm.fileoff = rebase_linkedit_offset(comm.fileoff, from, to)?,
+            Symtab(ref mut comm) => {
+                comm.symoff = rebase(comm.symoff)?;
+                comm.stroff = rebase(comm.stroff)?;
+            },
+            Dysymtab(ref mut comm) => {
+                comm.tocoff = rebase(comm.tocoff)?;
+                comm.modtaboff = rebase(comm.modtaboff)?;
+                comm.extrefsymoff = rebase(comm.extrefsymoff)?;
+                comm.indirectsymoff = rebase(comm.indirectsymoff)?;
+                comm.extreloff = rebase(comm.extreloff)?;
+                comm.locreloff = rebase(comm.locreloff)?;
+            },
+            DyldInfo(ref mut comm) | DyldInfoOnly(ref mut comm) => {
+                comm.rebase_off = rebase(comm.rebase_off)?;
+                comm.bind_off = rebase(comm.bind_off)?;
+                comm.weak_bind_off = rebase(comm.weak_bind_off)?;
+                comm.lazy_bind_off = rebase(comm.lazy_bind_off)?;
+                comm.export_off = rebase(comm.export_off)?;
+            },
+              CodeSignature(ref mut comm)
+            | SegmentSplitInfo(ref mut comm)
+            | FunctionStarts(ref mut comm)
+            | DataInCode(ref mut comm)
+            | DylibCodeSignDrs(ref mut comm)
+            | LinkerOptimizationHint(ref mut comm)
+            | DyldChainedFixups(ref mut comm) => comm.dataoff = rebase(comm.dataoff)?,
+            _ => (),
+        }
+        Ok(())
+    }
     pub fn cmdsize(&self) -> usize {
         use self::CommandVariant::*;
         let cmdsize = match *self {
@@ -1423,6 +1765,11 @@ impl CommandVariant {
             DylibCodeSignDrs       (comm) => comm.cmdsize,
             LinkerOption           (comm) => comm.cmdsize,
             LinkerOptimizationHint (comm) => comm.cmdsize,
+            VersionMinTvos         (comm) => comm.cmdsize,
+            VersionMinWatchos      (comm) => comm.cmdsize,
+            BuildVersion           (comm) => comm.cmdsize,
+            DyldChainedFixups      (comm) => comm.cmdsize,
+            FilesetEntry           (comm) => comm.cmdsize,
             Unimplemented          (comm) => comm.cmdsize,
         };
         cmdsize as usize
@@ -1477,6 +1824,11 @@ impl CommandVariant {
             DylibCodeSignDrs       (comm) => comm.cmd,
             LinkerOption           (comm) => comm.cmd,
             LinkerOptimizationHint (comm) => comm.cmd,
+            VersionMinTvos         (comm) => comm.cmd,
+            VersionMinWatchos      (comm) => comm.cmd,
+            BuildVersion           (comm) => comm.cmd,
+            DyldChainedFixups      (comm) => comm.cmd,
+            FilesetEntry           (comm) => comm.cmd,
             Unimplemented          (comm) => comm.cmd,
         }
     }
@@ -1500,4 +1852,135 @@ impl LoadCommand {
         *offset = start + size;
         Ok(LoadCommand { offset: start, command })
     }
+    /// The raw bytes of this load command - the `cmd`, `cmdsize` and body - in `bytes`, which must be the Mach-o this command was parsed from;
+    /// the slice is truncated if the command reaches past the end of `bytes`
+    pub fn raw_bytes<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        let start = ::core::cmp::min(self.offset, bytes.len());
+        let end = ::core::cmp::min(self.offset.saturating_add(self.command.cmdsize()), bytes.len());
+        &bytes[start..end]
+    }
+}
+
+/// A load command struct, which can be extracted from the `CommandVariant`s which carry it
+pub trait LoadCommandPayload {
+    /// Returns the payload of `command`, if it is one of the variants carrying `Self`
+    fn from_variant(command: &CommandVariant) -> Option<&Self>;
+}
+
+macro_rules! load_command_payload {
+    ($typ:ty, $($variant:ident),+) => {
+        impl LoadCommandPayload for $typ {
+            fn from_variant(command: &CommandVariant) -> Option<&Self> {
+                match *command {
+                    $(CommandVariant::$variant(ref comm))|+ => Some(comm),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+load_command_payload!(SegmentCommand32, Segment32);
+load_command_payload!(SegmentCommand64, Segment64);
+load_command_payload!(UuidCommand, Uuid);
+load_command_payload!(SymtabCommand, Symtab);
+load_command_payload!(SymsegCommand, Symseg);
+load_command_payload!(ThreadCommand, Thread, Unixthread);
+load_command_payload!(FvmlibCommand, LoadFvmlib, IdFvmlib);
+load_command_payload!(IdentCommand, Ident);
+load_command_payload!(FvmfileCommand, Fvmfile);
+load_command_payload!(LoadCommandHeader, Prepage, Unimplemented);
+load_command_payload!(DysymtabCommand, Dysymtab);
+load_command_payload!(DylibCommand, LoadDylib, IdDylib, LoadWeakDylib, ReexportDylib, LazyLoadDylib, LoadUpwardDylib);
+load_command_payload!(DylinkerCommand, LoadDylinker, IdDylinker, DyldEnvironment);
+load_command_payload!(PreboundDylibCommand, PreboundDylib);
+load_command_payload!(RoutinesCommand32, Routines32);
+load_command_payload!(RoutinesCommand64, Routines64);
+load_command_payload!(SubFrameworkCommand, SubFramework);
+load_command_payload!(SubUmbrellaCommand, SubUmbrella);
+load_command_payload!(SubClientCommand, SubClient);
+load_command_payload!(SubLibraryCommand, SubLibrary);
+load_command_payload!(TwolevelHintsCommand, TwolevelHints);
+load_command_payload!(PrebindCksumCommand, PrebindCksum);
+load_command_payload!(RpathCommand, Rpath);
+load_command_payload!(LinkeditDataCommand, CodeSignature, SegmentSplitInfo, FunctionStarts, DataInCode, DylibCodeSignDrs, LinkerOption, LinkerOptimizationHint, DyldChainedFixups);
+load_command_payload!(EncryptionInfoCommand32, EncryptionInfo32);
+load_command_payload!(EncryptionInfoCommand64, EncryptionInfo64);
+load_command_payload!(DyldInfoCommand, DyldInfo, DyldInfoOnly);
+load_command_payload!(VersionMinCommand, VersionMinMacosx, VersionMinIphoneos, VersionMinTvos, VersionMinWatchos);
+load_command_payload!(BuildVersionCommand, BuildVersion);
+load_command_payload!(EntryPointCommand, Main);
+load_command_payload!(SourceVersionCommand, SourceVersion);
+load_command_payload!(FilesetEntryCommand, FilesetEntry);
+
+/// An iterator over the load commands carrying a `T`, yielding the offset of each command along with its payload
+pub struct LoadCommandsOf<'b, T: 'b> {
+    commands: slice::Iter<'b, LoadCommand>,
+    marker: PhantomData<&'b T>,
+}
+
+impl<'b, T: 'b> LoadCommandsOf<'b, T> {
+    /// Iterates over the `commands` carrying a `T`
+    pub fn new(commands: &'b [LoadCommand]) -> Self {
+        LoadCommandsOf { commands: commands.iter(), marker: PhantomData }
+    }
+}
+
+impl<'b, T: LoadCommandPayload + 'b> Iterator for LoadCommandsOf<'b, T> {
+    type Item = (usize, &'b T);
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(command) = self.commands.next() {
+            if let Some(payload) = T::from_variant(&command.command) {
+                return Some((command.offset, payload));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn twolevel_hints() {
+        let command = TwolevelHintsCommand { cmd: LC_TWOLEVEL_HINTS, cmdsize: SIZEOF_TWOLEVEL_HINTS_COMMAND as u32, offset: 4, nhints: 2 };
+        let bytes = [0xff, 0xff, 0xff, 0xff, 0x02, 0x34, 0x12, 0x00, 0x03, 0x00, 0x00, 0x01];
+        assert_eq!(command.hints(&bytes, scroll::LE).unwrap(), vec![
+            TwolevelHint { isub_image: 2, itoc: 0x1234 },
+            TwolevelHint { isub_image: 3, itoc: 0x10000 },
+        ]);
+        assert_eq!(command.hints(&bytes, scroll::BE).unwrap()[0], TwolevelHint { isub_image: 2, itoc: 0x341200 });
+        assert!(command.hints(&bytes[..11], scroll::LE).is_err());
+    }
+
+    #[test]
+    fn platforms() {
+        assert_eq!(Platform::from(PLATFORM_IOS), Platform::IOS);
+        assert!(Platform::from(PLATFORM_IOSSIMULATOR).is_simulator());
+        assert!(!Platform::from(PLATFORM_MACCATALYST).is_simulator());
+        assert_eq!(Platform::from(0x1234), Platform::Unknown(0x1234));
+    }
+
+    #[test]
+    fn build_version() {
+        use scroll::Pwrite;
+        assert_eq!(Version::from(0x000a_0e02), Version { major: 10, minor: 14, patch: 2 });
+        assert_eq!(Version::from(0x000d_0000).to_string(), "13.0.0");
+        assert!(Version::from(0x000a_0f00) > Version::from(0x000a_0e06));
+        let mut command = BuildVersionCommand { cmd: LC_BUILD_VERSION, cmdsize: 40, platform: PLATFORM_MACOS, minos: 0x000a_0e00, sdk: 0x000a_0f00, ntools: 2 };
+        let mut bytes = [0u8; 40];
+        bytes.pwrite_with(command, 0, scroll::LE).unwrap();
+        bytes.pwrite_with(BuildToolVersion { tool: TOOL_CLANG, version: 0x0400_0100 }, 24, scroll::LE).unwrap();
+        bytes.pwrite_with(BuildToolVersion { tool: TOOL_LD, version: 0x0262_0000 }, 32, scroll::LE).unwrap();
+        assert_eq!(command.tools(&bytes, 0, scroll::LE).unwrap(), vec![
+            BuildToolVersion { tool: TOOL_CLANG, version: 0x0400_0100 },
+            BuildToolVersion { tool: TOOL_LD, version: 0x0262_0000 },
+        ]);
+        assert!(command.tools(&bytes[..39], 0, scroll::LE).is_err());
+        command.ntools = 3;
+        assert!(command.tools(&bytes, 0, scroll::LE).is_err());
+        command.ntools = 0;
+        assert!(command.tools(&bytes, 0, scroll::LE).unwrap().is_empty());
+    }
 }
@@ -1,12 +1,20 @@
 //! The Mach-o, mostly zero-copy, binary format parser and raw struct definitions
+//!
+//! The parsers of the `__LINKEDIT` data (the bind opcodes, chained fixups, and so on) all take the binary's `&Ctx`, for its endianness and pointer width,
+//! and those which report locations in memory also take its `segments`, resolving a `(seg_index, seg_offset)` pair with `segment::resolve`.
 use core::fmt;
 use alloc::vec::Vec;
+use alloc::string::{String, ToString};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
 
 use scroll::{self, Pread, BE};
 use scroll::ctx::SizeWith;
 
 use error;
 use container;
+use options::{ParseOptions, Recovery};
+use anomaly::{self, Anomaly, Region};
 
 pub mod header;
 pub mod constants;
@@ -18,6 +26,11 @@ pub mod imports;
 pub mod bind_opcodes;
 pub mod relocation;
 pub mod segment;
+pub mod objc;
+pub mod chained_fixups;
+pub mod signature;
+pub mod function_starts;
+pub mod data_in_code;
 
 pub use self::constants::cputype as cputype;
 
@@ -31,15 +44,10 @@ pub fn parse_magic_and_ctx(bytes: &[u8], offset: usize) -> error::Result<(u32, O
     use mach::header::*;
     use container::Container;
     let magic = bytes.pread_with::<u32>(offset, BE)?;
-    let ctx = match magic {
-        MH_CIGAM_64 | MH_CIGAM | MH_MAGIC_64 | MH_MAGIC => {
-            let is_lsb = magic == MH_CIGAM || magic == MH_CIGAM_64;
-            let le = scroll::Endian::from(is_lsb);
-            let container = if magic == MH_MAGIC_64 || magic == MH_CIGAM_64 { Container::Big } else { Container::Little };
-            Some(container::Ctx::new(container, le))
-        },
-        _ => None,
-    };
+    let ctx = Container::from_magic(magic).map(|container| {
+        let is_lsb = magic == MH_CIGAM || magic == MH_CIGAM_64;
+        container::Ctx::new(container, scroll::Endian::from(is_lsb))
+    });
     Ok((magic, ctx))
 }
 
@@ -53,7 +61,8 @@ pub struct MachO<'a> {
     pub segments: segment::Segments<'a>,
     /// The "Nlist" style symbols in this binary - strippable
     pub symbols: Option<symbols::Symbols<'a>>,
-    /// The dylibs this library depends on
+    /// The dylibs this library depends on, of every kind of dylib load command, following this dylib's own install name (or `"self"`);
+    /// this is the table bind ordinals index into, see `libs_required`, `libs_weak`, etc. for each kind
     pub libs: Vec<&'a str>,
     /// The entry point (as a virtual memory address), 0 if none
     pub entry: u64,
@@ -65,10 +74,14 @@ pub struct MachO<'a> {
     pub little_endian: bool,
     /// Are we a 64-bit binary
     pub is_64: bool,
+    /// The load commands, by index, a lenient parse could not interpret, and the reason; always empty unless parsed with `ParseOptions::fail_fast` unset
+    pub errors: Vec<(usize, error::Error)>,
     data: &'a [u8],
     ctx: container::Ctx,
     export_trie: Option<exports::ExportTrie<'a>>,
+    dysymtab: Option<load_command::DysymtabCommand>,
     bind_interpreter: Option<imports::BindInterpreter<'a>>,
+    address_index: Option<Vec<(Cow<'a, str>, u64, u64)>>,
 }
 
 impl<'a> fmt::Debug for MachO<'a> {
@@ -83,6 +96,7 @@ impl<'a> fmt::Debug for MachO<'a> {
             .field("name",            &self.name)
             .field("little_endian",   &self.little_endian)
             .field("is_64",           &self.is_64)
+            .field("errors",          &self.errors)
             .field("symbols()",       &self.symbols().collect::<Vec<_>>())
             .field("exports()",       &self.exports())
             .field("imports()",       &self.imports())
@@ -90,11 +104,224 @@ impl<'a> fmt::Debug for MachO<'a> {
     }
 }
 
+impl container::Ctx {
+    /// The container size and byte order `mach` was parsed with
+    pub fn from_mach(mach: &MachO) -> Self {
+        mach.ctx
+    }
+}
+
 impl<'a> MachO<'a> {
     /// Is this a relocatable object file?
     pub fn is_object_file(&self) -> bool {
         self.header.filetype == header::MH_OBJECT
     }
+    /// Iterates over the load commands carrying a `T`, e.g., `load_commands_of::<load_command::DylibCommand>()`, yielding each command's offset and payload
+    pub fn load_commands_of<T: load_command::LoadCommandPayload>(&self) -> load_command::LoadCommandsOf<T> {
+        load_command::LoadCommandsOf::new(&self.load_commands)
+    }
+    /// The path of the dynamic linker named by `LC_LOAD_DYLINKER`, usually `/usr/lib/dyld`, if this binary has one
+    pub fn dynamic_linker(&self) -> Option<&'a str> {
+        self.load_commands.iter().filter_map(|cmd| match cmd.command {
+            load_command::CommandVariant::LoadDylinker(command) => {
+                // the name must lie within the command
+                cmd.raw_bytes(self.data).pread::<&str>(command.name as usize).ok()
+            },
+            _ => None,
+        }).next()
+    }
+    /// The run path search paths of the `LC_RPATH` commands, in load command order, verbatim, i.e., with their `@loader_path` or `@executable_path` prefixes
+    pub fn rpaths(&self) -> Vec<&'a str> {
+        self.load_commands.iter().filter_map(|cmd| match cmd.command {
+            load_command::CommandVariant::Rpath(command) => cmd.raw_bytes(self.data).pread::<&str>(command.path as usize).ok(),
+            _ => None,
+        }).collect()
+    }
+    /// The dylibs loaded by a `kind` load command, e.g., `LC_LOAD_WEAK_DYLIB`
+    fn libs_of(&self, kind: u32) -> Vec<&'a str> {
+        self.load_commands.iter().filter(|cmd| cmd.command.cmd() == kind).filter_map(|cmd| match cmd.command {
+              load_command::CommandVariant::LoadDylib      (command)
+            | load_command::CommandVariant::LoadUpwardDylib(command)
+            | load_command::CommandVariant::ReexportDylib  (command)
+            | load_command::CommandVariant::LoadWeakDylib  (command)
+            | load_command::CommandVariant::LazyLoadDylib  (command) => self.data.pread::<&str>(cmd.offset + command.dylib.name as usize).ok(),
+            _ => None,
+        }).collect()
+    }
+    /// The dylibs of the `LC_LOAD_DYLIB` commands, which must be present at load time
+    pub fn libs_required(&self) -> Vec<&'a str> {
+        self.libs_of(load_command::LC_LOAD_DYLIB)
+    }
+    /// The dylibs of the `LC_LOAD_WEAK_DYLIB` commands, which may be missing at load time
+    pub fn libs_weak(&self) -> Vec<&'a str> {
+        self.libs_of(load_command::LC_LOAD_WEAK_DYLIB)
+    }
+    /// The dylibs of the `LC_REEXPORT_DYLIB` commands, e.g., the sub-umbrellas of a framework, whose exports this dylib exports as its own
+    pub fn libs_reexport(&self) -> Vec<&'a str> {
+        self.libs_of(load_command::LC_REEXPORT_DYLIB)
+    }
+    /// The dylibs of the `LC_LOAD_UPWARD_DYLIB` commands, which may depend on this dylib in turn
+    pub fn libs_upward(&self) -> Vec<&'a str> {
+        self.libs_of(load_command::LC_LOAD_UPWARD_DYLIB)
+    }
+    /// The dylibs of the `LC_LAZY_LOAD_DYLIB` commands, which are loaded on first use
+    pub fn libs_lazy(&self) -> Vec<&'a str> {
+        self.libs_of(load_command::LC_LAZY_LOAD_DYLIB)
+    }
+    /// The two-level namespace hints of `LC_TWOLEVEL_HINTS`, if this binary has them
+    pub fn twolevel_hints(&self) -> error::Result<Option<Vec<load_command::TwolevelHint>>> {
+        match self.load_commands_of::<load_command::TwolevelHintsCommand>().next() {
+            Some((_, command)) => Ok(Some(command.hints(self.data, self.ctx.le)?)),
+            None => Ok(None),
+        }
+    }
+    /// The entries of the chained imports table of `LC_DYLD_CHAINED_FIXUPS`, in table order, or none if this binary does not use chained fixups
+    pub fn chained_imports(&self) -> error::Result<Vec<chained_fixups::ChainedImport<'a>>> {
+        let command = self.load_commands.iter().filter_map(|cmd| match cmd.command {
+            load_command::CommandVariant::DyldChainedFixups(command) => Some(command),
+            _ => None,
+        }).next();
+        match command {
+            Some(command) => chained_fixups::ChainedFixups::parse(self.data, &command, &self.ctx)?.imports(),
+            None => Ok(Vec::new()),
+        }
+    }
+    /// The embedded code signature of `LC_CODE_SIGNATURE`, or none if this binary isn't signed; the signature must lie within `__LINKEDIT`
+    pub fn code_signature(&self) -> error::Result<Option<signature::SuperBlob<'a>>> {
+        let command = self.load_commands.iter().filter_map(|cmd| match cmd.command {
+            load_command::CommandVariant::CodeSignature(command) => Some(command),
+            _ => None,
+        }).next();
+        let command = match command {
+            Some(command) => command,
+            None => return Ok(None),
+        };
+        let linkedit = self.segments.iter().find(|segment| segment.name().ok() == Some("__LINKEDIT"));
+        match linkedit {
+            Some(linkedit) => signature::SuperBlob::parse(self.data, &command, (linkedit.fileoff, linkedit.filesize)).map(Some),
+            None => Err(error::Error::Malformed("binary has a code signature, but no __LINKEDIT segment".to_string())),
+        }
+    }
+    /// The virtual address of the entry point, from the first `LC_MAIN`, whose `entryoff` is relative to `__TEXT`, or else from the instruction pointer
+    /// of the first `LC_UNIXTHREAD`'s thread state, for the `cputype` of the header; unlike the `entry` field, it is an error if the binary has neither, e.g., a dylib
+    pub fn entry(&self) -> error::Result<u64> {
+        let mut thread = None;
+        for cmd in &self.load_commands {
+            match cmd.command {
+                load_command::CommandVariant::Main(command) => {
+                    return match self.segments.iter().find(|segment| segment.name().ok() == Some("__TEXT")) {
+                        Some(text) => Ok(text.vmaddr.wrapping_sub(text.fileoff).wrapping_add(command.entryoff)),
+                        None => Err(error::Error::Malformed(format!("image specifies LC_MAIN offset {:#x} but has no __TEXT segment", command.entryoff))),
+                    };
+                },
+                load_command::CommandVariant::Unixthread(ref command) if thread.is_none() => thread = Some(command),
+                _ => (),
+            }
+        }
+        match thread {
+            Some(command) => command.instruction_pointer(self.header.cputype),
+            None => Err(error::Error::Malformed("image has neither an LC_MAIN nor an LC_UNIXTHREAD entry point".to_string())),
+        }
+    }
+    /// The addresses of the functions in this binary, from `LC_FUNCTION_STARTS`, or none if it has no such command, or no `__TEXT` segment
+    pub fn function_starts(&self) -> Option<function_starts::FunctionStarts<'a>> {
+        let command = self.load_commands.iter().filter_map(|cmd| match cmd.command {
+            load_command::CommandVariant::FunctionStarts(command) => Some(command),
+            _ => None,
+        }).next();
+        let text = self.segments.iter().find(|segment| segment.name().ok() == Some("__TEXT"));
+        match (command, text) {
+            (Some(command), Some(text)) => Some(function_starts::FunctionStarts::parse(self.data, &command, text.vmaddr)),
+            _ => None,
+        }
+    }
+    /// The ranges of `__text` which hold data rather than instructions, from `LC_DATA_IN_CODE`, or none if this binary has no such command
+    pub fn data_in_code(&self) -> error::Result<Option<data_in_code::DataInCodeEntries<'a>>> {
+        let command = self.load_commands.iter().filter_map(|cmd| match cmd.command {
+            load_command::CommandVariant::DataInCode(command) => Some(command),
+            _ => None,
+        }).next();
+        match command {
+            Some(command) => data_in_code::DataInCodeEntries::parse(self.data, &command, self.ctx.le).map(Some),
+            None => Ok(None),
+        }
+    }
+    /// The platform, deployment target and SDK version this binary was built for, from `LC_BUILD_VERSION`, or else from the older `LC_VERSION_MIN_*` commands;
+    /// since those have no simulator variants, an iOS, tvOS or watchOS binary for x86 is taken to be a simulator binary
+    pub fn build_version(&self) -> Option<load_command::BuildVersion> {
+        use mach::load_command::{BuildVersion, CommandVariant, Platform, Version};
+        let mut version_min = None;
+        for cmd in &self.load_commands {
+            let (device, simulator, command) = match cmd.command {
+                CommandVariant::BuildVersion(command) => {
+                    // the tools are only informational, so don't lose the versions over them
+                    let tools = command.tools(self.data, cmd.offset, self.ctx.le).unwrap_or_else(|_| Vec::new());
+                    return Some(BuildVersion {
+                        platform: Platform::from(command.platform),
+                        minos: Version::from(command.minos),
+                        sdk: Version::from(command.sdk),
+                        tools,
+                    });
+                },
+                CommandVariant::VersionMinMacosx(command) => (Platform::MacOS, Platform::MacOS, command),
+                CommandVariant::VersionMinIphoneos(command) => (Platform::IOS, Platform::IOSSimulator, command),
+                CommandVariant::VersionMinTvos(command) => (Platform::TvOS, Platform::TvOSSimulator, command),
+                CommandVariant::VersionMinWatchos(command) => (Platform::WatchOS, Platform::WatchOSSimulator, command),
+                _ => continue,
+            };
+            version_min = version_min.or(Some((device, simulator, command)));
+        }
+        let is_x86 = self.header.cputype == cputype::CPU_TYPE_X86 || self.header.cputype == cputype::CPU_TYPE_X86_64;
+        version_min.map(|(device, simulator, command)| BuildVersion {
+            platform: if is_x86 { simulator } else { device },
+            minos: Version::from(command.version),
+            sdk: Version::from(command.sdk),
+            tools: Vec::new(),
+        })
+    }
+    /// The platform this binary was built for, see `build_version`
+    pub fn platform(&self) -> Option<load_command::Platform> {
+        self.build_version().map(|version| version.platform)
+    }
+    /// Whether this binary was built for one of the iOS, tvOS or watchOS simulators
+    pub fn is_simulator(&self) -> bool {
+        self.platform().map_or(false, |platform| platform.is_simulator())
+    }
+    /// The uuid of `LC_UUID`, which identifies this binary, e.g., to match it with its dSYM; see `load_command::Uuid` for its canonical form
+    pub fn uuid(&self) -> Option<[u8; 16]> {
+        self.load_commands_of::<load_command::UuidCommand>().next().map(|(_, command)| command.uuid)
+    }
+    /// The `A.B.C.D.E` version of the sources this binary was built from, of `LC_SOURCE_VERSION`
+    pub fn source_version(&self) -> Option<(u32, u32, u32, u32, u32)> {
+        self.load_commands_of::<load_command::SourceVersionCommand>().next().map(|(_, command)| command.unpacked())
+    }
+    /// Is this a set of Mach-o binaries, e.g., a kernelcache, see `fileset_entries`?
+    pub fn is_fileset(&self) -> bool {
+        self.header.filetype == header::MH_FILESET
+    }
+    /// The binaries of the `LC_FILESET_ENTRY` commands of an `MH_FILESET`, in load command order; each must have its id within its command,
+    /// and its header within the fileset
+    pub fn fileset_entries(&self) -> error::Result<Vec<load_command::FilesetEntry<'a>>> {
+        let mut entries = Vec::new();
+        for cmd in &self.load_commands {
+            if let load_command::CommandVariant::FilesetEntry(command) = cmd.command {
+                let entry_id = cmd.raw_bytes(self.data).pread::<&str>(command.entry_id as usize)?;
+                if command.fileoff >= self.data.len() as u64 {
+                    return Err(error::Error::Malformed(format!("fileset entry {} at file offset {:#x} is past the end of the {:#x} byte fileset", entry_id, command.fileoff, self.data.len())));
+                }
+                entries.push(load_command::FilesetEntry { vmaddr: command.vmaddr, fileoff: command.fileoff, entry_id });
+            }
+        }
+        Ok(entries)
+    }
+    /// Parses the binary of the fileset whose header is at `fileoff`, e.g., the `fileoff` of one of the `fileset_entries`, with the default `ParseOptions`;
+    /// like those of the fileset, its file offsets are relative to the start of the fileset, not to its header
+    pub fn parse_fileset_entry(&self, fileoff: u64) -> error::Result<MachO<'a>> {
+        if fileoff >= self.data.len() as u64 {
+            return Err(error::Error::Malformed(format!("fileset entry at file offset {:#x} is past the end of the {:#x} byte fileset", fileoff, self.data.len())));
+        }
+        Self::parse_at(self.data, fileoff as usize, ParseOptions::default())
+    }
     /// Return an iterator over all the symbols in this binary
     pub fn symbols(&self) -> symbols::SymbolIterator<'a> {
         if let &Some(ref symbols) = &self.symbols {
@@ -103,6 +330,118 @@ impl<'a> MachO<'a> {
             symbols::SymbolIterator::default()
         }
     }
+    /// The address the `r_address` of the `LC_DYSYMTAB` relocations is relative to, as dyld reckons it: the first writable segment
+    /// of an x86-64 binary or one with `MH_SPLIT_SEGS`, else the first segment; `None` if there is no such segment
+    pub fn relocation_base(&self) -> Option<u64> {
+        use mach::constants::VM_PROT_WRITE;
+        if self.header.cputype == cputype::CPU_TYPE_X86_64 || self.header.flags & header::MH_SPLIT_SEGS != 0 {
+            self.segments.iter().find(|segment| segment.initprot & VM_PROT_WRITE != 0).map(|segment| segment.vmaddr)
+        } else {
+            self.segments.iter().next().map(|segment| segment.vmaddr)
+        }
+    }
+    /// Returns the `LC_DYSYMTAB` partition of the symbol table, if this binary has one
+    pub fn dysymtab(&self) -> Option<symbols::Dysymtab<'a>> {
+        self.dysymtab.map(|command| symbols::Dysymtab::new(self.data, command, self.symbols.clone(), self.ctx))
+    }
+    /// The section symbols from `LC_SYMTAB`, and the regular exports from the export trie at addresses no symbol has (which is all a stripped dylib usually has left),
+    /// as `(name, address, size)`, sorted by address.
+    ///
+    /// Mach-o symbols have no size, so each is sized up to the next symbol, or the next function start from `LC_FUNCTION_STARTS`, or the end of its segment, whichever is closest
+    pub fn sorted_symbols(&self) -> error::Result<Vec<(Cow<'a, str>, u64, u64)>> {
+        use mach::symbols::{N_SECT, N_TYPE};
+        if let Some(ref index) = self.address_index {
+            return Ok(index.clone());
+        }
+        let mut symbols = Vec::new();
+        for symbol in self.symbols() {
+            let (name, nlist) = symbol?;
+            if !nlist.is_stab() && nlist.n_type & N_TYPE == N_SECT && !name.is_empty() {
+                symbols.push((Cow::Borrowed(name), nlist.n_value, 0));
+            }
+        }
+        // export addresses are relative to the mach header, i.e., the start of __TEXT
+        let base = self.segments.iter()
+            .find(|segment| segment.fileoff == 0 && segment.filesize != 0)
+            .map(|segment| segment.vmaddr);
+        if let Some(base) = base {
+            let defined = symbols.iter().map(|symbol| symbol.1).collect::<BTreeSet<_>>();
+            for export in self.exports()? {
+                if let exports::ExportInfo::Regular { address, .. } = export.info {
+                    let address = base.wrapping_add(address);
+                    if !defined.contains(&address) {
+                        symbols.push((Cow::Owned(export.name), address, 0));
+                    }
+                }
+            }
+        }
+        let mut starts = match self.function_starts() {
+            Some(starts) => starts.collect::<error::Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        starts.sort();
+        let segments = &self.segments;
+        ::symbol::sort_by_address(&mut symbols, |address| {
+            let next = match starts.binary_search(&address) {
+                Ok(idx) => starts.get(idx + 1),
+                Err(idx) => starts.get(idx),
+            };
+            let end = segments.iter()
+                .find(|segment| segment.vmaddr <= address && address - segment.vmaddr < segment.vmsize)
+                .map(|segment| segment.vmaddr + segment.vmsize);
+            match (next, end) {
+                (Some(&next), Some(end)) => Some(::core::cmp::min(next, end)),
+                (_, end) => end,
+            }
+        });
+        Ok(symbols)
+    }
+    /// Finds the symbol of `sorted_symbols` covering `address`, returning its name and the offset of `address` from the start of the symbol.
+    ///
+    /// This sorts the symbols on every call, unless `build_address_index` has been called
+    pub fn symbolicate(&self, address: u64) -> error::Result<Option<(String, u64)>> {
+        let found = match self.address_index {
+            Some(ref index) => ::symbol::containing(index, address).map(|(name, offset)| (name.to_string(), offset)),
+            None => ::symbol::containing(&self.sorted_symbols()?, address).map(|(name, offset)| (name.to_string(), offset)),
+        };
+        Ok(found)
+    }
+    /// Sorts the symbols once, which makes every subsequent `symbolicate` a binary search, and `sorted_symbols` a copy
+    pub fn build_address_index(&mut self) -> error::Result<()> {
+        self.address_index = None;
+        self.address_index = Some(self.sorted_symbols()?);
+        Ok(())
+    }
+    /// Finds the section `sectname` in the segment `segname`, e.g., `section("__TEXT", "__text")`, skipping any malformed sections on the way
+    pub fn section(&self, segname: &str, sectname: &str) -> Option<(segment::Section, segment::SectionData<'a>)> {
+        self.sections_where(|section| section.segname().ok() == Some(segname) && section.name().ok() == Some(sectname)).next()
+    }
+    fn sections_where<'b, F: Fn(&segment::Section) -> bool + 'b>(&'b self, f: F) -> Box<Iterator<Item=(segment::Section, segment::SectionData<'a>)> + 'b> {
+        Box::new(self.segments.iter()
+            .flat_map(|segment| segment.into_iter())
+            .filter_map(|section| section.ok())
+            .filter(move |&(ref section, _)| f(section)))
+    }
+    fn has_section_prefix(&self, prefix: &str) -> bool {
+        self.sections_where(|section| section.name().map_or(false, |name| name.starts_with(prefix))).next().is_some()
+    }
+    /// Whether this binary contains Objective-C metadata, i.e., any `__objc_*` sections, or the legacy `__OBJC` segment
+    pub fn has_objc(&self) -> bool {
+        self.has_section_prefix("__objc_") || self.segments.iter().any(|segment| segment.name().ok() == Some("__OBJC"))
+    }
+    /// Whether this binary contains Swift metadata, i.e., any `__swift5_*` (or older `__swift*`) sections
+    pub fn has_swift(&self) -> bool {
+        self.has_section_prefix("__swift")
+    }
+    /// Decodes the Objective-C image info, from `__objc_imageinfo` in any of the data segments, or from the legacy `__OBJC,__image_info`
+    pub fn objc_image_info(&self) -> Option<objc::ObjcImageInfo> {
+        let section = self.sections_where(|section| match (section.segname(), section.name()) {
+            (Ok("__DATA"), Ok("__objc_imageinfo")) | (Ok("__DATA_CONST"), Ok("__objc_imageinfo")) | (Ok("__DATA_DIRTY"), Ok("__objc_imageinfo")) => true,
+            (Ok("__OBJC"), Ok("__image_info")) => true,
+            _ => false,
+        }).next();
+        section.and_then(|(_, data)| objc::ObjcImageInfo::parse(data, self.ctx).ok())
+    }
     /// Return a vector of the relocations in this binary
     pub fn relocations(&self) -> error::Result<Vec<(usize, segment::RelocationIterator, segment::Section)>> {
         debug!("Iterating relocations");
@@ -125,16 +464,114 @@ impl<'a> MachO<'a> {
             Ok(vec![])
         }
     }
+    /// Return the exported symbols in this binary (if any), deduplicated by name, and sorted by name
+    pub fn sorted_exports(&self) -> error::Result<Vec<exports::Export>> {
+        let mut exports = self.exports()?;
+        // a stable sort keeps the first of every duplicate in front
+        exports.sort_by(|a, b| a.name.cmp(&b.name));
+        exports.dedup_by(|a, b| a.name == b.name);
+        Ok(exports)
+    }
     /// Return the imported symbols in this binary that dyld knows about (if any)
     pub fn imports(&self) -> error::Result<Vec<imports::Import>> {
+        self.imports_with_opts(&imports::ImportOptions::default())
+    }
+    /// Return the imported symbols in this binary that dyld knows about (if any), as selected by `opts`
+    pub fn imports_with_opts(&self, opts: &imports::ImportOptions) -> error::Result<Vec<imports::Import>> {
+        if let Some(ref interpreter) = self.bind_interpreter {
+            interpreter.imports_with_opts(self.libs.as_slice(), self.segments.as_slice(), &self.ctx, opts)
+        } else {
+            Ok(vec![])
+        }
+    }
+    /// Calls `f` with each imported symbol that dyld knows about (if any), without collecting them; see `BindInterpreter::for_each_import`
+    pub fn for_each_import<F: FnMut(imports::Import<'a>) -> error::Result<()>>(&self, f: F) -> error::Result<()> {
+        match self.bind_interpreter {
+            Some(ref interpreter) => interpreter.for_each_import(self.libs.as_slice(), self.segments.as_slice(), &self.ctx, f),
+            None => Ok(()),
+        }
+    }
+    /// Return the weak imports in this binary (if any), which dyld coalesces by name across every loaded image
+    pub fn weak_imports(&self) -> error::Result<Vec<imports::Import>> {
         if let Some(ref interpreter) = self.bind_interpreter {
-            interpreter.imports(self.libs.as_slice(), self.segments.as_slice(), &self.ctx)
+            interpreter.weak_imports(self.libs.as_slice(), self.segments.as_slice(), &self.ctx)
         } else {
             Ok(vec![])
         }
     }
+    /// Return the imported symbols in this binary (if any), deduplicated by `(name, dylib)`, and sorted by name, then dylib;
+    /// use `imports` for the opcode stream order, and every bound slot
+    pub fn sorted_imports(&self) -> error::Result<Vec<imports::Import>> {
+        self.imports_with_opts(&imports::ImportOptions { lazy: true, dedup: true, sorted: true })
+    }
+    /// Inspects the parsed segment and section tables for structural inconsistencies, like overlapping sections, or an entry point outside of any executable segment
+    pub fn anomalies(&self) -> Vec<Anomaly> {
+        use mach::constants::VM_PROT_EXECUTE;
+        let file_size = self.data.len() as u64;
+        let mut anomalies = Vec::new();
+        let mut segments = Vec::new();
+        let mut sections = Vec::new();
+        let mut section_index = 0;
+        for (i, segment) in self.segments.iter().enumerate() {
+            if let Some(range) = anomaly::push_out_of_bounds(&mut anomalies, Region::Segment(i), segment.fileoff, segment.filesize, file_size) {
+                segments.push((Region::Segment(i), range));
+            }
+            let is_empty = segment.filesize == 0 && segment.vmsize == 0;
+            for section in segment {
+                let index = section_index;
+                section_index += 1;
+                let section = if let Ok((section, _)) = section { section } else { continue };
+                if is_empty && section.size != 0 {
+                    anomalies.push(Anomaly::EmptySegment { segment: i, section: index });
+                }
+                // zerofill sections occupy no bytes in the file, whatever their offset claims
+                if section.is_zerofill() {
+                    continue;
+                }
+                if let Some(range) = anomaly::push_out_of_bounds(&mut anomalies, Region::Section(index), section.offset as u64, section.size, file_size) {
+                    sections.push((Region::Section(index), range));
+                }
+            }
+        }
+        anomaly::push_overlaps(&mut anomalies, &segments);
+        anomaly::push_overlaps(&mut anomalies, &sections);
+        if self.entry != 0 {
+            let entry = self.entry;
+            let is_executable = self.segments.iter().any(|segment| {
+                segment.initprot & VM_PROT_EXECUTE != 0 && anomaly::checked_range(segment.vmaddr, segment.vmsize)
+                    .map_or(false, |range| range.start <= entry && entry < range.end)
+            });
+            if !is_executable {
+                anomalies.push(Anomaly::EntryNotExecutable { entry: entry });
+            }
+        }
+        anomalies
+    }
     /// Parses the Mach-o binary from `bytes` at `offset`
-    pub fn parse(bytes: &'a [u8], mut offset: usize) -> error::Result<MachO<'a>> {
+    ///
+    /// `offset` is the base of the Mach-o in `bytes`, e.g., the offset of an architecture in a fat binary; since the load commands' file offsets are relative to that base,
+    /// so are all the file offsets goblin returns (load command offsets, segment `fileoff`s, import offsets, etc.)
+    pub fn parse(bytes: &'a [u8], offset: usize) -> error::Result<MachO<'a>> {
+        Self::parse_with(bytes, offset, ParseOptions::default())
+    }
+    /// Parses the Mach-o binary from `bytes` at `offset`, according to `opts`; see `parse` for how `offset` is used.
+    ///
+    /// Unless `opts.fail_fast`, a load command which can't be interpreted is recorded in `errors` rather than failing the parse:
+    /// a segment or a symbol table is missing from `segments` or `symbols`, a dylib's name in `libs` is empty (so the ordinals still line up),
+    /// and the entry point or install name is the one it would have been without the command. The header and the load command table itself are always required
+    ///
+    /// For a binary extracted from the dyld shared cache, set `opts.linkedit_base` to where its `__LINKEDIT` is in `bytes`, relative to `offset`;
+    /// otherwise its linkedit offsets point into the cache, past the end of `bytes`, and the parse fails
+    pub fn parse_with(bytes: &'a [u8], base_offset: usize, opts: ParseOptions) -> error::Result<MachO<'a>> {
+        if base_offset > bytes.len() {
+            return Err(error::Error::Malformed(format!("Mach-o base offset {:#x} is past the end of the {:#x} byte buffer", base_offset, bytes.len())));
+        }
+        // every offset in the load commands is relative to the start of the (thin) Mach-o, so work on that slice from here on
+        Self::parse_at(&bytes[base_offset..], 0, opts)
+    }
+    /// Parses the Mach-o binary whose header is at `offset` in `bytes`, and whose file offsets are relative to the start of `bytes`
+    fn parse_at(bytes: &'a [u8], mut offset: usize, opts: ParseOptions) -> error::Result<MachO<'a>> {
+        let mut recovery = Recovery::new(&opts);
         let (magic, maybe_ctx) = parse_magic_and_ctx(bytes, offset)?;
         let ctx = if let Some(ctx) = maybe_ctx { ctx } else { return Err(error::Error::BadMagic(magic as u64)) };
         debug!("Ctx: {:?}", ctx);
@@ -145,58 +582,76 @@ impl<'a> MachO<'a> {
         let is_64 = ctx.container.is_big();
         *offset = *offset + header::Header::size_with(&ctx.container);
         let ncmds = header.ncmds;
+        // where the linkedit offsets are relative to, and where `__LINKEDIT` is in `bytes`, if they differ
+        let linkedit = match opts.linkedit_base {
+            Some(base) => Some((linkedit_fileoff(bytes, *offset, ncmds, ctx.le)?, base)),
+            None => None,
+        };
         let mut cmds: Vec<load_command::LoadCommand> = Vec::with_capacity(ncmds);
         let mut symbols = None;
         let mut libs = vec!["self"];
         let mut export_trie = None;
+        let mut dysymtab = None;
         let mut bind_interpreter = None;
         let mut unixthread_entry_address = None;
         let mut main_entry_offset = None;
         let mut name = None;
         let mut segments = segment::Segments::new(ctx);
         for i in 0..ncmds {
-            let cmd = load_command::LoadCommand::parse(bytes, offset, ctx.le)?;
+            let mut cmd = load_command::LoadCommand::parse(bytes, offset, ctx.le)?;
+            if let Some((from, to)) = linkedit {
+                cmd.command.rebase_linkedit(from, to)?;
+            }
             debug!("{} - {:?}", i, cmd);
             match cmd.command {
                 load_command::CommandVariant::Segment32(command) => {
-                    // FIXME: we may want to be less strict about failure here, and just return an empty segment to allow parsing to continue?
-                    segments.push(segment::Segment::from_32(bytes, &command, cmd.offset, ctx)?)
+                    if let Some(segment) = recovery.recover(i, segment::Segment::from_32(bytes, &command, cmd.offset, ctx).map(Some))? {
+                        segments.push(segment)
+                    }
                 },
                 load_command::CommandVariant::Segment64(command) => {
-                    segments.push(segment::Segment::from_64(bytes, &command, cmd.offset, ctx)?)
+                    if let Some(segment) = recovery.recover(i, segment::Segment::from_64(bytes, &command, cmd.offset, ctx).map(Some))? {
+                        segments.push(segment)
+                    }
                 },
                 load_command::CommandVariant::Symtab(command) => {
-                    symbols = Some(symbols::Symbols::parse(bytes, &command, ctx)?);
+                    symbols = recovery.recover(i, symbols::Symbols::parse(bytes, &command, ctx).map(Some))?;
+                },
+                load_command::CommandVariant::Dysymtab(command) => {
+                    dysymtab = Some(command);
                 },
                   load_command::CommandVariant::LoadDylib      (command)
                 | load_command::CommandVariant::LoadUpwardDylib(command)
                 | load_command::CommandVariant::ReexportDylib  (command)
                 | load_command::CommandVariant::LoadWeakDylib  (command)
                 | load_command::CommandVariant::LazyLoadDylib  (command) => {
-                    let lib = bytes.pread::<&str>(cmd.offset + command.dylib.name as usize)?;
+                    let lib = recovery.recover(i, bytes.pread::<&str>(cmd.offset + command.dylib.name as usize).map_err(Into::into))?;
                     libs.push(lib);
                 },
                   load_command::CommandVariant::DyldInfo    (command)
                 | load_command::CommandVariant::DyldInfoOnly(command) => {
-                    export_trie = Some(exports::ExportTrie::new(bytes, &command));
-                    bind_interpreter = Some(imports::BindInterpreter::new(bytes, &command));
+                    if recovery.recover(i, command.check_bounds(bytes.len()).map(|_| true))? {
+                        export_trie = Some(exports::ExportTrie::new(bytes, &command));
+                        bind_interpreter = recovery.recover(i, imports::BindInterpreter::with_options(bytes, &command, &opts).map(Some))?;
+                    }
                 },
                 load_command::CommandVariant::Unixthread(command) => {
                     // dyld cares only about the first LC_UNIXTHREAD
                     if unixthread_entry_address.is_none() {
-                        unixthread_entry_address = Some(command.instruction_pointer(header.cputype)?);
+                        unixthread_entry_address = recovery.recover(i, command.instruction_pointer(header.cputype).map(Some))?;
                     }
                 },
                 load_command::CommandVariant::Main(command) => {
                     // dyld cares only about the first LC_MAIN
                     if main_entry_offset.is_none() {
-                        main_entry_offset = Some(command.entryoff);
+                        main_entry_offset = Some((i, command.entryoff));
                     }
                 },
                 load_command::CommandVariant::IdDylib(command) => {
-                    let id = bytes.pread::<&str>(cmd.offset + command.dylib.name as usize)?;
-                    libs[0] = id;
-                    name = Some(id);
+                    if let Some(id) = recovery.recover(i, bytes.pread::<&str>(cmd.offset + command.dylib.name as usize).map(Some).map_err(Into::into))? {
+                        libs[0] = id;
+                        name = Some(id);
+                    }
                 },
                 _ => ()
             }
@@ -205,7 +660,8 @@ impl<'a> MachO<'a> {
 
         // dyld prefers LC_MAIN over LC_UNIXTHREAD
         // choose the same way here
-        let (entry, old_style_entry) = if let Some(offset) = main_entry_offset {
+        let mut main_entry_address = None;
+        if let Some((i, offset)) = main_entry_offset {
             // map the entrypoint offset to a virtual memory address
             let base_address = segments.iter()
                 .filter(|s| &s.segname[0..7] == b"__TEXT\0")
@@ -213,9 +669,11 @@ impl<'a> MachO<'a> {
                 .next()
                 .ok_or_else(||
                     error::Error::Malformed(format!("image specifies LC_MAIN offset {} but has no __TEXT segment", offset))
-                )?;
-
-            (base_address + offset, false)
+                );
+            main_entry_address = recovery.recover(i, base_address.map(|base_address| Some(base_address + offset)))?;
+        }
+        let (entry, old_style_entry) = if let Some(address) = main_entry_address {
+            (address, false)
         } else if let Some(address) = unixthread_entry_address {
             (address, true)
         } else {
@@ -229,18 +687,33 @@ impl<'a> MachO<'a> {
             symbols: symbols,
             libs: libs,
             export_trie: export_trie,
+            dysymtab: dysymtab,
             bind_interpreter: bind_interpreter,
+            address_index: None,
             entry: entry,
             old_style_entry: old_style_entry,
             name: name,
             ctx: ctx,
             is_64: is_64,
             little_endian: little_endian,
+            errors: recovery.errors,
             data: bytes,
         })
     }
 }
 
+/// The `fileoff` of the `__LINKEDIT` segment among the `ncmds` load commands at `offset`
+fn linkedit_fileoff(bytes: &[u8], mut offset: usize, ncmds: usize, le: scroll::Endian) -> error::Result<u64> {
+    for _ in 0..ncmds {
+        match load_command::LoadCommand::parse(bytes, &mut offset, le)?.command {
+            load_command::CommandVariant::Segment32(command) if command.name().ok() == Some("__LINKEDIT") => return Ok(command.fileoff as u64),
+            load_command::CommandVariant::Segment64(command) if command.name().ok() == Some("__LINKEDIT") => return Ok(command.fileoff),
+            _ => (),
+        }
+    }
+    Err(error::Error::Malformed("ParseOptions::linkedit_base is set, but the binary has no __LINKEDIT segment".to_string()))
+}
+
 /// A Mach-o multi architecture (Fat) binary container
 pub struct MultiArch<'a> {
     data: &'a [u8],
@@ -248,6 +721,34 @@ pub struct MultiArch<'a> {
     pub narches: usize,
 }
 
+/// The bytes of the binary `arch` describes in the fat container `data`, whose `narches` headers start at `start`;
+/// fails unless they lie within the container, after the headers, and overlap no other architecture's
+fn arch_slice<'a>(data: &'a [u8], start: usize, narches: usize, arch: &fat::FatArch) -> error::Result<&'a [u8]> {
+    let range = |arch: &fat::FatArch| (arch.offset as u64)..(arch.offset as u64 + arch.size as u64);
+    let slice = range(arch);
+    if slice.end > data.len() as u64 {
+        return Err(error::Error::Malformed(format!("fat architecture at {:#x} of size {:#x} lies outside of the {:#x} byte container", arch.offset, arch.size, data.len())));
+    }
+    let headers_end = (start as u64).saturating_add((narches as u64).saturating_mul(fat::SIZEOF_FAT_ARCH as u64));
+    if anomaly::overlaps(&slice, &(0..headers_end)) {
+        return Err(error::Error::Malformed(format!("fat architecture at {:#x} of size {:#x} overlaps the fat headers", arch.offset, arch.size)));
+    }
+    let mut seen_self = false;
+    for index in 0..narches {
+        let other = data.pread_with::<fat::FatArch>(start + index * fat::SIZEOF_FAT_ARCH, scroll::BE)?;
+        let other = range(&other);
+        // skip `arch` itself, once
+        if other == slice && !seen_self {
+            seen_self = true;
+            continue;
+        }
+        if anomaly::overlaps(&slice, &other) {
+            return Err(error::Error::Malformed(format!("fat architecture at {:#x} of size {:#x} overlaps the one at {:#x}", arch.offset, arch.size, other.start)));
+        }
+    }
+    Ok(&data[slice.start as usize..slice.end as usize])
+}
+
 /// Iterator over the fat architecture headers in a `MultiArch` container
 pub struct FatArchIterator<'a> {
     index: usize,
@@ -289,8 +790,7 @@ impl<'a> Iterator for MachOIterator<'a> {
             self.index += 1;
             match self.data.pread_with::<fat::FatArch>(offset, scroll::BE) {
                 Ok(arch) => {
-                    let bytes = arch.slice(self.data);
-                    let binary = MachO::parse(bytes, 0);
+                    let binary = arch_slice(self.data, self.start, self.narches, &arch).and_then(|bytes| MachO::parse(bytes, 0));
                     Some(binary)
                 },
                 Err(e) => Some(Err(e.into()))
@@ -346,7 +846,7 @@ impl<'a> MultiArch<'a> {
         }
         let offset = (index * fat::SIZEOF_FAT_ARCH) + self.start;
         let arch = self.data.pread_with::<fat::FatArch>(offset, scroll::BE)?;
-        let bytes = arch.slice(self.data);
+        let bytes = arch_slice(self.data, self.start, self.narches, &arch)?;
         Ok(MachO::parse(bytes, 0)?)
     }
 
@@ -366,6 +866,27 @@ impl<'a> MultiArch<'a> {
         }
         Ok(None)
     }
+    /// Parses the binary for `cputype`, e.g., `CPU_TYPE_ARM64`, if there is one
+    pub fn find_binary(&self, cputype: cputype::CpuType) -> error::Result<Option<MachO<'a>>> {
+        match self.find_cputype(cputype)? {
+            Some(arch) => Ok(Some(MachO::parse(arch_slice(self.data, self.start, self.narches, &arch)?, 0)?)),
+            None => Ok(None),
+        }
+    }
+    /// Parses the binary for the architecture named `arch`, e.g., `"arm64"` or `"armv7s"`, as in `cputype::get_arch_from_flag`, if there is one
+    pub fn find_arch(&self, arch: &str) -> error::Result<Option<MachO<'a>>> {
+        let (cputype, cpusubtype) = match cputype::get_arch_from_flag(arch) {
+            Some(types) => types,
+            None => return Ok(None),
+        };
+        for fat_arch in self.iter_arches() {
+            let fat_arch = fat_arch?;
+            if fat_arch.cputype() == cputype && fat_arch.cpusubtype() == cpusubtype & !cputype::CPU_SUBTYPE_MASK {
+                return Ok(Some(MachO::parse(arch_slice(self.data, self.start, self.narches, &fat_arch)?, 0)?));
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl<'a> fmt::Debug for MultiArch<'a> {
@@ -389,6 +910,10 @@ pub enum Mach<'a> {
 impl<'a> Mach<'a> {
     /// Parse from `bytes` either a multi-arch binary or a regular mach-o binary
     pub fn parse(bytes: &'a [u8]) -> error::Result<Self> {
+        Self::parse_with(bytes, ParseOptions::default())
+    }
+    /// Like `parse`, but parses a regular mach-o binary according to `opts`, see `MachO::parse_with`; the architectures of a multi-arch binary are parsed on demand, with the default options
+    pub fn parse_with(bytes: &'a [u8], opts: ParseOptions) -> error::Result<Self> {
         let size = bytes.len();
         if size < 4 {
             let error = error::Error::Malformed(
@@ -403,7 +928,7 @@ impl<'a> Mach<'a> {
             },
             // we might be a regular binary
             _ => {
-                let binary = MachO::parse(bytes, 0)?;
+                let binary = MachO::parse_with(bytes, 0, opts)?;
                 Ok(Mach::Binary(binary))
             }
         }
@@ -0,0 +1,81 @@
+//! Objective-C runtime metadata, as emitted into `__objc_imageinfo` by the compiler.
+//!
+//! Every image with Objective-C (or Swift) code carries an `objc_image_info`, which the runtime consults before it loads any classes.
+
+use scroll::Pread;
+
+use error;
+use container;
+
+/// The image is a replacement for an image already loaded (fix and continue)
+pub const OBJC_IMAGE_IS_REPLACEMENT: u32 = 1 << 0;
+/// The image supports garbage collection
+pub const OBJC_IMAGE_SUPPORTS_GC: u32 = 1 << 1;
+/// The image requires garbage collection
+pub const OBJC_IMAGE_REQUIRES_GC: u32 = 1 << 2;
+/// The image's selectors and protocols have been uniqued by the dyld shared cache builder
+pub const OBJC_IMAGE_OPTIMIZED_BY_DYLD: u32 = 1 << 3;
+pub const OBJC_IMAGE_CORRECTED_SYNTHESIZE: u32 = 1 << 4;
+/// The image was built for a simulator platform
+pub const OBJC_IMAGE_IS_SIMULATED: u32 = 1 << 5;
+/// The image's category lists carry class properties
+pub const OBJC_IMAGE_HAS_CATEGORY_CLASS_PROPERTIES: u32 = 1 << 6;
+/// The mask of the Swift ABI version in the image info flags
+pub const OBJC_IMAGE_SWIFT_VERSION_MASK: u32 = 0xff << OBJC_IMAGE_SWIFT_VERSION_SHIFT;
+pub const OBJC_IMAGE_SWIFT_VERSION_SHIFT: u32 = 8;
+
+pub const SIZEOF_OBJC_IMAGE_INFO: usize = 8;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The `objc_image_info` of an image
+pub struct ObjcImageInfo {
+    /// The version of the image info, always 0
+    pub version: u32,
+    /// The `OBJC_IMAGE_*` flags of this image
+    pub flags: u32,
+}
+
+impl ObjcImageInfo {
+    /// Parses the image info from the contents of an `__objc_imageinfo` section
+    pub fn parse(bytes: &[u8], ctx: container::Ctx) -> error::Result<Self> {
+        let mut offset = 0;
+        let version = bytes.gread_with(&mut offset, ctx.le)?;
+        let flags = bytes.gread_with(&mut offset, ctx.le)?;
+        Ok(ObjcImageInfo { version, flags })
+    }
+    /// Whether the dyld shared cache builder has optimized this image
+    pub fn is_optimized_by_dyld(&self) -> bool {
+        self.flags & OBJC_IMAGE_OPTIMIZED_BY_DYLD != 0
+    }
+    /// Whether this image's categories carry class properties
+    pub fn has_category_class_properties(&self) -> bool {
+        self.flags & OBJC_IMAGE_HAS_CATEGORY_CLASS_PROPERTIES != 0
+    }
+    /// Whether this image was built for a simulator
+    pub fn is_simulated(&self) -> bool {
+        self.flags & OBJC_IMAGE_IS_SIMULATED != 0
+    }
+    /// The Swift ABI version the image was compiled with, or 0 if it contains no Swift code
+    pub fn swift_version(&self) -> u8 {
+        ((self.flags & OBJC_IMAGE_SWIFT_VERSION_MASK) >> OBJC_IMAGE_SWIFT_VERSION_SHIFT) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll;
+
+    #[test]
+    fn parse_image_info() {
+        let ctx = container::Ctx::new(container::Container::Big, scroll::LE);
+        let bytes = [0, 0, 0, 0, 0x48, 0x07, 0, 0];
+        let info = ObjcImageInfo::parse(&bytes, ctx).unwrap();
+        assert_eq!(info.version, 0);
+        assert!(info.is_optimized_by_dyld());
+        assert!(info.has_category_class_properties());
+        assert!(!info.is_simulated());
+        assert_eq!(info.swift_version(), 7);
+        assert!(ObjcImageInfo::parse(&bytes[..4], ctx).is_err());
+    }
+}
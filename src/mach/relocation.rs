@@ -78,6 +78,17 @@ impl RelocationInfo {
     pub fn to_str(&self, cputype: mach::cputype::CpuType) -> &'static str {
         reloc_to_str(self.r_type(), cputype)
     }
+    /// What this relocation writes: a PIC relocation is `PcRelative`, and an unsigned one (type 0 on every architecture) is `Absolute` if it is extern,
+    /// else `Relative`; any other is `RelocKind::Other(r_type)`
+    pub fn kind(&self) -> ::relocation::RelocKind {
+        use relocation::RelocKind;
+        match self.r_type() {
+            _ if self.is_pic() => RelocKind::PcRelative,
+            0 if self.is_extern() => RelocKind::Absolute,
+            0 => RelocKind::Relative,
+            r_type => RelocKind::Other(r_type as u32),
+        }
+    }
 }
 
 /// Absolute relocation type for Mach-O files
@@ -10,6 +10,8 @@ use container;
 use error;
 
 use mach::relocation::RelocationInfo;
+use mach::constants::{SECTION_TYPE, SECTION_ATTRIBUTES, S_ZEROFILL, S_GB_ZEROFILL, S_THREAD_LOCAL_ZEROFILL, S_ATTR_PURE_INSTRUCTIONS, S_ATTR_SOME_INSTRUCTIONS};
+use mach::constants::{VM_PROT_READ, VM_PROT_WRITE, VM_PROT_EXECUTE};
 use mach::load_command::{Section32, Section64, SegmentCommand32, SegmentCommand64, SIZEOF_SECTION_32, SIZEOF_SECTION_64, SIZEOF_SEGMENT_COMMAND_32, SIZEOF_SEGMENT_COMMAND_64, LC_SEGMENT, LC_SEGMENT_64};
 
 pub struct RelocationIterator<'a> {
@@ -35,8 +37,64 @@ impl<'a> Iterator for RelocationIterator<'a> {
     }
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+/// The `VM_PROT_*` permissions of a segment, i.e., its `initprot` or `maxprot`
+pub struct Protection(pub u32);
+
+impl Protection {
+    /// The raw `VM_PROT_*` bits
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+    /// Whether every bit of `prot` is set
+    pub fn contains(&self, prot: u32) -> bool {
+        self.0 & prot == prot
+    }
+    /// Whether `VM_PROT_EXECUTE` is set
+    pub fn is_exec(&self) -> bool {
+        self.contains(VM_PROT_EXECUTE)
+    }
+    /// Whether `VM_PROT_WRITE` is set
+    pub fn is_write(&self) -> bool {
+        self.contains(VM_PROT_WRITE)
+    }
+    /// Whether `VM_PROT_READ` is set
+    pub fn is_read(&self) -> bool {
+        self.contains(VM_PROT_READ)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+/// The `flags` of a section: its type, one of the `S_*`, in the low byte, and its `S_ATTR_*` attributes.
+///
+/// A section has no permissions of its own; whether it can be read or written is the `Protection` of its segment
+pub struct SectionFlags(pub u32);
+
+impl SectionFlags {
+    /// The raw bits
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+    /// Whether every bit of `flags` is set
+    pub fn contains(&self, flags: u32) -> bool {
+        self.0 & flags == flags
+    }
+    /// The section type, e.g., `S_ZEROFILL`
+    pub fn section_type(&self) -> u32 {
+        self.0 & SECTION_TYPE
+    }
+    /// The `S_ATTR_*` attributes
+    pub fn attributes(&self) -> u32 {
+        self.0 & SECTION_ATTRIBUTES
+    }
+    /// Whether the section holds instructions, i.e., has `S_ATTR_PURE_INSTRUCTIONS` or `S_ATTR_SOME_INSTRUCTIONS`
+    pub fn is_exec(&self) -> bool {
+        self.0 & (S_ATTR_PURE_INSTRUCTIONS | S_ATTR_SOME_INSTRUCTIONS) != 0
+    }
+}
+
 /// Generalized 32/64 bit Section
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Section {
     /// name of this section
     pub sectname:  [u8; 16],
@@ -56,6 +114,12 @@ pub struct Section {
     pub nreloc:    u32,
     /// flags (section type and attributes
     pub flags:     u32,
+    /// reserved (for offset or index), e.g., the index of the first indirect symbol of a stubs or pointer section
+    pub reserved1: u32,
+    /// reserved (for count or sizeof), e.g., the size of each stub of a stubs section
+    pub reserved2: u32,
+    /// reserved, and only in a 64-bit section
+    pub reserved3: u32,
 }
 
 impl Section {
@@ -67,6 +131,29 @@ impl Section {
     pub fn segname(&self) -> error::Result<&str> {
         Ok(self.segname.pread::<&str>(0)?)
     }
+    /// The typed `flags` of this section
+    pub fn section_flags(&self) -> SectionFlags {
+        SectionFlags(self.flags)
+    }
+    /// Whether this is a zero-fill section, which occupies no bytes in the file, e.g., `__DATA,__bss`
+    pub fn is_zerofill(&self) -> bool {
+        match self.flags & SECTION_TYPE {
+            S_ZEROFILL | S_GB_ZEROFILL | S_THREAD_LOCAL_ZEROFILL => true,
+            _ => false,
+        }
+    }
+    /// The contents of this section given `bytes`, which must be the original binary; a zero-fill section has no contents in the file, and is an error
+    pub fn data<'b>(&self, bytes: &'b [u8]) -> error::Result<&'b [u8]> {
+        if self.is_zerofill() {
+            return Err(error::Error::Malformed(format!("section {} is zero-fill, and has no data in the file", self.name().unwrap_or("BAD_SECTION_NAME"))));
+        }
+        let offset = self.offset as usize;
+        let end = (self.size as usize).checked_add(offset);
+        match end.and_then(|end| bytes.get(offset..end)) {
+            Some(data) => Ok(data),
+            None => Err(error::Error::Malformed(format!("section {} at offset {:#x} of size {:#x} is out of bounds", self.name().unwrap_or("BAD_SECTION_NAME"), offset, self.size))),
+        }
+    }
     /// Iterate this sections relocations given `data`; `data` must be the original binary
     pub fn iter_relocations<'b>(&self, data: &'b [u8], ctx: container::Ctx) -> RelocationIterator<'b> {
         let offset = self.reloff as usize;
@@ -93,9 +180,9 @@ impl From<Section> for Section64 {
             reloff:   section.reloff,
             nreloc:   section.nreloc,
             flags:    section.flags,
-            reserved1: 0,
-            reserved2: 0,
-            reserved3: 0,
+            reserved1: section.reserved1,
+            reserved2: section.reserved2,
+            reserved3: section.reserved3,
         }
     }
 }
@@ -112,8 +199,8 @@ impl From<Section> for Section32 {
             reloff:   section.reloff,
             nreloc:   section.nreloc,
             flags:    section.flags,
-            reserved1: 0,
-            reserved2: 0,
+            reserved1: section.reserved1,
+            reserved2: section.reserved2,
         }
     }
 }
@@ -130,6 +217,9 @@ impl fmt::Debug for Section {
             .field("reloff",   &self.reloff)
             .field("nreloc",   &self.nreloc)
             .field("flags",    &self.flags)
+            .field("reserved1", &self.reserved1)
+            .field("reserved2", &self.reserved2)
+            .field("reserved3", &self.reserved3)
             .finish()
     }
 }
@@ -146,6 +236,9 @@ impl From<Section32> for Section {
             reloff:   section.reloff,
             nreloc:   section.nreloc,
             flags:    section.flags,
+            reserved1: section.reserved1,
+            reserved2: section.reserved2,
+            reserved3: 0,
         }
     }
 }
@@ -162,6 +255,9 @@ impl From<Section64> for Section {
             reloff:   section.reloff,
             nreloc:   section.nreloc,
             flags:    section.flags,
+            reserved1: section.reserved1,
+            reserved2: section.reserved2,
+            reserved3: section.reserved3,
         }
     }
 }
@@ -412,6 +508,24 @@ impl<'a> Segment<'a> {
     pub fn name(&self) -> error::Result<&str> {
         Ok(self.segname.pread::<&str>(0)?)
     }
+    /// The permissions this segment is mapped with, i.e., its `initprot`
+    pub fn protection(&self) -> Protection {
+        Protection(self.initprot)
+    }
+    /// The most permissions this segment can be given, i.e., its `maxprot`
+    pub fn max_protection(&self) -> Protection {
+        Protection(self.maxprot)
+    }
+    /// The segment and section name of this segment's `index`th section header, like `Section::segname` and `Section::name`,
+    /// but borrowed from the binary rather than from a parsed `Section`
+    pub fn section_names(&self, index: usize) -> error::Result<(&'a str, &'a str)> {
+        // the `sectname` and `segname` start every section header
+        let offset = self.offset + Segment::size_with(&self.ctx) + index.saturating_mul(Section::size_with(&self.ctx));
+        match self.raw_data.get(offset..offset.saturating_add(32)) {
+            Some(names) if index < self.nsects as usize => Ok((names[16..].pread::<&str>(0)?, names[..16].pread::<&str>(0)?)),
+            _ => Err(error::Error::Malformed(format!("segment {} has no section header {}", self.name().unwrap_or(""), index))),
+        }
+    }
     /// Get the sections from this segment, erroring if any section couldn't be retrieved
     pub fn sections(&self) -> error::Result<Vec<(Section, SectionData<'a>)>> {
         let mut sections = Vec::new();
@@ -420,9 +534,29 @@ impl<'a> Segment<'a> {
         }
         Ok(sections)
     }
+    /// The contents of this segment given `bytes`, which must be the original binary, i.e., its `fileoff..fileoff + filesize`, erroring if they are out of bounds;
+    /// the same as its `data` field, for a segment which was parsed from `bytes`, and empty for a zero-fill segment, like `__PAGEZERO`
+    pub fn data<'b>(&self, bytes: &'b [u8]) -> error::Result<&'b [u8]> {
+        Segment::data_of(bytes, &self.segname, self.fileoff, self.filesize)
+    }
+    /// The data of the segment `segname` in `bytes`, at `fileoff`, of `filesize` bytes
+    fn data_of<'b>(bytes: &'b [u8], segname: &[u8; 16], fileoff: u64, filesize: u64) -> error::Result<&'b [u8]> {
+        match bytes.get(fileoff as usize..(fileoff as usize).saturating_add(filesize as usize)) {
+            Some(data) if fileoff <= ::core::usize::MAX as u64 && filesize <= ::core::usize::MAX as u64 => Ok(data),
+            _ => {
+                let name = segname.pread::<&str>(0).unwrap_or("");
+                let hint = if name == "__LINKEDIT" {
+                    "; if it was extracted from the dyld shared cache, its linkedit offsets are relative to the cache, see `ParseOptions::linkedit_base`"
+                } else {
+                    ""
+                };
+                Err(error::Error::Malformed(format!("segment {} at {:#x} of size {:#x} lies past the end of the {:#x} byte binary{}", name, fileoff, filesize, bytes.len(), hint)))
+            },
+        }
+    }
     /// Convert the raw C 32-bit segment command to a generalized version
     pub fn from_32(bytes: &'a[u8], segment: &SegmentCommand32, offset: usize, ctx: container::Ctx) -> Result<Self, error::Error> {
-        let data = bytes.pread_with(segment.fileoff as usize, segment.filesize as usize)?;
+        let data = Self::data_of(bytes, &segment.segname, segment.fileoff as u64, segment.filesize as u64)?;
         Ok(Segment {
             cmd:      segment.cmd,
             cmdsize:  segment.cmdsize,
@@ -443,7 +577,7 @@ impl<'a> Segment<'a> {
     }
     /// Convert the raw C 64-bit segment command to a generalized version
     pub fn from_64(bytes: &'a [u8], segment: &SegmentCommand64, offset: usize, ctx: container::Ctx) -> Result<Self, error::Error> {
-        let data = bytes.pread_with(segment.fileoff as usize, segment.filesize as usize)?;
+        let data = Self::data_of(bytes, &segment.segname, segment.fileoff, segment.filesize)?;
         Ok(Segment {
             cmd:      segment.cmd,
             cmdsize:  segment.cmdsize,
@@ -464,6 +598,134 @@ impl<'a> Segment<'a> {
     }
 }
 
+/// Builds a segment load command, with its section headers, laying out the sections one after another, each at an address aligned to its `align`.
+///
+/// The builder sets each section's `segname`, `addr`, and `offset` (0 for a zero-fill section), and the segment's `cmd`, `cmdsize`, and `nsects`;
+/// the segment's `vmsize` and `filesize` are at least what its sections need
+#[derive(Debug, Default)]
+pub struct SegmentBuilder {
+    segname: [u8; 16],
+    vmaddr: u64,
+    vmsize: u64,
+    fileoff: u64,
+    filesize: u64,
+    maxprot: u32,
+    initprot: u32,
+    flags: u32,
+    sections_at: u64,
+    sections: Vec<Section>,
+}
+
+impl SegmentBuilder {
+    /// A builder for the segment `name`, at address and file offset 0, without permissions or sections; a name longer than 16 bytes is an error
+    pub fn new(name: &str) -> error::Result<Self> {
+        let mut builder = SegmentBuilder::default();
+        if name.len() > builder.segname.len() {
+            return Err(error::Error::Malformed(format!("segment name {} is longer than 16 bytes", name)));
+        }
+        builder.segname[..name.len()].copy_from_slice(name.as_bytes());
+        Ok(builder)
+    }
+    /// Sets the address of the segment
+    pub fn vmaddr(mut self, vmaddr: u64) -> Self {
+        self.vmaddr = vmaddr;
+        self
+    }
+    /// Sets the least size of the segment in memory
+    pub fn vmsize(mut self, vmsize: u64) -> Self {
+        self.vmsize = vmsize;
+        self
+    }
+    /// Sets the file offset of the segment
+    pub fn fileoff(mut self, fileoff: u64) -> Self {
+        self.fileoff = fileoff;
+        self
+    }
+    /// Sets the least size of the segment in the file
+    pub fn filesize(mut self, filesize: u64) -> Self {
+        self.filesize = filesize;
+        self
+    }
+    /// Sets the most, and the initial, `VM_PROT_*` permissions of the segment
+    pub fn protection(mut self, maxprot: u32, initprot: u32) -> Self {
+        self.maxprot = maxprot;
+        self.initprot = initprot;
+        self
+    }
+    /// Sets the segment's flags, e.g., `SG_PROTECTED_VERSION_1`
+    pub fn flags(mut self, flags: u32) -> Self {
+        self.flags = flags;
+        self
+    }
+    /// Places the first section `offset` bytes into the segment, rather than at its start, e.g., past the Mach-o header and load commands in `__TEXT`
+    pub fn sections_at(mut self, offset: u64) -> Self {
+        self.sections_at = offset;
+        self
+    }
+    /// Adds `section` after the previously added ones
+    pub fn section(mut self, section: Section) -> Self {
+        self.sections.push(section);
+        self
+    }
+    /// The segment and its section headers, as laid out in the class of `ctx`, e.g., to know where to write each section's contents;
+    /// the segment's `data` is empty, the section headers being returned separately
+    pub fn layout(&self, ctx: container::Ctx) -> error::Result<(Segment<'static>, Vec<Section>)> {
+        let overflow = || error::Error::Malformed(format!("the sections of segment {} overflow its address space", self.segname.pread::<&str>(0).unwrap_or("BAD_SEGMENT_NAME")));
+        let mut sections = Vec::with_capacity(self.sections.len());
+        // the ends of the sections so far, relative to the segment
+        let mut vm_end = self.sections_at;
+        let mut file_end = self.sections_at;
+        for section in &self.sections {
+            let mut section = section.clone();
+            let align = match 1u64.checked_shl(section.align) {
+                Some(align) if section.align < 64 => align,
+                _ => return Err(error::Error::Malformed(format!("section alignment 2^{} is too large", section.align))),
+            };
+            let start = vm_end.checked_add(align - 1).ok_or_else(overflow)? & !(align - 1);
+            let end = start.checked_add(section.size).ok_or_else(overflow)?;
+            section.segname = self.segname;
+            section.addr = self.vmaddr.checked_add(start).ok_or_else(overflow)?;
+            if section.is_zerofill() {
+                section.offset = 0;
+            } else {
+                let offset = self.fileoff.checked_add(start).ok_or_else(overflow)?;
+                if offset > u64::from(::core::u32::MAX) {
+                    return Err(error::Error::Malformed(format!("section at file offset {:#x} is past the reach of a 32-bit offset", offset)));
+                }
+                section.offset = offset as u32;
+                file_end = end;
+            }
+            vm_end = end;
+            sections.push(section);
+        }
+        let segment = Segment {
+            cmdsize: (Segment::size_with(&ctx) + sections.len() * Section::size_with(&ctx)) as u32,
+            segname: self.segname,
+            vmaddr: self.vmaddr,
+            vmsize: ::core::cmp::max(self.vmsize, vm_end),
+            fileoff: self.fileoff,
+            filesize: ::core::cmp::max(self.filesize, file_end),
+            maxprot: self.maxprot,
+            initprot: self.initprot,
+            nsects: sections.len() as u32,
+            flags: self.flags,
+            .. Segment::new(ctx, &[])
+        };
+        Ok((segment, sections))
+    }
+    /// Lays out and writes the segment load command, followed by its section headers, in the class and byte order of `ctx`
+    pub fn build(&self, ctx: container::Ctx) -> error::Result<Vec<u8>> {
+        let (segment, sections) = self.layout(ctx)?;
+        let mut bytes = vec![0u8; segment.cmdsize as usize];
+        let mut offset = 0;
+        bytes.gwrite_with(segment, &mut offset, ctx)?;
+        for section in sections {
+            bytes.gwrite_with(section, &mut offset, ctx)?;
+        }
+        Ok(bytes)
+    }
+}
+
 #[derive(Debug, Default)]
 /// An opaque 32/64-bit container for Mach-o segments
 pub struct Segments<'a> {
@@ -492,6 +754,19 @@ impl<'a, 'b> IntoIterator for &'b Segments<'a> {
     }
 }
 
+/// Resolves the location `seg_offset` bytes into the segment at `seg_index`, as named by the bind, rebase and chained fixup streams, to its file offset and virtual address;
+/// every linkedit parser resolves its locations through this, rather than doing the arithmetic itself
+pub fn resolve(segments: &[Segment], seg_index: usize, seg_offset: u64) -> error::Result<(u64, u64)> {
+    let segment = match segments.get(seg_index) {
+        Some(segment) => segment,
+        None => return Err(error::Error::Malformed(format!("segment {} is referenced but there are only {} segments", seg_index, segments.len()))),
+    };
+    match (segment.fileoff.checked_add(seg_offset), segment.vmaddr.checked_add(seg_offset)) {
+        (Some(offset), Some(address)) => Ok((offset, address)),
+        _ => Err(error::Error::Malformed(format!("offset {:#x} into segment {} overflows", seg_offset, seg_index))),
+    }
+}
+
 impl<'a> Segments<'a> {
     /// Construct a new generalized segment container from this `ctx`
     pub fn new(ctx: container::Ctx) -> Self {
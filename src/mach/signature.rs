@@ -0,0 +1,288 @@
+//! The code signature of `LC_CODE_SIGNATURE`, an embedded signature `SuperBlob` in the `__LINKEDIT` segment.
+//!
+//! The super blob is an index of typed blobs: the CodeDirectory, which hashes the binary's pages and carries its signing identifier,
+//! the requirements, the entitlements plist, and the CMS signature itself. Unlike the rest of the Mach-o, all of it is big endian.
+
+use core::fmt::{self, Debug};
+use core::str;
+use scroll::{Pread, BE};
+use alloc::vec::Vec;
+
+use error;
+use mach::load_command;
+
+/// An embedded signature super blob, the blob `LC_CODE_SIGNATURE` points to
+pub const CSMAGIC_EMBEDDED_SIGNATURE: u32 = 0xfade_0cc0;
+/// A detached signature super blob
+pub const CSMAGIC_DETACHED_SIGNATURE: u32 = 0xfade_0cc1;
+pub const CSMAGIC_REQUIREMENT: u32 = 0xfade_0c00;
+/// A requirements set
+pub const CSMAGIC_REQUIREMENTS: u32 = 0xfade_0c01;
+/// A CodeDirectory
+pub const CSMAGIC_CODEDIRECTORY: u32 = 0xfade_0c02;
+/// An XML plist of entitlements
+pub const CSMAGIC_EMBEDDED_ENTITLEMENTS: u32 = 0xfade_7171;
+/// DER encoded entitlements
+pub const CSMAGIC_EMBEDDED_DER_ENTITLEMENTS: u32 = 0xfade_7172;
+/// A CMS signature, among other things
+pub const CSMAGIC_BLOBWRAPPER: u32 = 0xfade_0b01;
+
+pub const CSSLOT_CODEDIRECTORY: u32 = 0;
+pub const CSSLOT_INFOSLOT: u32 = 1;
+pub const CSSLOT_REQUIREMENTS: u32 = 2;
+pub const CSSLOT_RESOURCEDIR: u32 = 3;
+pub const CSSLOT_APPLICATION: u32 = 4;
+pub const CSSLOT_ENTITLEMENTS: u32 = 5;
+pub const CSSLOT_DER_ENTITLEMENTS: u32 = 7;
+/// The first of the alternate CodeDirectories, which use other hash types
+pub const CSSLOT_ALTERNATE_CODEDIRECTORIES: u32 = 0x1000;
+pub const CSSLOT_SIGNATURESLOT: u32 = 0x1_0000;
+
+pub const CS_HASHTYPE_SHA1: u8 = 1;
+pub const CS_HASHTYPE_SHA256: u8 = 2;
+pub const CS_HASHTYPE_SHA256_TRUNCATED: u8 = 3;
+pub const CS_HASHTYPE_SHA384: u8 = 4;
+
+/// The first CodeDirectory version with a scatter vector
+pub const CS_SUPPORTSSCATTER: u32 = 0x2_0100;
+/// The first CodeDirectory version with a team identifier
+pub const CS_SUPPORTSTEAMID: u32 = 0x2_0200;
+
+/// The size of a blob's header: its magic and length
+pub const SIZEOF_BLOB_HEADER: usize = 8;
+/// The size of a super blob's header: its magic, length and count
+pub const SIZEOF_SUPER_BLOB_HEADER: usize = 12;
+pub const SIZEOF_BLOB_INDEX: usize = 8;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Pread, Pwrite, SizeWith)]
+/// An entry of the super blob's index
+pub struct BlobIndex {
+    /// The `CSSLOT_*` of the blob
+    pub typ: u32,
+    /// The offset of the blob, relative to the start of the super blob
+    pub offset: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// A blob of the super blob, which may have a magic goblin doesn't know
+pub struct Blob<'a> {
+    /// The `CSSLOT_*` the index lists the blob under
+    pub slot: u32,
+    /// The `CSMAGIC_*` of the blob
+    pub magic: u32,
+    /// The blob, including its header
+    pub data: &'a [u8],
+}
+
+impl<'a> Debug for Blob<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "slot: {:#x} magic: {:#x} size: {:#x}", self.slot, self.magic, self.data.len())
+    }
+}
+
+impl<'a> Blob<'a> {
+    /// The blob's contents, after its header
+    pub fn contents(&self) -> &'a [u8] {
+        &self.data[SIZEOF_BLOB_HEADER..]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The fixed part of a CodeDirectory, along with its identifiers
+pub struct CodeDirectory<'a> {
+    pub version: u32,
+    /// The `CS_*` code signing flags, e.g., `CS_ADHOC`
+    pub flags: u32,
+    /// The offset of the hash of the first code slot, relative to the start of the CodeDirectory
+    pub hash_offset: u32,
+    /// The number of special slots, hashing the other blobs, in front of `hash_offset`
+    pub n_special_slots: u32,
+    /// The number of code slots, hashing a page of the binary each
+    pub n_code_slots: u32,
+    /// The number of bytes of the binary which are hashed
+    pub code_limit: u32,
+    /// The size of a hash, in bytes
+    pub hash_size: u8,
+    /// A `CS_HASHTYPE_*` value
+    pub hash_type: u8,
+    pub platform: u8,
+    /// The log2 of the page size the code slots hash, or 0 for a single unpaged hash
+    pub page_size: u8,
+    /// The signing identifier, e.g., `com.apple.ls`
+    pub ident: &'a str,
+    /// The team identifier, if the version has one and it is set
+    pub team_id: Option<&'a str>,
+}
+
+impl<'a> CodeDirectory<'a> {
+    /// Parses the CodeDirectory `blob`
+    pub fn parse(blob: &Blob<'a>) -> error::Result<CodeDirectory<'a>> {
+        let data = blob.data;
+        if blob.magic != CSMAGIC_CODEDIRECTORY {
+            return Err(error::Error::Malformed(format!("blob in slot {:#x} has magic {:#x}, which is not a CodeDirectory", blob.slot, blob.magic)));
+        }
+        let mut offset = SIZEOF_BLOB_HEADER;
+        let offset = &mut offset;
+        let version: u32 = data.gread_with(offset, BE)?;
+        let flags: u32 = data.gread_with(offset, BE)?;
+        let hash_offset: u32 = data.gread_with(offset, BE)?;
+        let ident_offset: u32 = data.gread_with(offset, BE)?;
+        let n_special_slots: u32 = data.gread_with(offset, BE)?;
+        let n_code_slots: u32 = data.gread_with(offset, BE)?;
+        let code_limit: u32 = data.gread_with(offset, BE)?;
+        let hash_size: u8 = data.gread(offset)?;
+        let hash_type: u8 = data.gread(offset)?;
+        let platform: u8 = data.gread(offset)?;
+        let page_size: u8 = data.gread(offset)?;
+        let ident: &'a str = data.pread(ident_offset as usize)?;
+        let team_id = if version >= CS_SUPPORTSTEAMID {
+            // spare2 and the scatter vector come first
+            let team_offset: u32 = data.pread_with(*offset + 8, BE)?;
+            if team_offset != 0 { Some(data.pread::<&'a str>(team_offset as usize)?) } else { None }
+        } else {
+            None
+        };
+        Ok(CodeDirectory { version, flags, hash_offset, n_special_slots, n_code_slots, code_limit, hash_size, hash_type, platform, page_size, ident, team_id })
+    }
+}
+
+#[derive(Debug)]
+/// The embedded signature super blob of a binary
+pub struct SuperBlob<'a> {
+    /// The `CSMAGIC_*` of the super blob, usually `CSMAGIC_EMBEDDED_SIGNATURE`
+    pub magic: u32,
+    /// The blobs, in index order
+    pub blobs: Vec<Blob<'a>>,
+}
+
+impl<'a> SuperBlob<'a> {
+    /// Reads the super blob `command` points to, which must lie within `linkedit`, the file range of the `__LINKEDIT` segment;
+    /// `bytes` must be the Mach-o the command was parsed from
+    pub fn parse(bytes: &'a [u8], command: &load_command::LinkeditDataCommand, linkedit: (u64, u64)) -> error::Result<Self> {
+        let (start, end) = (command.dataoff as u64, command.dataoff as u64 + command.datasize as u64);
+        if start < linkedit.0 || end > linkedit.0.saturating_add(linkedit.1) {
+            return Err(error::Error::Malformed(format!("code signature at {:#x} of size {:#x} is not within __LINKEDIT", start, command.datasize)));
+        }
+        let data = match bytes.get(start as usize..end as usize) {
+            Some(data) => data,
+            None => return Err(error::Error::Malformed(format!("code signature at {:#x} of size {:#x} is out of bounds", start, command.datasize))),
+        };
+        SuperBlob::parse_blob(data)
+    }
+    /// Reads the super blob at the start of `data`, bounds checking every blob against it
+    pub fn parse_blob(data: &'a [u8]) -> error::Result<Self> {
+        let offset = &mut 0;
+        let magic: u32 = data.gread_with(offset, BE)?;
+        let length: u32 = data.gread_with(offset, BE)?;
+        let count: u32 = data.gread_with(offset, BE)?;
+        // the signature is usually padded, so its length may be shorter than the command's datasize, but never longer
+        let data = match data.get(..length as usize) {
+            Some(data) if length as usize >= SIZEOF_SUPER_BLOB_HEADER => data,
+            _ => return Err(error::Error::Malformed(format!("code signature of length {:#x} does not fit in its {:#x} bytes", length, data.len()))),
+        };
+        if count as usize > (data.len() - SIZEOF_SUPER_BLOB_HEADER) / SIZEOF_BLOB_INDEX {
+            return Err(error::Error::Malformed(format!("code signature of length {:#x} cannot index {} blobs", length, count)));
+        }
+        let mut blobs = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let index: BlobIndex = data.gread_with(offset, BE)?;
+            let blob_offset = index.offset as usize;
+            let blob_magic: u32 = data.pread_with(blob_offset, BE)?;
+            let blob_length: u32 = data.pread_with(blob_offset + 4, BE)?;
+            let blob = match blob_offset.checked_add(blob_length as usize).and_then(|end| data.get(blob_offset..end)) {
+                Some(blob) if blob_length as usize >= SIZEOF_BLOB_HEADER => blob,
+                _ => return Err(error::Error::Malformed(format!("code signature blob in slot {:#x} at {:#x} of length {:#x} is out of bounds", index.typ, blob_offset, blob_length))),
+            };
+            blobs.push(Blob { slot: index.typ, magic: blob_magic, data: blob });
+        }
+        Ok(SuperBlob { magic, blobs })
+    }
+    /// The blob in `slot`, if there is one
+    pub fn blob(&self, slot: u32) -> Option<&Blob<'a>> {
+        self.blobs.iter().find(|blob| blob.slot == slot)
+    }
+    /// The primary CodeDirectory, if there is one
+    pub fn code_directory(&self) -> error::Result<Option<CodeDirectory<'a>>> {
+        match self.blob(CSSLOT_CODEDIRECTORY) {
+            Some(blob) => CodeDirectory::parse(blob).map(Some),
+            None => Ok(None),
+        }
+    }
+    /// The XML plist of entitlements, if there is one and it is valid UTF-8
+    pub fn entitlements(&self) -> Option<&'a str> {
+        match self.blob(CSSLOT_ENTITLEMENTS) {
+            Some(blob) if blob.magic == CSMAGIC_EMBEDDED_ENTITLEMENTS => str::from_utf8(blob.contents()).ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    fn blob(magic: u32, contents: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0u8; SIZEOF_BLOB_HEADER];
+        bytes.pwrite_with(magic, 0, BE).unwrap();
+        bytes.pwrite_with((SIZEOF_BLOB_HEADER + contents.len()) as u32, 4, BE).unwrap();
+        bytes.extend_from_slice(contents);
+        bytes
+    }
+
+    fn super_blob(blobs: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut bytes = vec![0u8; SIZEOF_SUPER_BLOB_HEADER + blobs.len() * SIZEOF_BLOB_INDEX];
+        bytes.pwrite_with(CSMAGIC_EMBEDDED_SIGNATURE, 0, BE).unwrap();
+        bytes.pwrite_with(blobs.len() as u32, 8, BE).unwrap();
+        for (i, &(slot, ref blob)) in blobs.iter().enumerate() {
+            let index = BlobIndex { typ: slot, offset: bytes.len() as u32 };
+            bytes.pwrite_with(index, SIZEOF_SUPER_BLOB_HEADER + i * SIZEOF_BLOB_INDEX, BE).unwrap();
+            bytes.extend_from_slice(blob);
+        }
+        let length = bytes.len() as u32;
+        bytes.pwrite_with(length, 4, BE).unwrap();
+        // padding, as codesign leaves it
+        bytes.extend_from_slice(&[0; 16]);
+        bytes
+    }
+
+    #[test]
+    fn parse_super_blob() {
+        // version 0x20200 CodeDirectory, with its ident and team id after the fixed part
+        let mut code_directory = vec![0u8; 44];
+        code_directory.pwrite_with(CS_SUPPORTSTEAMID, 0, BE).unwrap();
+        code_directory.pwrite_with(0x2u32, 4, BE).unwrap();
+        code_directory.pwrite_with(52u32, 12, BE).unwrap();
+        code_directory.pwrite_with(1u32, 20, BE).unwrap();
+        code_directory[28] = 32;
+        code_directory[29] = CS_HASHTYPE_SHA256;
+        code_directory[31] = 12;
+        code_directory.pwrite_with(58u32, 40, BE).unwrap();
+        code_directory.extend_from_slice(b"hello\0TEAM\0");
+        let plist = b"<plist version=\"1.0\"><dict/></plist>";
+        let bytes = super_blob(&[
+            (CSSLOT_CODEDIRECTORY, blob(CSMAGIC_CODEDIRECTORY, &code_directory)),
+            (CSSLOT_ENTITLEMENTS, blob(CSMAGIC_EMBEDDED_ENTITLEMENTS, plist)),
+            (CSSLOT_SIGNATURESLOT, blob(0xfade_ffff, b"unknown")),
+        ]);
+        let signature = SuperBlob::parse_blob(&bytes).unwrap();
+        assert_eq!(signature.magic, CSMAGIC_EMBEDDED_SIGNATURE);
+        assert_eq!(signature.blobs.len(), 3);
+        assert_eq!(signature.blob(CSSLOT_SIGNATURESLOT).unwrap().contents(), b"unknown");
+        assert_eq!(signature.entitlements(), Some(str::from_utf8(plist).unwrap()));
+        let code_directory = signature.code_directory().unwrap().unwrap();
+        assert_eq!((code_directory.ident, code_directory.team_id), ("hello", Some("TEAM")));
+        assert_eq!((code_directory.hash_type, code_directory.hash_size, code_directory.page_size), (CS_HASHTYPE_SHA256, 32, 12));
+        assert_eq!(code_directory.n_code_slots, 1);
+
+        let command = load_command::LinkeditDataCommand { cmd: load_command::LC_CODE_SIGNATURE, cmdsize: 16, dataoff: 0, datasize: bytes.len() as u32 };
+        assert!(SuperBlob::parse(&bytes, &command, (0, bytes.len() as u64)).is_ok());
+        assert!(SuperBlob::parse(&bytes, &command, (0, bytes.len() as u64 - 1)).is_err());
+        // a blob reaching past the end of the super blob
+        let mut truncated = bytes.clone();
+        let length = bytes.len() as u32 - 16 - 1;
+        truncated.pwrite_with(length, 4, BE).unwrap();
+        assert!(SuperBlob::parse_blob(&truncated).is_err());
+    }
+}
@@ -5,9 +5,17 @@
 use scroll::{ctx, Pread, Pwrite};
 use scroll::ctx::SizeWith;
 use error;
+use strtab::Strtab;
 use container::{self, Container};
 use mach::load_command;
+use mach::relocation;
 use core::fmt::{self, Debug};
+use alloc::vec::Vec;
+
+/// An indirect symbol table entry for a local symbol that was stripped, rather than an index into the symbol table
+pub const INDIRECT_SYMBOL_LOCAL: u32 = 0x8000_0000;
+/// An indirect symbol table entry for an absolute symbol that was stripped
+pub const INDIRECT_SYMBOL_ABS: u32 = 0x4000_0000;
 
 // The n_type field really contains four fields which are used via the following masks.
 /// if any of these bits set, a symbolic debugging entry
@@ -78,6 +86,38 @@ pub const N_ECOMM:   u8 = 0xe4;
 pub const N_ECOML:   u8 = 0xe8;
 pub const N_LENG:    u8 = 0xfe;
 
+// The n_desc field of an undefined symbol says how it is referenced, in its low bits, and which library defines it, in its high byte
+/// mask for the reference type bits
+pub const REFERENCE_TYPE: u16 = 0x7;
+pub const REFERENCE_FLAG_UNDEFINED_NON_LAZY: u16 = 0;
+pub const REFERENCE_FLAG_UNDEFINED_LAZY: u16 = 1;
+pub const REFERENCE_FLAG_DEFINED: u16 = 2;
+pub const REFERENCE_FLAG_PRIVATE_DEFINED: u16 = 3;
+pub const REFERENCE_FLAG_PRIVATE_UNDEFINED_NON_LAZY: u16 = 4;
+pub const REFERENCE_FLAG_PRIVATE_UNDEFINED_LAZY: u16 = 5;
+/// the symbol is referenced by the dynamic linker, and must not be stripped
+pub const REFERENCED_DYNAMICALLY: u16 = 0x10;
+/// the definition is a Thumb function
+pub const N_ARM_THUMB_DEF: u16 = 0x8;
+/// the symbol must not be dead stripped
+pub const N_NO_DEAD_STRIP: u16 = 0x20;
+/// the undefined symbol may be missing at runtime, in which case its address is 0
+pub const N_WEAK_REF: u16 = 0x40;
+/// the definition may be overridden by a non-weak one
+pub const N_WEAK_DEF: u16 = 0x80;
+/// the definition is a resolver function, returning the symbol's actual address
+pub const N_SYMBOL_RESOLVER: u16 = 0x100;
+/// the definition is an alternate entry point, which must stay with the preceding symbol's
+pub const N_ALT_ENTRY: u16 = 0x200;
+
+/// the library ordinal of a symbol defined in this image
+pub const SELF_LIBRARY_ORDINAL: u8 = 0x0;
+pub const MAX_LIBRARY_ORDINAL: u8 = 0xfd;
+/// the library ordinal of a symbol looked up in every image, with a flat namespace
+pub const DYNAMIC_LOOKUP_ORDINAL: u8 = 0xfe;
+/// the library ordinal of a symbol defined in the main executable
+pub const EXECUTABLE_ORDINAL: u8 = 0xff;
+
 pub const NLIST_TYPE_MASK: u8 = 0xe;
 pub const NLIST_TYPE_GLOBAL: u8 = 0x1;
 pub const NLIST_TYPE_LOCAL: u8 = 0x0;
@@ -151,6 +191,25 @@ impl Debug for Nlist64 {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// The kind of an nlist symbol, from its `n_type`
+pub enum SymbolType {
+    /// A symbolic debugging entry, of this `n_type`, e.g., `N_FUN`
+    Stab(u8),
+    /// Undefined, or a common symbol if its `n_value` is nonzero
+    Undefined,
+    /// Absolute, its `n_value` is not an address in any section
+    Absolute,
+    /// Defined in the section with this ordinal, counting from 1 across all segments, in load command order
+    SectionDefined(u8),
+    /// Undefined, but prebound to its address in a dylib
+    Prebound,
+    /// The same as the symbol whose name is at `n_value` in the string table
+    Indirect,
+    /// An `N_TYPE` value with no meaning
+    Unknown(u8),
+}
+
 #[derive(Debug, Clone,)]
 pub struct Nlist {
     /// index into the string table
@@ -178,14 +237,83 @@ impl Nlist {
     pub fn is_global(&self) -> bool {
         self.n_type & N_EXT != 0
     }
-    /// Whether this symbol is undefined or not
+    /// Whether this symbol is undefined or not; symbolic debugging entries never are
     pub fn is_undefined(&self) -> bool {
-        self.n_sect == 0 && self.n_type & N_TYPE == N_UNDF
+        !self.is_stab() && self.n_sect == 0 && self.n_type & N_TYPE == N_UNDF
     }
     /// Whether this symbol is a symbolic debugging entry
     pub fn is_stab(&self) -> bool {
         self.n_type & N_STAB != 0
     }
+    /// Whether this symbol is visible outside of its object file, i.e., `N_EXT` is set; symbolic debugging entries never are
+    pub fn is_external(&self) -> bool {
+        !self.is_stab() && self.n_type & N_EXT != 0
+    }
+    /// Whether this symbol was external, but was made private by the static linker, i.e., `N_PEXT` is set
+    pub fn is_private_external(&self) -> bool {
+        !self.is_stab() && self.n_type & N_PEXT != 0
+    }
+    /// The kind of this symbol
+    pub fn symbol_type(&self) -> SymbolType {
+        if self.is_stab() {
+            return SymbolType::Stab(self.n_type);
+        }
+        match self.n_type & N_TYPE {
+            N_UNDF => SymbolType::Undefined,
+            N_ABS => SymbolType::Absolute,
+            N_SECT => SymbolType::SectionDefined(self.n_sect as u8),
+            N_PBUD => SymbolType::Prebound,
+            N_INDR => SymbolType::Indirect,
+            n_type => SymbolType::Unknown(n_type),
+        }
+    }
+    /// Whether this undefined symbol may be missing at runtime
+    pub fn is_weak_ref(&self) -> bool {
+        !self.is_stab() && self.n_desc & N_WEAK_REF != 0
+    }
+    /// Whether this definition may be overridden by a non-weak one
+    pub fn is_weak_def(&self) -> bool {
+        !self.is_stab() && self.n_type & N_TYPE == N_SECT && self.n_desc & N_WEAK_DEF != 0
+    }
+    /// The ordinal of the library an undefined external symbol is imported from, in the high byte of `n_desc`; `None` for any other symbol, including common symbols.
+    ///
+    /// Like bind ordinals, 1 is the first dylib in `MachO::libs`, and 0, `DYNAMIC_LOOKUP_ORDINAL` and `EXECUTABLE_ORDINAL` are special, see `library`
+    pub fn library_ordinal(&self) -> Option<u8> {
+        match self.symbol_type() {
+            SymbolType::Undefined if self.is_external() && self.n_value == 0 => Some((self.n_desc >> 8) as u8),
+            SymbolType::Prebound if self.is_external() => Some((self.n_desc >> 8) as u8),
+            _ => None,
+        }
+    }
+    /// The library this undefined external symbol is imported from, named as in `Import::dylib`, given the binary's `libs`; `None` if it isn't imported, or the ordinal is out of range
+    pub fn library<'a>(&self, libs: &[&'a str]) -> Option<&'a str> {
+        match self.library_ordinal() {
+            Some(DYNAMIC_LOOKUP_ORDINAL) => Some("flat-lookup"),
+            Some(EXECUTABLE_ORDINAL) => Some("main-executable"),
+            Some(ordinal) => libs.get(ordinal as usize).map(|lib| *lib),
+            None => None,
+        }
+    }
+}
+
+impl<'a> ::symbol::Symbol for (&'a str, Nlist) {
+    fn name(&self) -> &str {
+        self.0
+    }
+    fn address(&self) -> u64 {
+        self.1.n_value
+    }
+    /// Nlists don't record what their symbols are, so this is a guess: a symbol defined in the first section,
+    /// which is `__TEXT,__text` in binaries linked by `ld64`
+    fn is_function(&self) -> bool {
+        !self.1.is_stab() && self.1.get_type() == N_SECT && self.1.n_sect == 1
+    }
+    fn is_global(&self) -> bool {
+        self.1.is_global()
+    }
+    fn is_undefined(&self) -> bool {
+        self.1.is_undefined()
+    }
 }
 
 impl ctx::SizeWith<container::Ctx> for Nlist {
@@ -314,6 +442,11 @@ impl<'a, T: ?Sized> ctx::TryFromCtx<'a, SymbolsCtx, T> for Symbols<'a> where T:
     }
 }
 
+/// The name at `n_strx` in the string table at `strtab` in `data`, which must be NUL terminated before the end of `data`
+fn symbol_name(data: &[u8], strtab: usize, n_strx: usize) -> error::Result<&str> {
+    Strtab::new(data.get(strtab..).unwrap_or(&[]), 0x0).get_at(n_strx)
+}
+
 #[derive(Default)]
 pub struct SymbolIterator<'a> {
     data: &'a [u8],
@@ -333,11 +466,11 @@ impl<'a> Iterator for SymbolIterator<'a> {
             self.count += 1;
             match self.data.gread_with::<Nlist>(&mut self.offset, self.ctx) {
                 Ok(symbol) => {
-                    match self.data.pread(self.strtab + symbol.n_strx) {
+                    match symbol_name(self.data, self.strtab, symbol.n_strx) {
                         Ok(name) => {
                             Some(Ok((name, symbol)))
                         },
-                        Err(e) => return Some(Err(e.into()))
+                        Err(e) => return Some(Err(e))
                     }
                 },
                 Err(e) => return Some(Err(e.into()))
@@ -346,6 +479,7 @@ impl<'a> Iterator for SymbolIterator<'a> {
     }
 }
 
+#[derive(Clone)]
 /// A zero-copy "nlist" style symbol table ("stab"), including the string table
 pub struct Symbols<'a> {
     data: &'a [u8],
@@ -395,10 +529,28 @@ impl<'a> Symbols<'a> {
         }
     }
 
+    /// Iterates over the (at most) `count` symbols starting at `index`
+    pub fn iter_range(&self, index: usize, count: usize) -> SymbolIterator<'a> {
+        let index = ::core::cmp::min(index, self.nsyms);
+        SymbolIterator {
+            offset: self.start + index * Nlist::size_with(&self.ctx),
+            nsyms: ::core::cmp::min(count, self.nsyms - index),
+            count: 0,
+            data: self.data,
+            ctx: self.ctx,
+            strtab: self.strtab,
+        }
+    }
+
+    /// The number of symbols in this table
+    pub fn len(&self) -> usize {
+        self.nsyms
+    }
+
     /// Parses a single Nlist symbol from the binary, with its accompanying name
     pub fn get(&self, index: usize) -> ::error::Result<(&'a str, Nlist)> {
         let sym: Nlist = self.data.pread_with(self.start + (index * Nlist::size_with(&self.ctx)), self.ctx)?;
-        let name = self.data.pread(self.strtab + sym.n_strx)?;
+        let name = symbol_name(self.data, self.strtab, sym.n_strx)?;
         Ok((name, sym))
     }
 }
@@ -420,3 +572,133 @@ impl<'a> Debug for Symbols<'a> {
         writeln!(fmt, "}}")
     }
 }
+
+/// The `LC_DYSYMTAB` partition of the symbol table into local, defined external and undefined symbols, along with the indirect symbol table
+pub struct Dysymtab<'a> {
+    /// The load command itself, with the raw indices, counts and file offsets
+    pub command: load_command::DysymtabCommand,
+    symbols: Option<Symbols<'a>>,
+    data: &'a [u8],
+    ctx: container::Ctx,
+}
+
+impl<'a> Dysymtab<'a> {
+    /// Creates the partition described by `command` of `symbols`; `data` is the whole binary
+    pub fn new(data: &'a [u8], command: load_command::DysymtabCommand, symbols: Option<Symbols<'a>>, ctx: container::Ctx) -> Self {
+        Dysymtab { command, symbols, data, ctx }
+    }
+
+    fn symbols(&self, index: u32, count: u32) -> SymbolIterator<'a> {
+        match self.symbols {
+            Some(ref symbols) => symbols.iter_range(index as usize, count as usize),
+            None => SymbolIterator::default(),
+        }
+    }
+
+    /// Iterates over the local symbols, i.e., `ilocalsym..ilocalsym + nlocalsym`
+    pub fn local_symbols(&self) -> SymbolIterator<'a> {
+        self.symbols(self.command.ilocalsym, self.command.nlocalsym)
+    }
+
+    /// Iterates over the externally defined symbols (exports), i.e., `iextdefsym..iextdefsym + nextdefsym`
+    pub fn defined_external_symbols(&self) -> SymbolIterator<'a> {
+        self.symbols(self.command.iextdefsym, self.command.nextdefsym)
+    }
+
+    /// Iterates over the undefined symbols (imports), i.e., `iundefsym..iundefsym + nundefsym`
+    pub fn undefined_symbols(&self) -> SymbolIterator<'a> {
+        self.symbols(self.command.iundefsym, self.command.nundefsym)
+    }
+
+    /// Parses the indirect symbol table: symbol table indices (or `INDIRECT_SYMBOL_LOCAL`/`INDIRECT_SYMBOL_ABS`) for each stub and pointer slot, in section order
+    pub fn indirect_symbols(&self) -> error::Result<Vec<u32>> {
+        let count = self.command.nindirectsyms as usize;
+        let mut offset = self.command.indirectsymoff as usize;
+        if count.checked_mul(4).and_then(|size| size.checked_add(offset)).map_or(true, |end| end > self.data.len()) {
+            return Err(error::Error::Malformed(format!("indirect symbol table at {:#x} with {} entries extends past the end of the binary", offset, count)));
+        }
+        let mut indirect_symbols = Vec::with_capacity(count);
+        for _ in 0..count {
+            indirect_symbols.push(self.data.gread_with::<u32>(&mut offset, self.ctx.le)?);
+        }
+        Ok(indirect_symbols)
+    }
+
+    fn relocations(&self, offset: u32, count: u32, table: &str) -> error::Result<Vec<relocation::RelocationInfo>> {
+        let count = count as usize;
+        let mut offset = offset as usize;
+        if count.checked_mul(relocation::SIZEOF_RELOCATION_INFO).and_then(|size| size.checked_add(offset)).map_or(true, |end| end > self.data.len()) {
+            return Err(error::Error::Malformed(format!("{} relocations at {:#x} with {} entries extend past the end of the binary", table, offset, count)));
+        }
+        let mut relocations = Vec::with_capacity(count);
+        for _ in 0..count {
+            relocations.push(self.data.gread_with::<relocation::RelocationInfo>(&mut offset, self.ctx.le)?);
+        }
+        Ok(relocations)
+    }
+
+    /// Parses the external relocations, `extreloff`, of the undefined symbols dyld binds, e.g., in a binary built without `LC_DYLD_INFO`;
+    /// their `r_address` is relative to the base `MachO::relocation_base`
+    pub fn external_relocations(&self) -> error::Result<Vec<relocation::RelocationInfo>> {
+        self.relocations(self.command.extreloff, self.command.nextrel, "external")
+    }
+
+    /// Parses the local relocations, `locreloff`, which dyld slides; their `r_address` is relative to the base `MachO::relocation_base`
+    pub fn local_relocations(&self) -> error::Result<Vec<relocation::RelocationInfo>> {
+        self.relocations(self.command.locreloff, self.command.nlocrel, "local")
+    }
+}
+
+impl<'a> Debug for Dysymtab<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Dysymtab")
+            .field("command", &self.command)
+            .field("indirect_symbols()", &self.indirect_symbols())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nlist(n_type: u8, n_sect: usize, n_desc: u16, n_value: u64) -> Nlist {
+        Nlist { n_strx: 1, n_type, n_sect, n_desc, n_value }
+    }
+
+    #[test]
+    fn symbol_types() {
+        let libs = ["self", "/usr/lib/libSystem.B.dylib", "/usr/lib/libc++.1.dylib"];
+        // the `N_FUN` stab of a function in section 1, as `ld -r` leaves it
+        let stab = nlist(N_FUN, 1, 0, 0x100000f50);
+        assert!(stab.is_stab());
+        assert!(!stab.is_external() && !stab.is_undefined());
+        assert_eq!(stab.symbol_type(), SymbolType::Stab(N_FUN));
+        // `N_GSYM` stabs have NO_SECT, which doesn't make them undefined
+        assert!(!nlist(N_GSYM, NO_SECT as usize, 0, 0).is_undefined());
+        // `_main`, defined in __text
+        let main = nlist(N_SECT | N_EXT, 1, REFERENCED_DYNAMICALLY, 0x100000f50);
+        assert!(main.is_external() && !main.is_private_external() && !main.is_weak_def());
+        assert_eq!(main.symbol_type(), SymbolType::SectionDefined(1));
+        assert_eq!(main.library_ordinal(), None);
+        assert_eq!(main.library(&libs), None);
+        assert!(nlist(N_SECT | N_EXT, 1, N_WEAK_DEF, 0).is_weak_def());
+        // `_printf`, lazily imported from libSystem, and a weak import from libc++
+        let printf = nlist(N_UNDF | N_EXT, NO_SECT as usize, 1 << 8 | REFERENCE_FLAG_UNDEFINED_LAZY, 0);
+        assert!(printf.is_undefined());
+        assert_eq!(printf.symbol_type(), SymbolType::Undefined);
+        assert_eq!(printf.library_ordinal(), Some(1));
+        assert_eq!(printf.library(&libs), Some("/usr/lib/libSystem.B.dylib"));
+        let weak = nlist(N_UNDF | N_EXT, NO_SECT as usize, 2 << 8 | N_WEAK_REF, 0);
+        assert!(weak.is_weak_ref());
+        assert_eq!(weak.library(&libs), Some("/usr/lib/libc++.1.dylib"));
+        assert_eq!(nlist(N_UNDF | N_EXT, NO_SECT as usize, (DYNAMIC_LOOKUP_ORDINAL as u16) << 8, 0).library(&libs), Some("flat-lookup"));
+        assert_eq!(nlist(N_UNDF | N_EXT, NO_SECT as usize, 7 << 8, 0).library(&libs), None);
+        // a common symbol's n_value is its size, and its n_desc its alignment
+        assert_eq!(nlist(N_UNDF | N_EXT, NO_SECT as usize, 3 << 8, 8).library_ordinal(), None);
+        assert_eq!(nlist(N_ABS, NO_SECT as usize, 0, 0).symbol_type(), SymbolType::Absolute);
+        assert_eq!(nlist(N_INDR | N_EXT, NO_SECT as usize, 0, 0).symbol_type(), SymbolType::Indirect);
+        assert_eq!(nlist(N_PBUD | N_EXT, NO_SECT as usize, 1 << 8, 0).library_ordinal(), Some(1));
+        assert_eq!(nlist(0x6, NO_SECT as usize, 0, 0).symbol_type(), SymbolType::Unknown(0x6));
+    }
+}
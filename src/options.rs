@@ -0,0 +1,64 @@
+//! Options which tune how much work, and memory, the parsers are willing to spend on a binary, and how strict they are about it.
+//!
+//! The defaults are generous enough for any well formed binary; lower them when parsing untrusted input under tight resource limits.
+
+/// The default for `ParseOptions::max_imports`
+pub const DEFAULT_MAX_IMPORTS: usize = 1 << 20;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Options for parsing a binary; see e.g., `MachO::parse_with`
+pub struct ParseOptions {
+    /// The maximum number of imports a bind opcode stream may produce before it is considered malformed
+    pub max_imports: usize,
+    /// Whether a malformed component fails the whole parse (the default), or is left empty, with its error recorded in the result;
+    /// see `Elf::parse_with` and `MachO::parse_with` for which components can be recovered from
+    pub fail_fast: bool,
+    /// For a Mach-o extracted from the dyld shared cache, the offset of its `__LINKEDIT` in the bytes being parsed.
+    ///
+    /// The cache's images share one `__LINKEDIT`, and an extracted image's load commands still give its linkedit offsets (`symoff`, `bind_off`, `export_off`, etc.)
+    /// and the `__LINKEDIT` segment's `fileoff` relative to the cache; with this set, `MachO::parse_with` rebases them all from that `fileoff` to this offset.
+    /// E.g., for an image whose `__LINKEDIT` was copied to the end of its other segments, this is the sum of their `filesize`s
+    pub linkedit_base: Option<u64>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            max_imports: DEFAULT_MAX_IMPORTS,
+            fail_fast: true,
+            linkedit_base: None,
+        }
+    }
+}
+
+#[cfg(any(all(feature = "elf32", feature = "elf64", feature = "endian_fd"), feature = "mach32", feature = "mach64"))]
+pub(crate) use self::recovery::Recovery;
+
+#[cfg(any(all(feature = "elf32", feature = "elf64", feature = "endian_fd"), feature = "mach32", feature = "mach64"))]
+mod recovery {
+    use error;
+    use alloc::vec::Vec;
+    use super::ParseOptions;
+
+    /// The errors of the components a lenient parse has skipped, each identified by a `C`
+    pub struct Recovery<C> {
+        fail_fast: bool,
+        pub errors: Vec<(C, error::Error)>,
+    }
+
+    impl<C> Recovery<C> {
+        pub fn new(opts: &ParseOptions) -> Self {
+            Recovery { fail_fast: opts.fail_fast, errors: Vec::new() }
+        }
+        /// Returns `result`, unless it is an error and the parse is lenient, in which case the error is recorded against `component`, and its value is the default, i.e., empty, one
+        pub fn recover<T: Default>(&mut self, component: C, result: error::Result<T>) -> error::Result<T> {
+            match result {
+                Err(err) if !self.fail_fast => {
+                    self.errors.push((component, err));
+                    Ok(T::default())
+                },
+                result => result,
+            }
+        }
+    }
+}
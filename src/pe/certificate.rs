@@ -0,0 +1,112 @@
+//! The Authenticode signatures of `IMAGE_DIRECTORY_ENTRY_SECURITY`, a table of `WIN_CERTIFICATE`s.
+//!
+//! Unlike every other data directory, the certificate table's `virtual_address` is a file offset, not an rva: the table isn't mapped into memory, usually being appended to the end of the file.
+//! Each entry is padded to an 8 byte boundary.
+
+use alloc::vec::Vec;
+use scroll::{self, Pread};
+use error;
+
+use pe::data_directories;
+
+pub const WIN_CERT_REVISION_1_0: u16 = 0x0100;
+pub const WIN_CERT_REVISION_2_0: u16 = 0x0200;
+
+/// An X.509 certificate
+pub const WIN_CERT_TYPE_X509: u16 = 0x0001;
+/// A PKCS#7 `SignedData`, i.e., an Authenticode signature
+pub const WIN_CERT_TYPE_PKCS_SIGNED_DATA: u16 = 0x0002;
+pub const WIN_CERT_TYPE_RESERVED_1: u16 = 0x0003;
+/// A terminal server protocol stack certificate
+pub const WIN_CERT_TYPE_TS_STACK_SIGNED: u16 = 0x0004;
+
+/// The size of the `dwLength`, `wRevision` and `wCertificateType` before each certificate
+pub const SIZEOF_WIN_CERTIFICATE_HEADER: usize = 8;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+/// A `WIN_CERTIFICATE` of the certificate table
+pub struct CertificateData<'a> {
+    /// The size of the entry, including its header, but not the padding after it
+    pub length: u32,
+    /// One of the `WIN_CERT_REVISION_*`
+    pub revision: u16,
+    /// One of the `WIN_CERT_TYPE_*`
+    pub certificate_type: u16,
+    /// The certificate itself, e.g., the DER encoded PKCS#7 `SignedData` of a `WIN_CERT_TYPE_PKCS_SIGNED_DATA`
+    pub certificate: &'a [u8],
+}
+
+impl<'a> CertificateData<'a> {
+    /// Parses the certificate table `directory` locates in `bytes`, which must be the whole file, since the directory's `virtual_address` is a file offset
+    pub fn parse(bytes: &'a [u8], directory: &data_directories::DataDirectory) -> error::Result<Vec<Self>> {
+        let start = directory.virtual_address as usize;
+        let end = start.saturating_add(directory.size as usize);
+        if end > bytes.len() {
+            return Err(error::Error::Malformed(format!("certificate table at file offset {:#x} of size {:#x} is past the end of the {:#x} byte file", start, directory.size, bytes.len())));
+        }
+        let mut certificates = Vec::new();
+        let mut offset = start;
+        // anything shorter than a header is padding
+        while end - offset >= SIZEOF_WIN_CERTIFICATE_HEADER {
+            let length: u32 = bytes.pread_with(offset, scroll::LE)?;
+            let revision: u16 = bytes.pread_with(offset + 4, scroll::LE)?;
+            let certificate_type: u16 = bytes.pread_with(offset + 6, scroll::LE)?;
+            let size = length as usize;
+            if size < SIZEOF_WIN_CERTIFICATE_HEADER || size > end - offset {
+                return Err(error::Error::Malformed(format!("certificate at {:#x} of length {:#x} does not fit in the certificate table ending at {:#x}", offset, length, end)));
+            }
+            certificates.push(CertificateData {
+                length,
+                revision,
+                certificate_type,
+                certificate: &bytes[offset + SIZEOF_WIN_CERTIFICATE_HEADER..offset + size],
+            });
+            // the next entry starts at the next 8 byte boundary, relative to the table
+            offset += size;
+            offset += (8 - (offset - start) % 8) % 8;
+            if offset > end {
+                break;
+            }
+        }
+        Ok(certificates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    #[test]
+    fn parse_certificates() {
+        // a signature of 13 bytes, padded to 8 bytes, then another, appended to 0x200 bytes of image
+        let mut bytes = vec![0u8; 0x200];
+        for (i, signature) in [&b"\x30\x82signature"[..], &b"\x30\x82nested-sig"[..]].iter().enumerate() {
+            let mut entry = vec![0u8; SIZEOF_WIN_CERTIFICATE_HEADER];
+            entry.pwrite_with((SIZEOF_WIN_CERTIFICATE_HEADER + signature.len()) as u32, 0, scroll::LE).unwrap();
+            entry.pwrite_with(WIN_CERT_REVISION_2_0, 4, scroll::LE).unwrap();
+            entry.pwrite_with(WIN_CERT_TYPE_PKCS_SIGNED_DATA, 6, scroll::LE).unwrap();
+            entry.extend_from_slice(signature);
+            if i == 0 {
+                entry.resize(24, 0);
+            }
+            bytes.extend(entry);
+        }
+        let directory = data_directories::DataDirectory { virtual_address: 0x200, size: bytes.len() as u32 - 0x200 };
+        let certificates = CertificateData::parse(&bytes, &directory).unwrap();
+        assert_eq!(certificates.len(), 2);
+        assert_eq!(certificates[0].certificate, b"\x30\x82signature");
+        assert_eq!(certificates[0].length, 19);
+        assert_eq!(certificates[1].certificate, b"\x30\x82nested-sig");
+        assert_eq!(certificates[1].revision, WIN_CERT_REVISION_2_0);
+        assert_eq!(certificates[1].certificate_type, WIN_CERT_TYPE_PKCS_SIGNED_DATA);
+        // the table must be in the file
+        let past = data_directories::DataDirectory { size: directory.size + 1, .. directory };
+        assert!(CertificateData::parse(&bytes, &past).is_err());
+        // as must every certificate
+        bytes.pwrite_with(0x1000u32, 0x200, scroll::LE).unwrap();
+        assert!(CertificateData::parse(&bytes, &directory).is_err());
+        bytes.pwrite_with(4u32, 0x200, scroll::LE).unwrap();
+        assert!(CertificateData::parse(&bytes, &directory).is_err());
+    }
+}
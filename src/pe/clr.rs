@@ -0,0 +1,108 @@
+//! The CLR (aka COR20) header of .NET assemblies, found via the CLR runtime header data directory
+
+use scroll::{self, Pread};
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+
+/// The image contains only IL code, and no native code
+pub const COMIMAGE_FLAGS_ILONLY: u32 = 0x0000_0001;
+/// The image can only be loaded into a 32-bit process
+pub const COMIMAGE_FLAGS_32BITREQUIRED: u32 = 0x0000_0002;
+pub const COMIMAGE_FLAGS_IL_LIBRARY: u32 = 0x0000_0004;
+/// The image has a strong name signature
+pub const COMIMAGE_FLAGS_STRONGNAMESIGNED: u32 = 0x0000_0008;
+/// The entry point is an RVA to native code, rather than a metadata token for a managed method
+pub const COMIMAGE_FLAGS_NATIVE_ENTRYPOINT: u32 = 0x0000_0010;
+pub const COMIMAGE_FLAGS_TRACKDEBUGDATA: u32 = 0x0001_0000;
+/// The image prefers to be loaded into a 32-bit process
+pub const COMIMAGE_FLAGS_32BITPREFERRED: u32 = 0x0002_0000;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+/// The `flags` of a CLR header, its `COMIMAGE_FLAGS_*`
+pub struct ClrFlags(pub u32);
+
+impl ClrFlags {
+    /// The raw `COMIMAGE_FLAGS_*` bits
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+    /// Whether every bit of `flags` is set
+    pub fn contains(&self, flags: u32) -> bool {
+        self.0 & flags == flags
+    }
+    /// Whether the image contains only IL code, i.e., has `COMIMAGE_FLAGS_ILONLY`
+    pub fn is_il_only(&self) -> bool {
+        self.contains(COMIMAGE_FLAGS_ILONLY)
+    }
+    /// Whether the image can only be loaded into a 32-bit process, i.e., has `COMIMAGE_FLAGS_32BITREQUIRED`
+    pub fn is_32bit_required(&self) -> bool {
+        self.contains(COMIMAGE_FLAGS_32BITREQUIRED)
+    }
+    /// Whether the image prefers to be loaded into a 32-bit process, i.e., has `COMIMAGE_FLAGS_32BITPREFERRED`
+    pub fn is_32bit_preferred(&self) -> bool {
+        self.contains(COMIMAGE_FLAGS_32BITPREFERRED)
+    }
+    /// Whether the image has a strong name signature, i.e., has `COMIMAGE_FLAGS_STRONGNAMESIGNED`
+    pub fn is_strong_name_signed(&self) -> bool {
+        self.contains(COMIMAGE_FLAGS_STRONGNAMESIGNED)
+    }
+    /// Whether the entry point is native code, i.e., has `COMIMAGE_FLAGS_NATIVE_ENTRYPOINT`
+    pub fn has_native_entry_point(&self) -> bool {
+        self.contains(COMIMAGE_FLAGS_NATIVE_ENTRYPOINT)
+    }
+}
+
+// https://docs.microsoft.com/en-us/dotnet/framework/unmanaged-api/metadata/image-cor20-header-structure
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct Cor20Header {
+    /// The size of this header in bytes
+    pub cb: u32,
+    pub major_runtime_version: u16,
+    pub minor_runtime_version: u16,
+    pub metadata: data_directories::DataDirectory,
+    /// The `COMIMAGE_FLAGS_*` of this image
+    pub flags: u32,
+    /// A metadata token for the managed entry point, or an RVA if `COMIMAGE_FLAGS_NATIVE_ENTRYPOINT` is set
+    pub entry_point_token: u32,
+    pub resources: data_directories::DataDirectory,
+    pub strong_name_signature: data_directories::DataDirectory,
+    pub code_manager_table: data_directories::DataDirectory,
+    pub vtable_fixups: data_directories::DataDirectory,
+    pub export_address_table_jumps: data_directories::DataDirectory,
+    pub managed_native_header: data_directories::DataDirectory,
+}
+
+pub const SIZEOF_COR20_HEADER: usize = 72;
+
+impl Cor20Header {
+    pub fn parse(bytes: &[u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
+        let rva = dd.virtual_address as usize;
+        let offset = utils::find_offset(rva, sections, file_alignment).ok_or(error::Error::Malformed(format!("Cannot map Cor20Header rva {:#x} into offset", rva)))?;
+        let header: Self = bytes.pread_with(offset, scroll::LE)?;
+        Ok(header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    #[test]
+    fn parse_cor20_header() {
+        let mut header = Cor20Header::default();
+        header.cb = SIZEOF_COR20_HEADER as u32;
+        header.flags = COMIMAGE_FLAGS_ILONLY | COMIMAGE_FLAGS_STRONGNAMESIGNED;
+        let mut bytes = [0u8; SIZEOF_COR20_HEADER];
+        assert_eq!(bytes.pwrite_with(header, 0, scroll::LE).unwrap(), SIZEOF_COR20_HEADER);
+        let parsed: Cor20Header = bytes.pread_with(0, scroll::LE).unwrap();
+        assert_eq!(parsed, header);
+        assert!(ClrFlags(parsed.flags).is_il_only());
+        assert!(!ClrFlags(parsed.flags).has_native_entry_point());
+    }
+}
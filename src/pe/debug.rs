@@ -9,16 +9,42 @@ use pe::data_directories;
 pub struct DebugData<'a> {
     pub image_debug_directory: ImageDebugDirectory,
     pub codeview_pdb70_debug_info: Option<CodeviewPDB70DebugInfo<'a>>,
+    /// The `IMAGE_DEBUG_TYPE_POGO` record, if any of the debug directories has one
+    pub pogo_debug_info: Option<PogoDebugInfo<'a>>,
+    /// The `IMAGE_DEBUG_TYPE_VC_FEATURE` record, if any of the debug directories has one
+    pub vc_feature_debug_info: Option<VcFeatureDebugInfo>,
 }
 
 impl<'a> DebugData<'a> {
     pub fn parse(bytes: &'a [u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
         let image_debug_directory = ImageDebugDirectory::parse(bytes, dd, sections, file_alignment)?;
         let codeview_pdb70_debug_info = CodeviewPDB70DebugInfo::parse(bytes, &image_debug_directory)?;
+        let mut pogo_debug_info = None;
+        let mut vc_feature_debug_info = None;
+        // the data directory is an array of debug directories; the records after the first are optional extras,
+        // so a malformed one is skipped rather than failing the whole parse
+        let rva = dd.virtual_address as usize;
+        if let Some(offset) = utils::find_offset(rva, sections, file_alignment) {
+            let count = dd.size as usize / SIZEOF_IMAGE_DEBUG_DIRECTORY;
+            for i in 0..count {
+                let idd: ImageDebugDirectory = match bytes.pread_with(offset + i * SIZEOF_IMAGE_DEBUG_DIRECTORY, scroll::LE) {
+                    Ok(idd) => idd,
+                    Err(_) => break,
+                };
+                if pogo_debug_info.is_none() {
+                    pogo_debug_info = PogoDebugInfo::parse(bytes, &idd).unwrap_or(None);
+                }
+                if vc_feature_debug_info.is_none() {
+                    vc_feature_debug_info = VcFeatureDebugInfo::parse(bytes, &idd).unwrap_or(None);
+                }
+            }
+        }
 
         Ok(DebugData{
             image_debug_directory: image_debug_directory,
-            codeview_pdb70_debug_info: codeview_pdb70_debug_info
+            codeview_pdb70_debug_info: codeview_pdb70_debug_info,
+            pogo_debug_info: pogo_debug_info,
+            vc_feature_debug_info: vc_feature_debug_info,
         })
     }
     
@@ -51,7 +77,31 @@ pub const IMAGE_DEBUG_TYPE_FPO: u32 = 3;
 pub const IMAGE_DEBUG_TYPE_MISC: u32 = 4;
 pub const IMAGE_DEBUG_TYPE_EXCEPTION: u32 = 5;
 pub const IMAGE_DEBUG_TYPE_FIXUP: u32 = 6;
+pub const IMAGE_DEBUG_TYPE_OMAP_TO_SRC: u32 = 7;
+pub const IMAGE_DEBUG_TYPE_OMAP_FROM_SRC: u32 = 8;
 pub const IMAGE_DEBUG_TYPE_BORLAND: u32 = 9;
+pub const IMAGE_DEBUG_TYPE_CLSID: u32 = 11;
+/// Counts of the objects compiled with the various security features of MSVC
+pub const IMAGE_DEBUG_TYPE_VC_FEATURE: u32 = 12;
+/// The sections the linker laid out for profile guided optimization, or link time code generation
+pub const IMAGE_DEBUG_TYPE_POGO: u32 = 13;
+pub const IMAGE_DEBUG_TYPE_ILTCG: u32 = 14;
+pub const IMAGE_DEBUG_TYPE_MPX: u32 = 15;
+/// The image was built reproducibly, and its timestamps are hashes
+pub const IMAGE_DEBUG_TYPE_REPRO: u32 = 16;
+pub const IMAGE_DEBUG_TYPE_EX_DLLCHARACTERISTICS: u32 = 20;
+
+pub const SIZEOF_IMAGE_DEBUG_DIRECTORY: usize = 28;
+
+/// Returns the `size_of_data` bytes at `pointer_to_raw_data`, if they are in bounds
+fn raw_data<'a>(bytes: &'a [u8], idd: &ImageDebugDirectory) -> error::Result<&'a [u8]> {
+    let offset = idd.pointer_to_raw_data as usize;
+    let size = idd.size_of_data as usize;
+    match offset.checked_add(size).and_then(|end| bytes.get(offset..end)) {
+        Some(data) => Ok(data),
+        None => Err(error::Error::Malformed(format!("ImageDebugDirectory raw data at {:#x} of size {:#x} is out of bounds", offset, size))),
+    }
+}
 
 impl ImageDebugDirectory {
     fn parse(bytes: &[u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
@@ -118,3 +168,118 @@ impl<'a> CodeviewPDB70DebugInfo<'a> {
         }))
     }
 }
+
+/// The signature of a POGO record written for link time code generation
+pub const POGO_SIGNATURE_LTCG: u32 = 0x4c544347;
+/// The signature of a POGO record written for profile guided optimization
+pub const POGO_SIGNATURE_PGU: u32 = 0x50475500;
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+/// The `IMAGE_DEBUG_TYPE_POGO` record: a signature, followed by the names and extents of the (sub)sections the linker laid out
+pub struct PogoDebugInfo<'a> {
+    pub signature: u32,
+    /// The raw entries following the signature
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+/// One section of a POGO record, e.g., `.text$mn`
+pub struct PogoEntry<'a> {
+    pub rva: u32,
+    pub size: u32,
+    pub name: &'a str,
+}
+
+impl<'a> PogoDebugInfo<'a> {
+    pub fn parse(bytes: &'a [u8], idd: &ImageDebugDirectory) -> error::Result<Option<Self>> {
+        if idd.data_type != IMAGE_DEBUG_TYPE_POGO {
+            return Ok(None);
+        }
+        let data = raw_data(bytes, idd)?;
+        let signature: u32 = data.pread_with(0, scroll::LE)?;
+        Ok(Some(PogoDebugInfo { signature, data: &data[4..] }))
+    }
+    /// Iterates over the entries of this record, stopping at the first malformed one
+    pub fn entries(&self) -> PogoEntryIterator<'a> {
+        PogoEntryIterator { data: self.data, offset: 0 }
+    }
+}
+
+/// An iterator over the entries of a POGO record
+pub struct PogoEntryIterator<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for PogoEntryIterator<'a> {
+    type Item = PogoEntry<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut offset = self.offset;
+        let rva: u32 = if let Ok(rva) = self.data.gread_with(&mut offset, scroll::LE) { rva } else { return None };
+        let size: u32 = if let Ok(size) = self.data.gread_with(&mut offset, scroll::LE) { size } else { return None };
+        let name: &'a str = if let Ok(name) = self.data.pread(offset) { name } else { return None };
+        // the name is nul terminated, and padded to a multiple of 4 bytes
+        self.offset = (offset + name.len() + 1 + 3) & !3;
+        Some(PogoEntry { rva, size, name })
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+/// The `IMAGE_DEBUG_TYPE_VC_FEATURE` record: how many of the linked objects were compiled with which MSVC security features
+pub struct VcFeatureDebugInfo {
+    /// Objects built by compilers older than VC++ 11.00
+    pub pre_vc_plus_plus_11: u32,
+    /// C/C++ objects
+    pub c_and_c_plus_plus: u32,
+    /// Objects compiled with stack cookies (`/GS`)
+    pub gs: u32,
+    /// Objects compiled with additional security checks (`/sdl`)
+    pub sdl: u32,
+    /// Objects compiled with control flow guard instrumentation (`/guard:cf`)
+    pub guard_n: u32,
+}
+
+pub const SIZEOF_VC_FEATURE_DEBUG_INFO: usize = 20;
+
+impl VcFeatureDebugInfo {
+    pub fn parse(bytes: &[u8], idd: &ImageDebugDirectory) -> error::Result<Option<Self>> {
+        if idd.data_type != IMAGE_DEBUG_TYPE_VC_FEATURE {
+            return Ok(None);
+        }
+        let data = raw_data(bytes, idd)?;
+        Ok(Some(data.pread_with(0, scroll::LE)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pogo_and_vc_feature() {
+        let mut bytes = vec![0u8; 8];
+        // LTCG, then `.text$mn` at 0x1000 and `.rdata` at 0x2000
+        bytes.extend_from_slice(&[0x47, 0x43, 0x54, 0x4c]);
+        bytes.extend_from_slice(&[0x00, 0x10, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(b".text$mn\0\0\0\0");
+        bytes.extend_from_slice(&[0x00, 0x20, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(b".rdata\0\0");
+        let mut idd = ImageDebugDirectory { data_type: IMAGE_DEBUG_TYPE_POGO, size_of_data: (bytes.len() - 8) as u32, pointer_to_raw_data: 8, .. Default::default() };
+        let pogo = PogoDebugInfo::parse(&bytes, &idd).unwrap().unwrap();
+        assert_eq!(pogo.signature, POGO_SIGNATURE_LTCG);
+        assert_eq!(pogo.entries().collect::<Vec<_>>(), vec![
+            PogoEntry { rva: 0x1000, size: 0x20, name: ".text$mn" },
+            PogoEntry { rva: 0x2000, size: 0x8, name: ".rdata" },
+        ]);
+        assert_eq!(VcFeatureDebugInfo::parse(&bytes, &idd).unwrap(), None);
+        idd.size_of_data += 1;
+        assert!(PogoDebugInfo::parse(&bytes, &idd).is_err());
+
+        let bytes = [1, 0, 0, 0, 0x20, 0, 0, 0, 0x20, 0, 0, 0, 0x4, 0, 0, 0, 0x1f, 0, 0, 0];
+        let idd = ImageDebugDirectory { data_type: IMAGE_DEBUG_TYPE_VC_FEATURE, size_of_data: 20, pointer_to_raw_data: 0, .. Default::default() };
+        let features = VcFeatureDebugInfo::parse(&bytes, &idd).unwrap().unwrap();
+        assert_eq!(features, VcFeatureDebugInfo { pre_vc_plus_plus_11: 1, c_and_c_plus_plus: 0x20, gs: 0x20, sdl: 4, guard_n: 0x1f });
+    }
+}
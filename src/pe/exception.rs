@@ -0,0 +1,649 @@
+//! Exception handling and stack unwinding information, as found in the `.pdata` section (`IMAGE_DIRECTORY_ENTRY_EXCEPTION`).
+//!
+//! The function table is an array of `RUNTIME_FUNCTION` entries sorted by address, whose encoding depends on the machine type;
+//! `ExceptionData` picks the right decoder from the COFF header's machine; x64 and ARM64 are supported.
+
+use scroll::{self, Pread};
+use alloc::vec::Vec;
+use alloc::collections::BTreeSet;
+
+use error;
+
+use pe::header;
+use pe::utils;
+use pe::section_table;
+use pe::data_directories;
+
+/// ARM64 `IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY`
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct Arm64RuntimeFunction {
+    /// The function start RVA
+    pub begin_address: u32,
+    /// Either packed unwind data, or the RVA of the `.xdata` unwind record, depending on the low two bits
+    pub unwind_data: u32,
+}
+
+pub const SIZEOF_ARM64_RUNTIME_FUNCTION: usize = 8;
+
+/// `unwind_data` is the RVA of an `.xdata` record
+pub const ARM64_UNWIND_FLAG_XDATA: u8 = 0;
+/// `unwind_data` is packed unwind data for a function with a canonical prologue and epilogue
+pub const ARM64_UNWIND_FLAG_PACKED: u8 = 1;
+/// `unwind_data` is packed unwind data for a function fragment without a prologue
+pub const ARM64_UNWIND_FLAG_PACKED_FRAGMENT: u8 = 2;
+
+impl Arm64RuntimeFunction {
+    /// The low two bits of `unwind_data`, one of the `ARM64_UNWIND_FLAG_*` values (3 is reserved)
+    pub fn flag(&self) -> u8 {
+        (self.unwind_data & 0x3) as u8
+    }
+    /// Whether the unwind data is packed into this entry, rather than stored in `.xdata`
+    pub fn is_packed(&self) -> bool {
+        let flag = self.flag();
+        flag == ARM64_UNWIND_FLAG_PACKED || flag == ARM64_UNWIND_FLAG_PACKED_FRAGMENT
+    }
+    /// The RVA of this function's `.xdata` unwind record, if it has one
+    pub fn xdata_rva(&self) -> Option<u32> {
+        if self.flag() == ARM64_UNWIND_FLAG_XDATA {
+            Some(self.unwind_data & !0x3)
+        } else {
+            None
+        }
+    }
+    /// Decodes the packed unwind data, if this entry has some
+    pub fn packed_unwind_data(&self) -> Option<Arm64PackedUnwindData> {
+        if !self.is_packed() {
+            return None;
+        }
+        let data = self.unwind_data;
+        Some(Arm64PackedUnwindData {
+            flag: self.flag(),
+            function_length: ((data >> 2) & 0x7ff) * 4,
+            reg_f: ((data >> 13) & 0x7) as u8,
+            reg_i: ((data >> 16) & 0xf) as u8,
+            h: (data >> 20) & 0x1 == 1,
+            cr: ((data >> 21) & 0x3) as u8,
+            frame_size: ((data >> 23) & 0x1ff) * 16,
+        })
+    }
+}
+
+/// The fields of ARM64 packed unwind data
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Arm64PackedUnwindData {
+    /// Either `ARM64_UNWIND_FLAG_PACKED` or `ARM64_UNWIND_FLAG_PACKED_FRAGMENT`
+    pub flag: u8,
+    /// The length of the function in bytes
+    pub function_length: u32,
+    /// The number of non-volatile floating point registers (d8-d15) saved, minus one; 0 if none
+    pub reg_f: u8,
+    /// The number of non-volatile integer registers (x19-x28) saved
+    pub reg_i: u8,
+    /// Whether the function homes the integer parameter registers (x0-x7)
+    pub h: bool,
+    /// Whether and how the function sets up a frame chain with x29 (fp) and x30 (lr)
+    pub cr: u8,
+    /// The number of bytes of stack allocated by the function
+    pub frame_size: u32,
+}
+
+/// An ARM64 epilogue scope from an `.xdata` record
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Arm64EpilogueScope {
+    /// The offset in bytes of the epilogue, relative to the start of the function
+    pub start_offset: u32,
+    /// The byte index of the first unwind code describing this epilogue
+    pub start_index: u16,
+}
+
+/// An ARM64 `.xdata` unwind record
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Arm64UnwindInfo<'a> {
+    /// The length of the function in bytes
+    pub function_length: u32,
+    /// The version of this record; only 0 is defined
+    pub version: u8,
+    /// Whether an exception handler follows the unwind codes
+    pub has_exception_data: bool,
+    /// Whether the function has a single epilogue, described without any epilogue scope
+    pub single_epilogue: bool,
+    /// The number of epilogue scopes, or, if `single_epilogue`, the index of the first unwind code of the epilogue
+    pub epilogue_count: u32,
+    /// The number of 32-bit words of unwind codes
+    pub code_words: u32,
+    /// The epilogue scopes, empty if `single_epilogue`
+    pub epilogue_scopes: Vec<Arm64EpilogueScope>,
+    /// The raw unwind code bytes
+    pub unwind_codes: &'a [u8],
+    /// The RVA of the language specific exception handler, if `has_exception_data`
+    pub exception_handler: Option<u32>,
+}
+
+impl<'a> Arm64UnwindInfo<'a> {
+    /// Parses an `.xdata` record from `bytes` at `offset`
+    pub fn parse(bytes: &'a [u8], mut offset: usize) -> error::Result<Self> {
+        let offset = &mut offset;
+        let header: u32 = bytes.gread_with(offset, scroll::LE)?;
+        let version = ((header >> 18) & 0x3) as u8;
+        if version != 0 {
+            return Err(error::Error::Malformed(format!("Unsupported ARM64 unwind info version {}", version)));
+        }
+        let has_exception_data = (header >> 20) & 0x1 == 1;
+        let single_epilogue = (header >> 21) & 0x1 == 1;
+        let mut epilogue_count = (header >> 22) & 0x1f;
+        let mut code_words = (header >> 27) & 0x1f;
+        if epilogue_count == 0 && code_words == 0 {
+            // the counts did not fit, and are stored in an extension word
+            let extension: u32 = bytes.gread_with(offset, scroll::LE)?;
+            epilogue_count = extension & 0xffff;
+            code_words = (extension >> 16) & 0xff;
+        }
+        let mut epilogue_scopes = Vec::new();
+        if !single_epilogue {
+            for _ in 0..epilogue_count {
+                let scope: u32 = bytes.gread_with(offset, scroll::LE)?;
+                epilogue_scopes.push(Arm64EpilogueScope {
+                    start_offset: (scope & 0x3ffff) * 4,
+                    start_index: ((scope >> 22) & 0x3ff) as u16,
+                });
+            }
+        }
+        let unwind_codes: &'a [u8] = bytes.gread_with(offset, code_words as usize * 4)?;
+        let exception_handler = if has_exception_data {
+            Some(bytes.gread_with(offset, scroll::LE)?)
+        } else {
+            None
+        };
+        Ok(Arm64UnwindInfo {
+            function_length: (header & 0x3ffff) * 4,
+            version: version,
+            has_exception_data: has_exception_data,
+            single_epilogue: single_epilogue,
+            epilogue_count: epilogue_count,
+            code_words: code_words,
+            epilogue_scopes: epilogue_scopes,
+            unwind_codes: unwind_codes,
+            exception_handler: exception_handler,
+        })
+    }
+}
+
+/// x64 `RUNTIME_FUNCTION`
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct X64RuntimeFunction {
+    /// The function start RVA
+    pub begin_address: u32,
+    /// The function end RVA, exclusive
+    pub end_address: u32,
+    /// The RVA of the function's `UNWIND_INFO`
+    pub unwind_info_address: u32,
+}
+
+pub const SIZEOF_X64_RUNTIME_FUNCTION: usize = 12;
+
+/// The function has an exception handler
+pub const UNW_FLAG_EHANDLER: u8 = 0x1;
+/// The function has a termination handler
+pub const UNW_FLAG_UHANDLER: u8 = 0x2;
+/// The unwind info is continued by that of another function, e.g., the primary part of a function split by the optimizer
+pub const UNW_FLAG_CHAININFO: u8 = 0x4;
+
+pub const UWOP_PUSH_NONVOL: u8 = 0;
+pub const UWOP_ALLOC_LARGE: u8 = 1;
+pub const UWOP_ALLOC_SMALL: u8 = 2;
+pub const UWOP_SET_FPREG: u8 = 3;
+pub const UWOP_SAVE_NONVOL: u8 = 4;
+pub const UWOP_SAVE_NONVOL_FAR: u8 = 5;
+/// Describes an epilogue, in version 2 unwind info
+pub const UWOP_EPILOG: u8 = 6;
+pub const UWOP_SPARE_CODE: u8 = 7;
+pub const UWOP_SAVE_XMM128: u8 = 8;
+pub const UWOP_SAVE_XMM128_FAR: u8 = 9;
+pub const UWOP_PUSH_MACHFRAME: u8 = 10;
+
+/// What an x64 prologue instruction did, which unwinding undoes
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum X64UnwindOperation {
+    /// Pushed the integer register with this number (0 is `rax`, 15 is `r15`)
+    PushNonVolatile(u8),
+    /// Allocated this many bytes of stack
+    Alloc(u32),
+    /// Set the frame register to `rsp` plus the frame offset, see `X64UnwindInfo::frame_register`
+    SetFramePointer,
+    /// Saved the integer register with this number at this offset from the stack (or frame) pointer
+    SaveNonVolatile { register: u8, offset: u32 },
+    /// Saved the xmm register with this number at this offset from the stack (or frame) pointer
+    SaveXmm128 { register: u8, offset: u32 },
+    /// A hardware exception or interrupt pushed a machine frame, with an error code if `true`
+    PushMachineFrame(bool),
+    /// Describes an epilogue rather than the prologue, in version 2 unwind info
+    Epilog,
+    /// The reserved `UWOP_SPARE_CODE`
+    Spare,
+}
+
+/// An x64 unwind code, one or more slots of the unwind code array
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct X64UnwindCode {
+    /// The offset of the end of the prologue instruction from the start of the prologue
+    pub code_offset: u8,
+    pub operation: X64UnwindOperation,
+}
+
+/// Iterator over the unwind codes of an `X64UnwindInfo`, in the order they are undone
+#[derive(Debug, Clone)]
+pub struct X64UnwindCodeIterator<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> X64UnwindCodeIterator<'a> {
+    fn next_code(&mut self) -> error::Result<X64UnwindCode> {
+        let offset = &mut self.offset;
+        let code_offset: u8 = self.bytes.gread(offset)?;
+        let op: u8 = self.bytes.gread(offset)?;
+        let (unwind_op, op_info) = (op & 0xf, op >> 4);
+        let operation = match unwind_op {
+            UWOP_PUSH_NONVOL => X64UnwindOperation::PushNonVolatile(op_info),
+            UWOP_ALLOC_LARGE if op_info == 0 => X64UnwindOperation::Alloc(self.bytes.gread_with::<u16>(offset, scroll::LE)? as u32 * 8),
+            UWOP_ALLOC_LARGE if op_info == 1 => X64UnwindOperation::Alloc(self.bytes.gread_with(offset, scroll::LE)?),
+            UWOP_ALLOC_SMALL => X64UnwindOperation::Alloc(op_info as u32 * 8 + 8),
+            UWOP_SET_FPREG => X64UnwindOperation::SetFramePointer,
+            UWOP_SAVE_NONVOL => X64UnwindOperation::SaveNonVolatile { register: op_info, offset: self.bytes.gread_with::<u16>(offset, scroll::LE)? as u32 * 8 },
+            UWOP_SAVE_NONVOL_FAR => X64UnwindOperation::SaveNonVolatile { register: op_info, offset: self.bytes.gread_with(offset, scroll::LE)? },
+            UWOP_EPILOG => {
+                *offset += 2;
+                X64UnwindOperation::Epilog
+            },
+            UWOP_SPARE_CODE => {
+                *offset += 4;
+                X64UnwindOperation::Spare
+            },
+            UWOP_SAVE_XMM128 => X64UnwindOperation::SaveXmm128 { register: op_info, offset: self.bytes.gread_with::<u16>(offset, scroll::LE)? as u32 * 16 },
+            UWOP_SAVE_XMM128_FAR => X64UnwindOperation::SaveXmm128 { register: op_info, offset: self.bytes.gread_with(offset, scroll::LE)? },
+            UWOP_PUSH_MACHFRAME => X64UnwindOperation::PushMachineFrame(op_info == 1),
+            _ => return Err(error::Error::Malformed(format!("Unknown unwind operation {} (info {}) at prologue offset {:#x}", unwind_op, op_info, code_offset))),
+        };
+        // the extra slots of the last code must have been in the array too
+        if *offset > self.bytes.len() {
+            return Err(error::Error::Malformed(format!("Unwind code at prologue offset {:#x} overruns the unwind code array", code_offset)));
+        }
+        Ok(X64UnwindCode { code_offset, operation })
+    }
+}
+
+impl<'a> Iterator for X64UnwindCodeIterator<'a> {
+    type Item = error::Result<X64UnwindCode>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.bytes.len() {
+            return None;
+        }
+        let code = self.next_code();
+        if code.is_err() {
+            self.offset = self.bytes.len();
+        }
+        Some(code)
+    }
+}
+
+/// An x64 `UNWIND_INFO`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct X64UnwindInfo<'a> {
+    /// The version of this record, 1 or 2
+    pub version: u8,
+    /// The `UNW_FLAG_*` values
+    pub flags: u8,
+    /// The length of the prologue in bytes
+    pub size_of_prolog: u8,
+    /// The number of 16-bit slots in the unwind code array
+    pub count_of_codes: u8,
+    /// The number of the register used as the frame pointer, 0 if the function doesn't use one
+    pub frame_register: u8,
+    /// The scaled offset from `rsp` the frame register is set to; the offset is `frame_offset * 16`
+    pub frame_offset: u8,
+    /// The raw unwind code array, see `unwind_codes`
+    pub unwind_code_bytes: &'a [u8],
+    /// The RVA of the exception or termination handler, if `flags` has `UNW_FLAG_EHANDLER` or `UNW_FLAG_UHANDLER`
+    pub exception_handler: Option<u32>,
+    /// The function whose unwind info continues this one, if `flags` has `UNW_FLAG_CHAININFO`
+    pub chained_function: Option<X64RuntimeFunction>,
+}
+
+impl<'a> X64UnwindInfo<'a> {
+    /// Parses an `UNWIND_INFO` from `bytes` at `offset`
+    pub fn parse(bytes: &'a [u8], mut offset: usize) -> error::Result<Self> {
+        let offset = &mut offset;
+        let version_and_flags: u8 = bytes.gread(offset)?;
+        let version = version_and_flags & 0x7;
+        if version != 1 && version != 2 {
+            return Err(error::Error::Malformed(format!("Unsupported x64 unwind info version {}", version)));
+        }
+        let flags = version_and_flags >> 3;
+        let size_of_prolog: u8 = bytes.gread(offset)?;
+        let count_of_codes: u8 = bytes.gread(offset)?;
+        let frame: u8 = bytes.gread(offset)?;
+        let unwind_code_bytes: &'a [u8] = bytes.gread_with(offset, count_of_codes as usize * 2)?;
+        // the array is padded to an even number of slots
+        if count_of_codes & 1 == 1 {
+            *offset += 2;
+        }
+        let mut exception_handler = None;
+        let mut chained_function = None;
+        if flags & UNW_FLAG_CHAININFO != 0 {
+            chained_function = Some(bytes.gread_with(offset, scroll::LE)?);
+        } else if flags & (UNW_FLAG_EHANDLER | UNW_FLAG_UHANDLER) != 0 {
+            exception_handler = Some(bytes.gread_with(offset, scroll::LE)?);
+        }
+        Ok(X64UnwindInfo {
+            version: version,
+            flags: flags,
+            size_of_prolog: size_of_prolog,
+            count_of_codes: count_of_codes,
+            frame_register: frame & 0xf,
+            frame_offset: frame >> 4,
+            unwind_code_bytes: unwind_code_bytes,
+            exception_handler: exception_handler,
+            chained_function: chained_function,
+        })
+    }
+    /// Iterate the unwind codes
+    pub fn unwind_codes(&self) -> X64UnwindCodeIterator<'a> {
+        X64UnwindCodeIterator { bytes: self.unwind_code_bytes, offset: 0 }
+    }
+}
+
+/// A function table entry, decoded according to the binary's machine type
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum RuntimeFunction {
+    X64(X64RuntimeFunction),
+    Arm64(Arm64RuntimeFunction),
+}
+
+impl RuntimeFunction {
+    /// The function start RVA
+    pub fn begin_address(&self) -> u32 {
+        match *self {
+            RuntimeFunction::X64(ref function) => function.begin_address,
+            RuntimeFunction::Arm64(ref function) => function.begin_address,
+        }
+    }
+}
+
+/// The unwind information of a `RuntimeFunction`
+#[derive(Debug, PartialEq, Clone)]
+pub enum UnwindInfo<'a> {
+    /// An x64 `UNWIND_INFO`
+    X64(X64UnwindInfo<'a>),
+    /// ARM64 unwind data packed into the function table entry itself
+    Arm64Packed(Arm64PackedUnwindData),
+    /// An ARM64 `.xdata` unwind record
+    Arm64(Arm64UnwindInfo<'a>),
+}
+
+/// Iterator over the entries of a function table
+#[derive(Debug)]
+pub struct RuntimeFunctionIterator<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    end: usize,
+    machine: u16,
+}
+
+impl<'a> Iterator for RuntimeFunctionIterator<'a> {
+    type Item = error::Result<RuntimeFunction>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.end {
+            return None;
+        }
+        let function = match self.machine {
+            header::COFF_MACHINE_X86_64 => self.bytes.gread_with(&mut self.offset, scroll::LE).map(RuntimeFunction::X64),
+            header::COFF_MACHINE_ARM64 => self.bytes.gread_with(&mut self.offset, scroll::LE).map(RuntimeFunction::Arm64),
+            _ => unreachable!(),
+        };
+        if function.is_err() {
+            self.offset = self.end;
+        }
+        Some(function.map_err(|e| e.into()))
+    }
+}
+
+/// The function table of a binary, for the machine types which have one
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ExceptionData<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    size: usize,
+    file_alignment: u32,
+    machine: u16,
+}
+
+impl<'a> ExceptionData<'a> {
+    /// Whether goblin knows how to decode the function table of `machine`
+    pub fn is_supported(machine: u16) -> bool {
+        machine == header::COFF_MACHINE_X86_64 || machine == header::COFF_MACHINE_ARM64
+    }
+    /// Parses the function table of the exception `directory` for the given COFF `machine`
+    pub fn parse(bytes: &'a [u8], directory: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32, machine: u16) -> error::Result<Self> {
+        if !Self::is_supported(machine) {
+            return Err(error::Error::Malformed(format!("Unsupported machine {:#x} for exception data", machine)));
+        }
+        let entry_size = Self::entry_size(machine);
+        let size = directory.size as usize;
+        if size % entry_size != 0 {
+            return Err(error::Error::Malformed(format!("Exception directory size {:#x} is not a multiple of the function entry size {}", size, entry_size)));
+        }
+        let rva = directory.virtual_address as usize;
+        let range = utils::find_range(rva, size, sections, file_alignment)
+            .ok_or_else(|| error::Error::Malformed(format!("Cannot map exception directory rva {:#x} (size {:#x}) into offset", rva, size)))?;
+        if range.end > bytes.len() {
+            return Err(error::Error::Malformed(format!("Exception directory {:#x}..{:#x} is out of bounds", range.start, range.end)));
+        }
+        Ok(ExceptionData {
+            bytes: bytes,
+            offset: range.start,
+            size: size,
+            file_alignment: file_alignment,
+            machine: machine,
+        })
+    }
+    fn entry_size(machine: u16) -> usize {
+        match machine {
+            header::COFF_MACHINE_X86_64 => SIZEOF_X64_RUNTIME_FUNCTION,
+            _ => SIZEOF_ARM64_RUNTIME_FUNCTION,
+        }
+    }
+    /// The number of entries in the function table
+    pub fn len(&self) -> usize {
+        self.size / Self::entry_size(self.machine)
+    }
+    /// Whether the function table is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Iterate the function table entries
+    pub fn functions(&self) -> RuntimeFunctionIterator<'a> {
+        RuntimeFunctionIterator {
+            bytes: self.bytes,
+            offset: self.offset,
+            end: self.offset + self.size,
+            machine: self.machine,
+        }
+    }
+    /// Get the function table entry at `index`
+    pub fn get_function(&self, index: usize) -> error::Result<RuntimeFunction> {
+        if index >= self.len() {
+            return Err(error::Error::Malformed(format!("Requested function {}, but there are only {} functions", index, self.len())));
+        }
+        let mut iter = self.functions();
+        iter.offset += index * Self::entry_size(self.machine);
+        iter.next().unwrap()
+    }
+    /// Decode the unwind information of `function`, resolving any unwind record RVAs against `sections`
+    pub fn get_unwind_info(&self, function: &RuntimeFunction, sections: &[section_table::SectionTable]) -> error::Result<UnwindInfo<'a>> {
+        match *function {
+            RuntimeFunction::X64(ref function) => Ok(UnwindInfo::X64(self.get_x64_unwind_info(function, sections)?)),
+            RuntimeFunction::Arm64(ref function) => {
+                if let Some(packed) = function.packed_unwind_data() {
+                    return Ok(UnwindInfo::Arm64Packed(packed));
+                }
+                let rva = function.xdata_rva()
+                    .ok_or_else(|| error::Error::Malformed(format!("Reserved unwind data flag for function at {:#x}", function.begin_address)))? as usize;
+                let offset = utils::find_offset(rva, sections, self.file_alignment)
+                    .ok_or_else(|| error::Error::Malformed(format!("Cannot map unwind info rva {:#x} into offset", rva)))?;
+                Ok(UnwindInfo::Arm64(Arm64UnwindInfo::parse(self.bytes, offset)?))
+            }
+        }
+    }
+    fn get_x64_unwind_info(&self, function: &X64RuntimeFunction, sections: &[section_table::SectionTable]) -> error::Result<X64UnwindInfo<'a>> {
+        let rva = function.unwind_info_address as usize;
+        let offset = utils::find_offset(rva, sections, self.file_alignment)
+            .ok_or_else(|| error::Error::Malformed(format!("Cannot map unwind info rva {:#x} into offset", rva)))?;
+        X64UnwindInfo::parse(self.bytes, offset)
+    }
+    /// Decode the unwind information of `function`, followed by that of each function it is chained to, in order;
+    /// fails if the chain refers back to unwind information already in it, rather than looping forever
+    pub fn get_unwind_info_chain(&self, function: &RuntimeFunction, sections: &[section_table::SectionTable]) -> error::Result<Vec<UnwindInfo<'a>>> {
+        let mut function = match *function {
+            RuntimeFunction::X64(function) => function,
+            _ => return Ok(vec![self.get_unwind_info(function, sections)?]),
+        };
+        let mut chain = Vec::new();
+        let mut seen = BTreeSet::new();
+        loop {
+            if !seen.insert(function.unwind_info_address) {
+                return Err(error::Error::Malformed(format!("Unwind info at rva {:#x} is chained to itself", function.unwind_info_address)));
+            }
+            let info = self.get_x64_unwind_info(&function, sections)?;
+            let chained = info.chained_function;
+            chain.push(UnwindInfo::X64(info));
+            match chained {
+                Some(chained) => function = chained,
+                None => return Ok(chain),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+    use pe::section_table::identity_mapped_sections;
+
+    const PACKED: u32 = 1 | (0x10 << 2) | (2 << 16) | (3 << 21) | (2 << 23);
+
+    #[test]
+    fn x64_function_table_and_unwind_info() {
+        let mut bytes = vec![0u8; 0x2000];
+        let functions = [
+            X64RuntimeFunction { begin_address: 0x1100, end_address: 0x1140, unwind_info_address: 0x1800 },
+            // the cold part of the first function, continuing its unwind info
+            X64RuntimeFunction { begin_address: 0x1140, end_address: 0x1160, unwind_info_address: 0x1840 },
+            X64RuntimeFunction { begin_address: 0x1160, end_address: 0x1180, unwind_info_address: 0x1880 },
+        ];
+        for (i, function) in functions.iter().enumerate() {
+            bytes.pwrite_with(*function, 0x1000 + i * SIZEOF_X64_RUNTIME_FUNCTION, scroll::LE).unwrap();
+        }
+        // `push rbx; sub rsp, 0x20; movaps [rsp+0x10], xmm6`, with an exception handler
+        bytes[0x1800..0x1804].copy_from_slice(&[1 | (UNW_FLAG_EHANDLER << 3), 0x0e, 4, 0]);
+        bytes[0x1804..0x180c].copy_from_slice(&[0x0e, UWOP_SAVE_XMM128 | (6 << 4), 0x01, 0x00, 0x05, UWOP_ALLOC_SMALL | (3 << 4), 0x01, UWOP_PUSH_NONVOL | (3 << 4)]);
+        bytes.pwrite_with(0x1a00u32, 0x180c, scroll::LE).unwrap();
+        bytes[0x1840..0x1844].copy_from_slice(&[1 | (UNW_FLAG_CHAININFO << 3), 0, 0, 0]);
+        bytes.pwrite_with(functions[0], 0x1844, scroll::LE).unwrap();
+        // chained to itself
+        bytes[0x1880..0x1884].copy_from_slice(&[1 | (UNW_FLAG_CHAININFO << 3), 0, 0, 0]);
+        bytes.pwrite_with(functions[2], 0x1884, scroll::LE).unwrap();
+
+        let directory = data_directories::DataDirectory { virtual_address: 0x1000, size: 3 * SIZEOF_X64_RUNTIME_FUNCTION as u32 };
+        let sections = identity_mapped_sections();
+        let exception_data = ExceptionData::parse(&bytes, &directory, &sections, 0x200, header::COFF_MACHINE_X86_64).unwrap();
+        assert_eq!(exception_data.len(), 3);
+        let function = exception_data.get_function(0).unwrap();
+        assert_eq!(function, RuntimeFunction::X64(functions[0]));
+        let info = match exception_data.get_unwind_info(&function, &sections).unwrap() {
+            UnwindInfo::X64(info) => info,
+            info => panic!("expected x64 unwind info, got {:?}", info),
+        };
+        assert_eq!((info.version, info.size_of_prolog, info.count_of_codes), (1, 0x0e, 4));
+        assert_eq!(info.exception_handler, Some(0x1a00));
+        assert_eq!(info.chained_function, None);
+        assert_eq!(info.unwind_codes().collect::<error::Result<Vec<_>>>().unwrap(), vec![
+            X64UnwindCode { code_offset: 0x0e, operation: X64UnwindOperation::SaveXmm128 { register: 6, offset: 0x10 } },
+            X64UnwindCode { code_offset: 0x05, operation: X64UnwindOperation::Alloc(0x20) },
+            X64UnwindCode { code_offset: 0x01, operation: X64UnwindOperation::PushNonVolatile(3) },
+        ]);
+        let chain = exception_data.get_unwind_info_chain(&exception_data.get_function(1).unwrap(), &sections).unwrap();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[1], UnwindInfo::X64(info));
+        assert!(exception_data.get_unwind_info_chain(&exception_data.get_function(2).unwrap(), &sections).is_err());
+
+        // a far save needs two more slots than the array has
+        let truncated = [1, 0, 1, 0, 0x04, UWOP_SAVE_NONVOL_FAR, 0, 0];
+        let info = X64UnwindInfo::parse(&truncated, 0).unwrap();
+        assert!(info.unwind_codes().next().unwrap().is_err());
+        assert!(X64UnwindInfo::parse(&[3, 0, 0, 0], 0).is_err());
+    }
+
+    #[test]
+    fn arm64_packed_unwind_data() {
+        // a 0x40 byte function saving 2 integer registers, with a chained frame and 0x20 bytes of stack
+        let function = Arm64RuntimeFunction { begin_address: 0x1000, unwind_data: PACKED };
+        assert!(function.is_packed());
+        assert_eq!(function.xdata_rva(), None);
+        let packed = function.packed_unwind_data().unwrap();
+        assert_eq!(packed, Arm64PackedUnwindData {
+            flag: ARM64_UNWIND_FLAG_PACKED,
+            function_length: 0x40,
+            reg_f: 0,
+            reg_i: 2,
+            h: false,
+            cr: 3,
+            frame_size: 0x20,
+        });
+    }
+
+    #[test]
+    fn arm64_function_table_and_xdata() {
+        let mut bytes = vec![0u8; 0x2000];
+        // .pdata: one packed entry, one entry pointing at .xdata at rva 0x1800
+        bytes.pwrite_with(Arm64RuntimeFunction { begin_address: 0x1100, unwind_data: PACKED }, 0x1000, scroll::LE).unwrap();
+        bytes.pwrite_with(Arm64RuntimeFunction { begin_address: 0x1140, unwind_data: 0x1800 }, 0x1008, scroll::LE).unwrap();
+        // .xdata: 0x80 byte function, exception data, 2 epilogue scopes, 1 code word
+        let header: u32 = 0x20 | (1 << 20) | (2 << 22) | (1 << 27);
+        bytes.pwrite_with(header, 0x1800, scroll::LE).unwrap();
+        bytes.pwrite_with::<u32>(0x18 | (1 << 22), 0x1804, scroll::LE).unwrap();
+        bytes.pwrite_with::<u32>(0x1c | (2 << 22), 0x1808, scroll::LE).unwrap();
+        bytes[0x180c..0x1810].copy_from_slice(&[0xe1, 0xe4, 0xe3, 0xe4]);
+        bytes.pwrite_with::<u32>(0x1a00, 0x1810, scroll::LE).unwrap();
+
+        let directory = data_directories::DataDirectory { virtual_address: 0x1000, size: 16 };
+        let sections = identity_mapped_sections();
+        assert!(ExceptionData::parse(&bytes, &directory, &sections, 0x200, header::COFF_MACHINE_X86).is_err());
+        let exception_data = ExceptionData::parse(&bytes, &directory, &sections, 0x200, header::COFF_MACHINE_ARM64).unwrap();
+        assert_eq!(exception_data.len(), 2);
+        let functions = exception_data.functions().collect::<error::Result<Vec<_>>>().unwrap();
+        assert_eq!(functions.iter().map(|function| function.begin_address()).collect::<Vec<_>>(), vec![0x1100, 0x1140]);
+        match exception_data.get_unwind_info(&functions[0], &sections).unwrap() {
+            UnwindInfo::Arm64Packed(packed) => assert_eq!(packed.function_length, 0x40),
+            info => panic!("expected packed unwind data, got {:?}", info),
+        }
+        let info = match exception_data.get_unwind_info(&exception_data.get_function(1).unwrap(), &sections).unwrap() {
+            UnwindInfo::Arm64(info) => info,
+            info => panic!("expected .xdata unwind info, got {:?}", info),
+        };
+        assert_eq!(info.function_length, 0x80);
+        assert!(info.has_exception_data);
+        assert!(!info.single_epilogue);
+        assert_eq!(info.epilogue_scopes, vec![
+            Arm64EpilogueScope { start_offset: 0x60, start_index: 1 },
+            Arm64EpilogueScope { start_offset: 0x70, start_index: 2 },
+        ]);
+        assert_eq!(info.unwind_codes, &[0xe1, 0xe4, 0xe3, 0xe4]);
+        assert_eq!(info.exception_handler, Some(0x1a00));
+        assert!(exception_data.get_function(2).is_err());
+    }
+}
@@ -136,11 +136,12 @@ impl<'a> ExportData<'a> {
     }
 }
 
-#[derive(Debug)]
-/// PE binaries have two kinds of reexports, either specifying the dll's name, or the ordinal value of the dll
+#[derive(Debug, PartialEq)]
+/// PE binaries have two kinds of reexports (aka forwarders), either specifying the export's name in the dll, e.g., `NTDLL.RtlAllocateHeap`,
+/// or its ordinal, e.g., `NTDLL.#123`
 pub enum Reexport<'a> {
   DLLName { export: &'a str, lib: &'a str },
-  DLLOrdinal { export: &'a str, ordinal: usize }
+  DLLOrdinal { lib: &'a str, ordinal: usize }
 }
 
 impl<'a> scroll::ctx::TryFromCtx<'a, scroll::Endian> for Reexport<'a> {
@@ -150,41 +151,20 @@ impl<'a> scroll::ctx::TryFromCtx<'a, scroll::Endian> for Reexport<'a> {
     fn try_from_ctx(bytes: &'a [u8], _ctx: scroll::Endian) -> Result<(Self, Self::Size), Self::Error> {
         use scroll::{Pread};
         let reexport = bytes.pread::<&str>(0)?;
-        let reexport_len = reexport.len();
         debug!("reexport: {}", &reexport);
-        for o in 0..reexport_len {
-            let c: u8 = bytes.pread(o)?;
-            debug!("reexport offset: {:#x} char: {:#x}", o, c);
-            match c {
-                // '.'
-                0x2e => {
-                    let i = o - 1;
-                    let dll: &'a str = bytes.pread_with(0, ::scroll::ctx::StrCtx::Length(i))?;
-                    debug!("dll: {:?}", &dll);
-                    let len = reexport_len - i - 1;
-                    let rest: &'a [u8] = bytes.pread_with(o, len)?;
-                    debug!("rest: {:?}", &rest);
-                    let len = rest.len() - 1;
-                    match rest[0] {
-                        // '#'
-                        0x23 => {
-                            // UNTESTED
-                            let ordinal = rest.pread_with::<&str>(1, ::scroll::ctx::StrCtx::Length(len))?;
-                            let ordinal = ordinal.parse::<u32>().map_err(|_e| error::Error::Malformed(format!("Cannot parse reexport ordinal from {} bytes", bytes.len())))?;
-                            // FIXME: return size
-                            return Ok((Reexport::DLLOrdinal { export: dll, ordinal: ordinal as usize }, 0))
-                        },
-                        _ => {
-                            let export = rest.pread_with::<&str>(1, ::scroll::ctx::StrCtx::Length(len))?;
-                            // FIXME: return size
-                            return Ok((Reexport::DLLName { export: export, lib: dll }, 0))
-                        }
-                    }
-                },
-                _ => {}
-            }
+        // the dll name is everything up to the first '.', without its extension
+        let (lib, export) = match reexport.find('.') {
+            Some(dot) if dot > 0 && dot + 1 < reexport.len() => (&reexport[..dot], &reexport[dot + 1..]),
+            _ => return Err(error::Error::Malformed(format!("Reexport {:#} is malformed", reexport))),
+        };
+        debug!("dll: {:?} export: {:?}", lib, export);
+        // FIXME: return size
+        if export.starts_with('#') {
+            let ordinal = export[1..].parse::<u16>().map_err(|_e| error::Error::Malformed(format!("Cannot parse reexport ordinal from {:?}", reexport)))?;
+            Ok((Reexport::DLLOrdinal { lib, ordinal: ordinal as usize }, 0))
+        } else {
+            Ok((Reexport::DLLName { export, lib }, 0))
         }
-        Err(error::Error::Malformed(format!("Reexport {:#} is malformed", reexport)))
     }
 }
 
@@ -192,6 +172,26 @@ impl<'a> Reexport<'a> {
     pub fn parse(bytes: &'a [u8], offset: usize) -> ::error::Result<Reexport<'a>> {
         bytes.pread(offset)
     }
+    /// The name of the dll the export is forwarded to, without its extension
+    pub fn lib(&self) -> &'a str {
+        match *self {
+            Reexport::DLLName { lib, .. } | Reexport::DLLOrdinal { lib, .. } => lib,
+        }
+    }
+    /// The name of the export in `lib`, if it is forwarded by name
+    pub fn name(&self) -> Option<&'a str> {
+        match *self {
+            Reexport::DLLName { export, .. } => Some(export),
+            Reexport::DLLOrdinal { .. } => None,
+        }
+    }
+    /// The ordinal of the export in `lib`, if it is forwarded by ordinal
+    pub fn ordinal(&self) -> Option<usize> {
+        match *self {
+            Reexport::DLLName { .. } => None,
+            Reexport::DLLOrdinal { ordinal, .. } => Some(ordinal),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -204,6 +204,26 @@ pub struct Export<'a> {
     pub reexport: Option<Reexport<'a>>,
 }
 
+impl<'a> ::symbol::Symbol for Export<'a> {
+    fn name(&self) -> &str {
+        self.name.unwrap_or("")
+    }
+    fn address(&self) -> u64 {
+        self.rva as u64
+    }
+    /// The export table doesn't say whether an export is code or data, so every export but a forwarder is taken to be a function
+    fn is_function(&self) -> bool {
+        self.reexport.is_none()
+    }
+    fn is_global(&self) -> bool {
+        true
+    }
+    /// Forwarders are defined by another dll
+    fn is_undefined(&self) -> bool {
+        self.reexport.is_some()
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 struct ExportCtx<'a> {
     pub ptr: u32,
@@ -337,6 +357,20 @@ mod tests {
           0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
           0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ];
 
+    #[test]
+    fn parse_reexports() {
+        // kernel32 forwards most of its exports to kernelbase, and some to ntdll
+        let reexport = Reexport::parse(b"api-ms-win-core-heap-l1-1-0.HeapFree\0", 0).unwrap();
+        assert_eq!(reexport, Reexport::DLLName { export: "HeapFree", lib: "api-ms-win-core-heap-l1-1-0" });
+        let reexport = Reexport::parse(b"\0NTDLL.RtlAllocateHeap\0", 1).unwrap();
+        assert_eq!((reexport.lib(), reexport.name(), reexport.ordinal()), ("NTDLL", Some("RtlAllocateHeap"), None));
+        let reexport = Reexport::parse(b"MSVCRT.#123\0", 0).unwrap();
+        assert_eq!((reexport.lib(), reexport.name(), reexport.ordinal()), ("MSVCRT", None, Some(123)));
+        for malformed in &[&b"NTDLL\0"[..], b".RtlAllocateHeap\0", b"NTDLL.\0", b"MSVCRT.#\0", b"MSVCRT.#70000\0"] {
+            assert!(Reexport::parse(malformed, 0).is_err());
+        }
+    }
+
     #[test]
     fn size_export_directory_table() {
         assert_eq!(::std::mem::size_of::<ExportDirectoryTable>(), SIZEOF_EXPORT_DIRECTORY_TABLE);
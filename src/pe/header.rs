@@ -47,6 +47,7 @@ pub const SIZEOF_COFF_HEADER: usize = 24;
 pub const COFF_MAGIC: u32 = 0x00004550;
 pub const COFF_MACHINE_X86: u16 = 0x14c;
 pub const COFF_MACHINE_X86_64: u16 = 0x8664;
+pub const COFF_MACHINE_ARM64: u16 = 0xaa64;
 
 impl CoffHeader {
     pub fn parse(bytes: &[u8], offset: &mut usize) -> error::Result<Self> {
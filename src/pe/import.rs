@@ -13,8 +13,8 @@ use pe::data_directories;
 
 pub const IMPORT_BY_ORDINAL_32: u32 = 0x8000_0000;
 pub const IMPORT_BY_ORDINAL_64: u64 = 0x8000_0000_0000_0000;
-pub const IMPORT_RVA_MASK_32: u32 = 0x8fff_ffff;
-pub const IMPORT_RVA_MASK_64: u64 = 0x0000_0000_8fff_ffff;
+pub const IMPORT_RVA_MASK_32: u32 = 0x7fff_ffff;
+pub const IMPORT_RVA_MASK_64: u64 = 0x0000_0000_7fff_ffff;
 
 pub trait Bitfield<'a>: Into<u64> + PartialEq + Eq + LowerHex + Debug + TryFromCtx<'a, scroll::Endian, Error=scroll::Error, Size=usize> {
     fn is_ordinal(&self) -> bool;
@@ -201,17 +201,50 @@ impl<'a> ImportData<'a> {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// How an import refers to what it imports from its DLL, as told by the high bit of its import lookup table entry
+pub enum ImportBy<'a> {
+    /// By name, with a hint at the name's index in the DLL's export name pointer table
+    Name { hint: u16, name: &'a str },
+    /// By ordinal alone, without a name, as is common for `ws2_32.dll` and the MFC DLLs
+    Ordinal(u16),
+}
+
 #[derive(Debug)]
 /// A synthesized symbol import, the name is pre-indexed, and the binary offset is computed, as well as which dll it belongs to
 pub struct Import<'a> {
+    /// The imported name, or, for an import by ordinal, a synthesized `ORDINAL n`; see `import_by`
     pub name: Cow<'a, str>,
     pub dll: &'a str,
+    /// The ordinal of an import by ordinal, or the hint of an import by name
     pub ordinal: u16,
+    /// What is imported, exactly as the import lookup table has it
+    pub import_by: ImportBy<'a>,
     pub offset: usize,
     pub rva: usize,
     pub size: usize,
 }
 
+impl<'a> ::symbol::Symbol for Import<'a> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    /// The rva of the import's slot in the import address table
+    fn address(&self) -> u64 {
+        self.rva as u64
+    }
+    /// Like exports, imports don't say whether they are code or data, and nearly all of them are functions
+    fn is_function(&self) -> bool {
+        true
+    }
+    fn is_global(&self) -> bool {
+        true
+    }
+    fn is_undefined(&self) -> bool {
+        true
+    }
+}
+
 impl<'a> Import<'a> {
     pub fn parse<T: Bitfield<'a>>(_bytes: &'a [u8], import_data: &ImportData<'a>, _sections: &[section_table::SectionTable]) -> error::Result<Vec<Import<'a>>> {
         let mut imports = Vec::new();
@@ -223,23 +256,23 @@ impl<'a> Import<'a> {
                 for (i, entry) in import_lookup_table.iter().enumerate() {
                     let offset = import_base + (i * T::size_of());
                     use self::SyntheticImportLookupTableEntry::*;
-                    let (rva, name, ordinal) =
+                    let (rva, name, ordinal, import_by) =
                         match entry {
                             &HintNameTableRVA ((rva, ref hint_entry)) => {
                                 // if hint_entry.name = "" && hint_entry.hint = 0 {
                                 //     println!("<PE.Import> warning hint/name table rva from {} without hint {:#x}", dll, rva);
                                 // }
-                                (rva, Cow::Borrowed(hint_entry.name), hint_entry.hint.clone())
+                                (rva, Cow::Borrowed(hint_entry.name), hint_entry.hint.clone(), ImportBy::Name { hint: hint_entry.hint, name: hint_entry.name })
                             },
                             &OrdinalNumber(ordinal) => {
                                 let name = format!("ORDINAL {}", ordinal);
-                                (0x0, Cow::Owned(name), ordinal)
+                                (0x0, Cow::Owned(name), ordinal, ImportBy::Ordinal(ordinal))
                             },
                         };
                     let import =
                         Import {
                             name: name,
-                            ordinal: ordinal, dll: dll,
+                            ordinal: ordinal, dll: dll, import_by: import_by,
                             size: T::size_of(), offset: offset, rva: rva as usize
                         };
                     imports.push(import);
@@ -249,3 +282,150 @@ impl<'a> Import<'a> {
         Ok (imports)
     }
 }
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Pread, Pwrite, SizeWith)]
+/// An `IMAGE_BOUND_IMPORT_DESCRIPTOR`; an `IMAGE_BOUND_FORWARDER_REF` has the same layout, with the last field reserved
+pub struct BoundImportDescriptor {
+    /// The timestamp of the DLL the binary was bound against; if the loaded DLL's differs, the loader resolves the imports as usual
+    pub time_date_stamp: u32,
+    /// The offset of the DLL's name, relative to the first descriptor
+    pub offset_module_name: u16,
+    /// The number of `IMAGE_BOUND_FORWARDER_REF`s following this descriptor
+    pub number_of_module_forwarder_refs: u16,
+}
+
+pub const SIZEOF_BOUND_IMPORT_DESCRIPTOR: usize = 8;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A DLL which a bound import forwards to, with the timestamp it was bound against
+pub struct BoundForwarderRef<'a> {
+    pub time_date_stamp: u32,
+    pub name: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A DLL the binary was pre-bound against, from the bound import directory, `IMAGE_DIRECTORY_ENTRY_BOUND_IMPORT`
+pub struct BoundImportData<'a> {
+    pub time_date_stamp: u32,
+    pub name: &'a str,
+    /// The DLLs the bound imports from this one forward to, which must also be unchanged for the binding to hold
+    pub forwarder_refs: Vec<BoundForwarderRef<'a>>,
+}
+
+impl<'a> BoundImportData<'a> {
+    /// Parses the descriptors of the bound import directory `dd` up to the all zero one.
+    ///
+    /// The directory is usually in the headers, after the section table, outside of any section; an rva no section contains is taken to be one of the headers, which are mapped as is
+    pub fn parse(bytes: &'a [u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Vec<Self>> {
+        let rva = dd.virtual_address as usize;
+        let start = utils::find_offset(rva, sections, file_alignment).unwrap_or(rva);
+        let table = match start.checked_add(dd.size as usize).and_then(|end| bytes.get(start..end)) {
+            Some(table) => table,
+            None => return Err(error::Error::Malformed(format!("bound import directory at {:#x} of size {:#x} is out of bounds", start, dd.size))),
+        };
+        // the names are at offsets relative to the first descriptor, inside the directory
+        let name = |descriptor: &BoundImportDescriptor| -> error::Result<&'a str> {
+            let offset = descriptor.offset_module_name as usize;
+            if offset >= table.len() {
+                return Err(error::Error::Malformed(format!("bound import name at offset {:#x} is outside of the {:#x} byte directory", offset, table.len())));
+            }
+            Ok(table.pread::<&str>(offset)?)
+        };
+        let mut bound_imports = Vec::new();
+        let offset = &mut 0;
+        loop {
+            let descriptor: BoundImportDescriptor = table.gread_with(offset, scroll::LE)?;
+            if descriptor == (BoundImportDescriptor { time_date_stamp: 0, offset_module_name: 0, number_of_module_forwarder_refs: 0 }) {
+                break;
+            }
+            let mut forwarder_refs = Vec::new();
+            for _ in 0..descriptor.number_of_module_forwarder_refs {
+                let forwarder: BoundImportDescriptor = table.gread_with(offset, scroll::LE)?;
+                forwarder_refs.push(BoundForwarderRef { time_date_stamp: forwarder.time_date_stamp, name: name(&forwarder)? });
+            }
+            bound_imports.push(BoundImportData { time_date_stamp: descriptor.time_date_stamp, name: name(&descriptor)?, forwarder_refs });
+        }
+        Ok(bound_imports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    /// An image with a single section, mapping rva 0x1000 to file offset 0x200, which imports from `WS2_32.dll` by the `thunks`
+    fn image_importing<T: Copy + Into<u64>>(thunks: &[T]) -> (Vec<u8>, Vec<section_table::SectionTable>, data_directories::DataDirectory) {
+        let size = ::core::mem::size_of::<T>();
+        let mut bytes = vec![0u8; 0x400];
+        let entry = ImportDirectoryEntry { import_lookup_table_rva: 0x1040, time_date_stamp: 0, forwarder_chain: 0, name_rva: 0x10c0, import_address_table_rva: 0x1080 };
+        bytes.pwrite_with(entry, 0x200, scroll::LE).unwrap();
+        for (i, &thunk) in thunks.iter().enumerate() {
+            for &table in [0x240, 0x280].iter() {
+                if size == 8 {
+                    bytes.pwrite_with(thunk.into(), table + i * size, scroll::LE).unwrap();
+                } else {
+                    bytes.pwrite_with(thunk.into() as u32, table + i * size, scroll::LE).unwrap();
+                }
+            }
+        }
+        bytes.pwrite_with("WS2_32.dll", 0x2c0, ()).unwrap();
+        // a hint/name entry at rva 0x10d0
+        bytes.pwrite_with(0x35u16, 0x2d0, scroll::LE).unwrap();
+        bytes.pwrite_with("WSACleanup", 0x2d2, ()).unwrap();
+        let mut section = section_table::SectionTable::default();
+        section.virtual_address = 0x1000;
+        section.virtual_size = 0x200;
+        section.pointer_to_raw_data = 0x200;
+        section.size_of_raw_data = 0x200;
+        (bytes, vec![section], data_directories::DataDirectory { virtual_address: 0x1000, size: 2 * SIZEOF_IMPORT_DIRECTORY_ENTRY as u32 })
+    }
+
+    #[test]
+    fn imports_by_ordinal() {
+        // `socket` and `WSAStartup`, purely by ordinal
+        let (bytes, sections, dd) = image_importing(&[IMPORT_BY_ORDINAL_32 | 23, IMPORT_BY_ORDINAL_32 | 115]);
+        let import_data = ImportData::parse::<u32>(&bytes, &dd, &sections, 0x200).unwrap();
+        let imports = Import::parse::<u32>(&bytes, &import_data, &sections).unwrap();
+        let by = imports.iter().map(|import| (import.dll, import.import_by)).collect::<Vec<_>>();
+        assert_eq!(by, vec![("WS2_32.dll", ImportBy::Ordinal(23)), ("WS2_32.dll", ImportBy::Ordinal(115))]);
+        assert_eq!((imports[1].ordinal, &*imports[1].name), (115, "ORDINAL 115"));
+        // a 64-bit thunk is flagged by its top bit, and the imports by name and by ordinal may be mixed
+        let (bytes, sections, dd) = image_importing(&[IMPORT_BY_ORDINAL_64 | 115, 0x10d0u64]);
+        let import_data = ImportData::parse::<u64>(&bytes, &dd, &sections, 0x200).unwrap();
+        let imports = Import::parse::<u64>(&bytes, &import_data, &sections).unwrap();
+        let by = imports.iter().map(|import| import.import_by).collect::<Vec<_>>();
+        assert_eq!(by, vec![ImportBy::Ordinal(115), ImportBy::Name { hint: 0x35, name: "WSACleanup" }]);
+        let (bytes, sections, dd) = image_importing(&[0x10d0u32]);
+        let import_data = ImportData::parse::<u32>(&bytes, &dd, &sections, 0x200).unwrap();
+        let imports = Import::parse::<u32>(&bytes, &import_data, &sections).unwrap();
+        assert_eq!(imports[0].import_by, ImportBy::Name { hint: 0x35, name: "WSACleanup" });
+    }
+
+    #[test]
+    fn bound_imports() {
+        // kernel32 forwarding to ntdll, then user32, then the terminator and the names, at 0x10 in the headers
+        let mut bytes = vec![0u8; 0x10];
+        let descriptors = [(0x5000_0001, 0x20, 1), (0x5000_0002, 0x2d, 0), (0x5000_0003, 0x37, 0), (0, 0, 0)];
+        for &(time_date_stamp, offset_module_name, number_of_module_forwarder_refs) in descriptors.iter() {
+            let descriptor = BoundImportDescriptor { time_date_stamp, offset_module_name, number_of_module_forwarder_refs };
+            let offset = bytes.len();
+            bytes.resize(offset + SIZEOF_BOUND_IMPORT_DESCRIPTOR, 0);
+            bytes.pwrite_with(descriptor, offset, scroll::LE).unwrap();
+        }
+        bytes.extend_from_slice(b"KERNEL32.dll\0ntdll.dll\0USER32.dll\0");
+        let dd = data_directories::DataDirectory { virtual_address: 0x10, size: bytes.len() as u32 - 0x10 };
+        let bound_imports = BoundImportData::parse(&bytes, &dd, &[], 0x200).unwrap();
+        assert_eq!(bound_imports, vec![
+            BoundImportData { time_date_stamp: 0x5000_0001, name: "KERNEL32.dll", forwarder_refs: vec![BoundForwarderRef { time_date_stamp: 0x5000_0002, name: "ntdll.dll" }] },
+            BoundImportData { time_date_stamp: 0x5000_0003, name: "USER32.dll", forwarder_refs: vec![] },
+        ]);
+        // a name past the directory
+        bytes.pwrite_with(0x100u16, 0x10 + 4, scroll::LE).unwrap();
+        assert!(BoundImportData::parse(&bytes, &dd, &[], 0x200).is_err());
+        // as is the directory itself
+        let dd = data_directories::DataDirectory { size: dd.size + 1, .. dd };
+        assert!(BoundImportData::parse(&bytes, &dd, &[], 0x200).is_err());
+    }
+}
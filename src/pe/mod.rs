@@ -3,7 +3,9 @@
 
 // TODO: panics with unwrap on None for apisetschema.dll, fhuxgraphics.dll and some others
 
+use core::fmt;
 use alloc::vec::Vec;
+use scroll::{self, Pread};
 
 pub mod header;
 pub mod optional_header;
@@ -13,6 +15,13 @@ pub mod data_directories;
 pub mod export;
 pub mod import;
 pub mod debug;
+pub mod exception;
+pub mod clr;
+pub mod rich;
+pub mod tls;
+pub mod resource;
+pub mod relocation;
+pub mod certificate;
 mod utils;
 
 use error;
@@ -20,8 +29,17 @@ use container;
 
 /// Size of a single symbol in the COFF Symbol Table.
 const COFF_SYMBOL_SIZE: u32 = 18;
+/// The offset of `CheckSum` in the optional header, which is the same for PE32 and PE32+
+const CHECKSUM_OFFSET: usize = 64;
+
+/// The file offset of the optional header's `CheckSum`, or `None` if `bytes` has no `e_lfanew`
+fn checksum_offset(bytes: &[u8]) -> Option<usize> {
+    match bytes.pread_with::<u32>(header::PE_POINTER_OFFSET as usize, scroll::LE) {
+        Ok(pe_pointer) => Some(pe_pointer as usize + header::SIZEOF_COFF_HEADER + CHECKSUM_OFFSET),
+        Err(_) => None,
+    }
+}
 
-#[derive(Debug)]
 /// An analyzed PE32/PE32+ binary
 pub struct PE<'a> {
     /// The PE header
@@ -51,10 +69,139 @@ pub struct PE<'a> {
     /// The list of libraries which this binary imports symbols from
     pub libraries: Vec<&'a str>,
     /// Debug information, if any, contained in the PE header
-    pub debug_data: Option<debug::DebugData<'a>>
+    pub debug_data: Option<debug::DebugData<'a>>,
+    /// The function table used for stack unwinding, for the machine types goblin can decode it for (x64 and ARM64); `None` if it is malformed
+    pub exception_data: Option<exception::ExceptionData<'a>>,
+    /// The CLR header, if this is a .NET assembly
+    pub clr_header: Option<clr::Cor20Header>,
+    /// The TLS directory and its callbacks, if there is one
+    pub tls_data: Option<tls::TlsData>,
+    /// The rich header identifying the toolchain which built this binary, if it was linked by the Microsoft linker
+    pub rich_header: Option<rich::RichHeader>,
+    /// The resources, e.g., the version info and manifest, if there are any
+    pub resource_data: Option<resource::ResourceData>,
+    /// The base relocations, which rebase this binary when it can't be loaded at its preferred `image_base`, if there are any
+    pub relocation_data: Option<relocation::RelocationData<'a>>,
+    /// The Authenticode signatures and other certificates of the certificate table; empty if the binary isn't signed, or if the table is malformed
+    pub certificates: Vec<certificate::CertificateData<'a>>,
+    /// The DLLs the imports were pre-bound against, from the bound import directory; empty if the binary isn't bound
+    pub bound_imports: Vec<import::BoundImportData<'a>>,
+    bytes: &'a [u8],
+}
+
+impl<'a> fmt::Debug for PE<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("PE")
+            .field("header",      &self.header)
+            .field("sections",    &self.sections)
+            .field("size",        &self.size)
+            .field("name",        &self.name)
+            .field("is_lib",      &self.is_lib)
+            .field("is_64",       &self.is_64)
+            .field("entry",       &self.entry)
+            .field("image_base",  &self.image_base)
+            .field("export_data", &self.export_data)
+            .field("import_data", &self.import_data)
+            .field("exports",     &self.exports)
+            .field("imports",     &self.imports)
+            .field("libraries",   &self.libraries)
+            .field("debug_data",  &self.debug_data)
+            .field("exception_data", &self.exception_data)
+            .field("clr_header",  &self.clr_header)
+            .field("tls_data",    &self.tls_data)
+            .field("resource_data", &self.resource_data)
+            .field("relocation_data", &self.relocation_data)
+            .field("certificates", &self.certificates)
+            .field("bound_imports", &self.bound_imports)
+            .field("rich_header", &self.rich_header)
+            .finish()
+    }
+}
+
+impl container::Ctx {
+    /// The context for reading `pe`, which is always little endian
+    pub fn from_pe(pe: &PE) -> Self {
+        container::Ctx::le(if pe.is_64 { container::Container::Big } else { container::Container::Little })
+    }
 }
 
 impl<'a> PE<'a> {
+    /// The file alignment of this binary's sections, or 0 if there is no optional header
+    fn file_alignment(&self) -> u32 {
+        self.header.optional_header.map_or(0, |optional_header| optional_header.windows_fields.file_alignment)
+    }
+    /// Maps `rva` to an offset in the file, or `None` if no section contains it
+    pub fn rva_to_offset(&self, rva: usize) -> Option<usize> {
+        utils::find_offset(rva, &self.sections, self.file_alignment())
+    }
+    /// Returns exactly `size` bytes at `rva`, or `None` if they are not entirely contained in a single section of the file
+    pub fn rva_slice(&self, rva: usize, size: usize) -> Option<&'a [u8]> {
+        match utils::find_range(rva, size, &self.sections, self.file_alignment()) {
+            Some(range) => self.bytes.get(range),
+            None => None,
+        }
+    }
+    /// Returns the bytes described by the data directory `dir`, or `None` if they are out of range.
+    ///
+    /// **NB**: the certificate table's `virtual_address` is a file offset rather than an RVA, and cannot be resolved with this method
+    pub fn directory_bytes(&self, dir: &data_directories::DataDirectory) -> Option<&'a [u8]> {
+        self.rva_slice(dir.virtual_address as usize, dir.size as usize)
+    }
+    /// Whether this is a .NET assembly, i.e., it has a CLR header
+    pub fn is_dotnet(&self) -> bool {
+        self.clr_header.is_some()
+    }
+    /// The `COMIMAGE_FLAGS_*` in the CLR header, if this is a .NET assembly
+    pub fn clr_flags(&self) -> Option<clr::ClrFlags> {
+        self.clr_header.map(|header| clr::ClrFlags(header.flags))
+    }
+    /// Whether this is a .NET assembly consisting of IL only
+    pub fn is_pure_il(&self) -> bool {
+        self.clr_flags().map_or(false, |flags| flags.is_il_only())
+    }
+    /// Whether this is a mixed-mode .NET assembly, containing native code as well as IL; these are loaded like native binaries
+    pub fn is_mixed_mode(&self) -> bool {
+        self.clr_flags().map_or(false, |flags| !flags.is_il_only())
+    }
+    /// Returns the data of the resource `entry`, or `None` if it is out of range
+    pub fn resource_bytes(&self, entry: &resource::ResourceEntry) -> Option<&'a [u8]> {
+        self.rva_slice(entry.data_rva as usize, entry.size as usize)
+    }
+    /// The first `RT_MANIFEST` resource, the XML side-by-side assembly manifest
+    pub fn manifest(&self) -> Option<&'a [u8]> {
+        self.resource_data.as_ref().and_then(|resources| resources.find(resource::RT_MANIFEST)).and_then(|entry| self.resource_bytes(entry))
+    }
+    /// The first `RT_VERSION` resource, the raw `VS_VERSIONINFO`
+    pub fn version_info(&self) -> Option<&'a [u8]> {
+        self.resource_data.as_ref().and_then(|resources| resources.find(resource::RT_VERSION)).and_then(|entry| self.resource_bytes(entry))
+    }
+    /// Computes the checksum of the image in `bytes` the way `CheckSumMappedFile` does: the ones' complement sum of its 16-bit little endian words,
+    /// with the `CheckSum` field as zero and an odd trailing byte padded with zero, plus the length of the file
+    pub fn compute_checksum(bytes: &[u8]) -> u32 {
+        let field = checksum_offset(bytes);
+        // the field needn't be word aligned, so mask it out byte by byte
+        let byte = |i: usize| -> u32 {
+            match field {
+                Some(field) if i >= field && i - field < 4 => 0,
+                _ => bytes.get(i).map_or(0, |byte| *byte as u32),
+            }
+        };
+        let mut sum: u32 = 0;
+        for i in (0..bytes.len()).step_by(2) {
+            sum += byte(i) | byte(i + 1) << 8;
+            sum = (sum & 0xffff) + (sum >> 16);
+        }
+        sum.wrapping_add(bytes.len() as u32)
+    }
+    /// Whether the `CheckSum` stored in the optional header of `bytes` matches `compute_checksum`; a stored checksum of 0 means the linker didn't compute one,
+    /// and never verifies
+    pub fn verify_checksum(bytes: &[u8]) -> bool {
+        let stored = match checksum_offset(bytes) {
+            Some(field) => bytes.pread_with::<u32>(field, scroll::LE).unwrap_or(0),
+            None => 0,
+        };
+        stored != 0 && stored == Self::compute_checksum(bytes)
+    }
     /// Reads a PE binary from the underlying `bytes`
     pub fn parse(bytes: &'a [u8]) -> error::Result<Self> {
         let header = header::Header::parse(bytes)?;
@@ -69,6 +216,8 @@ impl<'a> PE<'a> {
             debug!("({}) {:#?}", i, section);
             sections.push(section);
         }
+        // the rich header is informational, so a malformed one doesn't fail the parse
+        let rich_header = rich::RichHeader::parse(bytes, header.dos_header.pe_pointer as usize).unwrap_or(None);
         let is_lib = characteristic::is_dll(header.coff_header.characteristics);
         let mut entry = 0;
         let mut image_base = 0;
@@ -79,6 +228,13 @@ impl<'a> PE<'a> {
         let mut import_data = None;
         let mut libraries = vec![];
         let mut debug_data = None;
+        let mut exception_data = None;
+        let mut clr_header = None;
+        let mut tls_data = None;
+        let mut resource_data = None;
+        let mut relocation_data = None;
+        let mut certificates = vec![];
+        let mut bound_imports = vec![];
         let mut is_64 = false;
         if let Some(optional_header) = header.optional_header {
             entry = optional_header.standard_fields.address_of_entry_point as usize;
@@ -117,6 +273,34 @@ impl<'a> PE<'a> {
             if let &Some(debug_table) = optional_header.data_directories.get_debug_table() {
                 debug_data = Some(debug::DebugData::parse(bytes, &debug_table, &sections, file_alignment)?);
             }
+            let machine = header.coff_header.machine;
+            if exception::ExceptionData::is_supported(machine) {
+                if let &Some(exception_table) = optional_header.data_directories.get_exception_table() {
+                    // the function table is only needed to unwind, so a malformed one doesn't fail the parse
+                    exception_data = exception::ExceptionData::parse(bytes, &exception_table, &sections, file_alignment, machine).ok();
+                }
+            }
+            if let &Some(tls_table) = optional_header.data_directories.get_tls_table() {
+                // packers like to point the callbacks at data which only exists once unpacked, so don't fail the parse over them
+                tls_data = tls::TlsData::parse(bytes, &tls_table, &sections, file_alignment, image_base as u64, is_64).ok();
+            }
+            if let &Some(resource_table) = optional_header.data_directories.get_resource_table() {
+                resource_data = resource::ResourceData::parse(bytes, &resource_table, &sections, file_alignment).ok();
+            }
+            if let &Some(base_relocation_table) = optional_header.data_directories.get_base_relocation_table() {
+                relocation_data = relocation::RelocationData::parse(bytes, &base_relocation_table, &sections, file_alignment).ok();
+            }
+            if let &Some(certificate_table) = optional_header.data_directories.get_certificate_table() {
+                // the signature isn't needed to load the image, and a truncated one usually just means the file was cut short
+                certificates = certificate::CertificateData::parse(bytes, &certificate_table).unwrap_or(vec![]);
+            }
+            if let &Some(bound_import_table) = optional_header.data_directories.get_bound_import_table() {
+                // the loader resolves the imports as usual when the binding is stale or bogus, so it doesn't fail the parse either
+                bound_imports = import::BoundImportData::parse(bytes, &bound_import_table, &sections, file_alignment).unwrap_or(vec![]);
+            }
+            if let &Some(clr_runtime_header) = optional_header.data_directories.get_clr_runtime_header() {
+                clr_header = clr::Cor20Header::parse(bytes, &clr_runtime_header, &sections, file_alignment).ok();
+            }
         }
         Ok( PE {
             header: header,
@@ -133,6 +317,144 @@ impl<'a> PE<'a> {
             imports: imports,
             libraries: libraries,
             debug_data: debug_data,
+            exception_data: exception_data,
+            clr_header: clr_header,
+            tls_data: tls_data,
+            rich_header: rich_header,
+            resource_data: resource_data,
+            relocation_data: relocation_data,
+            certificates: certificates,
+            bound_imports: bound_imports,
+            bytes: bytes,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    /// A patterned image with its PE header at `pe_pointer`
+    fn image(size: usize, pe_pointer: u32) -> Vec<u8> {
+        let mut bytes = (0..size).map(|i| (i * 7 + 3) as u8).collect::<Vec<_>>();
+        bytes[0] = b'M';
+        bytes[1] = b'Z';
+        bytes.pwrite_with(pe_pointer, header::PE_POINTER_OFFSET as usize, scroll::LE).unwrap();
+        bytes.pwrite_with(header::COFF_MAGIC, pe_pointer as usize, scroll::LE).unwrap();
+        bytes
+    }
+
+    /// A PE32+ for x64 with the data `directories`, by index, and a single read/write section mapping rva `0x1000..0x1200` to the same size at file offset 0x200
+    fn pe64(directories: &[(usize, data_directories::DataDirectory)]) -> Vec<u8> {
+        use pe::optional_header::{StandardFields64, WindowsFields64, MAGIC_64};
+        let mut bytes = vec![0u8; 0x400];
+        bytes[0] = b'M';
+        bytes[1] = b'Z';
+        bytes.pwrite_with(0x40u32, header::PE_POINTER_OFFSET as usize, scroll::LE).unwrap();
+        let optional_header_size = optional_header::SIZEOF_STANDARD_FIELDS_64 + optional_header::SIZEOF_WINDOWS_FIELDS_64 + 16 * data_directories::SIZEOF_DATA_DIRECTORY;
+        bytes.pwrite_with(header::COFF_MAGIC, 0x40, scroll::LE).unwrap();
+        bytes.pwrite_with(header::COFF_MACHINE_X86_64, 0x44, scroll::LE).unwrap();
+        bytes.pwrite_with(1u16, 0x46, scroll::LE).unwrap();
+        bytes.pwrite_with(optional_header_size as u16, 0x54, scroll::LE).unwrap();
+        bytes.pwrite_with(0x22u16, 0x56, scroll::LE).unwrap();
+        let mut offset = 0x58;
+        bytes.gwrite_with(StandardFields64 { magic: MAGIC_64, .. StandardFields64::default() }, &mut offset, scroll::LE).unwrap();
+        let windows_fields = WindowsFields64 {
+            image_base: 0x1_4000_0000,
+            section_alignment: 0x1000,
+            file_alignment: 0x200,
+            size_of_image: 0x2000,
+            size_of_headers: 0x200,
+            number_of_rva_and_sizes: 16,
+            .. WindowsFields64::default()
+        };
+        bytes.gwrite_with(windows_fields, &mut offset, scroll::LE).unwrap();
+        for &(index, directory) in directories {
+            bytes.pwrite_with(directory, offset + index * data_directories::SIZEOF_DATA_DIRECTORY, scroll::LE).unwrap();
+        }
+        offset += 16 * data_directories::SIZEOF_DATA_DIRECTORY;
+        bytes[offset..offset + 5].copy_from_slice(b".data");
+        // the virtual size and address, then the raw size and pointer; the characteristics are initialized data, read and write
+        for (i, &field) in [0x200u32, 0x1000, 0x200, 0x200].iter().enumerate() {
+            bytes.pwrite_with(field, offset + 8 + i * 4, scroll::LE).unwrap();
+        }
+        bytes.pwrite_with(0xc000_0040u32, offset + 36, scroll::LE).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn malformed_exception_data() {
+        let directory = data_directories::DataDirectory { virtual_address: 0x1000, size: 0x18 };
+        assert!(PE::parse(&pe64(&[(3, directory)])).unwrap().exception_data.is_some());
+        // a truncated function table, and one outside of any section, only lose the table
+        for &directory in [data_directories::DataDirectory { size: 0x13, .. directory }, data_directories::DataDirectory { virtual_address: 0x9000, .. directory }].iter() {
+            let bytes = pe64(&[(3, directory)]);
+            let pe = PE::parse(&bytes).unwrap();
+            assert!(pe.exception_data.is_none());
+            assert_eq!(pe.sections.len(), 1);
+        }
+    }
+
+    #[test]
+    fn clr_flags() {
+        let mut bytes = pe64(&[(14, data_directories::DataDirectory { virtual_address: 0x1000, size: clr::SIZEOF_COR20_HEADER as u32 })]);
+        assert!(PE::parse(&pe64(&[])).unwrap().clr_flags().is_none());
+        let header = clr::Cor20Header {
+            cb: clr::SIZEOF_COR20_HEADER as u32,
+            flags: clr::COMIMAGE_FLAGS_ILONLY | clr::COMIMAGE_FLAGS_32BITPREFERRED,
+            .. clr::Cor20Header::default()
+        };
+        // the section at rva 0x1000 starts at file offset 0x200
+        bytes.pwrite_with(header, 0x200, scroll::LE).unwrap();
+        let pe = PE::parse(&bytes).unwrap();
+        assert!(pe.is_dotnet() && pe.is_pure_il());
+        let flags = pe.clr_flags().unwrap();
+        assert_eq!(flags.bits(), header.flags);
+        assert!(flags.is_il_only() && flags.is_32bit_preferred());
+        assert!(!flags.is_32bit_required() && !flags.is_strong_name_signed() && !flags.has_native_entry_point());
+        assert!(flags.contains(clr::COMIMAGE_FLAGS_ILONLY | clr::COMIMAGE_FLAGS_32BITPREFERRED));
+    }
+
+    #[test]
+    fn malformed_certificates() {
+        // a 0x10 byte certificate at the end of the file, which the table then claims continues past it
+        let mut bytes = pe64(&[]);
+        bytes.pwrite_with(0x10u32, 0x3f0, scroll::LE).unwrap();
+        bytes.pwrite_with(certificate::WIN_CERT_REVISION_2_0, 0x3f4, scroll::LE).unwrap();
+        bytes.pwrite_with(certificate::WIN_CERT_TYPE_PKCS_SIGNED_DATA, 0x3f6, scroll::LE).unwrap();
+        let directory_offset = 0x58 + optional_header::SIZEOF_STANDARD_FIELDS_64 + optional_header::SIZEOF_WINDOWS_FIELDS_64 + 4 * data_directories::SIZEOF_DATA_DIRECTORY;
+        bytes.pwrite_with(data_directories::DataDirectory { virtual_address: 0x3f0, size: 0x10 }, directory_offset, scroll::LE).unwrap();
+        assert_eq!(PE::parse(&bytes).unwrap().certificates.len(), 1);
+        bytes.pwrite_with(data_directories::DataDirectory { virtual_address: 0x3f0, size: 0x20 }, directory_offset, scroll::LE).unwrap();
+        let pe = PE::parse(&bytes).unwrap();
+        assert!(pe.certificates.is_empty());
+        assert_eq!(pe.sections.len(), 1);
+    }
+
+    #[test]
+    fn checksum() {
+        let mut bytes = image(0x200, 0x80);
+        let field = 0x80 + header::SIZEOF_COFF_HEADER + CHECKSUM_OFFSET;
+        // the expected checksums agree with pefile's
+        let checksum = PE::compute_checksum(&bytes);
+        assert_eq!(checksum, 0x9642);
+        assert!(!PE::verify_checksum(&bytes));
+        bytes.pwrite_with(checksum, field, scroll::LE).unwrap();
+        assert_eq!(PE::compute_checksum(&bytes), checksum);
+        assert!(PE::verify_checksum(&bytes));
+        bytes[0x100] ^= 1;
+        assert!(!PE::verify_checksum(&bytes));
+        bytes[0x100] ^= 1;
+        // an odd length pads the last word
+        assert_eq!(PE::compute_checksum(&bytes[..0x1ff]), 0x9a40);
+        // the checksum field straddles two words when the PE header isn't aligned
+        assert_eq!(PE::compute_checksum(&image(0x200, 0x81)), 0xa200);
+        // a stored checksum of zero never verifies
+        let mut bytes = vec![0u8; 0x100];
+        bytes.pwrite_with(0x40u32, header::PE_POINTER_OFFSET as usize, scroll::LE).unwrap();
+        assert_eq!(PE::compute_checksum(&bytes), 0x140);
+        assert!(!PE::verify_checksum(&bytes));
+        assert!(!PE::verify_checksum(&[]));
+    }
+}
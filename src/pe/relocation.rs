@@ -0,0 +1,254 @@
+//! The base relocations of `IMAGE_DIRECTORY_ENTRY_BASERELOC`, which the loader applies when it can't load an image at its preferred base address.
+//!
+//! The directory is a sequence of blocks, each a page rva and a size, followed by 16-bit entries: a 4-bit type and a 12-bit offset into the page.
+
+use scroll::{self, Pread};
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+
+pub const IMAGE_REL_BASED_ABSOLUTE: u8 = 0;
+pub const IMAGE_REL_BASED_HIGH: u8 = 1;
+pub const IMAGE_REL_BASED_LOW: u8 = 2;
+pub const IMAGE_REL_BASED_HIGHLOW: u8 = 3;
+pub const IMAGE_REL_BASED_HIGHADJ: u8 = 4;
+pub const IMAGE_REL_BASED_DIR64: u8 = 10;
+
+// https://docs.microsoft.com/en-us/windows/win32/debug/pe-format#the-reloc-section-image-only
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageBaseRelocation {
+    /// The rva of the page the entries of this block are offsets into
+    pub virtual_address: u32,
+    /// The size of this block, including this header
+    pub size_of_block: u32,
+}
+
+pub const SIZEOF_IMAGE_BASE_RELOCATION: usize = 8;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// What a base relocation adds the difference between the actual and the preferred image base to
+pub enum RelocType {
+    /// Nothing; pads a block to a 32-bit boundary
+    Absolute,
+    /// The high 16 bits of the difference, to the 16-bit field
+    High,
+    /// The low 16 bits of the difference, to the 16-bit field
+    Low,
+    /// All 32 bits of the difference, to the 32-bit field
+    HighLow,
+    /// The high 16 bits of the difference, to the 16-bit field, with the low 16 bits of the 32-bit value in the following entry
+    HighAdj,
+    /// The difference, to the 64-bit field
+    Dir64,
+    /// A machine specific type, e.g., `IMAGE_REL_BASED_ARM_MOV32`
+    Other(u8),
+}
+
+impl From<u8> for RelocType {
+    fn from(typ: u8) -> Self {
+        match typ {
+            IMAGE_REL_BASED_ABSOLUTE => RelocType::Absolute,
+            IMAGE_REL_BASED_HIGH => RelocType::High,
+            IMAGE_REL_BASED_LOW => RelocType::Low,
+            IMAGE_REL_BASED_HIGHLOW => RelocType::HighLow,
+            IMAGE_REL_BASED_HIGHADJ => RelocType::HighAdj,
+            IMAGE_REL_BASED_DIR64 => RelocType::Dir64,
+            typ => RelocType::Other(typ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// A base relocation
+pub struct Relocation {
+    /// The rva of the field to fix up
+    pub rva: u32,
+    pub typ: RelocType,
+}
+
+impl Relocation {
+    /// What this relocation writes: a whole `HighLow` or `Dir64` field is `Relative`, any other is `RelocKind::Other` of its `IMAGE_REL_BASED_*`
+    pub fn kind(&self) -> ::relocation::RelocKind {
+        use relocation::RelocKind;
+        match self.typ {
+            RelocType::HighLow | RelocType::Dir64 => RelocKind::Relative,
+            RelocType::Absolute => RelocKind::Other(IMAGE_REL_BASED_ABSOLUTE as u32),
+            RelocType::High => RelocKind::Other(IMAGE_REL_BASED_HIGH as u32),
+            RelocType::Low => RelocKind::Other(IMAGE_REL_BASED_LOW as u32),
+            RelocType::HighAdj => RelocKind::Other(IMAGE_REL_BASED_HIGHADJ as u32),
+            RelocType::Other(typ) => RelocKind::Other(typ as u32),
+        }
+    }
+}
+
+/// Iterator over the base relocations of a directory, skipping the `IMAGE_REL_BASED_ABSOLUTE` padding
+#[derive(Debug)]
+pub struct RelocationIterator<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    /// The page rva of the current block, and the end of its entries
+    block: Option<(u32, usize)>,
+}
+
+impl<'a> RelocationIterator<'a> {
+    fn next_relocation(&mut self) -> error::Result<Option<Relocation>> {
+        loop {
+            let (page, end) = match self.block {
+                Some((page, end)) if self.offset < end => (page, end),
+                _ => {
+                    // a trailing `IMAGE_REL_BASED_HIGHADJ` may have skipped past the end of its block
+                    if let Some((_, end)) = self.block.take() {
+                        self.offset = end;
+                    }
+                    if self.offset >= self.bytes.len() {
+                        return Ok(None);
+                    }
+                    let block: ImageBaseRelocation = self.bytes.pread_with(self.offset, scroll::LE)?;
+                    let size = block.size_of_block as usize;
+                    // like the loader, a block of size 0 terminates the table, e.g., before the directory's alignment padding
+                    if size == 0 {
+                        self.offset = self.bytes.len();
+                        return Ok(None);
+                    }
+                    // the block must hold at least its header, which is all an empty block has, and fit in the directory
+                    if size < SIZEOF_IMAGE_BASE_RELOCATION || size > self.bytes.len() - self.offset {
+                        return Err(error::Error::Malformed(format!("base relocation block at {:#x} of size {:#x} does not fit in the {:#x} byte directory", self.offset, size, self.bytes.len())));
+                    }
+                    self.block = Some((block.virtual_address, self.offset + size));
+                    self.offset += SIZEOF_IMAGE_BASE_RELOCATION;
+                    continue;
+                },
+            };
+            if end - self.offset < 2 {
+                return Err(error::Error::Malformed(format!("base relocation block ending at {:#x} has an odd size", end)));
+            }
+            let entry: u16 = self.bytes.gread_with(&mut self.offset, scroll::LE)?;
+            let typ = RelocType::from((entry >> 12) as u8);
+            match typ {
+                RelocType::Absolute => continue,
+                // the following entry is the adjustment, rather than a relocation
+                RelocType::HighAdj => self.offset += 2,
+                _ => (),
+            }
+            return Ok(Some(Relocation { rva: page.wrapping_add((entry & 0xfff) as u32), typ }));
+        }
+    }
+}
+
+impl<'a> Iterator for RelocationIterator<'a> {
+    type Item = error::Result<Relocation>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_relocation() {
+            Ok(Some(relocation)) => Some(Ok(relocation)),
+            Ok(None) => None,
+            Err(error) => {
+                // a malformed block ends the iteration
+                self.offset = self.bytes.len();
+                self.block = None;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// The base relocation directory of a binary
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct RelocationData<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> RelocationData<'a> {
+    /// Locates the base relocation `directory`; the blocks are only parsed when iterated
+    pub fn parse(bytes: &'a [u8], directory: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
+        let rva = directory.virtual_address as usize;
+        let size = directory.size as usize;
+        let range = utils::find_range(rva, size, sections, file_alignment)
+            .ok_or_else(|| error::Error::Malformed(format!("Cannot map base relocation directory rva {:#x} (size {:#x}) into offset", rva, size)))?;
+        match bytes.get(range.clone()) {
+            Some(bytes) => Ok(RelocationData { bytes }),
+            None => Err(error::Error::Malformed(format!("Base relocation directory {:#x}..{:#x} is out of bounds", range.start, range.end))),
+        }
+    }
+    /// Iterates the relocations of every block, in order
+    pub fn relocations(&self) -> RelocationIterator<'a> {
+        RelocationIterator { bytes: self.bytes, offset: 0, block: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use scroll::Pwrite;
+    use pe::section_table::identity_mapped_sections;
+
+    #[test]
+    fn parse_relocations() {
+        // the `.reloc` of a 64-bit dll: two pointers in the page at 0x3000, padded to 32 bits, then one in the page at 0x5000
+        let mut bytes = vec![0u8; 0x2000];
+        let entries: [&[u16]; 2] = [&[0xa010, 0xa018, 0x0000], &[0xaff8]];
+        let mut offset = 0x1000;
+        for (page, entries) in [0x3000u32, 0x5000].iter().zip(entries.iter()) {
+            let size = (SIZEOF_IMAGE_BASE_RELOCATION + entries.len() * 2) as u32;
+            bytes.gwrite_with(ImageBaseRelocation { virtual_address: *page, size_of_block: size }, &mut offset, scroll::LE).unwrap();
+            for entry in entries.iter() {
+                bytes.gwrite_with(*entry, &mut offset, scroll::LE).unwrap();
+            }
+        }
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: offset as u32 - 0x1000 };
+        let relocations = RelocationData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200).unwrap();
+        let relocations: Vec<Relocation> = relocations.relocations().map(|relocation| relocation.unwrap()).collect();
+        assert_eq!(relocations, vec![
+            Relocation { rva: 0x3010, typ: RelocType::Dir64 },
+            Relocation { rva: 0x3018, typ: RelocType::Dir64 },
+            Relocation { rva: 0x5ff8, typ: RelocType::Dir64 },
+        ]);
+        assert_eq!(RelocType::from(IMAGE_REL_BASED_HIGHLOW), RelocType::HighLow);
+        assert_eq!(RelocType::from(5), RelocType::Other(5));
+        assert_eq!(relocations[0].kind(), ::relocation::RelocKind::Relative);
+        assert_eq!(Relocation { rva: 0, typ: RelocType::HighAdj }.kind(), ::relocation::RelocKind::Other(IMAGE_REL_BASED_HIGHADJ as u32));
+        // the directory must lie in a section
+        let dd = data_directories::DataDirectory { virtual_address: 0x1ff0, size: 0x20 };
+        assert!(RelocationData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200).is_err());
+    }
+
+    #[test]
+    fn malformed_relocations() {
+        let mut bytes = vec![0u8; 0x2000];
+        bytes.pwrite_with(ImageBaseRelocation { virtual_address: 0x3000, size_of_block: 0x10 }, 0x1000, scroll::LE).unwrap();
+        bytes.pwrite_with(0x3010u16, 0x1008, scroll::LE).unwrap();
+        // the block is larger than the directory, so only its header is read
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: 0xc };
+        let mut relocations = RelocationData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200).unwrap().relocations();
+        assert!(relocations.next().unwrap().is_err());
+        assert!(relocations.next().is_none());
+        // a block too small for its own header
+        bytes.pwrite_with(4u32, 0x1004, scroll::LE).unwrap();
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: 0x10 };
+        let mut relocations = RelocationData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200).unwrap().relocations();
+        assert!(relocations.next().unwrap().is_err());
+        assert!(relocations.next().is_none());
+    }
+
+    #[test]
+    fn empty_and_terminating_blocks() {
+        // an empty block, a block with one pointer, then a block of size 0, which ends the table before the garbage after it
+        let mut bytes = vec![0u8; 0x2000];
+        bytes.pwrite_with(ImageBaseRelocation { virtual_address: 0x3000, size_of_block: SIZEOF_IMAGE_BASE_RELOCATION as u32 }, 0x1000, scroll::LE).unwrap();
+        bytes.pwrite_with(ImageBaseRelocation { virtual_address: 0x4000, size_of_block: 0xa }, 0x1008, scroll::LE).unwrap();
+        bytes.pwrite_with(0xa020u16, 0x1010, scroll::LE).unwrap();
+        bytes.pwrite_with(ImageBaseRelocation { virtual_address: 0x5000, size_of_block: 0 }, 0x1012, scroll::LE).unwrap();
+        bytes.pwrite_with(0xffff_ffffu32, 0x101a, scroll::LE).unwrap();
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: 0x20 };
+        let relocations = RelocationData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200).unwrap();
+        let relocations: Vec<Relocation> = relocations.relocations().map(|relocation| relocation.unwrap()).collect();
+        assert_eq!(relocations, vec![Relocation { rva: 0x4020, typ: RelocType::Dir64 }]);
+        // only empty blocks
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: 0x8 };
+        assert_eq!(RelocationData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200).unwrap().relocations().count(), 0);
+    }
+}
@@ -0,0 +1,272 @@
+//! The resource directory, a three level tree of type, name and language, whose leaves are the resources: version info, icons, manifests, strings, and so on.
+//!
+//! Every level is an `IMAGE_RESOURCE_DIRECTORY` followed by its named entries, then its id entries; an entry's name is either an id,
+//! or if the high bit is set, an offset to a length prefixed UTF-16 string, and its `OffsetToData` either points to a subdirectory, if the high bit is set,
+//! or to an `IMAGE_RESOURCE_DATA_ENTRY`. All of these offsets are relative to the start of the resource directory.
+
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::collections::btree_set::BTreeSet;
+use scroll::{self, Pread};
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+
+pub const RT_CURSOR: u16 = 1;
+pub const RT_BITMAP: u16 = 2;
+pub const RT_ICON: u16 = 3;
+pub const RT_MENU: u16 = 4;
+pub const RT_DIALOG: u16 = 5;
+pub const RT_STRING: u16 = 6;
+pub const RT_FONTDIR: u16 = 7;
+pub const RT_FONT: u16 = 8;
+pub const RT_ACCELERATOR: u16 = 9;
+pub const RT_RCDATA: u16 = 10;
+pub const RT_MESSAGETABLE: u16 = 11;
+pub const RT_GROUP_CURSOR: u16 = 12;
+pub const RT_GROUP_ICON: u16 = 14;
+/// The `VS_VERSIONINFO` structure, with the file and product version, and the string file info
+pub const RT_VERSION: u16 = 16;
+pub const RT_DLGINCLUDE: u16 = 17;
+pub const RT_PLUGPLAY: u16 = 19;
+pub const RT_VXD: u16 = 20;
+pub const RT_ANICURSOR: u16 = 21;
+pub const RT_ANIICON: u16 = 22;
+pub const RT_HTML: u16 = 23;
+/// The side-by-side assembly manifest, an XML document
+pub const RT_MANIFEST: u16 = 24;
+
+/// The high bit of an entry's name, which makes it an offset to a string rather than an id
+pub const IMAGE_RESOURCE_NAME_IS_STRING: u32 = 0x8000_0000;
+/// The high bit of an entry's `OffsetToData`, which makes it an offset to a subdirectory rather than a data entry
+pub const IMAGE_RESOURCE_DATA_IS_DIRECTORY: u32 = 0x8000_0000;
+
+// https://docs.microsoft.com/en-us/windows/win32/debug/pe-format#the-rsrc-section
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageResourceDirectory {
+    pub characteristics: u32,
+    pub time_date_stamp: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub number_of_named_entries: u16,
+    pub number_of_id_entries: u16,
+}
+
+pub const SIZEOF_IMAGE_RESOURCE_DIRECTORY: usize = 16;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageResourceDirectoryEntry {
+    /// An id, or the offset of a name if `IMAGE_RESOURCE_NAME_IS_STRING` is set
+    pub name: u32,
+    /// The offset of a data entry, or of a subdirectory if `IMAGE_RESOURCE_DATA_IS_DIRECTORY` is set
+    pub offset_to_data: u32,
+}
+
+pub const SIZEOF_IMAGE_RESOURCE_DIRECTORY_ENTRY: usize = 8;
+
+impl ImageResourceDirectoryEntry {
+    pub fn is_named(&self) -> bool {
+        self.name & IMAGE_RESOURCE_NAME_IS_STRING != 0
+    }
+    pub fn is_directory(&self) -> bool {
+        self.offset_to_data & IMAGE_RESOURCE_DATA_IS_DIRECTORY != 0
+    }
+    /// The offset of the subdirectory or data entry, without the flag
+    pub fn offset(&self) -> u32 {
+        self.offset_to_data & !IMAGE_RESOURCE_DATA_IS_DIRECTORY
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageResourceDataEntry {
+    /// The RVA of the resource's data; unlike the offsets in the tree, this isn't relative to the resource directory
+    pub offset_to_data: u32,
+    pub size: u32,
+    pub code_page: u32,
+    pub reserved: u32,
+}
+
+pub const SIZEOF_IMAGE_RESOURCE_DATA_ENTRY: usize = 16;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// The type, name or language of a resource
+pub enum ResourceId {
+    /// A numeric id, such as one of the `RT_*` for a type, or a `LANGID` for a language
+    Id(u16),
+    Name(String),
+}
+
+impl ResourceId {
+    /// The id of `entry`, reading its name from the resource directory at `base`, if it has one
+    fn parse(bytes: &[u8], base: usize, entry: &ImageResourceDirectoryEntry) -> error::Result<Self> {
+        if !entry.is_named() {
+            return Ok(ResourceId::Id(entry.name as u16));
+        }
+        let mut offset = base + (entry.name & !IMAGE_RESOURCE_NAME_IS_STRING) as usize;
+        let len: u16 = bytes.gread_with(&mut offset, scroll::LE)?;
+        let mut name = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            name.push(bytes.gread_with::<u16>(&mut offset, scroll::LE)?);
+        }
+        Ok(ResourceId::Name(String::from_utf16_lossy(&name)))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// A resource, i.e., a leaf of the resource directory
+pub struct ResourceEntry {
+    pub type_id: ResourceId,
+    pub name_id: ResourceId,
+    pub lang: ResourceId,
+    /// The RVA of this resource's data
+    pub data_rva: u32,
+    pub size: u32,
+    pub code_page: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ResourceData {
+    /// The root of the resource directory
+    pub image_resource_directory: ImageResourceDirectory,
+    /// Every resource, in the order of the tree
+    pub entries: Vec<ResourceEntry>,
+}
+
+/// Reads the entries of the directory at `offset` in the resource directory at `base`; directories are only walked once, which makes a cyclic tree malformed
+fn directory_entries(bytes: &[u8], base: usize, offset: u32, visited: &mut BTreeSet<u32>) -> error::Result<Vec<ImageResourceDirectoryEntry>> {
+    if !visited.insert(offset) {
+        return Err(error::Error::Malformed(format!("resource directory at {:#x} is referenced more than once", offset)));
+    }
+    let mut offset = base + offset as usize;
+    let directory: ImageResourceDirectory = bytes.gread_with(&mut offset, scroll::LE)?;
+    let count = directory.number_of_named_entries as usize + directory.number_of_id_entries as usize;
+    let mut entries = Vec::with_capacity(count);
+    for _ in 0..count {
+        entries.push(bytes.gread_with(&mut offset, scroll::LE)?);
+    }
+    Ok(entries)
+}
+
+/// The offset of the subdirectory `entry` points to, which it must at every level but the last
+fn subdirectory(entry: &ImageResourceDirectoryEntry) -> error::Result<u32> {
+    if entry.is_directory() {
+        Ok(entry.offset())
+    } else {
+        Err(error::Error::Malformed(format!("resource directory entry {:#x} is a leaf above the language level", entry.name)))
+    }
+}
+
+impl ResourceData {
+    pub fn parse(bytes: &[u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
+        let rva = dd.virtual_address as usize;
+        let base = utils::find_offset(rva, sections, file_alignment).ok_or(error::Error::Malformed(format!("Cannot map ImageResourceDirectory rva {:#x} into offset", rva)))?;
+        let image_resource_directory = bytes.pread_with(base, scroll::LE)?;
+        let mut visited = BTreeSet::new();
+        let mut entries = Vec::new();
+        // the tree has exactly three levels, so there is no need to recurse
+        for type_entry in directory_entries(bytes, base, 0, &mut visited)? {
+            let type_id = ResourceId::parse(bytes, base, &type_entry)?;
+            for name_entry in directory_entries(bytes, base, subdirectory(&type_entry)?, &mut visited)? {
+                let name_id = ResourceId::parse(bytes, base, &name_entry)?;
+                for lang_entry in directory_entries(bytes, base, subdirectory(&name_entry)?, &mut visited)? {
+                    if lang_entry.is_directory() {
+                        return Err(error::Error::Malformed(format!("resource directory is deeper than three levels at {:#x}", lang_entry.offset())));
+                    }
+                    let lang = ResourceId::parse(bytes, base, &lang_entry)?;
+                    let data: ImageResourceDataEntry = bytes.pread_with(base + lang_entry.offset() as usize, scroll::LE)?;
+                    entries.push(ResourceEntry {
+                        type_id: type_id.clone(),
+                        name_id: name_id.clone(),
+                        lang: lang,
+                        data_rva: data.offset_to_data,
+                        size: data.size,
+                        code_page: data.code_page,
+                    });
+                }
+            }
+        }
+        Ok(ResourceData { image_resource_directory, entries })
+    }
+    /// The first resource of the type `type_id`, one of the `RT_*`
+    pub fn find(&self, type_id: u16) -> Option<&ResourceEntry> {
+        self.entries.iter().find(|entry| entry.type_id == ResourceId::Id(type_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+    use pe::section_table::identity_mapped_sections;
+
+    /// Writes a directory with `entries`, the first `named` of which are named, at `offset` in the resource directory
+    fn directory(bytes: &mut [u8], offset: usize, named: u16, entries: &[(u32, u32)]) {
+        let directory = ImageResourceDirectory { number_of_named_entries: named, number_of_id_entries: entries.len() as u16 - named, .. Default::default() };
+        let mut offset = 0x1000 + offset;
+        bytes.gwrite_with(directory, &mut offset, scroll::LE).unwrap();
+        for &(name, offset_to_data) in entries {
+            bytes.gwrite_with(ImageResourceDirectoryEntry { name, offset_to_data }, &mut offset, scroll::LE).unwrap();
+        }
+    }
+
+    /// A named type `MUI`, and an `RT_VERSION` and `RT_MANIFEST`, each with a single name and language
+    fn resources() -> Vec<u8> {
+        const DIR: u32 = IMAGE_RESOURCE_DATA_IS_DIRECTORY;
+        let mut bytes = vec![0u8; 0x2000];
+        directory(&mut bytes, 0x0, 1, &[(IMAGE_RESOURCE_NAME_IS_STRING | 0x100, DIR | 0x40), (RT_VERSION as u32, DIR | 0x60), (RT_MANIFEST as u32, DIR | 0x80)]);
+        for (i, offset) in [0x40, 0x60, 0x80].iter().enumerate() {
+            directory(&mut bytes, *offset, 0, &[(1, DIR | (0xa0 + i as u32 * 0x20))]);
+            directory(&mut bytes, 0xa0 + i * 0x20, 0, &[(0x409, 0x120 + i as u32 * 0x10)]);
+            let data = ImageResourceDataEntry { offset_to_data: 0x1200 + i as u32 * 0x100, size: 4 + i as u32, .. Default::default() };
+            bytes.pwrite_with(data, 0x1120 + i * 0x10, scroll::LE).unwrap();
+        }
+        let mut offset = 0x1100;
+        bytes.gwrite_with(3u16, &mut offset, scroll::LE).unwrap();
+        for c in "MUI".encode_utf16() {
+            bytes.gwrite_with(c, &mut offset, scroll::LE).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn parse_resources() {
+        let bytes = resources();
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: 0x200 };
+        let resources = ResourceData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200).unwrap();
+        assert_eq!(resources.image_resource_directory.number_of_named_entries, 1);
+        let summary = resources.entries.iter().map(|entry| (entry.type_id.clone(), entry.data_rva, entry.size)).collect::<Vec<_>>();
+        assert_eq!(summary, vec![
+            (ResourceId::Name("MUI".to_string()), 0x1200, 4),
+            (ResourceId::Id(RT_VERSION), 0x1300, 5),
+            (ResourceId::Id(RT_MANIFEST), 0x1400, 6),
+        ]);
+        assert!(resources.entries.iter().all(|entry| entry.name_id == ResourceId::Id(1) && entry.lang == ResourceId::Id(0x409)));
+        assert_eq!(resources.find(RT_MANIFEST).unwrap().data_rva, 0x1400);
+        assert_eq!(resources.find(RT_ICON), None);
+    }
+
+    #[test]
+    fn malformed_resources() {
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: 0x200 };
+        // a name directory pointing back at itself
+        let mut cyclic = resources();
+        directory(&mut cyclic, 0x80, 0, &[(1, IMAGE_RESOURCE_DATA_IS_DIRECTORY | 0x80)]);
+        assert!(ResourceData::parse(&cyclic, &dd, &identity_mapped_sections(), 0x200).is_err());
+        // a language entry pointing at another directory
+        let mut deep = resources();
+        directory(&mut deep, 0xe0, 0, &[(0x409, IMAGE_RESOURCE_DATA_IS_DIRECTORY | 0x180)]);
+        assert!(ResourceData::parse(&deep, &dd, &identity_mapped_sections(), 0x200).is_err());
+        // a type entry pointing straight at data
+        let mut shallow = resources();
+        directory(&mut shallow, 0x0, 0, &[(RT_VERSION as u32, 0x120)]);
+        assert!(ResourceData::parse(&shallow, &dd, &identity_mapped_sections(), 0x200).is_err());
+    }
+}
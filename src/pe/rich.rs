@@ -0,0 +1,180 @@
+//! The undocumented "Rich" header, which the Microsoft linker places between the DOS stub and the PE header.
+//!
+//! It records the `@comp.id` (product id and build number) of every tool which contributed objects to the binary, and how many.
+//! The header is XOR'd with a key, which doubles as a checksum over the DOS header, stub and the entries; it starts with the
+//! encoded `DanS` marker and three zero padding dwords, and ends with a plain `Rich` marker followed by the key.
+
+use alloc::vec::Vec;
+use scroll::{self, Pread};
+use error;
+
+use pe::header;
+
+/// `Rich`, which marks the end of the rich header
+pub const RICH_MARKER: u32 = 0x6863_6952;
+/// `DanS`, encoded with the key, which marks the start of the rich header
+pub const DANS_MARKER: u32 = 0x536e_6144;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// A tool which contributed to the binary, and how many of its objects did
+pub struct RichEntry {
+    /// The product id, the high half of the `@comp.id`
+    pub product_id: u16,
+    /// The build number of the product, the low half of the `@comp.id`
+    pub build: u16,
+    /// The number of objects, or imports, produced by this tool
+    pub count: u32,
+}
+
+impl RichEntry {
+    /// The `@comp.id` of this entry
+    pub fn comp_id(&self) -> u32 {
+        (self.product_id as u32) << 16 | self.build as u32
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// The decoded rich header
+pub struct RichHeader {
+    /// The file offset of the `DanS` marker, i.e., the start of the rich header
+    pub offset: usize,
+    /// The XOR key, which should equal `checksum`
+    pub key: u32,
+    /// The checksum recomputed from the DOS header, the stub and the entries
+    pub checksum: u32,
+    /// The tools which contributed to the binary, in the order the linker recorded them
+    pub entries: Vec<RichEntry>,
+}
+
+impl RichHeader {
+    /// Finds and decodes the rich header before `pe_pointer`, the offset of the PE header; returns `None` if the binary has none
+    pub fn parse(bytes: &[u8], pe_pointer: usize) -> error::Result<Option<Self>> {
+        let end = pe_pointer.min(bytes.len()) & !3;
+        let mut rich = None;
+        let mut offset = 0;
+        while offset + 8 <= end {
+            if bytes.pread_with::<u32>(offset, scroll::LE)? == RICH_MARKER {
+                rich = Some(offset);
+                break;
+            }
+            offset += 4;
+        }
+        let rich = match rich {
+            Some(rich) => rich,
+            None => return Ok(None),
+        };
+        let key: u32 = bytes.pread_with(rich + 4, scroll::LE)?;
+        let mut dans = None;
+        let mut offset = rich;
+        while offset >= 4 {
+            offset -= 4;
+            if bytes.pread_with::<u32>(offset, scroll::LE)? ^ key == DANS_MARKER {
+                dans = Some(offset);
+                break;
+            }
+        }
+        let dans = match dans {
+            Some(dans) => dans,
+            None => return Err(error::Error::Malformed(format!("Rich marker at {:#x} has no DanS marker before it", rich))),
+        };
+        // the marker is followed by three zero dwords, and then the pairs of `@comp.id` and count
+        let start = dans + 16;
+        if start > rich || (rich - start) % 8 != 0 {
+            return Err(error::Error::Malformed(format!("rich header at {:#x} of size {:#x} is malformed", dans, rich - dans)));
+        }
+        let mut entries = Vec::with_capacity((rich - start) / 8);
+        let mut offset = start;
+        while offset < rich {
+            let comp_id = bytes.gread_with::<u32>(&mut offset, scroll::LE)? ^ key;
+            let count = bytes.gread_with::<u32>(&mut offset, scroll::LE)? ^ key;
+            entries.push(RichEntry { product_id: (comp_id >> 16) as u16, build: comp_id as u16, count });
+        }
+        let checksum = checksum(&bytes[..dans], &entries);
+        Ok(Some(RichHeader { offset: dans, key, checksum, entries }))
+    }
+
+    /// Whether the key matches the checksum, i.e., neither the DOS header, the stub nor the entries were tampered with
+    pub fn is_valid(&self) -> bool {
+        self.key == self.checksum
+    }
+}
+
+/// The linker's checksum: the offset of the header, plus every byte of the DOS header and stub but `e_lfanew`
+/// rotated by its offset, plus every `@comp.id` rotated by its count
+fn checksum(dos: &[u8], entries: &[RichEntry]) -> u32 {
+    let mut checksum = dos.len() as u32;
+    let pe_pointer_offset = header::PE_POINTER_OFFSET as usize;
+    for (i, byte) in dos.iter().enumerate() {
+        if i >= pe_pointer_offset && i < pe_pointer_offset + 4 {
+            continue;
+        }
+        checksum = checksum.wrapping_add((*byte as u32).rotate_left(i as u32));
+    }
+    for entry in entries {
+        checksum = checksum.wrapping_add(entry.comp_id().rotate_left(entry.count));
+    }
+    checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    const ENTRIES: [RichEntry; 2] = [
+        RichEntry { product_id: 0x0104, build: 0x6fc6, count: 3 },
+        RichEntry { product_id: 0x0001, build: 0x0000, count: 0x9b },
+    ];
+
+    fn binary() -> Vec<u8> {
+        let mut bytes = vec![0u8; 0x80 + 16 + ENTRIES.len() * 8 + 8];
+        bytes[0] = b'M';
+        bytes[1] = b'Z';
+        let pe_pointer = bytes.len() as u32;
+        bytes.pwrite_with(pe_pointer, header::PE_POINTER_OFFSET as usize, scroll::LE).unwrap();
+        let key = checksum(&bytes[..0x80], &ENTRIES);
+        let mut offset = 0x80;
+        bytes.gwrite_with(DANS_MARKER ^ key, &mut offset, scroll::LE).unwrap();
+        for _ in 0..3 {
+            bytes.gwrite_with(key, &mut offset, scroll::LE).unwrap();
+        }
+        for entry in &ENTRIES {
+            bytes.gwrite_with(entry.comp_id() ^ key, &mut offset, scroll::LE).unwrap();
+            bytes.gwrite_with(entry.count ^ key, &mut offset, scroll::LE).unwrap();
+        }
+        bytes.gwrite_with(RICH_MARKER, &mut offset, scroll::LE).unwrap();
+        bytes.gwrite_with(key, &mut offset, scroll::LE).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn parse_rich_header() {
+        let bytes = binary();
+        let rich = RichHeader::parse(&bytes, bytes.len()).unwrap().unwrap();
+        assert_eq!(rich.offset, 0x80);
+        assert_eq!(rich.entries, ENTRIES.to_vec());
+        assert_eq!(rich.entries[0].comp_id(), 0x0104_6fc6);
+        assert!(rich.is_valid());
+        // the checksum skips e_lfanew, but covers the rest of the DOS header, and the entries
+        let mut moved = bytes.clone();
+        moved[header::PE_POINTER_OFFSET as usize] = 0xff;
+        assert!(RichHeader::parse(&moved, bytes.len()).unwrap().unwrap().is_valid());
+        let mut tampered = bytes.clone();
+        tampered[0x40] = 1;
+        assert!(!RichHeader::parse(&tampered, bytes.len()).unwrap().unwrap().is_valid());
+        let mut tampered = bytes.clone();
+        tampered[0x90] ^= 1;
+        assert!(!RichHeader::parse(&tampered, bytes.len()).unwrap().unwrap().is_valid());
+    }
+
+    #[test]
+    fn missing_rich_header() {
+        let bytes = binary();
+        // the rich header must lie before the PE header
+        assert_eq!(RichHeader::parse(&bytes, 0x80).unwrap(), None);
+        assert_eq!(RichHeader::parse(&[0u8; 0x80], 0x80).unwrap(), None);
+        let mut no_dans = bytes.clone();
+        no_dans[0x80] ^= 1;
+        assert!(RichHeader::parse(&no_dans, bytes.len()).is_err());
+    }
+}
@@ -86,6 +86,38 @@ impl SectionTable {
             None => Ok(self.name.pread(0)?)
         }
     }
+
+    /// The typed `characteristics` of this section
+    pub fn flags(&self) -> SectionFlags {
+        SectionFlags(self.characteristics)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+/// The `characteristics` of a section, its `IMAGE_SCN_*` flags
+pub struct SectionFlags(pub u32);
+
+impl SectionFlags {
+    /// The raw `IMAGE_SCN_*` bits
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+    /// Whether every bit of `flags` is set
+    pub fn contains(&self, flags: u32) -> bool {
+        self.0 & flags == flags
+    }
+    /// Whether the section can be executed, i.e., has `IMAGE_SCN_MEM_EXECUTE`
+    pub fn is_exec(&self) -> bool {
+        self.contains(IMAGE_SCN_MEM_EXECUTE)
+    }
+    /// Whether the section can be written to, i.e., has `IMAGE_SCN_MEM_WRITE`
+    pub fn is_write(&self) -> bool {
+        self.contains(IMAGE_SCN_MEM_WRITE)
+    }
+    /// Whether the section can be read, i.e., has `IMAGE_SCN_MEM_READ`
+    pub fn is_read(&self) -> bool {
+        self.contains(IMAGE_SCN_MEM_READ)
+    }
 }
 
 /// The section should not be padded to the next boundary. This flag is obsolete and is replaced
@@ -144,3 +176,33 @@ pub const IMAGE_SCN_MEM_EXECUTE: u32 = 0x20000000;
 pub const IMAGE_SCN_MEM_READ: u32 = 0x40000000;
 /// The section can be written to.
 pub const IMAGE_SCN_MEM_WRITE: u32 = 0x80000000;
+
+#[cfg(test)]
+/// A section mapping rva `0x1000..0x2000` to the same file offsets, which the data directory tests place their tables in
+pub(crate) fn identity_mapped_sections() -> Vec<SectionTable> {
+    vec![SectionTable {
+        virtual_size: 0x1000,
+        virtual_address: 0x1000,
+        size_of_raw_data: 0x1000,
+        pointer_to_raw_data: 0x1000,
+        .. Default::default()
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags() {
+        let mut data = SectionTable::default();
+        data.characteristics = IMAGE_SCN_CNT_INITIALIZED_DATA | IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_WRITE;
+        let flags = data.flags();
+        assert!(flags.is_read() && flags.is_write() && !flags.is_exec());
+        assert!(flags.contains(IMAGE_SCN_CNT_INITIALIZED_DATA));
+        let text = SectionTable { characteristics: IMAGE_SCN_CNT_CODE | IMAGE_SCN_MEM_EXECUTE | IMAGE_SCN_MEM_READ, .. data };
+        let flags = text.flags();
+        assert!(flags.is_read() && !flags.is_write() && flags.is_exec());
+        assert_eq!(flags.bits(), text.characteristics);
+    }
+}
@@ -0,0 +1,166 @@
+//! The TLS directory, whose callbacks the loader runs on process and thread attach and detach, i.e., before the entry point
+
+use alloc::vec::Vec;
+use scroll::{self, Pread};
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+
+// https://docs.microsoft.com/en-us/windows/win32/debug/pe-format#the-tls-section
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageTlsDirectory32 {
+    pub start_address_of_raw_data: u32,
+    pub end_address_of_raw_data: u32,
+    pub address_of_index: u32,
+    pub address_of_callbacks: u32,
+    pub size_of_zero_fill: u32,
+    pub characteristics: u32,
+}
+
+pub const SIZEOF_IMAGE_TLS_DIRECTORY_32: usize = 24;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageTlsDirectory64 {
+    pub start_address_of_raw_data: u64,
+    pub end_address_of_raw_data: u64,
+    pub address_of_index: u64,
+    pub address_of_callbacks: u64,
+    pub size_of_zero_fill: u32,
+    pub characteristics: u32,
+}
+
+pub const SIZEOF_IMAGE_TLS_DIRECTORY_64: usize = 40;
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+/// Generalized 32/64 bit TLS directory; all of the addresses are virtual addresses, not RVAs
+pub struct ImageTlsDirectory {
+    /// The start of the template the loader copies into each thread's TLS block
+    pub start_address_of_raw_data: u64,
+    /// The end of the template
+    pub end_address_of_raw_data: u64,
+    /// Where the loader stores the TLS index
+    pub address_of_index: u64,
+    /// The null terminated array of TLS callback pointers
+    pub address_of_callbacks: u64,
+    /// The number of zero bytes following the template in each TLS block
+    pub size_of_zero_fill: u32,
+    /// The alignment of the TLS block, in the `IMAGE_SCN_ALIGN_*` bits
+    pub characteristics: u32,
+}
+
+impl From<ImageTlsDirectory32> for ImageTlsDirectory {
+    fn from(dir: ImageTlsDirectory32) -> Self {
+        ImageTlsDirectory {
+            start_address_of_raw_data: dir.start_address_of_raw_data as u64,
+            end_address_of_raw_data: dir.end_address_of_raw_data as u64,
+            address_of_index: dir.address_of_index as u64,
+            address_of_callbacks: dir.address_of_callbacks as u64,
+            size_of_zero_fill: dir.size_of_zero_fill,
+            characteristics: dir.characteristics,
+        }
+    }
+}
+
+impl From<ImageTlsDirectory64> for ImageTlsDirectory {
+    fn from(dir: ImageTlsDirectory64) -> Self {
+        ImageTlsDirectory {
+            start_address_of_raw_data: dir.start_address_of_raw_data,
+            end_address_of_raw_data: dir.end_address_of_raw_data,
+            address_of_index: dir.address_of_index,
+            address_of_callbacks: dir.address_of_callbacks,
+            size_of_zero_fill: dir.size_of_zero_fill,
+            characteristics: dir.characteristics,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TlsData {
+    pub image_tls_directory: ImageTlsDirectory,
+    /// The virtual addresses of the TLS callbacks, in the order the loader calls them
+    pub callbacks: Vec<u64>,
+}
+
+impl TlsData {
+    /// Parses the TLS directory and its callbacks; the directory's virtual addresses are mapped to the file with `image_base`
+    pub fn parse(bytes: &[u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32, image_base: u64, is_64: bool) -> error::Result<Self> {
+        let rva = dd.virtual_address as usize;
+        let offset = utils::find_offset(rva, sections, file_alignment).ok_or(error::Error::Malformed(format!("Cannot map ImageTlsDirectory rva {:#x} into offset", rva)))?;
+        let image_tls_directory: ImageTlsDirectory = if is_64 {
+            bytes.pread_with::<ImageTlsDirectory64>(offset, scroll::LE)?.into()
+        } else {
+            bytes.pread_with::<ImageTlsDirectory32>(offset, scroll::LE)?.into()
+        };
+        let mut callbacks = Vec::new();
+        let va = image_tls_directory.address_of_callbacks;
+        if va != 0 {
+            let rva = match va.checked_sub(image_base) {
+                Some(rva) => rva as usize,
+                None => return Err(error::Error::Malformed(format!("TLS callbacks at {:#x} are below the image base {:#x}", va, image_base))),
+            };
+            let mut offset = utils::find_offset(rva, sections, file_alignment).ok_or(error::Error::Malformed(format!("Cannot map TLS callbacks rva {:#x} into offset", rva)))?;
+            // the array is null terminated; running off the end of the file without a terminator is malformed
+            loop {
+                let callback = if is_64 {
+                    bytes.gread_with::<u64>(&mut offset, scroll::LE)?
+                } else {
+                    bytes.gread_with::<u32>(&mut offset, scroll::LE)? as u64
+                };
+                if callback == 0 {
+                    break;
+                }
+                callbacks.push(callback);
+            }
+        }
+        Ok(TlsData { image_tls_directory, callbacks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+    use pe::section_table::identity_mapped_sections;
+
+    const IMAGE_BASE: u64 = 0x1_4000_0000;
+
+    #[test]
+    fn parse_tls_callbacks() {
+        let mut bytes = vec![0u8; 0x2000];
+        let directory = ImageTlsDirectory64 { address_of_callbacks: IMAGE_BASE + 0x1100, size_of_zero_fill: 8, .. Default::default() };
+        bytes.pwrite_with(directory, 0x1000, scroll::LE).unwrap();
+        bytes.pwrite_with(IMAGE_BASE + 0x1500, 0x1100, scroll::LE).unwrap();
+        bytes.pwrite_with(IMAGE_BASE + 0x1600, 0x1108, scroll::LE).unwrap();
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: SIZEOF_IMAGE_TLS_DIRECTORY_64 as u32 };
+        let tls = TlsData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200, IMAGE_BASE, true).unwrap();
+        assert_eq!(tls.image_tls_directory, directory.into());
+        assert_eq!(tls.callbacks, vec![IMAGE_BASE + 0x1500, IMAGE_BASE + 0x1600]);
+        // the callbacks must lie above the image base
+        assert!(TlsData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200, IMAGE_BASE + 0x2000, true).is_err());
+        // an unterminated array runs off the end of the file
+        let mut unterminated = bytes.clone();
+        for offset in (0x1110..0x2000).step_by(8) {
+            unterminated.pwrite_with(IMAGE_BASE + 0x1500, offset, scroll::LE).unwrap();
+        }
+        assert!(TlsData::parse(&unterminated, &dd, &identity_mapped_sections(), 0x200, IMAGE_BASE, true).is_err());
+    }
+
+    #[test]
+    fn parse_tls_32() {
+        let mut bytes = vec![0u8; 0x2000];
+        bytes.pwrite_with(ImageTlsDirectory32 { address_of_callbacks: 0x40_1100, .. Default::default() }, 0x1000, scroll::LE).unwrap();
+        bytes.pwrite_with(0x40_1500u32, 0x1100, scroll::LE).unwrap();
+        let dd = data_directories::DataDirectory { virtual_address: 0x1000, size: SIZEOF_IMAGE_TLS_DIRECTORY_32 as u32 };
+        let tls = TlsData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200, 0x40_0000, false).unwrap();
+        assert_eq!(tls.callbacks, vec![0x40_1500]);
+        // without callbacks there is nothing to walk
+        bytes.pwrite_with(0u32, 0x100c, scroll::LE).unwrap();
+        assert!(TlsData::parse(&bytes, &dd, &identity_mapped_sections(), 0x200, 0x40_0000, false).unwrap().callbacks.is_empty());
+    }
+}
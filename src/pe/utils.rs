@@ -4,7 +4,8 @@ use error;
 
 use super::section_table;
 
-use std::cmp;
+use core::cmp;
+use core::ops::Range;
 
 pub fn is_in_range (rva: usize, r1: usize, r2: usize) -> bool {
     r1 <= rva && rva < r2
@@ -60,6 +61,23 @@ pub fn find_offset (rva: usize, sections: &[section_table::SectionTable], file_a
     None
 }
 
+/// Maps the `size` bytes at `rva` to a file range, or `None` if they are not entirely contained in a single section
+pub fn find_range (rva: usize, size: usize, sections: &[section_table::SectionTable], file_alignment: u32) -> Option<Range<usize>> {
+    for section in sections {
+        if is_in_section(rva, &section, file_alignment) {
+            let section_end = section.virtual_address as usize + section_read_size(section, file_alignment);
+            return match rva.checked_add(size) {
+                Some(end) if end <= section_end => {
+                    let offset = rva2offset(rva, &section);
+                    Some(offset..offset + size)
+                },
+                _ => None,
+            }
+        }
+    }
+    None
+}
+
 pub fn find_offset_or (rva: usize, sections: &[section_table::SectionTable], file_alignment: u32, msg: &str) -> error::Result<usize> {
     find_offset(rva, sections, file_alignment).ok_or(error::Error::Malformed(msg.to_string()))
 }
@@ -74,3 +92,25 @@ pub fn try_name<'a>(bytes: &'a [u8], rva: usize, sections: &[section_table::Sect
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_stay_inside_their_section() {
+        let text = section_table::SectionTable {
+            virtual_size: 0x1800,
+            virtual_address: 0x1000,
+            size_of_raw_data: 0x2000,
+            pointer_to_raw_data: 0x400,
+            .. Default::default()
+        };
+        let sections = [text];
+        assert_eq!(find_range(0x1000, 0x10, &sections, 0x200), Some(0x400..0x410));
+        assert_eq!(find_range(0x2000, 0x800, &sections, 0x200), Some(0x1400..0x1c00));
+        assert_eq!(find_range(0x2000, 0x1001, &sections, 0x200), None);
+        assert_eq!(find_range(0x2000, ::core::usize::MAX, &sections, 0x200), None);
+        assert_eq!(find_range(0x4000, 0x10, &sections, 0x200), None);
+    }
+}
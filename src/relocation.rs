@@ -0,0 +1,33 @@
+//! A format independent view of a binary's relocations, for tools which rebase or analyze binaries of any format.
+//!
+//! Each format's relocation type maps to a `RelocKind` with its `kind` method: `elf::Reloc::kind`, `mach::relocation::RelocationInfo::kind`,
+//! `mach::imports::Import::kind` and `pe::relocation::Relocation::kind`; `Object::relocations` iterates all of them for any binary.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// What a relocation writes to the field it relocates
+pub enum RelocKind {
+    /// The difference between the actual and the preferred load address, added to the field, e.g., `R_X86_64_RELATIVE`, or a PE base relocation
+    Relative,
+    /// The address of the symbol, plus the addend, e.g., `R_X86_64_64`, `R_X86_64_GLOB_DAT`, or a Mach-o bind of a pointer
+    Absolute,
+    /// The address of the symbol, plus the addend, less the address of the field, e.g., `R_X86_64_PC32`
+    PcRelative,
+    /// The address of the symbol, resolved on first call, e.g., `R_X86_64_JUMP_SLOT`, or a lazy Mach-o bind
+    JumpSlot,
+    /// The contents of the symbol, copied into this binary, e.g., `R_X86_64_COPY`
+    Copy,
+    /// Any other type, as the format spells it: the ELF `r_type`, the Mach-o `r_type` or bind type, or the PE `IMAGE_REL_BASED_*`
+    Other(u32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A relocation of any binary format
+pub struct GenericReloc<'a> {
+    /// The address of the field to relocate, which for PE is relative to the image base, and in a relocatable object to the section of the relocation
+    pub offset: u64,
+    pub kind: RelocKind,
+    /// The name of the symbol the relocation refers to, if any
+    pub symbol: Option<&'a str>,
+    /// The addend, which is 0 if the format keeps it in the field instead, e.g., an ELF `Rel`
+    pub addend: i64,
+}
@@ -66,6 +66,24 @@ impl<'a> Strtab<'a> {
             Some(get_str(offset, self.bytes, self.delim).map_err(|e| e.into()))
         }
     }
+    /// Gets the str starting at byte `offset`, like `get`, but strictly: the offset must lie inside the table, and the string must be delimited before the end of it,
+    /// else it is `Malformed`, rather than `None` or the string truncated at the end of the table.
+    /// Requires `feature = "alloc"`
+    #[cfg(feature = "alloc")]
+    pub fn get_at(&self, offset: usize) -> error::Result<&'a str> {
+        let delim = match self.delim {
+            ctx::StrCtx::Delimiter(delim) | ctx::StrCtx::DelimiterUntil(delim, _) => delim,
+            ctx::StrCtx::Length(_) => return Ok(get_str(offset, self.bytes, self.delim)?),
+        };
+        let bytes = match self.bytes.get(offset..) {
+            Some(bytes) if !bytes.is_empty() => bytes,
+            _ => return Err(error::Error::Malformed(format!("string table offset {:#x} is out of bounds of the {:#x} byte table", offset, self.bytes.len()))),
+        };
+        match bytes.iter().position(|byte| *byte == delim) {
+            Some(len) => str::from_utf8(&bytes[..len]).map_err(|_| error::Error::Malformed(format!("string at string table offset {:#x} is not valid utf8", offset))),
+            None => Err(error::Error::Malformed(format!("string at string table offset {:#x} runs past the end of the table", offset))),
+        }
+    }
     /// Gets a str reference from the backing bytes starting at byte `offset`.
     /// If the index is out of bounds, `None` is returned. Panics if bytes are invalid UTF-8.
     pub fn get_unsafe(&self, offset: usize) -> Option<&'a str> {
@@ -102,6 +120,20 @@ impl<'a> Index<usize> for Strtab<'a> {
     }
 }
 
+#[test]
+fn get_at() {
+    let bytes = b"\0printf\0memmove\0busta";
+    let strtab = Strtab::new(bytes, 0x0);
+    assert_eq!(strtab.get_at(1).unwrap(), "printf");
+    assert_eq!(strtab.get_at(0).unwrap(), "");
+    assert_eq!(strtab.get_at(11).unwrap(), "move");
+    // the lenient `get` truncates the last string at the end of the table
+    assert_eq!(strtab.get(16).unwrap().unwrap(), "busta");
+    assert!(strtab.get_at(16).is_err());
+    assert!(strtab.get_at(bytes.len()).is_err());
+    assert!(Strtab::new(b"\xff\0", 0x0).get_at(0).is_err());
+}
+
 #[test]
 fn as_vec_no_final_null() {
     let bytes = b"\0printf\0memmove\0busta";
@@ -0,0 +1,122 @@
+//! A format independent view of a binary's symbols, for tools which don't care which format they are looking at.
+//!
+//! `Symbol` is implemented for each format's symbol type, paired with its name where the format keeps the names elsewhere:
+//! `(&str, elf::Sym)`, `(&str, mach::symbols::Nlist)`, `pe::export::Export` and `pe::import::Import`;
+//! `Object::symbols` iterates all of them for any binary.
+
+/// A symbol of any binary format
+pub trait Symbol {
+    /// The symbol's name, which is empty if it has none
+    fn name(&self) -> &str;
+    /// The symbol's value; usually its address, which for PE is relative to the image base
+    fn address(&self) -> u64;
+    /// Whether the symbol refers to code
+    fn is_function(&self) -> bool;
+    /// Whether the symbol is visible outside of its binary (or object file)
+    fn is_global(&self) -> bool;
+    /// Whether the symbol refers to something in another binary, i.e., it is an import
+    fn is_undefined(&self) -> bool;
+    /// The symbol's name, demangled; see `goblin::demangle`
+    #[cfg(feature = "demangle")]
+    fn demangled(&self) -> ::alloc::borrow::Cow<str> {
+        ::demangle::demangle(self.name())
+    }
+}
+
+impl<'a, T: Symbol + ?Sized> Symbol for &'a T {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+    fn address(&self) -> u64 {
+        (**self).address()
+    }
+    fn is_function(&self) -> bool {
+        (**self).is_function()
+    }
+    fn is_global(&self) -> bool {
+        (**self).is_global()
+    }
+    fn is_undefined(&self) -> bool {
+        (**self).is_undefined()
+    }
+}
+
+if_alloc! {
+    use alloc::vec::Vec;
+
+    /// Sorts `symbols`, `(name, address, size)`s, by address and then name, dropping duplicates; then gives each symbol of size 0
+    /// the size up to the next symbol at a higher address, or up to `end(address)`, the end of the section or segment containing it, if that is closer.
+    ///
+    /// A size 0 symbol with neither stays 0
+    pub fn sort_by_address<S: Ord, F: Fn(u64) -> Option<u64>>(symbols: &mut Vec<(S, u64, u64)>, end: F) {
+        symbols.sort_by(|a, b| (a.1, &a.0).cmp(&(b.1, &b.0)));
+        symbols.dedup_by(|a, b| a.1 == b.1 && a.0 == b.0);
+        let mut next = None;
+        let mut current = None;
+        for symbol in symbols.iter_mut().rev() {
+            if current.map_or(false, |current| symbol.1 < current) {
+                next = current;
+            }
+            current = Some(symbol.1);
+            if symbol.2 == 0 {
+                let limit = match (next, end(symbol.1)) {
+                    (Some(next), Some(end)) => Some(::core::cmp::min(next, end)),
+                    (next, None) => next,
+                    (None, end) => end,
+                };
+                if let Some(limit) = limit {
+                    symbol.2 = limit.saturating_sub(symbol.1);
+                }
+            }
+        }
+    }
+
+    /// Finds the symbol of `sorted`, as sorted by `sort_by_address`, containing `address`, returning it and the offset of `address` into it.
+    ///
+    /// Only the closest symbols at or below `address` are candidates, so a symbol nested in a bigger one hides it; a size 0 symbol contains only its own address
+    pub fn containing<S>(sorted: &[(S, u64, u64)], address: u64) -> Option<(&S, u64)> {
+        // the number of symbols at or below address
+        let (mut lo, mut hi) = (0, sorted.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if sorted[mid].1 <= address {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        let start = match lo.checked_sub(1) {
+            Some(last) => sorted[last].1,
+            None => return None,
+        };
+        let offset = address - start;
+        sorted[..lo].iter()
+            .rev()
+            .take_while(|symbol| symbol.1 == start)
+            .filter(|symbol| offset < symbol.2 || offset == 0)
+            .last()
+            .map(|symbol| (&symbol.0, offset))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn sorted_and_sized() {
+            // b is sized by a, a by the end of its section at 0x40, c is sized, and d has nothing above it
+            let mut symbols = vec![("c", 0x60, 0x8), ("a", 0x20, 0), ("b", 0x10, 0), ("a", 0x20, 0), ("d", 0x100, 0)];
+            let end = |address| if address < 0x40 { Some(0x40) } else { None };
+            sort_by_address(&mut symbols, end);
+            assert_eq!(symbols, vec![("b", 0x10, 0x10), ("a", 0x20, 0x20), ("c", 0x60, 0x8), ("d", 0x100, 0)]);
+            assert_eq!(containing(&symbols, 0x1f), Some((&"b", 0xf)));
+            assert_eq!(containing(&symbols, 0x20), Some((&"a", 0)));
+            assert_eq!(containing(&symbols, 0x64), Some((&"c", 4)));
+            assert_eq!(containing(&symbols, 0x100), Some((&"d", 0)));
+            // below, between, and past the symbols
+            assert_eq!(containing(&symbols, 0xf), None);
+            assert_eq!(containing(&symbols, 0x48), None);
+            assert_eq!(containing(&symbols, 0x101), None);
+        }
+    }
+}
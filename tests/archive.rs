@@ -40,6 +40,8 @@ fn parse_archive() {
     match Archive::parse(&crt1a) {
         Ok(archive) => {
             assert_eq!(archive.member_of_symbol(START), Some("crt1.o"));
+            assert_eq!(archive.member_defining(START), Some("crt1.o"));
+            assert_eq!(archive.member_defining("main"), None);
             if let Some(member) = archive.get("crt1.o") {
                 assert_eq!(member.offset, 194);
                 assert_eq!(member.size(), 1928)
@@ -104,3 +106,36 @@ fn parse_self() {
         }
     }
 }
+
+#[test]
+fn parse_thin_archive() {
+    // `ar rcT thin.a a.o sub/b.o`, where the objects define `thin_a` and `thin_b`
+    let thin: Vec<u8> = include!("../etc/thin.rs");
+    let archive = Archive::parse(&thin).expect("parse thin archive");
+    assert!(archive.is_thin());
+    assert_eq!(archive.members(), vec!["a.o", "sub/b.o"]);
+    assert_eq!(archive.member_of_symbol("thin_b"), Some("sub/b.o"));
+    let member = archive.get("sub/b.o").unwrap();
+    assert!(member.is_thin());
+    assert_eq!(member.path(), Some("sub/b.o"));
+    assert_eq!(member.size(), 624);
+    // the contents are on disk, not in the archive
+    assert!(archive.extract("sub/b.o", &thin).is_err());
+    assert!(goblin::Object::parse(&thin).is_ok());
+
+    let crt1a: Vec<u8> = include!("../etc/crt1a.rs");
+    let archive = Archive::parse(&crt1a).unwrap();
+    assert!(!archive.is_thin());
+    assert_eq!(archive.get("crt1.o").unwrap().path(), None);
+}
+
+#[test]
+fn parse_sym64_archive() {
+    // `SYM64_THRESHOLD=0 llvm-ar rcs --format=gnu sym64.a a.o sub/b.o`, whose symbol index is a `/SYM64/` member
+    let sym64: Vec<u8> = include!("../etc/sym64.rs");
+    let archive = Archive::parse(&sym64).expect("parse archive with a 64-bit symbol index");
+    assert_eq!(archive.members(), vec!["a.o", "b.o"]);
+    assert_eq!(archive.member_of_symbol("thin_a"), Some("a.o"));
+    assert_eq!(archive.member_defining("thin_b"), Some("b.o"));
+    assert_eq!(archive.extract("b.o", &sym64).unwrap().len(), 624);
+}
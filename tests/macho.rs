@@ -1,4 +1,5 @@
 extern crate goblin;
+extern crate scroll;
 
 use goblin::mach::*;
 
@@ -15,6 +16,9 @@ fn parse_fat_header() {
             assert_eq!(arches[0].is_64(), true);
             assert_eq!(arches[1].is_64(), false);
             assert_eq!(arches.get(2).is_none(), true);
+            // the architectures lie past the end of these 48 bytes
+            assert!(multi.get(0).is_err());
+            assert!(multi.find_binary(cputype::CPU_TYPE_X86).is_err());
         },
         _ => {
             println!("got mach binary from fat");
@@ -122,3 +126,627 @@ fn relocations() {
     assert_eq!(reloc.is_pic(), true);
     assert_eq!(reloc.is_extern(), true);
 }
+
+#[test]
+fn anomalies() {
+    use goblin::anomaly::{Anomaly, Region};
+    let bytes = &DEADBEEF_MACH_64;
+    let binary = MachO::parse(&bytes[..], 0).unwrap();
+    assert_eq!(binary.anomalies(), vec![]);
+    // point __stubs (the second section) at the file offset of __text (the first section)
+    let find = |name: &[u8]| bytes.windows(name.len()).position(|window| window == name).unwrap();
+    let text = find(b"__text\0");
+    let stubs = find(b"__stubs\0");
+    let mut crafted = bytes.to_vec();
+    for i in 0..4 {
+        crafted[stubs + 48 + i] = bytes[text + 48 + i];
+    }
+    let binary = MachO::parse(&crafted, 0).unwrap();
+    assert_eq!(binary.anomalies(), vec![Anomaly::Overlap { first: Region::Section(0), second: Region::Section(1) }]);
+}
+
+#[test]
+fn load_commands_of() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let segments = binary.load_commands_of::<load_command::SegmentCommand64>()
+        .map(|(_, segment)| segment.segname)
+        .collect::<Vec<_>>();
+    assert_eq!(segments, binary.segments.iter().map(|segment| segment.segname).collect::<Vec<_>>());
+    let dylibs = binary.load_commands_of::<load_command::DylibCommand>()
+        .map(|(offset, dylib)| {
+            use scroll::Pread;
+            DEADBEEF_MACH_64[..].pread::<&str>(offset + dylib.dylib.name as usize).unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(dylibs, vec!["/usr/lib/libSystem.B.dylib"]);
+    assert_eq!(binary.load_commands_of::<load_command::SegmentCommand32>().count(), 0);
+}
+
+#[test]
+fn load_command_raw_bytes() {
+    use scroll::Pread;
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    for command in &binary.load_commands {
+        let raw = command.raw_bytes(&DEADBEEF_MACH_64[..]);
+        assert_eq!(raw.len(), command.command.cmdsize());
+        assert_eq!(raw.pread_with::<u32>(0, scroll::LE).unwrap(), command.command.cmd());
+    }
+    let last = binary.load_commands.last().unwrap();
+    assert_eq!(last.raw_bytes(&DEADBEEF_MACH_64[..last.offset + 4]).len(), 4);
+}
+
+/// A fat container with the deadbeef binary for x86_64 at 0x1000, and `size` bytes claimed by an i386 binary at `offset`
+fn fat(offset: u32, size: u32) -> Vec<u8> {
+    use scroll::{Pwrite, BE};
+    let mut bytes = vec![0u8; 0x1000];
+    bytes.pwrite_with(fat::FatHeader { magic: fat::FAT_MAGIC, nfat_arch: 2 }, 0, BE).unwrap();
+    let x86_64 = fat::FatArch { cputype: cputype::CPU_TYPE_X86_64, cpusubtype: cputype::CPU_SUBTYPE_X86_64_ALL, offset: 0x1000, size: DEADBEEF_MACH_64.len() as u32, align: 12 };
+    let i386 = fat::FatArch { cputype: cputype::CPU_TYPE_I386, cpusubtype: cputype::CPU_SUBTYPE_I386_ALL, offset: offset, size: size, align: 12 };
+    bytes.pwrite_with(x86_64, fat::SIZEOF_FAT_HEADER, BE).unwrap();
+    bytes.pwrite_with(i386, fat::SIZEOF_FAT_HEADER + fat::SIZEOF_FAT_ARCH, BE).unwrap();
+    bytes.extend_from_slice(&DEADBEEF_MACH_64[..]);
+    bytes
+}
+
+#[test]
+fn fat_find_binary() {
+    let bytes = fat(0x800, 0x10);
+    let multi = MultiArch::new(&bytes).unwrap();
+    let binary = multi.find_binary(cputype::CPU_TYPE_X86_64).unwrap().unwrap();
+    assert_eq!(binary.dynamic_linker(), Some("/usr/lib/dyld"));
+    assert!(multi.find_arch("x86_64").unwrap().is_some());
+    assert!(multi.find_binary(cputype::CPU_TYPE_ARM64).unwrap().is_none());
+    assert!(multi.find_arch("arm64").unwrap().is_none());
+    // the i386 slice overlaps the x86_64 one, and then the fat headers
+    let bytes = fat(0x1008, 0x10);
+    assert!(MultiArch::new(&bytes).unwrap().find_binary(cputype::CPU_TYPE_X86_64).is_err());
+    let bytes = fat(0x10, 0x10);
+    assert!(MultiArch::new(&bytes).unwrap().get(1).is_err());
+}
+
+#[test]
+fn dynamic_linker() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert_eq!(binary.dynamic_linker(), Some("/usr/lib/dyld"));
+}
+
+#[test]
+fn twolevel_hints() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert_eq!(binary.twolevel_hints().unwrap(), None);
+}
+
+#[test]
+fn chained_imports() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert!(binary.chained_imports().unwrap().is_empty());
+}
+
+#[test]
+fn code_signature() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert!(binary.code_signature().unwrap().is_none());
+}
+
+#[test]
+fn data_in_code() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert!(binary.data_in_code().unwrap().unwrap().is_empty());
+}
+
+#[test]
+fn weak_imports() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert!(binary.weak_imports().unwrap().is_empty());
+}
+
+#[test]
+fn parse_kind() {
+    let kind = goblin::Object::parse_kind(&DEADBEEF_MACH_64[..]).unwrap();
+    assert_eq!(kind, goblin::ObjectKind::Mach { cputype: goblin::mach::cputype::CPU_TYPE_X86_64, is_64: true, is_lsb: true });
+}
+
+#[test]
+fn platform() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert_eq!(binary.platform(), Some(goblin::mach::load_command::Platform::MacOS));
+    let build_version = binary.build_version().unwrap();
+    assert_eq!(build_version.minos.to_string(), "10.10.0");
+    assert!(build_version.tools.is_empty());
+    assert!(!binary.is_simulator());
+}
+
+#[test]
+fn ctx_from_mach() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert_eq!(goblin::container::Ctx::from_mach(&binary), goblin::container::Ctx::le(goblin::container::Container::Big));
+}
+
+#[test]
+fn object_symbols() {
+    let object = goblin::Object::parse(&DEADBEEF_MACH_64[..]).unwrap();
+    let names: Vec<_> = object.symbols().map(|sym| sym.name().to_string()).collect();
+    assert_eq!(names, ["__mh_execute_header", "_main", "_printf", "dyld_stub_binder"]);
+    let main = object.symbols().find(|sym| sym.name() == "_main").unwrap();
+    assert!(main.is_function() && main.is_global() && !main.is_undefined());
+    let printf = object.symbols().find(|sym| sym.name() == "_printf").unwrap();
+    assert!(!printf.is_function() && printf.is_undefined());
+}
+
+#[test]
+fn dysymtab() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let dysymtab = binary.dysymtab().unwrap();
+    assert_eq!(dysymtab.local_symbols().count(), 0);
+    fn names(iter: symbols::SymbolIterator) -> Vec<&str> {
+        iter.map(|symbol| symbol.unwrap().0).collect()
+    }
+    assert_eq!(names(dysymtab.defined_external_symbols()), vec!["__mh_execute_header", "_main"]);
+    assert_eq!(names(dysymtab.undefined_symbols()), vec!["_printf", "dyld_stub_binder"]);
+    // the `__stubs` slot for _printf, the two `__nl_symbol_ptr` slots, then the `__la_symbol_ptr` slot for _printf
+    assert_eq!(dysymtab.indirect_symbols().unwrap(), vec![2, 3, symbols::INDIRECT_SYMBOL_ABS, 2]);
+}
+
+#[test]
+fn imports_at_base_offset() {
+    // e.g., an architecture inside of a fat binary
+    let mut fat = vec![0u8; 0x1000];
+    fat.extend_from_slice(&DEADBEEF_MACH_64[..]);
+    let thin = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let binary = MachO::parse(&fat, 0x1000).unwrap();
+    let summary = |binary: &MachO| binary.imports().unwrap().iter().map(|import| (import.name.to_string(), import.offset, import.address)).collect::<Vec<_>>();
+    assert_eq!(summary(&binary), summary(&thin));
+    assert_eq!(binary.symbols().count(), thin.symbols().count());
+    assert!(binary.symbols().all(|symbol| symbol.is_ok()));
+    assert!(MachO::parse(&fat, fat.len() + 1).is_err());
+}
+
+#[test]
+fn symbolicate() {
+    let mut binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert_eq!(binary.symbolicate(0x100000f45).unwrap(), Some(("_main".to_string(), 5)));
+    assert_eq!(binary.symbolicate(0x100000000).unwrap(), Some(("__mh_execute_header".to_string(), 0)));
+    assert_eq!(binary.symbolicate(0x200000000).unwrap(), None);
+    // without a symbol table, the export trie still knows the names
+    binary.symbols = None;
+    assert_eq!(binary.symbolicate(0x100000f45).unwrap(), Some(("_main".to_string(), 5)));
+}
+
+#[test]
+fn objc_image_info() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert!(!binary.has_objc());
+    assert!(!binary.has_swift());
+    assert_eq!(binary.objc_image_info(), None);
+    // retitle __la_symbol_ptr as the image info of an optimized image, with Swift 5 code
+    let (section, _) = binary.section("__DATA", "__la_symbol_ptr").unwrap();
+    let mut bytes = DEADBEEF_MACH_64.to_vec();
+    let name = bytes.windows(16).position(|window| window == b"__la_symbol_ptr\0").unwrap();
+    bytes[name..name + 16].copy_from_slice(b"__objc_imageinfo");
+    let offset = section.offset as usize;
+    bytes[offset..offset + 8].copy_from_slice(&[0, 0, 0, 0, 0x08, 0x07, 0, 0]);
+    let binary = MachO::parse(&bytes, 0).unwrap();
+    assert!(binary.has_objc());
+    let info = binary.objc_image_info().unwrap();
+    assert!(info.is_optimized_by_dyld());
+    assert!(!info.has_category_class_properties());
+    assert_eq!(info.swift_version(), 7);
+}
+
+// reference output from `dyldinfo -bind -lazy_bind` for the deadbeef binary:
+// __DATA  __nl_symbol_ptr  0x100001000  pointer  0  libSystem  dyld_stub_binder
+// __DATA  __la_symbol_ptr  0x100001010  libSystem  _printf
+#[test]
+fn imports_golden() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let imports = binary.imports().unwrap();
+    let imports = imports.iter().map(|import| (import.name, import.dylib, import.is_lazy, import.address, import.offset)).collect::<Vec<_>>();
+    assert_eq!(imports, vec![
+        ("dyld_stub_binder", "/usr/lib/libSystem.B.dylib", false, 0x100001000, 0x1000),
+        ("_printf", "/usr/lib/libSystem.B.dylib", true, 0x100001010, 0x1010),
+    ]);
+    let sorted = binary.sorted_imports().unwrap();
+    assert_eq!(sorted.iter().map(|import| import.name).collect::<Vec<_>>(), vec!["_printf", "dyld_stub_binder"]);
+    let exports = binary.sorted_exports().unwrap();
+    assert_eq!(exports.iter().map(|export| export.name.as_str()).collect::<Vec<_>>(), vec!["__mh_execute_header", "_main"]);
+}
+
+#[test]
+fn for_each_import() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let mut names = Vec::new();
+    binary.for_each_import(|import| {
+        names.push(import.name);
+        Ok(())
+    }).unwrap();
+    assert_eq!(names, binary.imports().unwrap().iter().map(|import| import.name).collect::<Vec<_>>());
+}
+
+#[test]
+fn section_data() {
+    use goblin::mach::constants::S_ZEROFILL;
+    use goblin::mach::segment::Section;
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let mut found = false;
+    for segment in binary.segments.iter() {
+        assert_eq!(segment.data(&DEADBEEF_MACH_64).unwrap(), segment.data);
+        if segment.filesize != 0 {
+            // a binary cut short before the segment's contents end
+            let end = (segment.fileoff + segment.filesize) as usize;
+            assert!(segment.data(&DEADBEEF_MACH_64[..end - 1]).is_err());
+        }
+        for section in segment {
+            let (section, data) = section.unwrap();
+            assert!(!section.is_zerofill());
+            assert_eq!(section.data(&DEADBEEF_MACH_64).unwrap(), data);
+            if section.name().unwrap() == "__cstring" {
+                assert_eq!(data, b"0x%x\n\0");
+                found = true;
+            }
+        }
+    }
+    assert!(found);
+    let mut bss = Section::default();
+    bss.sectname[..5].copy_from_slice(b"__bss");
+    bss.size = 0x100;
+    bss.flags = S_ZEROFILL;
+    assert!(bss.is_zerofill());
+    assert!(bss.data(&DEADBEEF_MACH_64).is_err());
+    bss.flags = 0;
+    assert_eq!(bss.data(&DEADBEEF_MACH_64).unwrap(), &DEADBEEF_MACH_64[..0x100]);
+    bss.offset = DEADBEEF_MACH_64.len() as u32;
+    assert!(bss.data(&DEADBEEF_MACH_64).is_err());
+}
+
+#[test]
+fn protection() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let segment = |name| binary.segments.iter().find(|segment| segment.name().unwrap() == name).unwrap();
+    let text = segment("__TEXT").protection();
+    assert!(text.is_exec() && text.is_read() && !text.is_write());
+    assert_eq!(text.bits(), segment("__TEXT").initprot);
+    let data = segment("__DATA").protection();
+    assert!(!data.is_exec() && data.is_read() && data.is_write());
+    let max = segment("__TEXT").max_protection();
+    assert!(max.is_exec() && max.is_read() && max.is_write());
+    let sections = segment("__TEXT").sections().unwrap();
+    let text = sections.iter().find(|&&(ref section, _)| section.name().unwrap() == "__text").unwrap();
+    assert!(text.0.section_flags().is_exec());
+    let cstring = sections.iter().find(|&&(ref section, _)| section.name().unwrap() == "__cstring").unwrap();
+    assert!(!cstring.0.section_flags().is_exec());
+    assert_eq!(cstring.0.section_flags().section_type(), constants::S_CSTRING_LITERALS);
+}
+
+#[cfg(feature = "content_hash")]
+#[test]
+fn content_hash() {
+    use goblin::hash::Crc32;
+    use goblin::Object;
+    let mut bytes = DEADBEEF_MACH_64.to_vec();
+    let crc = Object::parse(&bytes).unwrap().content_hash(&bytes, Crc32::new()).unwrap().unwrap();
+    assert_eq!(Object::parse(&bytes).unwrap().content_hash(&bytes, Crc32::new()).unwrap(), Some(crc));
+    // the symbol tables and other `__LINKEDIT` contents aren't hashed
+    let linkedit = match Object::parse(&bytes).unwrap() {
+        Object::Mach(goblin::mach::Mach::Binary(macho)) => macho.segments.iter().find(|segment| segment.name().unwrap() == "__LINKEDIT").unwrap().fileoff as usize,
+        _ => unreachable!(),
+    };
+    bytes[linkedit] ^= 0xff;
+    bytes[DEADBEEF_MACH_64.len() - 1] ^= 0xff;
+    assert_eq!(Object::parse(&bytes).unwrap().content_hash(&bytes, Crc32::new()).unwrap(), Some(crc));
+    // the first byte of `__text`
+    bytes[0xf40] ^= 0xff;
+    assert_ne!(Object::parse(&bytes).unwrap().content_hash(&bytes, Crc32::new()).unwrap(), Some(crc));
+}
+
+#[test]
+fn segment_round_trip() {
+    use goblin::container::{Container, Ctx};
+    use goblin::mach::constants::VM_PROT_READ;
+    use goblin::mach::load_command::{LC_SEGMENT_64, SIZEOF_SEGMENT_COMMAND_32};
+    use goblin::mach::segment::{Section, SegmentBuilder};
+    use scroll::Pread;
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let ctx = Ctx::new(Container::Big, scroll::LE);
+    let commands = binary.load_commands.iter().filter(|command| command.command.cmd() == LC_SEGMENT_64);
+    let mut nsects = 0;
+    for (command, segment) in commands.zip(binary.segments.iter()) {
+        let original = &DEADBEEF_MACH_64[command.offset..command.offset + segment.cmdsize as usize];
+        let sections = segment.sections().unwrap();
+        let mut builder = SegmentBuilder::new(segment.name().unwrap()).unwrap()
+            .vmaddr(segment.vmaddr)
+            .vmsize(segment.vmsize)
+            .fileoff(segment.fileoff)
+            .filesize(segment.filesize)
+            .protection(segment.maxprot, segment.initprot)
+            .flags(segment.flags)
+            .sections_at(sections.first().map_or(0, |&(ref section, _)| section.addr - segment.vmaddr));
+        for (section, _) in sections {
+            // the builder lays them out again
+            builder = builder.section(Section { addr: 0, offset: 0, segname: [0; 16], .. section });
+            nsects += 1;
+        }
+        assert_eq!(builder.build(ctx).unwrap(), original);
+    }
+    assert!(nsects > 1);
+    // a 32-bit big endian segment, with each section aligned, and a zero-fill one at the end
+    let ctx = Ctx::new(Container::Little, scroll::BE);
+    let mut text = Section::default();
+    text.sectname[..6].copy_from_slice(b"__text");
+    text.size = 3;
+    text.align = 4;
+    let mut bss = Section { size: 0x20, align: 3, flags: constants::S_ZEROFILL, .. text.clone() };
+    bss.sectname = [0; 16];
+    bss.sectname[..5].copy_from_slice(b"__bss");
+    let builder = SegmentBuilder::new("__TEXT").unwrap().vmaddr(0x1000).fileoff(0x100).protection(VM_PROT_READ, VM_PROT_READ).sections_at(4).section(text).section(bss);
+    let bytes = builder.build(ctx).unwrap();
+    let segment = bytes.pread_with::<load_command::SegmentCommand32>(0, scroll::BE).unwrap();
+    assert_eq!((segment.nsects, segment.cmdsize as usize), (2, bytes.len()));
+    assert_eq!((segment.vmsize, segment.filesize), (0x38, 0x13));
+    assert_eq!(&segment.segname[..7], b"__TEXT\0");
+    let text = bytes.pread_with::<Section>(SIZEOF_SEGMENT_COMMAND_32, ctx).unwrap();
+    assert_eq!((text.name().unwrap(), text.segname().unwrap(), text.addr, text.offset), ("__text", "__TEXT", 0x1010, 0x110));
+    let bss = bytes.pread_with::<Section>(SIZEOF_SEGMENT_COMMAND_32 + 68, ctx).unwrap();
+    assert_eq!((bss.addr, bss.offset, bss.align), (0x1018, 0, 3));
+    assert!(SegmentBuilder::new("__A_VERY_LONG_NAME").is_err());
+}
+
+#[test]
+fn uuid_and_source_version() {
+    use goblin::mach::load_command::{SourceVersionCommand, Uuid, LC_SOURCE_VERSION};
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    // as `dwarfdump --uuid` prints it
+    assert_eq!(Uuid(binary.uuid().unwrap()).to_string(), "7ED20495-3323-3BF4-B68F-BF5D8C237BA8");
+    assert_eq!(binary.source_version(), Some((0, 0, 0, 0, 0)));
+    let version = (0xff_ffff << 40) | (2 << 30) | (3 << 20) | (1023 << 10) | 5;
+    let command = SourceVersionCommand { cmd: LC_SOURCE_VERSION, cmdsize: 16, version };
+    assert_eq!(command.unpacked(), (0xff_ffff, 2, 3, 1023, 5));
+}
+
+#[test]
+fn fileset() {
+    use scroll::{Pwrite, LE};
+    use goblin::container::{Container, Ctx};
+    use goblin::mach::load_command::*;
+    use goblin::mach::segment::SegmentBuilder;
+    // a kernelcache of one kext at 0x100, whose `__TEXT` offset is relative to the fileset, not to the kext
+    let entry = load_command_with_name(LC_FILESET_ENTRY, SIZEOF_FILESET_ENTRY_COMMAND, "com.example.driver");
+    let mut bytes = vec![0u8; header::SIZEOF_HEADER_64];
+    for (offset, value) in [header::MH_MAGIC_64, cputype::CPU_TYPE_ARM64, 0, header::MH_FILESET, 1, entry.len() as u32].iter().enumerate() {
+        bytes.pwrite_with(*value, offset * 4, LE).unwrap();
+    }
+    bytes.extend(entry);
+    let entry_offset = header::SIZEOF_HEADER_64;
+    bytes.pwrite_with(0xffff_fe00_0700_4000u64, entry_offset + 8, LE).unwrap();
+    bytes.pwrite_with(0x100u64, entry_offset + 16, LE).unwrap();
+    bytes.pwrite_with(SIZEOF_FILESET_ENTRY_COMMAND as u32, entry_offset + 24, LE).unwrap();
+    bytes.resize(0x100, 0);
+    let text = SegmentBuilder::new("__TEXT").unwrap().vmaddr(0xffff_fe00_0700_4000).vmsize(0x100).fileoff(0x100).filesize(0x100).build(Ctx::le(Container::Big)).unwrap();
+    let mut kext = vec![0u8; header::SIZEOF_HEADER_64];
+    for (offset, value) in [header::MH_MAGIC_64, cputype::CPU_TYPE_ARM64, 0, header::MH_KEXT_BUNDLE, 1, text.len() as u32].iter().enumerate() {
+        kext.pwrite_with(*value, offset * 4, LE).unwrap();
+    }
+    kext.extend(text);
+    kext.resize(0x100, 0);
+    bytes.extend(kext);
+    let fileset = MachO::parse(&bytes, 0).unwrap();
+    assert!(fileset.is_fileset());
+    let entries = fileset.fileset_entries().unwrap();
+    assert_eq!(entries, vec![FilesetEntry { vmaddr: 0xffff_fe00_0700_4000, fileoff: 0x100, entry_id: "com.example.driver" }]);
+    let kext = fileset.parse_fileset_entry(entries[0].fileoff).unwrap();
+    assert_eq!(kext.header.filetype, header::MH_KEXT_BUNDLE);
+    assert!(!kext.is_fileset());
+    assert_eq!(kext.segments.iter().next().unwrap().data.as_ptr(), bytes[0x100..].as_ptr());
+    assert!(fileset.parse_fileset_entry(bytes.len() as u64).is_err());
+    // an entry past the end of the fileset
+    bytes.pwrite_with(0x1000u64, entry_offset + 16, LE).unwrap();
+    assert!(MachO::parse(&bytes, 0).unwrap().fileset_entries().is_err());
+}
+
+#[test]
+fn object_relocations() {
+    use goblin::relocation::RelocKind;
+    let object = goblin::Object::parse(&DEADBEEF_MACH_64[..]).unwrap();
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let imports = binary.imports().unwrap();
+    let relocs: Vec<_> = object.relocations().unwrap().collect();
+    // only binds, which are its imports
+    assert!(!imports.is_empty());
+    assert_eq!(relocs.len(), imports.len());
+    for (reloc, import) in relocs.iter().zip(&imports) {
+        assert_eq!((reloc.offset, reloc.symbol, reloc.addend), (import.address, Some(import.name), import.addend));
+        assert_eq!(reloc.kind, if import.is_lazy { RelocKind::JumpSlot } else { RelocKind::Absolute });
+    }
+    assert!(binary.dysymtab().unwrap().external_relocations().unwrap().is_empty());
+}
+
+#[test]
+fn function_starts() {
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let starts: Vec<u64> = binary.function_starts().unwrap().map(|start| start.unwrap()).collect();
+    let main = binary.symbols().map(|sym| sym.unwrap()).find(|&(name, _)| name == "_main").unwrap().1;
+    assert_eq!(starts, vec![main.n_value]);
+}
+
+#[test]
+fn from_reader() {
+    use std::io::Cursor;
+    use goblin::io::OwnedObject;
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let owned = match goblin::Object::from_reader(Cursor::new(&DEADBEEF_MACH_64[..])).unwrap() {
+        OwnedObject::Mach(owned) => owned,
+        object => panic!("{:?} is not a Mach-o", object),
+    };
+    assert_eq!(owned.header.ncmds, binary.header.ncmds);
+    assert_eq!(owned.load_commands.len(), binary.load_commands.len());
+    assert_eq!(owned.libs, binary.libs);
+    assert!(owned.is_64 && owned.little_endian);
+    // only the x86_64 architecture
+    let mut bytes = fat(0, 0);
+    bytes[7] = 1;
+    match goblin::Object::from_reader(Cursor::new(&bytes)).unwrap() {
+        OwnedObject::MachFat(arches) => {
+            assert_eq!(arches.len(), 1);
+            assert_eq!(arches[0].0.cputype, cputype::CPU_TYPE_X86_64);
+            assert_eq!(arches[0].1.libs, binary.libs);
+        },
+        object => panic!("{:?} is not a fat Mach-o", object),
+    }
+}
+
+/// A load command naming `name`, which starts at `name_offset` in the command, padded to 8 bytes
+fn load_command_with_name(cmd: u32, name_offset: usize, name: &str) -> Vec<u8> {
+    use scroll::{Pwrite, LE};
+    let cmdsize = (name_offset + name.len() + 1 + 7) & !7;
+    let mut bytes = vec![0u8; cmdsize];
+    bytes.pwrite_with(cmd, 0, LE).unwrap();
+    bytes.pwrite_with(cmdsize as u32, 4, LE).unwrap();
+    bytes.pwrite_with(name_offset as u32, 8, LE).unwrap();
+    bytes[name_offset..name_offset + name.len()].copy_from_slice(name.as_bytes());
+    bytes
+}
+
+#[test]
+fn dylib_kinds() {
+    use scroll::{Pwrite, LE};
+    use goblin::mach::load_command::*;
+    // an umbrella framework, reexporting its sub-umbrella, as built by `ld -dylib -sub_umbrella`
+    // the name follows the 24 byte `dylib_command`
+    let dylib = |cmd, name| load_command_with_name(cmd, 24, name);
+    let commands = vec![
+        dylib(LC_ID_DYLIB, "@rpath/Umbrella.framework/Versions/A/Umbrella"),
+        load_command_with_name(LC_RPATH, SIZEOF_RPATH_COMMAND, "@loader_path/Frameworks"),
+        dylib(LC_REEXPORT_DYLIB, "@rpath/Umbrella.framework/Frameworks/Sub.framework/Versions/A/Sub"),
+        dylib(LC_LOAD_WEAK_DYLIB, "/usr/lib/libweak.dylib"),
+        dylib(LC_LOAD_DYLIB, "/usr/lib/libSystem.B.dylib"),
+        load_command_with_name(LC_RPATH, SIZEOF_RPATH_COMMAND, "@executable_path/../Frameworks"),
+    ];
+    let mut bytes = vec![0u8; header::SIZEOF_HEADER_64];
+    let sizeofcmds: usize = commands.iter().map(|command| command.len()).sum();
+    for (offset, value) in [header::MH_MAGIC_64, cputype::CPU_TYPE_X86_64, 3, header::MH_DYLIB, commands.len() as u32, sizeofcmds as u32].iter().enumerate() {
+        bytes.pwrite_with(*value, offset * 4, LE).unwrap();
+    }
+    for command in commands {
+        bytes.extend(command);
+    }
+    let binary = MachO::parse(&bytes, 0).unwrap();
+    assert_eq!(binary.name, Some("@rpath/Umbrella.framework/Versions/A/Umbrella"));
+    assert_eq!(binary.rpaths(), vec!["@loader_path/Frameworks", "@executable_path/../Frameworks"]);
+    assert_eq!(binary.libs_reexport(), vec!["@rpath/Umbrella.framework/Frameworks/Sub.framework/Versions/A/Sub"]);
+    assert_eq!(binary.libs_weak(), vec!["/usr/lib/libweak.dylib"]);
+    assert_eq!(binary.libs_required(), vec!["/usr/lib/libSystem.B.dylib"]);
+    assert!(binary.libs_upward().is_empty() && binary.libs_lazy().is_empty());
+    // the ordinal table keeps every kind, in load command order
+    assert_eq!(binary.libs.len(), 4);
+    assert_eq!(binary.libs[1..], ["@rpath/Umbrella.framework/Frameworks/Sub.framework/Versions/A/Sub", "/usr/lib/libweak.dylib", "/usr/lib/libSystem.B.dylib"]);
+}
+
+#[test]
+fn entry() {
+    use scroll::{Pwrite, LE};
+    use goblin::mach::load_command::*;
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    assert_eq!(binary.entry().unwrap(), 0x100000f40);
+    assert!(!binary.old_style_entry);
+    // a bare executable starting from a thread state: rip is the 17th register of x86-64, pc the 33rd of arm64
+    for &(cputype, flavor, count, pc) in [(cputype::CPU_TYPE_X86_64, 4, 42, 16), (cputype::CPU_TYPE_ARM64, 6, 68, 32)].iter() {
+        let cmdsize = 16 + count * 4;
+        let mut bytes = vec![0u8; header::SIZEOF_HEADER_64 + cmdsize];
+        for (offset, value) in [header::MH_MAGIC_64, cputype, 3, header::MH_EXECUTE, 1, cmdsize as u32].iter().enumerate() {
+            bytes.pwrite_with(*value, offset * 4, LE).unwrap();
+        }
+        for (offset, value) in [LC_UNIXTHREAD, cmdsize as u32, flavor, count as u32].iter().enumerate() {
+            bytes.pwrite_with(*value, header::SIZEOF_HEADER_64 + offset * 4, LE).unwrap();
+        }
+        bytes.pwrite_with(0x1_0000_1234u64, header::SIZEOF_HEADER_64 + 16 + pc * 8, LE).unwrap();
+        let binary = MachO::parse(&bytes, 0).unwrap();
+        assert_eq!(binary.entry().unwrap(), 0x1_0000_1234);
+        assert!(binary.old_style_entry);
+        // without the command, there is no entry point
+        bytes.pwrite_with(0u32, 16, LE).unwrap();
+        assert!(MachO::parse(&bytes, 0).unwrap().entry().is_err());
+    }
+}
+
+#[test]
+fn lenient_parse() {
+    use scroll::{Pwrite, LE};
+    use goblin::mach::load_command::*;
+    use goblin::options::ParseOptions;
+    // a dylib whose name lies past the end of the file, and an `LC_MAIN` without a `__TEXT` segment to locate it
+    let mut broken = load_command_with_name(LC_LOAD_DYLIB, 24, "/usr/lib/libbroken.dylib");
+    broken.pwrite_with(0xffffu32, 8, LE).unwrap();
+    let mut main = vec![0u8; 24];
+    for (offset, value) in [LC_MAIN, 24, 0x1000].iter().enumerate() {
+        main.pwrite_with(*value, offset * 4, LE).unwrap();
+    }
+    let commands = vec![broken, load_command_with_name(LC_LOAD_DYLIB, 24, "/usr/lib/libSystem.B.dylib"), main];
+    let mut bytes = vec![0u8; header::SIZEOF_HEADER_64];
+    let sizeofcmds: usize = commands.iter().map(|command| command.len()).sum();
+    for (offset, value) in [header::MH_MAGIC_64, cputype::CPU_TYPE_X86_64, 3, header::MH_EXECUTE, commands.len() as u32, sizeofcmds as u32].iter().enumerate() {
+        bytes.pwrite_with(*value, offset * 4, LE).unwrap();
+    }
+    for command in commands {
+        bytes.extend(command);
+    }
+    assert!(MachO::parse(&bytes, 0).is_err());
+    assert!(Mach::parse(&bytes).is_err());
+    let lenient = ParseOptions { fail_fast: false, .. ParseOptions::default() };
+    let binary = MachO::parse_with(&bytes, 0, lenient).unwrap();
+    assert_eq!(binary.errors.iter().map(|&(index, _)| index).collect::<Vec<_>>(), vec![0, 2]);
+    // the ordinals of the other dylibs are unchanged
+    assert_eq!(binary.libs, vec!["self", "", "/usr/lib/libSystem.B.dylib"]);
+    assert_eq!(binary.entry, 0);
+    match Mach::parse_with(&bytes, lenient).unwrap() {
+        Mach::Binary(binary) => assert_eq!(binary.errors.len(), 2),
+        Mach::Fat(_) => panic!("not a fat binary"),
+    }
+}
+
+#[test]
+fn dyld_shared_cache_linkedit() {
+    use scroll::{Pread, Pwrite, LE};
+    use goblin::mach::load_command::CommandVariant;
+    use goblin::options::ParseOptions;
+    // move the linkedit offsets of deadbeef to where its `__LINKEDIT` would be in a cache, as an extracted image keeps them
+    let binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    let (linkedit, _) = binary.segments.iter().enumerate().find(|&(_, segment)| segment.name().unwrap() == "__LINKEDIT").unwrap();
+    let base = binary.segments[linkedit].fileoff;
+    let mut bytes = DEADBEEF_MACH_64.to_vec();
+    for cmd in &binary.load_commands {
+        let mut command: CommandVariant = DEADBEEF_MACH_64.pread_with(cmd.offset, LE).unwrap();
+        command.rebase_linkedit(base, 0x1234_5000).unwrap();
+        match command {
+            CommandVariant::Segment64(command) => bytes.pwrite_with(command, cmd.offset, LE),
+            CommandVariant::Symtab(command) => bytes.pwrite_with(command, cmd.offset, LE),
+            CommandVariant::Dysymtab(command) => bytes.pwrite_with(command, cmd.offset, LE),
+            CommandVariant::DyldInfoOnly(command) => bytes.pwrite_with(command, cmd.offset, LE),
+            CommandVariant::FunctionStarts(command) | CommandVariant::DataInCode(command) | CommandVariant::DylibCodeSignDrs(command) => bytes.pwrite_with(command, cmd.offset, LE),
+            _ => Ok(0),
+        }.unwrap();
+    }
+    match MachO::parse(&bytes, 0) {
+        Err(goblin::error::Error::Malformed(message)) => assert!(message.contains("linkedit_base"), "{}", message),
+        result => panic!("{:?} is not malformed", result.map(|binary| binary.entry)),
+    }
+    let opts = ParseOptions { linkedit_base: Some(base), .. ParseOptions::default() };
+    let extracted = MachO::parse_with(&bytes, 0, opts).unwrap();
+    let summary = |binary: &MachO| binary.imports().unwrap().iter().map(|import| (import.name.to_string(), import.offset)).collect::<Vec<_>>();
+    assert_eq!(summary(&extracted), summary(&binary));
+    assert_eq!(extracted.exports().unwrap().len(), binary.exports().unwrap().len());
+    assert_eq!(extracted.symbols().map(|symbol| symbol.unwrap().0).collect::<Vec<_>>(), binary.symbols().map(|symbol| symbol.unwrap().0).collect::<Vec<_>>());
+    assert_eq!(extracted.segments[linkedit].fileoff, base);
+    // without a `__LINKEDIT` segment, the option can't be applied
+    assert!(MachO::parse_with(&DEADBEEF_MACH_64[..0x20], 0, opts).is_err());
+}
+
+#[test]
+fn sorted_symbols() {
+    let mut binary = MachO::parse(&DEADBEEF_MACH_64[..], 0).unwrap();
+    // the header is sized up to the function start of _main, and _main up to the end of __TEXT
+    let expected = vec![("__mh_execute_header".into(), 0x100000000, 0xf40), ("_main".into(), 0x100000f40, 0xc0)];
+    assert_eq!(binary.sorted_symbols().unwrap(), expected);
+    binary.build_address_index().unwrap();
+    assert_eq!(binary.sorted_symbols().unwrap(), expected);
+    assert_eq!(binary.symbolicate(0x100000fff).unwrap(), Some(("_main".to_string(), 0xbf)));
+    assert_eq!(binary.symbolicate(0x100001000).unwrap(), None);
+}